@@ -1,8 +1,14 @@
+DROP TABLE IF EXISTS archived_submissions CASCADE;
+DROP TABLE IF EXISTS archived_games CASCADE;
+DROP TABLE IF EXISTS game_end_summaries CASCADE;
+DROP TABLE IF EXISTS game_exercises CASCADE;
+DROP TABLE IF EXISTS exercise_tags CASCADE;
 DROP TABLE IF EXISTS group_ownership CASCADE;
 DROP TABLE IF EXISTS course_ownership CASCADE;
 DROP TABLE IF EXISTS game_ownership CASCADE;
 DROP TABLE IF EXISTS player_rewards CASCADE;
 DROP TABLE IF EXISTS player_unlocks CASCADE;
+DROP TABLE IF EXISTS game_checkpoints CASCADE;
 DROP TABLE IF EXISTS player_registrations CASCADE;
 DROP TABLE IF EXISTS player_groups CASCADE;
 DROP TABLE IF EXISTS submissions CASCADE;
@@ -40,6 +46,9 @@ CREATE TABLE games (
     module_lock DOUBLE PRECISION NOT NULL DEFAULT 0.0,
     exercise_lock BOOLEAN NOT NULL DEFAULT FALSE,
     total_exercises INTEGER NOT NULL DEFAULT 0,
+    success_threshold DOUBLE PRECISION NULL,
+    max_attempts_per_exercise INTEGER NULL,
+    min_meaningful_result DOUBLE PRECISION NULL,
     start_date TIMESTAMPTZ NOT NULL,
     end_date TIMESTAMPTZ NOT NULL,
     created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
@@ -55,7 +64,8 @@ CREATE TABLE modules (
     language VARCHAR(10) NOT NULL,
     start_date TIMESTAMPTZ NOT NULL,
     end_date TIMESTAMPTZ NOT NULL,
-    CONSTRAINT fk_modules_course FOREIGN KEY (course_id) REFERENCES courses (id) ON DELETE CASCADE
+    CONSTRAINT fk_modules_course FOREIGN KEY (course_id) REFERENCES courses (id) ON DELETE CASCADE,
+    CONSTRAINT uq_modules_course_order UNIQUE (course_id, language, "order") DEFERRABLE INITIALLY DEFERRED
 );
 CREATE TABLE exercises (
     id BIGSERIAL PRIMARY KEY,
@@ -78,7 +88,8 @@ CREATE TABLE exercises (
     difficulty VARCHAR(50) NOT NULL,
     created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-    CONSTRAINT fk_exercises_module FOREIGN KEY (module_id) REFERENCES modules (id) ON DELETE CASCADE
+    CONSTRAINT fk_exercises_module FOREIGN KEY (module_id) REFERENCES modules (id) ON DELETE CASCADE,
+    CONSTRAINT uq_exercises_module_order UNIQUE (module_id, language, programming_language, "order") DEFERRABLE INITIALLY DEFERRED
 );
 CREATE TABLE players (
     id BIGSERIAL PRIMARY KEY,
@@ -88,12 +99,15 @@ CREATE TABLE players (
     points INTEGER NOT NULL DEFAULT 0,
     created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
     last_active TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
-    disabled BOOLEAN NOT NULL DEFAULT FALSE
+    disabled BOOLEAN NOT NULL DEFAULT FALSE,
+    deleted_at TIMESTAMPTZ NULL
 );
 CREATE TABLE groups (
     id BIGSERIAL PRIMARY KEY,
     display_name VARCHAR(100) NOT NULL,
-    display_avatar TEXT
+    display_avatar TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
 );
 CREATE TABLE instructors (
     id BIGSERIAL PRIMARY KEY,
@@ -119,6 +133,7 @@ CREATE TABLE invites (
     instructor_id BIGINT NOT NULL,
     game_id BIGINT NULL,
     group_id BIGINT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
     CONSTRAINT fk_invites_instructor FOREIGN KEY (instructor_id) REFERENCES instructors (id) ON DELETE CASCADE,
     CONSTRAINT fk_invites_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE SET NULL,
     CONSTRAINT fk_invites_group FOREIGN KEY (group_id) REFERENCES groups (id) ON DELETE SET NULL
@@ -138,6 +153,7 @@ CREATE TABLE submissions (
     earned_rewards JSONB NOT NULL,
     entered_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
     submitted_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    time_spent_seconds INTEGER NULL CHECK (time_spent_seconds >= 0),
     CONSTRAINT fk_submissions_exercise FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE,
     CONSTRAINT fk_submissions_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE CASCADE,
     CONSTRAINT fk_submissions_player FOREIGN KEY (player_id) REFERENCES players (id) ON DELETE CASCADE
@@ -165,6 +181,22 @@ CREATE TABLE player_registrations (
     CONSTRAINT fk_playerregistrations_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE CASCADE,
     UNIQUE (player_id, game_id)
 );
+CREATE TABLE game_checkpoints (
+    id BIGSERIAL PRIMARY KEY,
+    player_registrations_id BIGINT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    game_state JSONB NOT NULL,
+    saved_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_gamecheckpoints_registration FOREIGN KEY (player_registrations_id) REFERENCES player_registrations (id) ON DELETE CASCADE,
+    UNIQUE (player_registrations_id, name)
+);
+CREATE TABLE exercise_tags (
+    exercise_id BIGINT NOT NULL,
+    tag VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (exercise_id, tag),
+    CONSTRAINT fk_exercisetags_exercise FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE
+);
 CREATE TABLE player_unlocks (
     player_id BIGINT NOT NULL,
     exercise_id BIGINT NOT NULL,
@@ -185,7 +217,7 @@ CREATE TABLE player_rewards (
     CONSTRAINT fk_playerrewards_player FOREIGN KEY (player_id) REFERENCES players (id) ON DELETE CASCADE,
     CONSTRAINT fk_playerrewards_reward FOREIGN KEY (reward_id) REFERENCES rewards (id) ON DELETE CASCADE,
     CONSTRAINT fk_playerrewards_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE SET NULL,
-    CONSTRAINT uq_player_reward_game UNIQUE (player_id, reward_id, game_id, obtained_at)
+    CONSTRAINT uq_player_reward_game UNIQUE (player_id, reward_id, game_id)
 );
 CREATE TABLE game_ownership (
     game_id BIGINT NOT NULL,
@@ -195,6 +227,13 @@ CREATE TABLE game_ownership (
     CONSTRAINT fk_gameownership_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE CASCADE,
     CONSTRAINT fk_gameownership_instructor FOREIGN KEY (instructor_id) REFERENCES instructors (id) ON DELETE CASCADE
 );
+CREATE TABLE game_exercises (
+    game_id BIGINT NOT NULL,
+    exercise_id BIGINT NOT NULL,
+    PRIMARY KEY (game_id, exercise_id),
+    CONSTRAINT fk_gameexercises_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE CASCADE,
+    CONSTRAINT fk_gameexercises_exercise FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE
+);
 CREATE TABLE course_ownership (
     course_id BIGINT NOT NULL,
     instructor_id BIGINT NOT NULL,
@@ -211,6 +250,32 @@ CREATE TABLE group_ownership (
     CONSTRAINT fk_groupownership_group FOREIGN KEY (group_id) REFERENCES groups (id) ON DELETE CASCADE,
     CONSTRAINT fk_groupownership_instructor FOREIGN KEY (instructor_id) REFERENCES instructors (id) ON DELETE CASCADE
 );
+CREATE TABLE game_end_summaries (
+    id BIGSERIAL PRIMARY KEY,
+    game_id BIGINT NOT NULL UNIQUE,
+    player_count INTEGER NOT NULL,
+    average_progress DOUBLE PRECISION NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_gameendsummaries_game FOREIGN KEY (game_id) REFERENCES games (id) ON DELETE CASCADE
+);
+CREATE TABLE archived_games (
+    id BIGSERIAL PRIMARY KEY,
+    game_id BIGINT NOT NULL,
+    course_id BIGINT NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    snapshot JSONB NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE TABLE archived_submissions (
+    id BIGSERIAL PRIMARY KEY,
+    submission_id BIGINT NOT NULL,
+    game_id BIGINT NOT NULL,
+    player_id BIGINT NOT NULL,
+    exercise_id BIGINT NOT NULL,
+    entered_at TIMESTAMPTZ NOT NULL,
+    snapshot JSONB NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
 
 CREATE INDEX idx_games_course_id ON games (course_id);
 CREATE INDEX idx_modules_course_id ON modules (course_id);
@@ -221,10 +286,13 @@ CREATE INDEX idx_submissions_player_id ON submissions (player_id);
 CREATE INDEX idx_player_groups_group_id ON player_groups (group_id);
 CREATE INDEX idx_player_registrations_player_id ON player_registrations (player_id);
 CREATE INDEX idx_player_registrations_game_id ON player_registrations (game_id);
+CREATE INDEX idx_exercise_tags_tag ON exercise_tags (tag);
 CREATE INDEX idx_player_unlocks_exercise_id ON player_unlocks (exercise_id);
 CREATE INDEX idx_player_rewards_reward_id ON player_rewards (reward_id);
+CREATE UNIQUE INDEX uq_players_email_lower ON players (LOWER(email));
 CREATE INDEX idx_player_rewards_game_id ON player_rewards (game_id);
 CREATE INDEX idx_game_ownership_instructor_id ON game_ownership (instructor_id);
+CREATE INDEX idx_game_exercises_exercise_id ON game_exercises (exercise_id);
 CREATE INDEX idx_course_ownership_instructor_id ON course_ownership (instructor_id);
 CREATE INDEX idx_group_ownership_instructor_id ON group_ownership (instructor_id);
 CREATE INDEX idx_rewards_course_id ON rewards (course_id);
@@ -232,6 +300,7 @@ CREATE INDEX idx_invites_uuid ON invites (uuid);
 CREATE INDEX idx_invites_instructor_id ON invites (instructor_id);
 CREATE INDEX idx_invites_game_id ON invites (game_id);
 CREATE INDEX idx_invites_group_id ON invites (group_id);
+CREATE INDEX idx_archived_games_course_id ON archived_games (course_id);
 CREATE INDEX idx_exercises_mode_parameters_gin ON exercises USING GIN (mode_parameters);
 CREATE INDEX idx_submissions_metrics_gin ON submissions USING GIN (metrics);
 CREATE INDEX idx_submissions_result_description_gin ON submissions USING GIN (result_description);
@@ -1,5 +1,6 @@
 mod helper;
 
 pub(crate) mod editor;
+pub(crate) mod identity;
 pub(crate) mod student;
 pub(crate) mod teacher;
@@ -1,26 +1,47 @@
+use crate::auth::ActingInstructor;
 use crate::errors::AppError;
 use crate::model::editor::{
-    CourseQueryResult, ExerciseQueryResult, ExportCourseResponse, ExportExerciseResponse,
-    ExportModuleResponse, ModuleQueryResult, NewCourse, NewCourseOwnership, NewExercise, NewModule,
+    CourseEnrollmentSummary, CourseExerciseDifficulty, CourseQueryResult, CourseTreeResponse,
+    ExerciseGameSummary, ExerciseMode, ExerciseQueryResult, ExerciseTreeNode, ExportCourseResponse,
+    ExportExerciseResponse, ExportModuleResponse, GameEnrollment, GameExerciseCount,
+    ImportDryRunSummary, ModuleQueryResult, ModuleTreeNode, NewArchivedGame, NewCourse,
+    NewCourseOwnership, NewExercise, NewExerciseTag, NewModule,
+};
+use crate::payloads::editor::{
+    DeleteCoursePayload, ExerciseTagPayload, ExportCourseParams, ExportModuleParams,
+    GetCourseEnrollmentSummaryParams, GetCourseExerciseDifficultyParams, GetCourseTreeParams,
+    GetExerciseGamesParams, ImportCourseData, ImportCoursePayload, ImportModuleData,
+    ImportModulePayload, RecalculateCourseGamesPayload, RenumberCoursePayload,
+    ReorderExercisesPayload, ReorderModulesPayload,
 };
-use crate::payloads::editor::{ExportCourseParams, ImportCoursePayload};
 use crate::response::ApiResponse;
 use crate::schema::{
-    course_ownership::dsl as course_owner_dsl, courses::dsl as courses_dsl,
-    exercises::dsl as exercises_dsl, instructors::dsl as instructors_dsl,
-    modules::dsl as modules_dsl,
+    archived_games::dsl as archived_games_dsl, course_ownership::dsl as course_owner_dsl,
+    courses::dsl as courses_dsl, exercise_tags::dsl as exercise_tags_dsl,
+    exercises::dsl as exercises_dsl, game_exercises::dsl as ge_dsl, games::dsl as games_dsl,
+    instructors::dsl as instructors_dsl, modules::dsl as modules_dsl,
+    player_registrations::dsl as pr_dsl, rewards::dsl as rewards_dsl, submissions::dsl as sub_dsl,
 };
-use axum::Json;
+use crate::webhook::{GAME_ARCHIVED, Webhook};
 use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
 use bigdecimal::{BigDecimal, FromPrimitive};
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use deadpool_diesel::postgres::Pool;
-use diesel::dsl::exists;
-use diesel::result::Error as DieselError;
-use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
-use std::collections::HashMap;
+use diesel::dsl::{count_distinct, count_star, exists, sql};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::sql_types::{BigInt, Jsonb};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl, QueryResult,
+    RunQueryDsl,
+};
+use serde_json::Value as JsonValue;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::instrument;
-use tracing::log::{debug, error, info};
+use tracing::log::{debug, error, info, warn};
 
 /// Imports a complete course structure from JSON data.
 ///
@@ -28,20 +49,25 @@ use tracing::log::{debug, error, info};
 /// Assigns ownership of the new course to the requesting instructor.
 /// Requires the requesting instructor to exist.
 /// Performs all database operations within a single transaction.
+/// With `dry_run` set, the same transaction is run and then rolled back, and a summary is returned instead.
 ///
 /// Request Body: `ImportCoursePayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
 /// * `bool`: true if the course was successfully imported (200 OK).
+/// * `ImportDryRunSummary`: What would have been created, plus warnings, when `dry_run` is set (200 OK).
 /// * `404 Not Found`: If the requesting instructor specified in the payload does not exist.
+/// * `409 Conflict`: If two modules of the course, or two exercises of a module, share the same `order` and language.
+/// * `422 Unprocessable Entity`: If an exercise has an unknown `mode` or `mode_parameters` of the wrong shape.
 /// * `500 Internal Server Error`: If a database error (pool, interaction, query) or transaction failure occurs.
 #[instrument(skip(pool, payload))]
 pub async fn import_course(
     State(pool): State<Pool>,
     Json(payload): Json<ImportCoursePayload>,
-) -> Result<ApiResponse<bool>, AppError> {
+) -> Result<Response, AppError> {
     let instructor_id = payload.instructor_id;
     let course_title = payload.course_data.title.clone();
+    let dry_run = payload.dry_run;
 
     info!(
         "Attempting to import course '{}' requested by instructor {}",
@@ -72,107 +98,218 @@ pub async fn import_course(
         instructor_id
     );
 
-    let conn = pool.get().await?;
-    let import_result = conn
-        .interact(move |conn_sync| {
-            conn_sync.transaction(|tx_conn| {
-                let course_data = payload.course_data;
-                let new_course = NewCourse {
-                    title: course_data.title,
-                    description: course_data.description,
-                    languages: course_data.languages,
-                    programming_languages: course_data.programming_languages,
-                    gamification_rule_conditions: course_data.gamification_rule_conditions,
-                    gamification_complex_rules: course_data.gamification_complex_rules,
-                    gamification_rule_results: course_data.gamification_rule_results,
-                    public: payload.public,
-                };
-                let new_course_id = diesel::insert_into(courses_dsl::courses)
-                    .values(&new_course)
-                    .returning(courses_dsl::id)
-                    .get_result::<i64>(tx_conn)?;
-                info!("Inserted course with ID: {}", new_course_id);
-
-                let new_ownership = NewCourseOwnership {
-                    course_id: new_course_id,
-                    instructor_id: payload.instructor_id,
-                    owner: true,
-                };
-                diesel::insert_into(course_owner_dsl::course_ownership)
-                    .values(&new_ownership)
-                    .execute(tx_conn)?;
-                info!(
-                    "Inserted course ownership for instructor {}",
-                    payload.instructor_id
-                );
-
-                let now = Utc::now();
-                let default_end_date = now + Duration::days(365);
-
-                for module_data in course_data.modules {
-                    let new_module = NewModule {
-                        course_id: new_course_id,
-                        order: module_data.order,
-                        title: module_data.title,
-                        description: module_data.description,
-                        language: module_data.language,
-                        start_date: module_data.start_date.unwrap_or(now),
-                        end_date: module_data.end_date.unwrap_or(default_end_date),
-                    };
-                    let new_module_id = diesel::insert_into(modules_dsl::modules)
-                        .values(&new_module)
-                        .returning(modules_dsl::id)
-                        .get_result::<i64>(tx_conn)?;
-                    info!(
-                        "Inserted module '{}' with ID: {}",
-                        new_module.title, new_module_id
-                    );
+    for module_data in &payload.course_data.modules {
+        validate_import_module(module_data)?;
+    }
+    validate_unique_orders(
+        payload
+            .course_data
+            .modules
+            .iter()
+            .map(|m| (m.language.as_str(), m.order)),
+        "module",
+        "the course",
+    )?;
 
-                    for exercise_data in module_data.exercises {
-                        let new_exercise = NewExercise {
-                            version: BigDecimal::from_f64(1.0)
-                                .unwrap_or_else(|| BigDecimal::from(1)),
-                            module_id: new_module_id,
-                            order: exercise_data.order,
-                            title: exercise_data.title,
-                            description: exercise_data.description,
-                            language: exercise_data.language,
-                            programming_language: exercise_data.programming_language,
-                            init_code: exercise_data.init_code,
-                            pre_code: exercise_data.pre_code,
-                            post_code: exercise_data.post_code,
-                            test_code: exercise_data.test_code,
-                            check_source: exercise_data.check_source,
-                            hidden: exercise_data.hidden,
-                            locked: exercise_data.locked,
-                            mode: exercise_data.mode,
-                            mode_parameters: exercise_data.mode_parameters,
-                            difficulty: exercise_data.difficulty,
-                        };
-                        diesel::insert_into(exercises_dsl::exercises)
-                            .values(&new_exercise)
-                            .execute(tx_conn)?;
-                    }
-                    info!("Inserted exercises for module ID {}", new_module_id);
-                }
-                Ok::<(), DieselError>(())
-            })
-        })
-        .await?;
+    let mut summary = ImportDryRunSummary {
+        course_title: course_title.clone(),
+        module_count: 0,
+        exercise_count: 0,
+        warnings: import_warnings(&payload.course_data),
+    };
+    let import_result = super::helper::interact(&pool, move |conn_sync| {
+        let result = conn_sync.transaction(|tx_conn| {
+            let course_data = payload.course_data;
+            let new_course = NewCourse {
+                title: course_data.title,
+                description: course_data.description,
+                languages: course_data.languages,
+                programming_languages: course_data.programming_languages,
+                gamification_rule_conditions: course_data.gamification_rule_conditions,
+                gamification_complex_rules: course_data.gamification_complex_rules,
+                gamification_rule_results: course_data.gamification_rule_results,
+                public: payload.public,
+            };
+            let new_course_id = diesel::insert_into(courses_dsl::courses)
+                .values(&new_course)
+                .returning(courses_dsl::id)
+                .get_result::<i64>(tx_conn)?;
+            info!("Inserted course with ID: {}", new_course_id);
+
+            let new_ownership = NewCourseOwnership {
+                course_id: new_course_id,
+                instructor_id: payload.instructor_id,
+                owner: true,
+            };
+            diesel::insert_into(course_owner_dsl::course_ownership)
+                .values(&new_ownership)
+                .execute(tx_conn)?;
+            info!(
+                "Inserted course ownership for instructor {}",
+                payload.instructor_id
+            );
+
+            for module_data in course_data.modules {
+                let order = module_data.order;
+                let (_, exercise_count) =
+                    insert_module(tx_conn, new_course_id, order, module_data)?;
+                summary.module_count += 1;
+                summary.exercise_count += exercise_count;
+            }
+
+            if dry_run {
+                // Check the deferred constraints now, as the commit would, before rolling back.
+                diesel::sql_query("SET CONSTRAINTS ALL IMMEDIATE").execute(tx_conn)?;
+                return Err(DieselError::RollbackTransaction);
+            }
+            Ok::<(), DieselError>(())
+        });
+        match result {
+            Err(DieselError::RollbackTransaction) if dry_run => Ok(Some(summary)),
+            other => other.map(|()| None),
+        }
+    })
+    .await?;
 
     match import_result {
-        Ok(()) => {
+        Ok(Some(summary)) => {
+            info!(
+                "Dry run of course import '{}' for instructor {} rolled back ({} modules, {} exercises)",
+                course_title, instructor_id, summary.module_count, summary.exercise_count
+            );
+            Ok(ApiResponse::ok(summary).into_response())
+        }
+        Ok(None) => {
             info!(
                 "Successfully imported course '{}' for instructor {}",
                 course_title, instructor_id
             );
-            Ok(ApiResponse::ok(true))
+            Ok(ApiResponse::ok(true).into_response())
         }
         Err(diesel_err) => {
             error!("Course import transaction failed: {:?}", diesel_err);
-            Err(AppError::from(diesel_err))
+            Err(map_order_conflict(diesel_err))
+        }
+    }
+}
+
+/// Inserts a module and its exercises into a course at the given order.
+/// Missing module dates default to now and one year from now.
+/// Returns the ID of the new module and the number of inserted exercises.
+fn insert_module(
+    tx_conn: &mut PgConnection,
+    course_id: i64,
+    order: i32,
+    module_data: ImportModuleData,
+) -> QueryResult<(i64, i64)> {
+    let now = Utc::now();
+    let new_module = NewModule {
+        course_id,
+        order,
+        title: module_data.title,
+        description: module_data.description,
+        language: module_data.language,
+        start_date: module_data.start_date.unwrap_or(now),
+        end_date: module_data.end_date.unwrap_or(now + Duration::days(365)),
+    };
+    let new_module_id = diesel::insert_into(modules_dsl::modules)
+        .values(&new_module)
+        .returning(modules_dsl::id)
+        .get_result::<i64>(tx_conn)?;
+    info!(
+        "Inserted module '{}' with ID: {}",
+        new_module.title, new_module_id
+    );
+
+    let mut exercise_count = 0;
+    for exercise_data in module_data.exercises {
+        let new_exercise = NewExercise {
+            version: BigDecimal::from_f64(1.0).unwrap_or_else(|| BigDecimal::from(1)),
+            module_id: new_module_id,
+            order: exercise_data.order,
+            title: exercise_data.title,
+            description: exercise_data.description,
+            language: exercise_data.language,
+            programming_language: exercise_data.programming_language,
+            init_code: exercise_data.init_code,
+            pre_code: exercise_data.pre_code,
+            post_code: exercise_data.post_code,
+            test_code: exercise_data.test_code,
+            check_source: exercise_data.check_source,
+            hidden: exercise_data.hidden,
+            locked: exercise_data.locked,
+            mode: exercise_data.mode,
+            mode_parameters: exercise_data.mode_parameters,
+            difficulty: exercise_data.difficulty,
+        };
+        exercise_count += diesel::insert_into(exercises_dsl::exercises)
+            .values(&new_exercise)
+            .execute(tx_conn)? as i64;
+    }
+    info!("Inserted exercises for module ID {}", new_module_id);
+    Ok((new_module_id, exercise_count))
+}
+
+/// Validates the exercises of an imported module: known modes and unique orders per language variant.
+fn validate_import_module(module_data: &ImportModuleData) -> Result<(), AppError> {
+    for exercise_data in &module_data.exercises {
+        validate_exercise_mode(
+            &exercise_data.title,
+            &exercise_data.mode,
+            &exercise_data.mode_parameters,
+        )?;
+    }
+    validate_unique_orders(
+        module_data.exercises.iter().map(|e| {
+            (
+                format!("{}/{}", e.language, e.programming_language),
+                e.order,
+            )
+        }),
+        "exercise",
+        &format!("module '{}'", module_data.title),
+    )
+}
+
+/// Lists non-fatal problems in an import: modules without exercises, and modules or exercises
+/// whose language or programming language is not listed by the course.
+fn import_warnings(course_data: &ImportCourseData) -> Vec<String> {
+    let listed = |list: &str| -> HashSet<String> {
+        list.split(',')
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    };
+    let languages = listed(&course_data.languages);
+    let programming_languages = listed(&course_data.programming_languages);
+
+    let mut warnings = Vec::new();
+    for module_data in &course_data.modules {
+        if module_data.exercises.is_empty() {
+            warnings.push(format!("Module '{}' has no exercises.", module_data.title));
+        }
+        if !languages.contains(&module_data.language) {
+            warnings.push(format!(
+                "Module '{}' uses language '{}', which the course does not list.",
+                module_data.title, module_data.language
+            ));
+        }
+        for exercise_data in &module_data.exercises {
+            if !languages.contains(&exercise_data.language) {
+                warnings.push(format!(
+                    "Exercise '{}' uses language '{}', which the course does not list.",
+                    exercise_data.title, exercise_data.language
+                ));
+            }
+            if !programming_languages.contains(&exercise_data.programming_language) {
+                warnings.push(format!(
+                    "Exercise '{}' uses programming language '{}', which the course does not list.",
+                    exercise_data.title, exercise_data.programming_language
+                ));
+            }
         }
     }
+    warnings
 }
 
 /// Exports the full structure of a course (details, modules, exercises) as JSON.
@@ -245,34 +382,8 @@ pub async fn export_course(
 
     let module_ids: Vec<i64> = modules_db.iter().map(|m| m.id).collect();
     let exercises_db = if !module_ids.is_empty() {
-        super::helper::run_query(&pool, {
-            move |conn| {
-                exercises_dsl::exercises
-                    .filter(exercises_dsl::module_id.eq_any(&module_ids))
-                    .select((
-                        exercises_dsl::id,
-                        exercises_dsl::module_id,
-                        exercises_dsl::order,
-                        exercises_dsl::title,
-                        exercises_dsl::description,
-                        exercises_dsl::language,
-                        exercises_dsl::programming_language,
-                        exercises_dsl::init_code,
-                        exercises_dsl::pre_code,
-                        exercises_dsl::post_code,
-                        exercises_dsl::test_code,
-                        exercises_dsl::check_source,
-                        exercises_dsl::hidden,
-                        exercises_dsl::locked,
-                        exercises_dsl::mode,
-                        exercises_dsl::mode_parameters,
-                        exercises_dsl::difficulty,
-                    ))
-                    .order_by((exercises_dsl::module_id, exercises_dsl::order.asc()))
-                    .load::<ExerciseQueryResult>(conn)
-            }
-        })
-        .await?
+        super::helper::run_query(&pool, move |conn| load_export_exercises(conn, &module_ids))
+            .await?
     } else {
         Vec::new()
     };
@@ -284,27 +395,10 @@ pub async fn export_course(
 
     let mut exercises_by_module: HashMap<i64, Vec<ExportExerciseResponse>> = HashMap::new();
     for ex_query_res in exercises_db {
-        let ex_response = ExportExerciseResponse {
-            order: ex_query_res.order,
-            title: ex_query_res.title,
-            description: ex_query_res.description,
-            language: ex_query_res.language,
-            programming_language: ex_query_res.programming_language,
-            init_code: ex_query_res.init_code,
-            pre_code: ex_query_res.pre_code,
-            post_code: ex_query_res.post_code,
-            test_code: ex_query_res.test_code,
-            check_source: ex_query_res.check_source,
-            hidden: ex_query_res.hidden,
-            locked: ex_query_res.locked,
-            mode: ex_query_res.mode,
-            mode_parameters: ex_query_res.mode_parameters,
-            difficulty: ex_query_res.difficulty,
-        };
         exercises_by_module
             .entry(ex_query_res.module_id)
             .or_default()
-            .push(ex_response);
+            .push(ExportExerciseResponse::from(ex_query_res));
     }
 
     let assembled_modules: Vec<ExportModuleResponse> = modules_db
@@ -313,15 +407,7 @@ pub async fn export_course(
             let exercises = exercises_by_module
                 .remove(&mod_query_res.id)
                 .unwrap_or_default();
-            ExportModuleResponse {
-                order: mod_query_res.order,
-                title: mod_query_res.title,
-                description: mod_query_res.description,
-                language: mod_query_res.language,
-                start_date: mod_query_res.start_date,
-                end_date: mod_query_res.end_date,
-                exercises,
-            }
+            export_module_response(mod_query_res, exercises)
         })
         .collect();
 
@@ -339,3 +425,1371 @@ pub async fn export_course(
     info!("Successfully prepared export data for course {}", course_id);
     Ok(ApiResponse::ok(final_response))
 }
+
+/// Loads the exercises of the given modules in export order (by module, then exercise order).
+fn load_export_exercises(
+    conn: &mut PgConnection,
+    module_ids: &[i64],
+) -> QueryResult<Vec<ExerciseQueryResult>> {
+    exercises_dsl::exercises
+        .filter(exercises_dsl::module_id.eq_any(module_ids))
+        .select((
+            exercises_dsl::id,
+            exercises_dsl::module_id,
+            exercises_dsl::order,
+            exercises_dsl::title,
+            exercises_dsl::description,
+            exercises_dsl::language,
+            exercises_dsl::programming_language,
+            exercises_dsl::init_code,
+            exercises_dsl::pre_code,
+            exercises_dsl::post_code,
+            exercises_dsl::test_code,
+            exercises_dsl::check_source,
+            exercises_dsl::hidden,
+            exercises_dsl::locked,
+            exercises_dsl::mode,
+            exercises_dsl::mode_parameters,
+            exercises_dsl::difficulty,
+        ))
+        .order_by((exercises_dsl::module_id, exercises_dsl::order.asc()))
+        .load::<ExerciseQueryResult>(conn)
+}
+
+fn export_module_response(
+    module: ModuleQueryResult,
+    exercises: Vec<ExportExerciseResponse>,
+) -> ExportModuleResponse {
+    ExportModuleResponse {
+        order: module.order,
+        title: module.title,
+        description: module.description,
+        language: module.language,
+        start_date: module.start_date,
+        end_date: module.end_date,
+        exercises,
+    }
+}
+
+/// Exports a single module and its exercises as a standalone bundle, in the module format of `export_course`.
+///
+/// Requires the requesting instructor to be an owner of the module's course or an admin (ID 0).
+/// The bundle can be attached to another course with `import_module`.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor requesting the export.
+/// * `module_id`: The ID of the module to export.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `ExportModuleResponse`: The module with its exercises (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the module's course.
+/// * `404 Not Found`: If the module does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn export_module(
+    State(pool): State<Pool>,
+    Query(params): Query<ExportModuleParams>,
+) -> Result<ApiResponse<ExportModuleResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let module_id = params.module_id;
+
+    info!(
+        "Attempting to export module {} requested by instructor {}",
+        module_id, instructor_id
+    );
+    debug!("Export module params: {:?}", params);
+
+    let module = super::helper::run_query(&pool, move |conn| {
+        modules_dsl::modules
+            .find(module_id)
+            .first::<ModuleQueryResult>(conn)
+            .optional()
+    })
+    .await?;
+    let Some(module) = module else {
+        error!("Module with ID {} not found.", module_id);
+        return Err(AppError::NotFound(format!(
+            "Module with ID {} not found.",
+            module_id
+        )));
+    };
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, module._course_id)
+        .await?;
+    info!(
+        "Permission check passed for instructor {} on course {} (module {})",
+        instructor_id, module._course_id, module_id
+    );
+
+    let exercises =
+        super::helper::run_query(&pool, move |conn| load_export_exercises(conn, &[module_id]))
+            .await?
+            .into_iter()
+            .map(ExportExerciseResponse::from)
+            .collect::<Vec<_>>();
+
+    info!(
+        "Successfully prepared export data for module {} ({} exercises)",
+        module_id,
+        exercises.len()
+    );
+    Ok(ApiResponse::ok(export_module_response(module, exercises)))
+}
+
+/// Attaches a module bundle (as produced by `export_module`) to an existing course.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// The bundle's `order` is ignored: the module is appended after the course's last module in the same language.
+/// Exercises keep their orders within the module. The module and its exercises are inserted in a single transaction.
+/// The `total_exercises` of the course's games are not changed; see `recalculate_course_games`.
+///
+/// Request Body: `ImportModulePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The ID of the new module (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the course does not exist.
+/// * `409 Conflict`: If two exercises of the module share the same `order` and language.
+/// * `422 Unprocessable Entity`: If an exercise has an unknown `mode` or `mode_parameters` of the wrong shape.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn import_module(
+    State(pool): State<Pool>,
+    Json(payload): Json<ImportModulePayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let course_id = payload.course_id;
+
+    info!(
+        "Attempting to import module '{}' into course {} requested by instructor {}",
+        payload.module_data.title, course_id, instructor_id
+    );
+    debug!("Import module payload: {:?}", payload);
+
+    validate_import_module(&payload.module_data)?;
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    let module_data = payload.module_data;
+    let new_module_id = super::helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            // Lock the course so concurrent imports into it pick distinct orders.
+            courses_dsl::courses
+                .find(course_id)
+                .select(courses_dsl::id)
+                .for_update()
+                .first::<i64>(tx_conn)?;
+            let last_order = modules_dsl::modules
+                .filter(modules_dsl::course_id.eq(course_id))
+                .filter(modules_dsl::language.eq(&module_data.language))
+                .select(diesel::dsl::max(modules_dsl::order))
+                .first::<Option<i32>>(tx_conn)?;
+            let order = last_order.unwrap_or(0) + 1;
+
+            insert_module(tx_conn, course_id, order, module_data)
+                .map(|(new_module_id, _)| new_module_id)
+        })
+    })
+    .await?
+    .map_err(|diesel_err| {
+        error!("Module import transaction failed: {:?}", diesel_err);
+        map_order_conflict(diesel_err)
+    })?;
+
+    info!(
+        "Successfully imported module {} into course {} for instructor {}",
+        new_module_id, course_id, instructor_id
+    );
+    Ok(ApiResponse::ok(new_module_id))
+}
+
+/// Looks up the course an exercise belongs to and checks that the instructor owns it.
+async fn check_exercise_course_permission(
+    pool: &Pool,
+    instructor_id: i64,
+    exercise_id: i64,
+) -> Result<(), AppError> {
+    let course_id = super::helper::run_query(pool, move |conn| {
+        exercises_dsl::exercises
+            .find(exercise_id)
+            .inner_join(modules_dsl::modules)
+            .select(modules_dsl::course_id)
+            .first::<i64>(conn)
+            .optional()
+    })
+    .await?;
+
+    let Some(course_id) = course_id else {
+        error!("Exercise with ID {} not found.", exercise_id);
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    };
+
+    super::helper::check_instructor_course_permission(pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {} (exercise {})",
+        instructor_id, course_id, exercise_id
+    );
+    Ok(())
+}
+
+/// Adds a tag to an exercise. Tags are trimmed and lowercased; adding an existing tag is a no-op.
+///
+/// Requires the requesting instructor to be an owner of the exercise's course or an admin (ID 0).
+///
+/// Request Body: `ExerciseTagPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true once the exercise carries the tag (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the exercise's course.
+/// * `404 Not Found`: If the exercise does not exist.
+/// * `422 Unprocessable Entity`: If the tag is empty or longer than 50 characters.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn add_exercise_tag(
+    State(pool): State<Pool>,
+    Json(payload): Json<ExerciseTagPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let exercise_id = payload.exercise_id;
+
+    info!(
+        "Attempting to tag exercise {} requested by instructor {}",
+        exercise_id, instructor_id
+    );
+    debug!("Add exercise tag payload: {:?}", payload);
+
+    let tag = super::helper::normalize_exercise_tag(&payload.tag)?;
+    check_exercise_course_permission(&pool, instructor_id, exercise_id).await?;
+
+    let new_tag = NewExerciseTag {
+        exercise_id,
+        tag: tag.clone(),
+    };
+    let inserted = super::helper::run_query(&pool, move |conn| {
+        diesel::insert_into(exercise_tags_dsl::exercise_tags)
+            .values(&new_tag)
+            .on_conflict_do_nothing()
+            .execute(conn)
+    })
+    .await?;
+
+    if inserted == 0 {
+        info!("Exercise {} already tagged '{}'", exercise_id, tag);
+    } else {
+        info!("Tagged exercise {} with '{}'", exercise_id, tag);
+    }
+    Ok(ApiResponse::ok(true))
+}
+
+/// Removes a tag from an exercise. The tag is matched after trimming and lowercasing.
+///
+/// Requires the requesting instructor to be an owner of the exercise's course or an admin (ID 0).
+///
+/// Request Body: `ExerciseTagPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the tag was removed (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the exercise's course.
+/// * `404 Not Found`: If the exercise does not exist or does not carry the tag.
+/// * `422 Unprocessable Entity`: If the tag is empty or longer than 50 characters.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn remove_exercise_tag(
+    State(pool): State<Pool>,
+    Json(payload): Json<ExerciseTagPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let exercise_id = payload.exercise_id;
+
+    info!(
+        "Attempting to untag exercise {} requested by instructor {}",
+        exercise_id, instructor_id
+    );
+    debug!("Remove exercise tag payload: {:?}", payload);
+
+    let tag = super::helper::normalize_exercise_tag(&payload.tag)?;
+    check_exercise_course_permission(&pool, instructor_id, exercise_id).await?;
+
+    let tag_for_delete = tag.clone();
+    let deleted = super::helper::run_query(&pool, move |conn| {
+        diesel::delete(
+            exercise_tags_dsl::exercise_tags
+                .filter(exercise_tags_dsl::exercise_id.eq(exercise_id))
+                .filter(exercise_tags_dsl::tag.eq(tag_for_delete)),
+        )
+        .execute(conn)
+    })
+    .await?;
+
+    if deleted == 0 {
+        error!("Exercise {} does not carry tag '{}'", exercise_id, tag);
+        return Err(AppError::NotFound(format!(
+            "Exercise {} does not have tag '{}'.",
+            exercise_id, tag
+        )));
+    }
+
+    info!("Removed tag '{}' from exercise {}", tag, exercise_id);
+    Ok(ApiResponse::ok(true))
+}
+
+/// Validates an exercise's `mode` and the shape of its `mode_parameters`.
+/// Quiz exercises need a non-empty `options` array and fill-in exercises a non-empty `blanks` array.
+fn validate_exercise_mode(
+    title: &str,
+    mode: &str,
+    mode_parameters: &JsonValue,
+) -> Result<ExerciseMode, AppError> {
+    let Some(parsed_mode) = ExerciseMode::parse(mode) else {
+        error!("Exercise '{}' has unknown mode '{}'", title, mode);
+        return Err(AppError::UnprocessableEntity(format!(
+            "Exercise '{}' has unknown mode '{}'.",
+            title, mode
+        )));
+    };
+
+    let required_list = match parsed_mode {
+        ExerciseMode::Code => None,
+        ExerciseMode::Quiz => Some("options"),
+        ExerciseMode::FillIn => Some("blanks"),
+    };
+    let shape_is_valid = mode_parameters.is_object()
+        && required_list.is_none_or(|key| {
+            mode_parameters
+                .get(key)
+                .and_then(JsonValue::as_array)
+                .is_some_and(|items| !items.is_empty())
+        });
+
+    if !shape_is_valid {
+        error!(
+            "Exercise '{}' has invalid mode_parameters for mode '{}': {}",
+            title, mode, mode_parameters
+        );
+        let expected = match required_list {
+            Some(key) => format!("an object with a non-empty '{}' array", key),
+            None => "an object".to_string(),
+        };
+        return Err(AppError::UnprocessableEntity(format!(
+            "Exercise '{}' in mode '{}' requires mode_parameters to be {}.",
+            title, mode, expected
+        )));
+    }
+    Ok(parsed_mode)
+}
+
+/// Checks that no two children of the same parent and language variant share an `order` value.
+fn validate_unique_orders<V: std::fmt::Display + Eq + std::hash::Hash>(
+    orders: impl IntoIterator<Item = (V, i32)>,
+    child_name: &str,
+    parent_desc: &str,
+) -> Result<(), AppError> {
+    let mut seen = HashSet::new();
+    for (variant, order) in orders {
+        if !seen.insert((variant.to_string(), order)) {
+            error!(
+                "Duplicate {} order {} ({}) in {}",
+                child_name, order, variant, parent_desc
+            );
+            return Err(AppError::Conflict(format!(
+                "Duplicate {} order {} ({}) in {}.",
+                child_name, order, variant, parent_desc
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Maps a violation of the module/exercise order uniqueness constraints to `409 Conflict`.
+/// Any other error is converted as usual.
+fn map_order_conflict(err: DieselError) -> AppError {
+    if let DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info) = &err {
+        let child_name = match info.constraint_name() {
+            Some("uq_modules_course_order") => Some("module"),
+            Some("uq_exercises_module_order") => Some("exercise"),
+            _ => None,
+        };
+        if let Some(child_name) = child_name {
+            return AppError::Conflict(format!(
+                "Duplicate {} order: {}",
+                child_name,
+                info.details().unwrap_or(info.message())
+            ));
+        }
+    }
+    AppError::from(err)
+}
+
+/// Checks that `requested` lists every ID in `existing` exactly once.
+fn validate_reorder_ids(
+    requested: &[i64],
+    existing: &[i64],
+    child_name: &str,
+    parent_name: &str,
+    parent_id: i64,
+) -> Result<(), AppError> {
+    let requested_set: HashSet<i64> = requested.iter().copied().collect();
+    let existing_set: HashSet<i64> = existing.iter().copied().collect();
+
+    if requested_set.len() != requested.len() || requested_set != existing_set {
+        error!(
+            "Reorder rejected: {} IDs {:?} do not match {} {} children {:?}",
+            child_name, requested, parent_name, parent_id, existing
+        );
+        return Err(AppError::UnprocessableEntity(format!(
+            "The {} IDs must list every {} of {} {} exactly once.",
+            child_name, child_name, parent_name, parent_id
+        )));
+    }
+    Ok(())
+}
+
+/// Rewrites the order of a course's modules to follow the given list of module IDs.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// The list must contain every module of the course exactly once; modules are renumbered from 1.
+///
+/// Request Body: `ReorderModulesPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the modules were reordered (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the course does not exist.
+/// * `422 Unprocessable Entity`: If the IDs are missing, duplicated, or include modules of another course.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn reorder_modules(
+    State(pool): State<Pool>,
+    Json(payload): Json<ReorderModulesPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let course_id = payload.course_id;
+
+    info!(
+        "Attempting to reorder modules of course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Reorder modules payload: {:?}", payload);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    let existing_ids = super::helper::run_query(&pool, move |conn| {
+        modules_dsl::modules
+            .filter(modules_dsl::course_id.eq(course_id))
+            .select(modules_dsl::id)
+            .load::<i64>(conn)
+    })
+    .await?;
+    validate_reorder_ids(
+        &payload.module_ids,
+        &existing_ids,
+        "module",
+        "course",
+        course_id,
+    )?;
+
+    let module_ids = payload.module_ids;
+    let module_count = module_ids.len();
+    super::helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            for (position, module_id) in module_ids.into_iter().enumerate() {
+                diesel::update(modules_dsl::modules.find(module_id))
+                    .set(modules_dsl::order.eq(position as i32 + 1))
+                    .execute(tx_conn)?;
+            }
+            Ok::<(), DieselError>(())
+        })
+    })
+    .await?
+    .map_err(|diesel_err| {
+        error!("Module reorder transaction failed: {:?}", diesel_err);
+        map_order_conflict(diesel_err)
+    })?;
+
+    info!("Reordered {} modules of course {}", module_count, course_id);
+    Ok(ApiResponse::ok(true))
+}
+
+/// Rewrites the order of a module's exercises to follow the given list of exercise IDs.
+///
+/// Requires the requesting instructor to be an owner of the module's course or an admin (ID 0).
+/// The list must contain every exercise of the module exactly once; exercises are renumbered from 1.
+///
+/// Request Body: `ReorderExercisesPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the exercises were reordered (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the module's course.
+/// * `404 Not Found`: If the module does not exist.
+/// * `422 Unprocessable Entity`: If the IDs are missing, duplicated, or include exercises of another module.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn reorder_exercises(
+    State(pool): State<Pool>,
+    Json(payload): Json<ReorderExercisesPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let module_id = payload.module_id;
+
+    info!(
+        "Attempting to reorder exercises of module {} requested by instructor {}",
+        module_id, instructor_id
+    );
+    debug!("Reorder exercises payload: {:?}", payload);
+
+    let course_id = super::helper::run_query(&pool, move |conn| {
+        modules_dsl::modules
+            .find(module_id)
+            .select(modules_dsl::course_id)
+            .first::<i64>(conn)
+            .optional()
+    })
+    .await?;
+    let Some(course_id) = course_id else {
+        error!("Module with ID {} not found.", module_id);
+        return Err(AppError::NotFound(format!(
+            "Module with ID {} not found.",
+            module_id
+        )));
+    };
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {} (module {})",
+        instructor_id, course_id, module_id
+    );
+
+    let existing_ids = super::helper::run_query(&pool, move |conn| {
+        exercises_dsl::exercises
+            .filter(exercises_dsl::module_id.eq(module_id))
+            .select(exercises_dsl::id)
+            .load::<i64>(conn)
+    })
+    .await?;
+    validate_reorder_ids(
+        &payload.exercise_ids,
+        &existing_ids,
+        "exercise",
+        "module",
+        module_id,
+    )?;
+
+    let exercise_ids = payload.exercise_ids;
+    let exercise_count = exercise_ids.len();
+    super::helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            for (position, exercise_id) in exercise_ids.into_iter().enumerate() {
+                diesel::update(exercises_dsl::exercises.find(exercise_id))
+                    .set((
+                        exercises_dsl::order.eq(position as i32 + 1),
+                        exercises_dsl::updated_at.eq(Utc::now()),
+                    ))
+                    .execute(tx_conn)?;
+            }
+            Ok::<(), DieselError>(())
+        })
+    })
+    .await?
+    .map_err(|diesel_err| {
+        error!("Exercise reorder transaction failed: {:?}", diesel_err);
+        map_order_conflict(diesel_err)
+    })?;
+
+    info!(
+        "Reordered {} exercises of module {}",
+        exercise_count, module_id
+    );
+    Ok(ApiResponse::ok(true))
+}
+
+/// Compacts the module and exercise orders of a course so they run from 1 without gaps.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// Each language variant is numbered separately and relative order is preserved (ties are broken by ID).
+/// All updates happen in a single transaction.
+///
+/// Request Body: `RenumberCoursePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the course was renumbered (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the course does not exist.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn renumber_course(
+    State(pool): State<Pool>,
+    Json(payload): Json<RenumberCoursePayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let course_id = payload.course_id;
+
+    info!(
+        "Attempting to renumber course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Renumber course payload: {:?}", payload);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    super::helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| compact_course_orders(tx_conn, course_id))
+    })
+    .await?
+    .map_err(|diesel_err| {
+        error!("Course renumber transaction failed: {:?}", diesel_err);
+        map_order_conflict(diesel_err)
+    })?;
+
+    info!("Renumbered modules and exercises of course {}", course_id);
+    Ok(ApiResponse::ok(true))
+}
+
+/// Recomputes `total_exercises` of every game running a course, e.g. after the course was edited.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// As in `create_game`, a game counts the course's exercises in its programming language,
+/// unless it runs a subset of them (see `include_game_exercises`), which is counted instead.
+/// All updates happen in a single transaction.
+///
+/// Request Body: `RecalculateCourseGamesPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<GameExerciseCount>`: The updated games, ordered by ID, with their new count (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the course does not exist.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn recalculate_course_games(
+    State(pool): State<Pool>,
+    Json(payload): Json<RecalculateCourseGamesPayload>,
+) -> Result<ApiResponse<Vec<GameExerciseCount>>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let course_id = payload.course_id;
+
+    info!(
+        "Attempting to recalculate exercise totals of the games of course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Recalculate course games payload: {:?}", payload);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    let updated_games = super::helper::run_query(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            let games = games_dsl::games
+                .filter(games_dsl::course_id.eq(course_id))
+                .order_by(games_dsl::id.asc())
+                .select((games_dsl::id, games_dsl::programming_language))
+                .load::<(i64, String)>(tx_conn)?;
+
+            let counts = exercises_dsl::exercises
+                .inner_join(modules_dsl::modules)
+                .filter(modules_dsl::course_id.eq(course_id))
+                .group_by(exercises_dsl::programming_language)
+                .select((exercises_dsl::programming_language, count_star()))
+                .load::<(String, i64)>(tx_conn)?
+                .into_iter()
+                .collect::<HashMap<String, i64>>();
+
+            let game_ids: Vec<i64> = games.iter().map(|(game_id, _)| *game_id).collect();
+            let subset_sizes = ge_dsl::game_exercises
+                .filter(ge_dsl::game_id.eq_any(game_ids))
+                .group_by(ge_dsl::game_id)
+                .select((ge_dsl::game_id, count_star()))
+                .load::<(i64, i64)>(tx_conn)?
+                .into_iter()
+                .collect::<HashMap<i64, i64>>();
+
+            let mut updated_games = Vec::with_capacity(games.len());
+            for (game_id, programming_language) in games {
+                let total_exercises = subset_sizes
+                    .get(&game_id)
+                    .or_else(|| counts.get(&programming_language))
+                    .copied()
+                    .unwrap_or(0) as i32;
+                diesel::update(games_dsl::games.find(game_id))
+                    .set((
+                        games_dsl::total_exercises.eq(total_exercises),
+                        games_dsl::updated_at.eq(diesel::dsl::now),
+                    ))
+                    .execute(tx_conn)?;
+                updated_games.push(GameExerciseCount {
+                    game_id,
+                    total_exercises,
+                });
+            }
+            Ok(updated_games)
+        })
+    })
+    .await?;
+
+    info!(
+        "Recalculated exercise totals of {} games of course {}",
+        updated_games.len(),
+        course_id
+    );
+    Ok(ApiResponse::ok(updated_games))
+}
+
+/// Renumbers the modules of a course, and the exercises of each module, from 1 in their current order.
+/// Each language variant (module language; exercise language and programming language) is numbered separately.
+/// Must run inside a transaction: the order uniqueness constraints are only checked on commit.
+fn compact_course_orders(conn: &mut PgConnection, course_id: i64) -> QueryResult<()> {
+    let modules = modules_dsl::modules
+        .filter(modules_dsl::course_id.eq(course_id))
+        .order((
+            modules_dsl::language.asc(),
+            modules_dsl::order.asc(),
+            modules_dsl::id.asc(),
+        ))
+        .select((modules_dsl::id, modules_dsl::language))
+        .load::<(i64, String)>(conn)?;
+
+    let mut next_order: HashMap<String, i32> = HashMap::new();
+    for (module_id, language) in modules {
+        let position = next_order.entry(language).or_insert(0);
+        *position += 1;
+        diesel::update(modules_dsl::modules.find(module_id))
+            .set(modules_dsl::order.eq(*position))
+            .execute(conn)?;
+
+        let exercises = exercises_dsl::exercises
+            .filter(exercises_dsl::module_id.eq(module_id))
+            .order((
+                exercises_dsl::language.asc(),
+                exercises_dsl::programming_language.asc(),
+                exercises_dsl::order.asc(),
+                exercises_dsl::id.asc(),
+            ))
+            .select((
+                exercises_dsl::id,
+                exercises_dsl::language,
+                exercises_dsl::programming_language,
+            ))
+            .load::<(i64, String, String)>(conn)?;
+        let mut next_exercise_order: HashMap<(String, String), i32> = HashMap::new();
+        for (exercise_id, language, programming_language) in exercises {
+            let position = next_exercise_order
+                .entry((language, programming_language))
+                .or_insert(0);
+            *position += 1;
+            diesel::update(exercises_dsl::exercises.find(exercise_id))
+                .set(exercises_dsl::order.eq(*position))
+                .execute(conn)?;
+        }
+    }
+    Ok(())
+}
+
+/// Aggregates empirical difficulty for every exercise of a course, pooled across all games using it.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// A submission counts as successful when it reaches its own game's success threshold (50 by default).
+/// Difficulty is the percentage of unsuccessful attempts, 0 for exercises without attempts.
+///
+/// Query Parameters:
+/// * instructor_id as `i64`: The ID of the instructor requesting the statistics.
+/// * course_id as `i64`: The ID of the course.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<CourseExerciseDifficulty>`: Per-exercise statistics, in module and exercise order (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the specified course does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_course_exercise_difficulty(
+    State(pool): State<Pool>,
+    Query(params): Query<GetCourseExerciseDifficultyParams>,
+) -> Result<ApiResponse<Vec<CourseExerciseDifficulty>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let course_id = params.course_id;
+
+    info!(
+        "Fetching exercise difficulty for course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Get course exercise difficulty params: {:?}", params);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    let (exercises, attempt_counts) = super::helper::run_query(&pool, move |conn| {
+        let exercises = exercises_dsl::exercises
+            .inner_join(modules_dsl::modules)
+            .filter(modules_dsl::course_id.eq(course_id))
+            .order_by((modules_dsl::order.asc(), exercises_dsl::order.asc()))
+            .select((exercises_dsl::id, exercises_dsl::title))
+            .load::<(i64, String)>(conn)?;
+
+        let attempt_counts = sub_dsl::submissions
+            .inner_join(games_dsl::games)
+            .inner_join(exercises_dsl::exercises.inner_join(modules_dsl::modules))
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(games_dsl::course_id.eq(course_id))
+            .group_by(sub_dsl::exercise_id)
+            .select((
+                sub_dsl::exercise_id,
+                count_star(),
+                sql::<BigInt>(&format!(
+                    "COUNT(*) FILTER (WHERE submissions.result >= COALESCE(games.success_threshold, {}))",
+                    super::helper::DEFAULT_SUCCESS_THRESHOLD
+                )),
+            ))
+            .load::<(i64, i64, i64)>(conn)?
+            .into_iter()
+            .map(|(exercise_id, attempts, successful)| (exercise_id, (attempts, successful)))
+            .collect::<HashMap<i64, (i64, i64)>>();
+
+        Ok((exercises, attempt_counts))
+    })
+    .await?;
+
+    let response_data: Vec<CourseExerciseDifficulty> = exercises
+        .into_iter()
+        .map(|(exercise_id, title)| {
+            let (attempts, successful_attempts) =
+                attempt_counts.get(&exercise_id).copied().unwrap_or((0, 0));
+            let success_rate = if attempts > 0 {
+                successful_attempts as f64 / attempts as f64 * 100.0
+            } else {
+                0.0
+            };
+            CourseExerciseDifficulty {
+                exercise_id,
+                title,
+                attempts,
+                successful_attempts,
+                success_rate,
+                difficulty: if attempts > 0 {
+                    100.0 - success_rate
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect();
+
+    info!(
+        "Successfully computed difficulty for {} exercises of course {}",
+        response_data.len(),
+        course_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Lists the games built on the course containing an exercise, i.e. every game a change to it affects.
+///
+/// Requires the requesting instructor to be an owner of the exercise's course or an admin (ID 0).
+///
+/// Query Parameters:
+/// * instructor_id as `i64`: The ID of the instructor making the request.
+/// * exercise_id as `i64`: The ID of the exercise.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<ExerciseGameSummary>`: The games with their title, active flag and current player count, ordered by ID (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the exercise's course.
+/// * `404 Not Found`: If the exercise does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_games(
+    State(pool): State<Pool>,
+    Query(params): Query<GetExerciseGamesParams>,
+) -> Result<ApiResponse<Vec<ExerciseGameSummary>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let exercise_id = params.exercise_id;
+
+    info!(
+        "Fetching games using exercise {} requested by instructor {}",
+        exercise_id, instructor_id
+    );
+    debug!("Get exercise games params: {:?}", params);
+
+    check_exercise_course_permission(&pool, instructor_id, exercise_id).await?;
+
+    let (games, player_counts) = super::helper::run_query(&pool, move |conn| {
+        let course_id = exercises_dsl::exercises
+            .inner_join(modules_dsl::modules)
+            .filter(exercises_dsl::id.eq(exercise_id))
+            .select(modules_dsl::course_id)
+            .first::<i64>(conn)?;
+
+        let games = games_dsl::games
+            .filter(games_dsl::course_id.eq(course_id))
+            .order_by(games_dsl::id.asc())
+            .select((games_dsl::id, games_dsl::title, games_dsl::active))
+            .load::<(i64, String, bool)>(conn)?;
+
+        let player_counts = pr_dsl::player_registrations
+            .inner_join(games_dsl::games)
+            .filter(games_dsl::course_id.eq(course_id))
+            .filter(pr_dsl::left_at.is_null())
+            .group_by(pr_dsl::game_id)
+            .select((pr_dsl::game_id, count_star()))
+            .load::<(i64, i64)>(conn)?
+            .into_iter()
+            .collect::<HashMap<i64, i64>>();
+
+        Ok((games, player_counts))
+    })
+    .await?;
+
+    let response_data: Vec<ExerciseGameSummary> = games
+        .into_iter()
+        .map(|(game_id, title, active)| ExerciseGameSummary {
+            game_id,
+            title,
+            active,
+            player_count: player_counts.get(&game_id).copied().unwrap_or(0),
+        })
+        .collect();
+
+    info!(
+        "Exercise {} is used by {} games",
+        exercise_id,
+        response_data.len()
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Summarizes enrollment across every game built on a course.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// Only registrations of players who have not left their game are counted.
+///
+/// Query Parameters:
+/// * instructor_id as `i64`: The ID of the instructor requesting the summary.
+/// * course_id as `i64`: The ID of the course.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `CourseEnrollmentSummary`: Distinct players and registrations over all games, with a per-game breakdown ordered by game ID (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the specified course does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_course_enrollment_summary(
+    State(pool): State<Pool>,
+    Query(params): Query<GetCourseEnrollmentSummaryParams>,
+) -> Result<ApiResponse<CourseEnrollmentSummary>, AppError> {
+    let instructor_id = params.instructor_id;
+    let course_id = params.course_id;
+
+    info!(
+        "Fetching enrollment summary for course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Get course enrollment summary params: {:?}", params);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    let (games, registration_counts, distinct_players) =
+        super::helper::run_query(&pool, move |conn| {
+            let games = games_dsl::games
+                .filter(games_dsl::course_id.eq(course_id))
+                .order_by(games_dsl::id.asc())
+                .select((games_dsl::id, games_dsl::title, games_dsl::active))
+                .load::<(i64, String, bool)>(conn)?;
+
+            let active_registrations = pr_dsl::player_registrations
+                .inner_join(games_dsl::games)
+                .filter(games_dsl::course_id.eq(course_id))
+                .filter(pr_dsl::left_at.is_null());
+
+            let registration_counts = active_registrations
+                .group_by(pr_dsl::game_id)
+                .select((pr_dsl::game_id, count_star()))
+                .load::<(i64, i64)>(conn)?
+                .into_iter()
+                .collect::<HashMap<i64, i64>>();
+
+            // a player registered in several games of the course is counted once
+            let distinct_players = active_registrations
+                .select(count_distinct(pr_dsl::player_id))
+                .get_result::<i64>(conn)?;
+
+            Ok((games, registration_counts, distinct_players))
+        })
+        .await?;
+
+    let games: Vec<GameEnrollment> = games
+        .into_iter()
+        .map(|(game_id, title, active)| GameEnrollment {
+            game_id,
+            title,
+            active,
+            registrations: registration_counts.get(&game_id).copied().unwrap_or(0),
+        })
+        .collect();
+    let response_data = CourseEnrollmentSummary {
+        course_id,
+        distinct_players,
+        registrations: games.iter().map(|game| game.registrations).sum(),
+        games,
+    };
+
+    info!(
+        "Course {} has {} distinct players over {} registrations in {} games",
+        course_id,
+        response_data.distinct_players,
+        response_data.registrations,
+        response_data.games.len()
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Retrieves the full module tree of a course: the course with its modules, each with its exercises.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// Modules and exercises are each loaded with one query and nested in memory.
+///
+/// Query Parameters:
+/// * instructor_id as `i64`: The ID of the instructor requesting the tree.
+/// * course_id as `i64`: The ID of the course.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `CourseTreeResponse`: The course with modules and exercises, both ordered by `order` (200 OK).
+/// * `403 Forbidden`: If the requesting instructor does not own the course.
+/// * `404 Not Found`: If the specified course does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_course_tree(
+    State(pool): State<Pool>,
+    Query(params): Query<GetCourseTreeParams>,
+) -> Result<ApiResponse<CourseTreeResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let course_id = params.course_id;
+
+    info!(
+        "Fetching course tree for course {} requested by instructor {}",
+        course_id, instructor_id
+    );
+    debug!("Get course tree params: {:?}", params);
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    type CourseTuple = (i64, String, String, String, String, bool); // id, title, desc, langs, prog langs, public
+    type ModuleTuple = (
+        i64,
+        i32,
+        String,
+        String,
+        String,
+        DateTime<Utc>,
+        DateTime<Utc>,
+    ); // id, order, title, desc, lang, start, end
+
+    let (course, modules, exercises) = super::helper::run_query(&pool, move |conn| {
+        let course = courses_dsl::courses
+            .find(course_id)
+            .select((
+                courses_dsl::id,
+                courses_dsl::title,
+                courses_dsl::description,
+                courses_dsl::languages,
+                courses_dsl::programming_languages,
+                courses_dsl::public,
+            ))
+            .first::<CourseTuple>(conn)?;
+
+        let modules = modules_dsl::modules
+            .filter(modules_dsl::course_id.eq(course_id))
+            .order_by((modules_dsl::order.asc(), modules_dsl::id.asc()))
+            .select((
+                modules_dsl::id,
+                modules_dsl::order,
+                modules_dsl::title,
+                modules_dsl::description,
+                modules_dsl::language,
+                modules_dsl::start_date,
+                modules_dsl::end_date,
+            ))
+            .load::<ModuleTuple>(conn)?;
+
+        let exercises = exercises_dsl::exercises
+            .inner_join(modules_dsl::modules)
+            .filter(modules_dsl::course_id.eq(course_id))
+            .order_by((exercises_dsl::order.asc(), exercises_dsl::id.asc()))
+            .select((
+                exercises_dsl::module_id,
+                (
+                    exercises_dsl::id,
+                    exercises_dsl::order,
+                    exercises_dsl::title,
+                    exercises_dsl::language,
+                    exercises_dsl::programming_language,
+                    exercises_dsl::mode,
+                    exercises_dsl::difficulty,
+                    exercises_dsl::hidden,
+                    exercises_dsl::locked,
+                ),
+            ))
+            .load::<(i64, ExerciseTreeNode)>(conn)?;
+
+        Ok((course, modules, exercises))
+    })
+    .await?;
+
+    let mut exercises_by_module: HashMap<i64, Vec<ExerciseTreeNode>> = HashMap::new();
+    for (module_id, exercise) in exercises {
+        exercises_by_module
+            .entry(module_id)
+            .or_default()
+            .push(exercise);
+    }
+
+    let modules: Vec<ModuleTreeNode> = modules
+        .into_iter()
+        .map(
+            |(id, order, title, description, language, start_date, end_date)| ModuleTreeNode {
+                id,
+                order,
+                title,
+                description,
+                language,
+                start_date,
+                end_date,
+                exercises: exercises_by_module.remove(&id).unwrap_or_default(),
+            },
+        )
+        .collect();
+
+    let (id, title, description, languages, programming_languages, public) = course;
+    let response_data = CourseTreeResponse {
+        id,
+        title,
+        description,
+        languages,
+        programming_languages,
+        public,
+        modules,
+    };
+
+    info!(
+        "Successfully assembled tree of {} modules for course {}",
+        response_data.modules.len(),
+        course_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Deletes a course together with its modules, exercises, rewards, and ownership rows.
+///
+/// Requires the requesting instructor to be an owner of the course or an admin (ID 0).
+/// Refuses while games still reference the course, unless an admin sets `force`: the
+/// dependent games are then archived to `archived_games` (settings, player and submission
+/// counts, and the registration rows), their submissions are moved to `archived_submissions`,
+/// and the games are deleted.
+/// Performs all database operations within a single transaction.
+///
+/// Request Body: `DeleteCoursePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the course was deleted (200 OK).
+/// * `403 Forbidden`: If the instructor does not own the course, a non-admin sets `force`, or the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the specified course does not exist.
+/// * `409 Conflict`: If games still reference the course and `force` is not set.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn delete_course(
+    State(pool): State<Pool>,
+    Extension(webhook): Extension<Option<Arc<Webhook>>>,
+    acting_instructor: ActingInstructor,
+    Json(payload): Json<DeleteCoursePayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let course_id = payload.course_id;
+    let force = payload.force;
+
+    info!(
+        "Attempting to delete course {} requested by instructor {} (force: {})",
+        course_id, instructor_id, force
+    );
+    debug!("Delete course payload: {:?}", payload);
+
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
+    super::helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    if force && instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot force course deletion.",
+            instructor_id
+        );
+        return Err(AppError::Forbidden(
+            "Only admin users can force course deletion.".to_string(),
+        ));
+    }
+
+    let deletion_result: Result<Vec<i64>, AppError> =
+        super::helper::interact(&pool, move |conn_sync| {
+            conn_sync.transaction(|tx_conn| {
+                type ArchivedGameTuple = (
+                    i64,
+                    String,
+                    String,
+                    bool,
+                    bool,
+                    String,
+                    i32,
+                    Option<f64>,
+                    DateTime<Utc>,
+                    DateTime<Utc>,
+                ); // id, title, desc, public, active, lang, total_ex, threshold, start, end
+
+                let games = games_dsl::games
+                    .filter(games_dsl::course_id.eq(course_id))
+                    .select((
+                        games_dsl::id,
+                        games_dsl::title,
+                        games_dsl::description,
+                        games_dsl::public,
+                        games_dsl::active,
+                        games_dsl::programming_language,
+                        games_dsl::total_exercises,
+                        games_dsl::success_threshold,
+                        games_dsl::start_date,
+                        games_dsl::end_date,
+                    ))
+                    .load::<ArchivedGameTuple>(tx_conn)?;
+
+                if !games.is_empty() && !force {
+                    warn!(
+                        "Refusing to delete course {}: {} games still reference it.",
+                        course_id,
+                        games.len()
+                    );
+                    return Err(AppError::Conflict(format!(
+                        "Course {} is still used by {} game(s).",
+                        course_id,
+                        games.len()
+                    )));
+                }
+
+                for (
+                    game_id,
+                    title,
+                    description,
+                    public,
+                    active,
+                    programming_language,
+                    total_exercises,
+                    success_threshold,
+                    start_date,
+                    end_date,
+                ) in &games
+                {
+                    let registrations = pr_dsl::player_registrations
+                        .filter(pr_dsl::game_id.eq(game_id))
+                        .order_by(pr_dsl::id)
+                        .select(sql::<Jsonb>("to_jsonb(player_registrations)"))
+                        .load::<JsonValue>(tx_conn)?;
+                    // Move the game's submissions to `archived_submissions` before the game
+                    // deletion cascades to them, as the submission archiving job does.
+                    let submission_count = diesel::sql_query(
+                        "WITH moved AS ( \
+                            DELETE FROM submissions WHERE game_id = $1 RETURNING * \
+                        ) \
+                        INSERT INTO archived_submissions \
+                            (submission_id, game_id, player_id, exercise_id, entered_at, snapshot) \
+                        SELECT id, game_id, player_id, exercise_id, entered_at, to_jsonb(moved) FROM moved",
+                    )
+                    .bind::<BigInt, _>(*game_id)
+                    .execute(tx_conn)?;
+
+                    let archived_game = NewArchivedGame {
+                        game_id: *game_id,
+                        course_id,
+                        title: title.clone(),
+                        snapshot: json!({
+                            "description": description,
+                            "public": public,
+                            "active": active,
+                            "programming_language": programming_language,
+                            "total_exercises": total_exercises,
+                            "success_threshold": success_threshold,
+                            "start_date": start_date,
+                            "end_date": end_date,
+                            "player_count": registrations.len(),
+                            "submission_count": submission_count,
+                            "registrations": registrations,
+                        }),
+                    };
+                    diesel::insert_into(archived_games_dsl::archived_games)
+                        .values(&archived_game)
+                        .execute(tx_conn)?;
+                    diesel::delete(games_dsl::games.find(game_id)).execute(tx_conn)?;
+                    info!(
+                        "Archived and deleted game {} of course {}",
+                        game_id, course_id
+                    );
+                }
+
+                let module_ids = modules_dsl::modules
+                    .filter(modules_dsl::course_id.eq(course_id))
+                    .select(modules_dsl::id);
+                diesel::delete(
+                    exercises_dsl::exercises.filter(exercises_dsl::module_id.eq_any(module_ids)),
+                )
+                .execute(tx_conn)?;
+                diesel::delete(modules_dsl::modules.filter(modules_dsl::course_id.eq(course_id)))
+                    .execute(tx_conn)?;
+                diesel::delete(rewards_dsl::rewards.filter(rewards_dsl::course_id.eq(course_id)))
+                    .execute(tx_conn)?;
+                diesel::delete(
+                    course_owner_dsl::course_ownership
+                        .filter(course_owner_dsl::course_id.eq(course_id)),
+                )
+                .execute(tx_conn)?;
+                diesel::delete(courses_dsl::courses.find(course_id)).execute(tx_conn)?;
+
+                Ok(games.iter().map(|game| game.0).collect())
+            })
+        })
+        .await?;
+
+    let archived_game_ids = deletion_result?;
+    info!(
+        "Deleted course {} ({} dependent games archived)",
+        course_id,
+        archived_game_ids.len()
+    );
+    if let Some(webhook) = &webhook {
+        for game_id in archived_game_ids {
+            webhook.notify(GAME_ARCHIVED, game_id, instructor_id);
+        }
+    }
+    Ok(ApiResponse::ok(true))
+}
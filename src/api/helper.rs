@@ -1,26 +1,50 @@
+use crate::config::DefaultLanguages;
+use crate::db;
 use crate::errors::AppError;
+use crate::model::student::PlayerScoreResponse;
 use crate::schema::{
     course_ownership::dsl as course_owner_dsl, courses::dsl as courses_dsl,
-    game_ownership::dsl as go_dsl, games::dsl as games_dsl,
+    exercise_tags::dsl as exercise_tags_dsl, exercises::dsl as exercises_dsl,
+    game_exercises::dsl as ge_dsl, game_ownership::dsl as go_dsl, games::dsl as games_dsl,
     group_ownership::dsl as group_owner_dsl, groups::dsl as groups_dsl,
+    modules::dsl as modules_dsl, player_registrations::dsl as prs_dsl,
+    player_rewards::dsl as pr_rewards_dsl, player_unlocks::dsl as pus_dsl,
+    players::dsl as players_dsl, submissions::dsl as sub_dsl,
 };
+use bigdecimal::{BigDecimal, FromPrimitive};
+use chrono::{DateTime, Utc};
 use deadpool_diesel::postgres::Pool;
-use diesel::ExpressionMethods;
-use diesel::dsl::exists;
-use diesel::{PgConnection, QueryDsl, RunQueryDsl};
+use diesel::dsl::{count_distinct, exists};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, PgConnection, QueryDsl,
+    QueryResult, RunQueryDsl,
+};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use tracing::log::{debug, error, info, warn};
 
+/// Minimum submission result (in percent) counted as a success when a game sets no threshold.
+pub const DEFAULT_SUCCESS_THRESHOLD: f64 = 50.0;
+
+/// Maximum length of an exercise tag, matching the `exercise_tags.tag` column.
+pub const MAX_EXERCISE_TAG_LENGTH: usize = 50;
+
 pub(super) async fn run_query<T, F>(pool: &Pool, query: F) -> Result<T, AppError>
 where
     F: FnOnce(&mut PgConnection) -> Result<T, diesel::result::Error> + Send + 'static,
     T: Send + 'static,
 {
-    let conn = pool.get().await?;
-    debug!("DB connection object obtained from pool for interaction");
-
-    let result = conn.interact(query).await?;
+    interact(pool, query).await?.map_err(AppError::from)
+}
 
-    result.map_err(AppError::from)
+/// Runs `query` (typically a transaction) on a pooled connection, logging it if slow.
+/// Unlike `run_query`, the closure's return value is handed back unchanged.
+pub(super) async fn interact<T, F>(pool: &Pool, query: F) -> Result<T, AppError>
+where
+    F: FnOnce(&mut PgConnection) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    db::interact(pool, query).await
 }
 
 /// Checks if an instructor has permission for a specific entity.
@@ -213,3 +237,525 @@ pub async fn check_instructor_course_permission(
     )
     .await
 }
+
+/// Returns the minimum result a submission needs to count as successful in a game.
+/// Falls back to `DEFAULT_SUCCESS_THRESHOLD` if the game does not configure its own.
+/// Returns AppError::NotFound if the game doesn't exist.
+pub async fn game_success_threshold(pool: &Pool, game_id: i64) -> Result<BigDecimal, AppError> {
+    run_query(pool, move |conn| load_success_threshold(conn, game_id)).await
+}
+
+/// Same as `game_success_threshold`, on the given connection so it can take part in the caller's transaction.
+pub fn load_success_threshold(conn: &mut PgConnection, game_id: i64) -> QueryResult<BigDecimal> {
+    let threshold = games_dsl::games
+        .find(game_id)
+        .select(games_dsl::success_threshold)
+        .first::<Option<f64>>(conn)?;
+    let threshold = success_threshold(threshold);
+    debug!("Success threshold for game {}: {}", game_id, threshold);
+    Ok(threshold)
+}
+
+/// Converts a game's configured `success_threshold` (or its absence) into the threshold to compare results with.
+pub fn success_threshold(configured: Option<f64>) -> BigDecimal {
+    let threshold = configured.unwrap_or(DEFAULT_SUCCESS_THRESHOLD);
+    BigDecimal::from_f64(threshold).unwrap_or_else(|| BigDecimal::from(50))
+}
+
+/// Validates a client-supplied success threshold, which must lie within 0 to 100.
+pub fn validate_success_threshold(threshold: Option<f64>) -> Result<(), AppError> {
+    match threshold {
+        Some(value) if !(0.0..=100.0).contains(&value) => {
+            warn!("Rejecting success threshold {} outside 0-100.", value);
+            Err(AppError::UnprocessableEntity(format!(
+                "Success threshold {} must be between 0 and 100.",
+                value
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the minimum result a submission needs to count as an attempt in a game's statistics,
+/// or `None` if the game counts every submission.
+/// Returns AppError::NotFound if the game doesn't exist.
+pub async fn game_min_meaningful_result(
+    pool: &Pool,
+    game_id: i64,
+) -> Result<Option<BigDecimal>, AppError> {
+    let min_result = run_query(pool, move |conn| {
+        games_dsl::games
+            .find(game_id)
+            .select(games_dsl::min_meaningful_result)
+            .first::<Option<f64>>(conn)
+    })
+    .await?;
+    debug!(
+        "Minimum meaningful result for game {}: {:?}",
+        game_id, min_result
+    );
+
+    Ok(min_result.and_then(BigDecimal::from_f64))
+}
+
+/// Validates a client-supplied minimum meaningful result, which must lie within 0 to 100.
+pub fn validate_min_meaningful_result(min_result: Option<f64>) -> Result<(), AppError> {
+    match min_result {
+        Some(value) if !(0.0..=100.0).contains(&value) => {
+            warn!("Rejecting min_meaningful_result {} outside 0-100.", value);
+            Err(AppError::UnprocessableEntity(format!(
+                "min_meaningful_result {} must be between 0 and 100.",
+                value
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates a client-supplied attempt cap, which must allow at least one submission.
+pub fn validate_max_attempts(max_attempts: Option<i32>) -> Result<(), AppError> {
+    match max_attempts {
+        Some(value) if value < 1 => {
+            warn!("Rejecting max_attempts_per_exercise {} below 1.", value);
+            Err(AppError::UnprocessableEntity(format!(
+                "max_attempts_per_exercise {} must be at least 1.",
+                value
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Normalizes an exercise tag (trimmed, lowercase) so lookups are case-insensitive.
+/// Returns AppError::UnprocessableEntity if the tag is empty or too long.
+pub fn normalize_exercise_tag(tag: &str) -> Result<String, AppError> {
+    let normalized = tag.trim().to_lowercase();
+    if normalized.is_empty() || normalized.chars().count() > MAX_EXERCISE_TAG_LENGTH {
+        warn!("Rejecting invalid exercise tag '{}'.", tag);
+        return Err(AppError::UnprocessableEntity(format!(
+            "Exercise tag must be between 1 and {} characters.",
+            MAX_EXERCISE_TAG_LENGTH
+        )));
+    }
+    Ok(normalized)
+}
+
+/// Returns the IDs of exercises in a game's course that carry the given (normalized) tag.
+/// Only exercises in the game's programming language are included, ordered by module and exercise order.
+/// Returns AppError::NotFound if the game doesn't exist.
+pub async fn game_exercise_ids_by_tag(
+    pool: &Pool,
+    game_id: i64,
+    tag: String,
+) -> Result<Vec<i64>, AppError> {
+    let game_exists = run_query(pool, move |conn| {
+        diesel::select(exists(games_dsl::games.find(game_id))).get_result::<bool>(conn)
+    })
+    .await?;
+    if !game_exists {
+        error!("Game with ID {} not found.", game_id);
+        return Err(AppError::NotFound(format!(
+            "Game with ID {} not found.",
+            game_id
+        )));
+    }
+
+    run_query(pool, move |conn| {
+        let (course_id, programming_language) = games_dsl::games
+            .find(game_id)
+            .select((games_dsl::course_id, games_dsl::programming_language))
+            .first::<(i64, String)>(conn)?;
+
+        exercise_tags_dsl::exercise_tags
+            .inner_join(exercises_dsl::exercises.inner_join(modules_dsl::modules))
+            .filter(exercise_tags_dsl::tag.eq(tag))
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(exercises_dsl::programming_language.eq(programming_language))
+            .order_by((modules_dsl::order.asc(), exercises_dsl::order.asc()))
+            .select(exercises_dsl::id)
+            .load::<i64>(conn)
+    })
+    .await
+}
+
+/// Condition on `submissions` selecting the exercises that count towards progress in a game:
+/// those in its `game_exercises` subset, or every exercise if the game defines none.
+#[diesel::dsl::auto_type(no_type_alias)]
+pub fn counts_in_game(game_id: i64) -> _ {
+    let subset_ids: diesel::dsl::Select<
+        diesel::dsl::Filter<ge_dsl::game_exercises, diesel::dsl::Eq<ge_dsl::game_id, i64>>,
+        ge_dsl::exercise_id,
+    > = ge_dsl::game_exercises
+        .filter(ge_dsl::game_id.eq(game_id))
+        .select(ge_dsl::exercise_id);
+    diesel::dsl::not(exists(subset_ids)).or(sub_dsl::exercise_id.eq_any(subset_ids))
+}
+
+/// Checks whether an exercise counts towards progress in a game (see `counts_in_game`).
+pub fn exercise_counts_in_game(
+    conn: &mut PgConnection,
+    game_id: i64,
+    exercise_id: i64,
+) -> QueryResult<bool> {
+    let subset = ge_dsl::game_exercises.filter(ge_dsl::game_id.eq(game_id));
+    let (has_subset, included) = diesel::select((
+        exists(subset),
+        exists(subset.filter(ge_dsl::exercise_id.eq(exercise_id))),
+    ))
+    .get_result::<(bool, bool)>(conn)?;
+    Ok(!has_subset || included)
+}
+
+/// Lists the exercises that count towards a game: those in its `game_exercises` subset, or without
+/// a subset, the course's exercises in the game's programming language, as in `create_game`.
+pub fn game_exercise_ids(conn: &mut PgConnection, game_id: i64) -> QueryResult<Vec<i64>> {
+    let subset = ge_dsl::game_exercises
+        .filter(ge_dsl::game_id.eq(game_id))
+        .select(ge_dsl::exercise_id)
+        .load::<i64>(conn)?;
+    if !subset.is_empty() {
+        return Ok(subset);
+    }
+    let (course_id, programming_language) = games_dsl::games
+        .find(game_id)
+        .select((games_dsl::course_id, games_dsl::programming_language))
+        .first::<(i64, String)>(conn)?;
+    exercises_dsl::exercises
+        .inner_join(modules_dsl::modules)
+        .filter(modules_dsl::course_id.eq(course_id))
+        .filter(exercises_dsl::programming_language.eq(programming_language))
+        .select(exercises_dsl::id)
+        .load::<i64>(conn)
+}
+
+/// Recomputes a game's `total_exercises` and the `progress` of its registrations after its exercise subset changed.
+/// The total counts the exercises listed by `game_exercise_ids`.
+/// Runs on the given connection so it can take part in the caller's transaction.
+pub fn sync_game_exercise_totals(conn: &mut PgConnection, game_id: i64) -> QueryResult<i32> {
+    let total_exercises = game_exercise_ids(conn, game_id)?.len() as i32;
+    diesel::update(games_dsl::games.find(game_id))
+        .set((
+            games_dsl::total_exercises.eq(total_exercises),
+            games_dsl::updated_at.eq(diesel::dsl::now),
+        ))
+        .execute(conn)?;
+
+    let player_ids = prs_dsl::player_registrations
+        .filter(prs_dsl::game_id.eq(game_id))
+        .select(prs_dsl::player_id)
+        .distinct()
+        .load::<i64>(conn)?;
+    sync_player_progress(conn, game_id, &player_ids)?;
+    Ok(total_exercises)
+}
+
+/// Recomputes the `progress` of the given players' registrations in a game: the number of
+/// distinct exercises they first-solved that count towards the game (see `sync_game_exercise_totals`).
+/// Runs on the given connection so it can take part in the caller's transaction.
+pub fn sync_player_progress(
+    conn: &mut PgConnection,
+    game_id: i64,
+    player_ids: &[i64],
+) -> QueryResult<()> {
+    for &player_id in player_ids {
+        let solved = sub_dsl::submissions
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::first_solution.eq(true))
+            .filter(counts_in_game(game_id))
+            .select(count_distinct(sub_dsl::exercise_id))
+            .get_result::<i64>(conn)?;
+        diesel::update(
+            prs_dsl::player_registrations
+                .filter(prs_dsl::game_id.eq(game_id))
+                .filter(prs_dsl::player_id.eq(player_id)),
+        )
+        .set(prs_dsl::progress.eq(solved as i32))
+        .execute(conn)?;
+    }
+    Ok(())
+}
+
+/// Checks that a player exists.
+/// Returns AppError::NotFound if the player doesn't exist.
+fn ensure_player_exists(conn: &mut PgConnection, player_id: i64) -> Result<(), AppError> {
+    let player_exists =
+        diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)?;
+    if !player_exists {
+        error!("Player with ID {} not found.", player_id);
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} not found.",
+            player_id
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a player is actively registered (has not left) in a game.
+/// Runs on the given connection so it can take part in the caller's transaction.
+/// Returns AppError::NotFound if the game or the player doesn't exist.
+/// Returns AppError::Forbidden if the player never joined the game or has left it.
+pub fn ensure_player_registered(
+    conn: &mut PgConnection,
+    player_id: i64,
+    game_id: i64,
+) -> Result<(), AppError> {
+    let game_exists =
+        diesel::select(exists(games_dsl::games.find(game_id))).get_result::<bool>(conn)?;
+    if !game_exists {
+        error!("Game with ID {} not found.", game_id);
+        return Err(AppError::NotFound(format!(
+            "Game with ID {} not found.",
+            game_id
+        )));
+    }
+    ensure_player_exists(conn, player_id)?;
+
+    let registered = diesel::select(exists(
+        prs_dsl::player_registrations
+            .filter(prs_dsl::player_id.eq(player_id))
+            .filter(prs_dsl::game_id.eq(game_id))
+            .filter(prs_dsl::left_at.is_null()),
+    ))
+    .get_result::<bool>(conn)?;
+    if !registered {
+        warn!(
+            "Player {} is not registered in game {} (or has left it).",
+            player_id, game_id
+        );
+        return Err(AppError::Forbidden(format!(
+            "Player {} not registered in game {}.",
+            player_id, game_id
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a player is actively registered (has not left) in some game of the exercise's course.
+/// Returns AppError::NotFound if the exercise or the player doesn't exist.
+/// Returns AppError::Forbidden if the player is not registered in any such game.
+pub fn ensure_player_registered_for_exercise(
+    conn: &mut PgConnection,
+    player_id: i64,
+    exercise_id: i64,
+) -> Result<(), AppError> {
+    let course_id = exercises_dsl::exercises
+        .inner_join(modules_dsl::modules)
+        .filter(exercises_dsl::id.eq(exercise_id))
+        .select(modules_dsl::course_id)
+        .first::<i64>(conn)
+        .optional()?;
+    let Some(course_id) = course_id else {
+        error!("Exercise with ID {} not found.", exercise_id);
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    };
+    ensure_player_exists(conn, player_id)?;
+
+    let registered = diesel::select(exists(
+        prs_dsl::player_registrations
+            .inner_join(games_dsl::games)
+            .filter(prs_dsl::player_id.eq(player_id))
+            .filter(games_dsl::course_id.eq(course_id))
+            .filter(prs_dsl::left_at.is_null()),
+    ))
+    .get_result::<bool>(conn)?;
+    if !registered {
+        warn!(
+            "Player {} is not registered in any game containing exercise {}.",
+            player_id, exercise_id
+        );
+        return Err(AppError::Forbidden(format!(
+            "Player {} not registered in a game containing exercise {}.",
+            player_id, exercise_id
+        )));
+    }
+    Ok(())
+}
+
+/// Number of equal-width ranges `percentage_buckets` splits 0-100 into.
+pub const PERCENTAGE_BUCKETS: i64 = 10;
+
+/// A range of percentages (`min` to `max`, inclusive) and how many values fell into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentageBucket {
+    pub min: i32,
+    pub max: i32,
+    pub count: i64,
+}
+
+/// Distributes `(percentage, occurrences)` pairs into `PERCENTAGE_BUCKETS` ranges of width 10
+/// (0-9, 10-19, ..., 80-89), the last range (90-100) also holding 100. Percentages outside 0-100
+/// land in the nearest range. Every range is returned in ascending order, empty ones with a count of 0.
+pub fn percentage_buckets(values: impl IntoIterator<Item = (i64, i64)>) -> Vec<PercentageBucket> {
+    let width = 100 / PERCENTAGE_BUCKETS;
+    let mut buckets: Vec<PercentageBucket> = (0..PERCENTAGE_BUCKETS)
+        .map(|bucket| PercentageBucket {
+            min: (bucket * width) as i32,
+            max: if bucket == PERCENTAGE_BUCKETS - 1 {
+                100
+            } else {
+                (bucket * width + width - 1) as i32
+            },
+            count: 0,
+        })
+        .collect();
+    for (percentage, occurrences) in values {
+        let bucket = (percentage / width).clamp(0, PERCENTAGE_BUCKETS - 1);
+        buckets[bucket as usize].count += occurrences;
+    }
+    buckets
+}
+
+/// Points per solved exercise unless the course's rule results define `score.exercise_points`.
+pub const DEFAULT_EXERCISE_POINTS: i64 = 10;
+/// Points per obtained reward unless the course's rule results define `score.reward_points`.
+pub const DEFAULT_REWARD_POINTS: i64 = 5;
+/// Points deducted per hint unlock unless the course's rule results define `score.unlock_penalty`.
+pub const DEFAULT_UNLOCK_PENALTY: i64 = 2;
+
+/// Point values of `compute_player_score`, read from the optional `score` object of a course's
+/// `gamification_rule_results`, e.g. `{"score": {"exercise_points": 10, "reward_points": 5, "unlock_penalty": 2}}`.
+/// Missing values, or rule results that are not JSON, fall back to the defaults.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScoreRules {
+    pub exercise_points: i64,
+    pub reward_points: i64,
+    pub unlock_penalty: i64,
+}
+
+impl ScoreRules {
+    pub fn from_rule_results(rule_results: &str) -> Self {
+        let score = serde_json::from_str::<JsonValue>(rule_results)
+            .ok()
+            .and_then(|rules| rules.get("score").cloned())
+            .unwrap_or(JsonValue::Null);
+        let points = |key: &str, default: i64| {
+            score
+                .get(key)
+                .and_then(JsonValue::as_i64)
+                .unwrap_or(default)
+        };
+        Self {
+            exercise_points: points("exercise_points", DEFAULT_EXERCISE_POINTS),
+            reward_points: points("reward_points", DEFAULT_REWARD_POINTS),
+            unlock_penalty: points("unlock_penalty", DEFAULT_UNLOCK_PENALTY),
+        }
+    }
+}
+
+/// Computes a player's effective score in a game:
+/// * `base`: points for each exercise counting towards the game (see [`counts_in_game`])
+///   with a submission reaching the game's success threshold,
+/// * `rewards`: points for each reward obtained in the game (counting repeats),
+/// * `penalties`: points for each exercise of the course unlocked before the player solved it
+///   in this game (unlocks made by a correct submission itself are not penalised).
+///
+/// Returns AppError::NotFound if the player was never registered in the game.
+pub fn compute_player_score(
+    conn: &mut PgConnection,
+    player_id: i64,
+    game_id: i64,
+) -> Result<PlayerScoreResponse, AppError> {
+    let registration = prs_dsl::player_registrations
+        .inner_join(games_dsl::games.inner_join(courses_dsl::courses))
+        .filter(prs_dsl::player_id.eq(player_id))
+        .filter(prs_dsl::game_id.eq(game_id))
+        .select((
+            games_dsl::course_id,
+            courses_dsl::gamification_rule_results,
+            games_dsl::success_threshold,
+        ))
+        .first::<(i64, String, Option<f64>)>(conn)
+        .optional()?;
+    let Some((course_id, rule_results, threshold)) = registration else {
+        error!(
+            "Player {} is not registered in game {}. Cannot compute score.",
+            player_id, game_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} is not registered in game with ID {}.",
+            player_id, game_id
+        )));
+    };
+    let rules = ScoreRules::from_rule_results(&rule_results);
+    debug!("Scoring game {} with {:?}", game_id, rules);
+
+    let first_solved_at: HashMap<i64, DateTime<Utc>> = sub_dsl::submissions
+        .filter(sub_dsl::player_id.eq(player_id))
+        .filter(sub_dsl::game_id.eq(game_id))
+        .filter(sub_dsl::result.ge(success_threshold(threshold)))
+        .filter(counts_in_game(game_id))
+        .group_by(sub_dsl::exercise_id)
+        .select((
+            sub_dsl::exercise_id,
+            diesel::dsl::min(sub_dsl::submitted_at),
+        ))
+        .load::<(i64, Option<DateTime<Utc>>)>(conn)?
+        .into_iter()
+        .filter_map(|(exercise_id, solved_at)| solved_at.map(|at| (exercise_id, at)))
+        .collect();
+
+    let reward_count = pr_rewards_dsl::player_rewards
+        .filter(pr_rewards_dsl::player_id.eq(player_id))
+        .filter(pr_rewards_dsl::game_id.eq(game_id))
+        .select(diesel::dsl::sum(pr_rewards_dsl::count))
+        .first::<Option<i64>>(conn)?
+        .unwrap_or(0);
+
+    let penalised_unlocks = pus_dsl::player_unlocks
+        .inner_join(exercises_dsl::exercises.inner_join(modules_dsl::modules))
+        .filter(pus_dsl::player_id.eq(player_id))
+        .filter(modules_dsl::course_id.eq(course_id))
+        .select((pus_dsl::exercise_id, pus_dsl::unlocked_at))
+        .load::<(i64, DateTime<Utc>)>(conn)?
+        .into_iter()
+        .filter(|(exercise_id, unlocked_at)| {
+            first_solved_at
+                .get(exercise_id)
+                .is_none_or(|solved_at| solved_at > unlocked_at)
+        })
+        .count() as i64;
+
+    let base = first_solved_at.len() as i64 * rules.exercise_points;
+    let rewards = reward_count * rules.reward_points;
+    let penalties = penalised_unlocks * rules.unlock_penalty;
+    Ok(PlayerScoreResponse {
+        base,
+        rewards,
+        penalties,
+        total: base + rewards - penalties,
+    })
+}
+
+/// Picks the language a player is registered in when none was requested,
+/// preferring the configured languages the game's course offers.
+/// Returns AppError::NotFound if the game doesn't exist.
+pub fn default_registration_language(
+    conn: &mut PgConnection,
+    game_id: i64,
+    default_languages: &DefaultLanguages,
+) -> Result<String, AppError> {
+    let course_languages = games_dsl::games
+        .find(game_id)
+        .inner_join(courses_dsl::courses)
+        .select(courses_dsl::languages)
+        .first::<String>(conn)
+        .optional()?;
+    let Some(course_languages) = course_languages else {
+        error!("Game with ID {} not found.", game_id);
+        return Err(AppError::NotFound(format!(
+            "Game with ID {} not found.",
+            game_id
+        )));
+    };
+
+    let language = default_languages.resolve(&course_languages);
+    debug!(
+        "Default registration language for game {}: {} (course offers '{}')",
+        game_id, language, course_languages
+    );
+    Ok(language)
+}
@@ -0,0 +1,97 @@
+use super::helper;
+use crate::auth::Principal;
+use crate::db;
+use crate::errors::AppError;
+use crate::model::identity::{IdentityRole, WhoAmIResponse};
+use crate::response::ApiResponse;
+use crate::schema::{instructors::dsl as instructors_dsl, players::dsl as players_dsl};
+use axum::extract::State;
+use deadpool_diesel::postgres::Pool;
+use diesel::prelude::*;
+use tracing::log::warn;
+use tracing::{info, instrument};
+
+/// Resolves the authenticated caller to its local instructor or player record.
+/// The email claim of the validated token is matched against `instructors` first, then `players`.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `WhoAmIResponse`: The resolved local ID, role (`admin`, `instructor` or `player`) and email (200 OK).
+/// * `401 Unauthorized`: If the request carries no user identity.
+/// * `404 Not Found`: If no local instructor or player matches the token.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, principal))]
+pub async fn whoami(
+    State(pool): State<Pool>,
+    principal: Principal,
+) -> Result<ApiResponse<WhoAmIResponse>, AppError> {
+    let (subject, email) = match principal {
+        Principal::User { subject, email } => (subject, email),
+        Principal::Trusted | Principal::ServiceAccount => {
+            warn!("whoami called without a user identity");
+            return Err(AppError::Unauthorized(
+                "Request does not carry a user identity.".to_string(),
+            ));
+        }
+    };
+
+    info!("Resolving local identity for subject {}", subject);
+
+    let instructor_id = helper::run_query(&pool, {
+        let email = email.clone();
+        move |conn| {
+            instructors_dsl::instructors
+                .filter(instructors_dsl::email.eq(email))
+                .select(instructors_dsl::id)
+                .first::<i64>(conn)
+                .optional()
+        }
+    })
+    .await?;
+
+    if let Some(id) = instructor_id {
+        let role = if id == 0 {
+            IdentityRole::Admin
+        } else {
+            IdentityRole::Instructor
+        };
+        info!(
+            "Subject {} resolved to instructor {} ({:?})",
+            subject, id, role
+        );
+        return Ok(ApiResponse::ok(WhoAmIResponse { id, role, email }));
+    }
+
+    let player_id = helper::run_query(&pool, {
+        let email = email.clone();
+        move |conn| {
+            players_dsl::players
+                .filter(db::lower(players_dsl::email).eq(db::normalize_email(&email)))
+                .filter(players_dsl::deleted_at.is_null())
+                .select(players_dsl::id)
+                .first::<i64>(conn)
+                .optional()
+        }
+    })
+    .await?;
+
+    match player_id {
+        Some(id) => {
+            info!("Subject {} resolved to player {}", subject, id);
+            Ok(ApiResponse::ok(WhoAmIResponse {
+                id,
+                role: IdentityRole::Player,
+                email,
+            }))
+        }
+        None => {
+            warn!(
+                "Subject {} does not match any local instructor or player",
+                subject
+            );
+            Err(AppError::NotFound(format!(
+                "No local instructor or player found for subject {}.",
+                subject
+            )))
+        }
+    }
+}
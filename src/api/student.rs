@@ -1,59 +1,186 @@
 use super::helper;
+use crate::auth::ActingPlayer;
+use crate::config::{MaxCheckpoints, MaxCodeLength};
+use crate::evaluator::{EvaluationRequest, Evaluator};
+use crate::model::editor::ExerciseMode;
 use crate::model::student::{
-    CourseDataResponse, ExerciseDataResponse, GameMetadata, LastSolutionResponse,
-    ModuleDataResponse, NewPlayerReward, NewPlayerUnlock, NewSubmission,
+    CatalogPageResponse, CheckpointSummary, CompletionStatusResponse, CourseDataResponse,
+    CourseModuleData, ExerciseDataResponse, ExerciseLockState, ExerciseWorkspaceResponse,
+    GameMetadata, LastSolutionResponse, ModuleDataResponse, NewGameCheckpoint, NewPlayerReward,
+    NewPlayerUnlock, NewSubmission, ParsedCourseDataResponse, PlayerRankResponse,
+    PlayerRegistrationResponse, PlayerScoreResponse, PlayerStreakResponse,
+    StudentCourseTreeResponse, StudentExerciseTreeNode, StudentModuleTreeNode, SubmitResult,
 };
 use crate::payloads::student::{
-    GetCourseDataParams, GetExerciseDataParams, GetLastSolutionParams, GetModuleDataParams,
-    GetPlayerGamesParams, JoinGamePayload, LeaveGamePayload, LoadGamePayload, SaveGamePayload,
-    SetGameLangPayload, SubmitSolutionPayload, UnlockPayload,
+    CatalogPageParams, GetAvailableGamesParams, GetCompletionStatusParams, GetCourseDataParams,
+    GetCourseModulesDataParams, GetCourseTreeParams, GetExerciseDataParams,
+    GetExerciseLockStatesParams, GetExerciseWorkspaceParams, GetLastSolutionParams,
+    GetModuleDataParams, GetPlayerGamesParams, GetPlayerRankParams, GetPlayerRegistrationsParams,
+    GetPlayerScoreParams, GetPlayerStreakParams, JoinGamePayload, LeaveGamePayload,
+    ListCheckpointsParams, ListExercisesByTagParams, LoadCheckpointPayload, LoadGamePayload,
+    SaveCheckpointPayload, SaveGamePayload, SetGameLangPayload, SubmitSolutionPayload,
+    UnlockManyPayload, UnlockPayload,
 };
 use crate::{
     errors::AppError,
     model::student::NewPlayerRegistration,
     response::ApiResponse,
     schema::{
-        courses::dsl as courses_dsl, exercises::dsl as exercises_dsl, games::dsl as games_dsl,
-        modules::dsl as modules_dsl, player_registrations::dsl as prs_dsl,
-        player_unlocks::dsl as pus_dsl, players::dsl as players_dsl, rewards::dsl as rewards_dsl,
-        submissions::dsl as sub_dsl,
+        courses::dsl as courses_dsl, exercises::dsl as exercises_dsl,
+        game_checkpoints::dsl as gc_dsl, games::dsl as games_dsl, invites::dsl as invites_dsl,
+        modules::dsl as modules_dsl, player_groups::dsl as pg_dsl,
+        player_registrations::dsl as prs_dsl, player_unlocks::dsl as pus_dsl,
+        players::dsl as players_dsl, rewards::dsl as rewards_dsl, submissions::dsl as sub_dsl,
     },
 };
 use anyhow::anyhow;
 use axum::extract::{Path, Query};
-use axum::{extract::State, response::Json};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use axum::{Extension, extract::State, response::Json};
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use deadpool_diesel::postgres::Pool;
 use diesel::dsl::now;
 use diesel::prelude::*;
 use diesel::result::{DatabaseErrorKind, Error as DieselError};
 use serde_json::Value as JsonValue;
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::log::warn;
 use tracing::{debug, error, info, instrument};
 
-/// Queries all available games that are public and active.
+/// Default number of IDs returned per page by the catalog endpoints.
+const DEFAULT_CATALOG_PAGE_SIZE: i64 = 50;
+/// Maximum number of IDs returned per page by the catalog endpoints.
+const MAX_CATALOG_PAGE_SIZE: i64 = 100;
+
+/// Turns up to `limit + 1` IDs fetched in ascending order into a page with its continuation cursor.
+fn catalog_page(mut ids: Vec<i64>, limit: i64) -> CatalogPageResponse {
+    let next_cursor = if ids.len() as i64 > limit {
+        ids.truncate(limit as usize);
+        ids.last().copied()
+    } else {
+        None
+    };
+    CatalogPageResponse { ids, next_cursor }
+}
+
+/// Queries available games that are public and active, paged by game ID.
+///
+/// With a `player_id`, the active private games the player is eligible for are included too.
+/// A player is eligible for a private game when they are a current member of a group
+/// that an invite pairs with that game.
+///
+/// Query Parameters:
+/// * `player_id`: Optional player whose eligible private games are added.
+/// * `after_id`: Optional cursor; only games with a greater ID are returned.
+/// * `limit`: Optional page size (default 50, capped at 100).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `Vec<i64>`: List of game IDs (200 OK).
+/// * `CatalogPageResponse`: Game IDs in ascending order and the cursor for the next page, if any (200 OK).
+/// * `403 Forbidden`: If `player_id` is given and the caller is not authenticated as that player.
 /// * `500 Internal Server Error`: If a database error occurs.
-#[instrument(skip(pool))]
+#[instrument(skip(pool, acting_player, params))]
 pub async fn get_available_games(
     State(pool): State<Pool>,
-) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    acting_player: Result<ActingPlayer, AppError>,
+    Query(params): Query<GetAvailableGamesParams>,
+) -> Result<ApiResponse<CatalogPageResponse>, AppError> {
     info!("Fetching available games");
+    debug!("Get available games params: {:?}", params);
 
-    let game_ids = helper::run_query(&pool, |conn_sync| {
-        games_dsl::games
-            .filter(games_dsl::active.eq(true).and(games_dsl::public.eq(true)))
+    let player_id = params.player_id;
+    if let Some(player_id) = player_id {
+        acting_player?.ensure_acts_as(player_id)?;
+    }
+
+    let after_id = params.after_id.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_CATALOG_PAGE_SIZE)
+        .clamp(1, MAX_CATALOG_PAGE_SIZE);
+
+    let game_ids = helper::run_query(&pool, move |conn_sync| {
+        let mut query = games_dsl::games
+            .filter(games_dsl::active.eq(true))
+            .filter(games_dsl::id.gt(after_id))
+            .order_by(games_dsl::id.asc())
+            .limit(limit + 1)
             .select(games_dsl::id)
+            .into_boxed();
+        query = match player_id {
+            Some(player_id) => {
+                let player_group_ids = pg_dsl::player_groups
+                    .filter(pg_dsl::player_id.eq(player_id))
+                    .filter(pg_dsl::left_at.is_null())
+                    .select(pg_dsl::group_id);
+                let invited_game_ids = invites_dsl::invites
+                    .filter(invites_dsl::group_id.eq_any(player_group_ids.nullable()))
+                    .select(invites_dsl::game_id);
+                query.filter(
+                    games_dsl::public
+                        .eq(true)
+                        .or(games_dsl::id.nullable().eq_any(invited_game_ids)),
+                )
+            }
+            None => query.filter(games_dsl::public.eq(true)),
+        };
+        query.load::<i64>(conn_sync)
+    })
+    .await?;
+
+    let page = catalog_page(game_ids, limit);
+    info!(
+        "Successfully fetched {} available game IDs after {}",
+        page.ids.len(),
+        after_id
+    );
+    Ok(ApiResponse::ok(page))
+}
+
+/// Queries public courses, paged by course ID.
+///
+/// Query Parameters:
+/// * `after_id`: Optional cursor; only courses with a greater ID are returned.
+/// * `limit`: Optional page size (default 50, capped at 100).
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `CatalogPageResponse`: Course IDs in ascending order and the cursor for the next page, if any (200 OK).
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_public_courses(
+    State(pool): State<Pool>,
+    Query(params): Query<CatalogPageParams>,
+) -> Result<ApiResponse<CatalogPageResponse>, AppError> {
+    info!("Fetching public courses");
+    debug!("Get public courses params: {:?}", params);
+
+    let after_id = params.after_id.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_CATALOG_PAGE_SIZE)
+        .clamp(1, MAX_CATALOG_PAGE_SIZE);
+
+    let course_ids = helper::run_query(&pool, move |conn_sync| {
+        courses_dsl::courses
+            .filter(courses_dsl::public.eq(true))
+            .filter(courses_dsl::id.gt(after_id))
+            .order_by(courses_dsl::id.asc())
+            .limit(limit + 1)
+            .select(courses_dsl::id)
             .load::<i64>(conn_sync)
     })
     .await?;
 
-    info!("Successfully fetched {} available game IDs", game_ids.len());
-    Ok(ApiResponse::ok(game_ids))
+    let page = catalog_page(course_ids, limit);
+    info!(
+        "Successfully fetched {} public course IDs after {}",
+        page.ids.len(),
+        after_id
+    );
+    Ok(ApiResponse::ok(page))
 }
 
 /// Adds a player to a game.
@@ -101,35 +228,34 @@ pub async fn join_game(
             Ok(ApiResponse::ok(new_id))
         }
         Err(AppError::InternalServerError(ref err)) => {
-            if let Some(db_err) = err.downcast_ref::<DieselError>() {
-                if let DieselError::DatabaseError(kind, info) = db_err {
-                    match kind {
-                        DatabaseErrorKind::ForeignKeyViolation => {
-                            warn!(
-                                "Failed to join game due to foreign key violation for player_id: {} or game_id: {}. Details: {}",
-                                payload.player_id,
-                                payload.game_id,
-                                info.message()
-                            );
-                            return Err(AppError::NotFound(format!(
-                                "Player with ID {} or Game with ID {} not found.",
-                                payload.player_id, payload.game_id,
-                            )));
-                        }
-                        DatabaseErrorKind::UniqueViolation => {
-                            warn!(
-                                "Failed to join game due to unique constraint violation for player_id: {} and game_id: {}. Details: {}",
-                                payload.player_id,
-                                payload.game_id,
-                                info.message()
-                            );
-                            return Err(AppError::Conflict(format!(
-                                "Player {} is already registered in game {}.",
-                                payload.player_id, payload.game_id
-                            )));
-                        }
-                        _ => {}
+            if let Some(DieselError::DatabaseError(kind, info)) = err.downcast_ref::<DieselError>()
+            {
+                match kind {
+                    DatabaseErrorKind::ForeignKeyViolation => {
+                        warn!(
+                            "Failed to join game due to foreign key violation for player_id: {} or game_id: {}. Details: {}",
+                            payload.player_id,
+                            payload.game_id,
+                            info.message()
+                        );
+                        return Err(AppError::NotFound(format!(
+                            "Player with ID {} or Game with ID {} not found.",
+                            payload.player_id, payload.game_id,
+                        )));
                     }
+                    DatabaseErrorKind::UniqueViolation => {
+                        warn!(
+                            "Failed to join game due to unique constraint violation for player_id: {} and game_id: {}. Details: {}",
+                            payload.player_id,
+                            payload.game_id,
+                            info.message()
+                        );
+                        return Err(AppError::Conflict(format!(
+                            "Player {} is already registered in game {}.",
+                            payload.player_id, payload.game_id
+                        )));
+                    }
+                    _ => {}
                 }
             }
             Err(insert_result.unwrap_err())
@@ -140,11 +266,16 @@ pub async fn join_game(
 
 /// Saves a game state for a specific player registration.
 ///
+/// If `if_saved_at` is given, the state is only written when the stored `saved_at` is not
+/// newer than it, so concurrent clients cannot silently overwrite each other's saves.
+///
 /// Request Body: `SaveGamePayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
 /// * `bool`: true indicating success (200 OK).
+/// * `403 Forbidden`: If the player has left the game.
 /// * `404 Not Found`: If the player registration ID does not exist.
+/// * `409 Conflict`: If the stored game state was saved after `if_saved_at`.
 /// * `500 Internal Server Error`: If a database error occurs or if the update affects an unexpected number of rows.
 #[instrument(skip(pool, payload))]
 pub async fn save_game(
@@ -157,19 +288,67 @@ pub async fn save_game(
     );
     debug!("Save game payload: {:?}", payload);
 
+    let registration_id = payload.player_registrations_id;
+    let if_saved_at = payload.if_saved_at;
+
+    let left_at = helper::run_query(&pool, move |conn| {
+        prs_dsl::player_registrations
+            .find(registration_id)
+            .select(prs_dsl::left_at)
+            .first::<Option<DateTime<Utc>>>(conn)
+            .optional()
+    })
+    .await?;
+    match left_at {
+        None => {
+            error!(
+                "Not found, game state not saved for registration_id: {}",
+                registration_id
+            );
+            return Err(AppError::NotFound(format!(
+                "Player registration with ID {} not found",
+                registration_id
+            )));
+        }
+        Some(Some(left_at)) => {
+            warn!(
+                "Rejected save for registration_id: {} (player left the game at {})",
+                registration_id, left_at
+            );
+            return Err(AppError::Forbidden(format!(
+                "Player not registered in game (registration {} was left).",
+                registration_id
+            )));
+        }
+        Some(None) => {}
+    }
+
     let rows_affected = helper::run_query(&pool, move |conn_sync| {
-        let target =
-            prs_dsl::player_registrations.filter(prs_dsl::id.eq(payload.player_registrations_id));
+        let target = prs_dsl::player_registrations.filter(prs_dsl::id.eq(registration_id));
+        let changes = (
+            prs_dsl::game_state.eq(payload.game_state),
+            prs_dsl::saved_at.eq(now),
+        );
 
-        diesel::update(target)
-            .set((
-                prs_dsl::game_state.eq(payload.game_state),
-                prs_dsl::saved_at.eq(now),
-            ))
-            .execute(conn_sync)
+        match if_saved_at {
+            Some(expected_saved_at) => {
+                diesel::update(target.filter(prs_dsl::saved_at.le(expected_saved_at)))
+                    .set(changes)
+                    .execute(conn_sync)
+            }
+            None => diesel::update(target).set(changes).execute(conn_sync),
+        }
     })
     .await?;
 
+    if rows_affected == 0 && if_saved_at.is_some() {
+        warn!(
+            "Rejected stale save for registration_id: {} (expected saved_at {:?})",
+            registration_id, if_saved_at
+        );
+        return Err(AppError::Conflict("game state changed".to_string()));
+    }
+
     match rows_affected {
         0 => {
             error!(
@@ -234,13 +413,235 @@ pub async fn load_game(
     Ok(ApiResponse::ok(loaded_game_state))
 }
 
+/// Maximum length in characters of a checkpoint name.
+const MAX_CHECKPOINT_NAME_LENGTH: usize = 100;
+
+/// Saves a game state as a named checkpoint of a player registration, next to the single slot
+/// of `save_game`. Saving under an existing name overwrites that checkpoint.
+///
+/// Request Body: `SaveCheckpointPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true indicating success (200 OK).
+/// * `403 Forbidden`: If the player has left the game.
+/// * `404 Not Found`: If the player registration ID does not exist.
+/// * `409 Conflict`: If the registration already holds the maximum number of checkpoints (`--max-checkpoints-per-registration`).
+/// * `422 Unprocessable Entity`: If the name is empty or longer than 100 characters.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, max_checkpoints, payload))]
+pub async fn save_checkpoint(
+    State(pool): State<Pool>,
+    Extension(max_checkpoints): Extension<Arc<MaxCheckpoints>>,
+    Json(payload): Json<SaveCheckpointPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    info!(
+        "Attempting to save checkpoint '{}' for registration_id: {}",
+        payload.name, payload.player_registrations_id
+    );
+    debug!("Save checkpoint payload: {:?}", payload);
+
+    let registration_id = payload.player_registrations_id;
+    let name = normalize_checkpoint_name(&payload.name)?;
+    let game_state = payload.game_state;
+
+    helper::interact(&pool, {
+        let name = name.clone();
+        move |conn| {
+            conn.transaction(|tx_conn| {
+                // locking the registration serializes concurrent saves against the checkpoint limit
+                let left_at = prs_dsl::player_registrations
+                    .find(registration_id)
+                    .select(prs_dsl::left_at)
+                    .for_update()
+                    .first::<Option<DateTime<Utc>>>(tx_conn)
+                    .optional()?;
+                match left_at {
+                    None => {
+                        return Err(AppError::NotFound(format!(
+                            "Player registration with ID {} not found",
+                            registration_id
+                        )));
+                    }
+                    Some(Some(_)) => {
+                        return Err(AppError::Forbidden(format!(
+                            "Player not registered in game (registration {} was left).",
+                            registration_id
+                        )));
+                    }
+                    Some(None) => {}
+                }
+
+                let checkpoints = gc_dsl::game_checkpoints
+                    .filter(gc_dsl::player_registrations_id.eq(registration_id));
+                let overwrites: bool = diesel::select(diesel::dsl::exists(
+                    checkpoints.filter(gc_dsl::name.eq(&name)),
+                ))
+                .get_result(tx_conn)?;
+                if !overwrites {
+                    let existing = checkpoints.count().get_result::<i64>(tx_conn)?;
+                    max_checkpoints.ensure_room(existing)?;
+                }
+
+                diesel::insert_into(gc_dsl::game_checkpoints)
+                    .values(&NewGameCheckpoint {
+                        player_registrations_id: registration_id,
+                        name,
+                        game_state,
+                    })
+                    .on_conflict((gc_dsl::player_registrations_id, gc_dsl::name))
+                    .do_update()
+                    .set((
+                        gc_dsl::game_state.eq(diesel::upsert::excluded(gc_dsl::game_state)),
+                        gc_dsl::saved_at.eq(now),
+                    ))
+                    .execute(tx_conn)?;
+                Ok(())
+            })
+        }
+    })
+    .await?
+    .inspect_err(|e| {
+        warn!(
+            "Checkpoint '{}' not saved for registration_id: {}: {}",
+            name, registration_id, e
+        )
+    })?;
+
+    info!(
+        "Successfully saved checkpoint '{}' for registration_id: {}",
+        name, registration_id
+    );
+    Ok(ApiResponse::ok(true))
+}
+
+/// Queries the game state saved in a named checkpoint of a player registration.
+///
+/// Request Body: `LoadCheckpointPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `serde_json::Value`: The saved game state (200 OK).
+/// * `404 Not Found`: If the registration has no checkpoint with that name.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn load_checkpoint(
+    State(pool): State<Pool>,
+    Json(payload): Json<LoadCheckpointPayload>,
+) -> Result<ApiResponse<JsonValue>, AppError> {
+    info!(
+        "Attempting to load checkpoint '{}' for registration_id: {}",
+        payload.name, payload.player_registrations_id
+    );
+
+    let registration_id = payload.player_registrations_id;
+    let name = payload.name.trim().to_string();
+
+    let game_state = helper::run_query(&pool, {
+        let name = name.clone();
+        move |conn_sync| {
+            gc_dsl::game_checkpoints
+                .filter(gc_dsl::player_registrations_id.eq(registration_id))
+                .filter(gc_dsl::name.eq(name))
+                .select(gc_dsl::game_state)
+                .first::<JsonValue>(conn_sync)
+                .optional()
+        }
+    })
+    .await?
+    .ok_or_else(|| {
+        warn!(
+            "Checkpoint '{}' not found for registration_id: {}",
+            name, registration_id
+        );
+        AppError::NotFound(format!(
+            "Checkpoint '{}' not found for player registration with ID {}",
+            name, registration_id
+        ))
+    })?;
+
+    info!(
+        "Successfully loaded checkpoint '{}' for registration_id: {}",
+        name, registration_id
+    );
+    Ok(ApiResponse::ok(game_state))
+}
+
+/// Lists the named checkpoints of a player registration, most recently saved first.
+///
+/// Query Parameters:
+/// * `player_registrations_id`: The ID of the player registration.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<CheckpointSummary>`: Name and `saved_at` of each checkpoint, empty if there are none (200 OK).
+/// * `404 Not Found`: If the player registration ID does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn list_checkpoints(
+    State(pool): State<Pool>,
+    Query(params): Query<ListCheckpointsParams>,
+) -> Result<ApiResponse<Vec<CheckpointSummary>>, AppError> {
+    let registration_id = params.player_registrations_id;
+    info!(
+        "Listing checkpoints for registration_id: {}",
+        registration_id
+    );
+    debug!("List checkpoints params: {:?}", params);
+
+    let checkpoints = helper::run_query(&pool, move |conn_sync| {
+        let registration_exists = diesel::select(diesel::dsl::exists(
+            prs_dsl::player_registrations.find(registration_id),
+        ))
+        .get_result::<bool>(conn_sync)?;
+        if !registration_exists {
+            return Ok(None);
+        }
+        gc_dsl::game_checkpoints
+            .filter(gc_dsl::player_registrations_id.eq(registration_id))
+            .order_by((gc_dsl::saved_at.desc(), gc_dsl::name.asc()))
+            .select((gc_dsl::name, gc_dsl::saved_at))
+            .load::<CheckpointSummary>(conn_sync)
+            .map(Some)
+    })
+    .await?
+    .ok_or_else(|| {
+        error!(
+            "Cannot list checkpoints: registration_id {} not found",
+            registration_id
+        );
+        AppError::NotFound(format!(
+            "Player registration with ID {} not found",
+            registration_id
+        ))
+    })?;
+
+    info!(
+        "Found {} checkpoints for registration_id: {}",
+        checkpoints.len(),
+        registration_id
+    );
+    Ok(ApiResponse::ok(checkpoints))
+}
+
+/// Trims a checkpoint name, rejecting empty or overlong names with 422 Unprocessable Entity.
+fn normalize_checkpoint_name(name: &str) -> Result<String, AppError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() || trimmed.chars().count() > MAX_CHECKPOINT_NAME_LENGTH {
+        warn!("Rejecting invalid checkpoint name '{}'.", name);
+        return Err(AppError::UnprocessableEntity(format!(
+            "Checkpoint name must be between 1 and {} characters.",
+            MAX_CHECKPOINT_NAME_LENGTH
+        )));
+    }
+    Ok(trimmed.to_string())
+}
+
 /// Marks a player's registration in a game as inactive by setting the 'left_at' timestamp.
+/// Leaving a game the player has already left is a no-op, so clients can safely retry.
 ///
 /// Request Body: `LeaveGamePayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `()`: Empty success response (200 OK).
-/// * `404 Not Found`: If no active registration exists for the given player and game.
+/// * `()`: Empty success response, also if the registration was already left (200 OK).
+/// * `404 Not Found`: If no registration exists for the given player and game.
 /// * `500 Internal Server Error`: If a database error occurs or if the update affects an unexpected number of rows.
 #[instrument(skip(pool, payload))]
 pub async fn leave_game(
@@ -253,28 +654,40 @@ pub async fn leave_game(
     );
     debug!("Leave game payload: {:?}", payload);
 
-    let rows_affected = helper::run_query(&pool, move |conn_sync| {
-        let target = prs_dsl::player_registrations.filter(
+    let (rows_affected, registration_exists) = helper::run_query(&pool, move |conn_sync| {
+        let registration = prs_dsl::player_registrations.filter(
             prs_dsl::player_id
                 .eq(payload.player_id)
-                .and(prs_dsl::game_id.eq(payload.game_id))
-                .and(prs_dsl::left_at.is_null()),
+                .and(prs_dsl::game_id.eq(payload.game_id)),
         );
 
-        diesel::update(target)
+        let rows_affected = diesel::update(registration.filter(prs_dsl::left_at.is_null()))
             .set(prs_dsl::left_at.eq(now))
-            .execute(conn_sync)
+            .execute(conn_sync)?;
+        if rows_affected > 0 {
+            return Ok((rows_affected, true));
+        }
+        let registration_exists =
+            diesel::select(diesel::dsl::exists(registration)).get_result::<bool>(conn_sync)?;
+        Ok((rows_affected, registration_exists))
     })
     .await?;
 
     match rows_affected {
+        0 if registration_exists => {
+            info!(
+                "Player {} had already left game {}; nothing to do.",
+                payload.player_id, payload.game_id
+            );
+            Ok(ApiResponse::ok(()))
+        }
         0 => {
             error!(
-                "Player registration not found or already marked as left for player_id: {} and game_id: {}.",
+                "Player registration not found for player_id: {} and game_id: {}.",
                 payload.player_id, payload.game_id
             );
             Err(AppError::NotFound(format!(
-                "Active player registration not found for player ID {} and game ID {}",
+                "Player registration not found for player ID {} and game ID {}",
                 payload.player_id, payload.game_id
             )))
         }
@@ -398,15 +811,23 @@ pub async fn set_game_lang(
     }
 }
 
-/// Retrieves player registration IDs for a given player.
-/// Can filter for active registrations only.
+/// Default number of registration IDs returned per page by `get_player_games`.
+const DEFAULT_PLAYER_GAMES_PAGE_SIZE: i64 = 50;
+/// Maximum number of registration IDs returned per page by `get_player_games`.
+const MAX_PLAYER_GAMES_PAGE_SIZE: i64 = 100;
+
+/// Retrieves player registration IDs for a given player, paged by registration ID.
+/// Can filter for active registrations only. Games the player has left are excluded unless `include_left` is set.
 ///
 /// Query Parameters:
 /// * `player_id`: The ID of the player.
-/// * `active`: If true, only return registrations where the game is active and the player has not left.
+/// * `active`: If true, only return registrations where the game is active.
+/// * `include_left`: Optional; if true, also return registrations the player has left (default false).
+/// * `offset`: Optional number of registrations to skip (default 0).
+/// * `limit`: Optional page size (default 50, capped at 100).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `Vec<i64>`: List of player_registrations IDs (200 OK).
+/// * `Vec<i64>`: List of player_registrations IDs in ascending order (200 OK).
 /// * `404 Not Found`: If the specified player_id does not exist.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
@@ -416,10 +837,11 @@ pub async fn get_player_games(
 ) -> Result<ApiResponse<Vec<i64>>, AppError> {
     let player_id = params.player_id;
     let only_active = params.active;
+    let include_left = params.include_left;
 
     info!(
-        "Fetching player registrations for player_id: {}. Active only: {}",
-        player_id, only_active
+        "Fetching player registrations for player_id: {}. Active only: {}. Include left: {}",
+        player_id, only_active, include_left
     );
     debug!("Get player games params: {:?}", params);
 
@@ -438,49 +860,118 @@ pub async fn get_player_games(
     }
     info!("Player {} found. Fetching registrations...", player_id);
 
-    let registration_ids = if !only_active {
-        helper::run_query(&pool, move |conn_sync| {
-            prs_dsl::player_registrations
-                .filter(prs_dsl::player_id.eq(player_id))
-                .select(prs_dsl::id)
-                .load::<i64>(conn_sync)
-        })
-        .await?
-    } else {
-        helper::run_query(&pool, move |conn_sync| {
-            prs_dsl::player_registrations
-                .filter(prs_dsl::player_id.eq(player_id))
-                .filter(prs_dsl::left_at.is_null())
-                .inner_join(games_dsl::games.on(prs_dsl::game_id.eq(games_dsl::id)))
-                .filter(games_dsl::active.eq(true))
-                .select(prs_dsl::id)
-                .load::<i64>(conn_sync)
-        })
-        .await?
-    };
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PLAYER_GAMES_PAGE_SIZE)
+        .clamp(1, MAX_PLAYER_GAMES_PAGE_SIZE);
+
+    let registration_ids = helper::run_query(&pool, move |conn_sync| {
+        let mut query = prs_dsl::player_registrations
+            .inner_join(games_dsl::games.on(prs_dsl::game_id.eq(games_dsl::id)))
+            .filter(prs_dsl::player_id.eq(player_id))
+            .select(prs_dsl::id)
+            .into_boxed();
+        if !include_left {
+            query = query.filter(prs_dsl::left_at.is_null());
+        }
+        if only_active {
+            query = query.filter(games_dsl::active.eq(true));
+        }
+        query
+            .order_by(prs_dsl::id.asc())
+            .offset(offset)
+            .limit(limit)
+            .load::<i64>(conn_sync)
+    })
+    .await?;
 
     info!(
-        "Successfully fetched {} registrations for player_id: {}",
+        "Successfully fetched {} registrations for player_id: {} (offset {}, limit {})",
         registration_ids.len(),
-        player_id
+        player_id,
+        offset,
+        limit
     );
     Ok(ApiResponse::ok(registration_ids))
 }
 
+/// Retrieves every registration of a player together with the registered game's title.
+/// Includes registrations the player has left, distinguished by a non-null `left_at`.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<PlayerRegistrationResponse>`: Registrations ordered by `joined_at`, newest first (200 OK).
+/// * `404 Not Found`: If the specified player_id does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_player_registrations(
+    State(pool): State<Pool>,
+    Query(params): Query<GetPlayerRegistrationsParams>,
+) -> Result<ApiResponse<Vec<PlayerRegistrationResponse>>, AppError> {
+    let player_id = params.player_id;
+
+    info!("Fetching registration details for player_id: {}", player_id);
+    debug!("Get player registrations params: {:?}", params);
+
+    let player_exists = helper::run_query(&pool, move |conn| {
+        diesel::select(diesel::dsl::exists(players_dsl::players.find(player_id)))
+            .get_result::<bool>(conn)
+    })
+    .await?;
+
+    if !player_exists {
+        error!("Player with ID {} not found.", player_id);
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} not found.",
+            player_id
+        )));
+    }
+
+    let registrations = helper::run_query(&pool, move |conn_sync| {
+        prs_dsl::player_registrations
+            .inner_join(games_dsl::games.on(prs_dsl::game_id.eq(games_dsl::id)))
+            .filter(prs_dsl::player_id.eq(player_id))
+            .order_by((prs_dsl::joined_at.desc(), prs_dsl::id.desc()))
+            .select((
+                prs_dsl::id,
+                prs_dsl::game_id,
+                games_dsl::title,
+                prs_dsl::language,
+                prs_dsl::progress,
+                prs_dsl::joined_at,
+                prs_dsl::left_at,
+            ))
+            .load::<PlayerRegistrationResponse>(conn_sync)
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} registration details for player_id: {}",
+        registrations.len(),
+        player_id
+    );
+    Ok(ApiResponse::ok(registrations))
+}
+
 /// Retrieves detailed metadata for a specific player registration and its associated game.
 ///
 /// Path Parameters:
 /// * `registration_id`: The ID of the player_registration record.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `GameMetadata`: The combined metadata (200 OK).
+/// * `GameMetadata`: The combined metadata (200 OK, with an `ETag` header).
+/// * `304 Not Modified`: If `If-None-Match` matches the current `ETag`.
 /// * `404 Not Found`: If the specified player_registration ID does not exist.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool))]
 pub async fn get_game_metadata(
     State(pool): State<Pool>,
+    headers: HeaderMap,
     Path(registration_id): Path<i64>,
-) -> Result<ApiResponse<GameMetadata>, AppError> {
+) -> Result<Response, AppError> {
     info!(
         "Fetching game metadata for registration_id: {}",
         registration_id
@@ -489,6 +980,7 @@ pub async fn get_game_metadata(
     type QueryResultTuple = (
         i64,                   // pr.id
         i32,                   // pr.progress
+        DateTime<Utc>,         // pr.saved_at
         DateTime<Utc>,         // pr.joined_at
         Option<DateTime<Utc>>, // pr.left_at
         String,                // pr.language
@@ -509,6 +1001,7 @@ pub async fn get_game_metadata(
             .select((
                 prs_dsl::id,
                 prs_dsl::progress,
+                prs_dsl::saved_at,
                 prs_dsl::joined_at,
                 prs_dsl::left_at,
                 prs_dsl::language,
@@ -528,23 +1021,24 @@ pub async fn get_game_metadata(
     let metadata = GameMetadata {
         registration_id: data.0,
         progress: data.1,
-        joined_at: data.2,
-        left_at: data.3,
-        language: data.4,
-        game_id: data.5,
-        game_title: data.6,
-        game_active: data.7,
-        game_description: data.8,
-        game_programming_language: data.9,
-        game_total_exercises: data.10,
-        game_start_date: data.11,
-        game_end_date: data.12,
+        saved_at: data.2,
+        joined_at: data.3,
+        left_at: data.4,
+        language: data.5,
+        game_id: data.6,
+        game_title: data.7,
+        game_active: data.8,
+        game_description: data.9,
+        game_programming_language: data.10,
+        game_total_exercises: data.11,
+        game_start_date: data.12,
+        game_end_date: data.13,
     };
     info!(
         "Successfully fetched game metadata for registration_id: {}",
         registration_id
     );
-    Ok(ApiResponse::ok(metadata))
+    Ok(ApiResponse::ok(metadata).into_conditional_response(&headers))
 }
 
 /// Retrieves course gamification data and relevant module IDs for a specific game and language.
@@ -574,39 +1068,7 @@ pub async fn get_course_data(
         game_id, language
     );
 
-    type CourseInfoTuple = (i64, String, String, String); // course_id, conditions, complex, results
-
-    let (course_id, conditions, complex_rules, results) =
-        helper::run_query(&pool, move |conn_sync| {
-            games_dsl::games
-                .filter(games_dsl::id.eq(game_id))
-                .inner_join(courses_dsl::courses.on(games_dsl::course_id.eq(courses_dsl::id)))
-                .select((
-                    courses_dsl::id,
-                    courses_dsl::gamification_rule_conditions,
-                    courses_dsl::gamification_complex_rules,
-                    courses_dsl::gamification_rule_results,
-                ))
-                .first::<CourseInfoTuple>(conn_sync)
-        })
-        .await?;
-
-    let lang_for_modules = language.clone();
-    let module_ids_result = helper::run_query(&pool, move |conn_sync| {
-        modules_dsl::modules
-            .filter(modules_dsl::course_id.eq(course_id))
-            .filter(modules_dsl::language.eq(lang_for_modules))
-            .select(modules_dsl::id)
-            .load::<i64>(conn_sync)
-    })
-    .await?;
-
-    let response_data = CourseDataResponse {
-        gamification_rule_conditions: conditions,
-        gamification_complex_rules: complex_rules,
-        gamification_rule_results: results,
-        module_ids: module_ids_result,
-    };
+    let response_data = load_course_data(&pool, game_id, language.clone()).await?;
 
     info!(
         "Successfully fetched course data and {} module IDs for game_id: {} and language: {}",
@@ -617,16 +1079,266 @@ pub async fn get_course_data(
     Ok(ApiResponse::ok(response_data))
 }
 
-/// Retrieves module details and filtered exercise IDs.
+/// Retrieves the same data as `get_course_data`, with the gamification rules parsed as JSON.
 ///
 /// Query Parameters:
-/// * `module_id`: The ID of the module.
-/// * `language`: The language to filter exercises by.
-/// * `programming_language`: The programming language to filter exercises by.
+/// * `game_id`: The ID of the game.
+/// * `language`: The language to filter modules by.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `ModuleDataResponse`: Module details and filtered exercise IDs (200 OK).
-/// * `404 Not Found`: If the specified module ID does not exist.
+/// * `ParsedCourseDataResponse`: Course gamification rules as JSON values and filtered module IDs (200 OK).
+/// * `404 Not Found`: If the specified game ID or its associated course does not exist.
+/// * `422 Unprocessable Entity`: If a stored gamification rule blob is not valid JSON.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_course_data_parsed(
+    State(pool): State<Pool>,
+    Query(params): Query<GetCourseDataParams>,
+) -> Result<ApiResponse<ParsedCourseDataResponse>, AppError> {
+    let language = params.language;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching parsed course data for game_id: {} and language: {}",
+        game_id, language
+    );
+
+    let course_data = load_course_data(&pool, game_id, language).await?;
+    let parse = |field: &str, blob: &str| {
+        serde_json::from_str::<JsonValue>(blob).map_err(|e| {
+            error!(
+                "Course of game {} has invalid JSON in {}: {}",
+                game_id, field, e
+            );
+            AppError::UnprocessableEntity(format!(
+                "The course of game {} has invalid JSON in {}: {}.",
+                game_id, field, e
+            ))
+        })
+    };
+    let response_data = ParsedCourseDataResponse {
+        gamification_rule_conditions: parse(
+            "gamification_rule_conditions",
+            &course_data.gamification_rule_conditions,
+        )?,
+        gamification_complex_rules: parse(
+            "gamification_complex_rules",
+            &course_data.gamification_complex_rules,
+        )?,
+        gamification_rule_results: parse(
+            "gamification_rule_results",
+            &course_data.gamification_rule_results,
+        )?,
+        module_ids: course_data.module_ids,
+    };
+
+    info!(
+        "Successfully parsed course data with {} module IDs for game_id: {}",
+        response_data.module_ids.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Loads a game's course gamification rules and the IDs of its modules in `language`.
+async fn load_course_data(
+    pool: &Pool,
+    game_id: i64,
+    language: String,
+) -> Result<CourseDataResponse, AppError> {
+    type CourseInfoTuple = (i64, String, String, String); // course_id, conditions, complex, results
+
+    let (course_id, conditions, complex_rules, results) =
+        helper::run_query(pool, move |conn_sync| {
+            games_dsl::games
+                .filter(games_dsl::id.eq(game_id))
+                .inner_join(courses_dsl::courses.on(games_dsl::course_id.eq(courses_dsl::id)))
+                .select((
+                    courses_dsl::id,
+                    courses_dsl::gamification_rule_conditions,
+                    courses_dsl::gamification_complex_rules,
+                    courses_dsl::gamification_rule_results,
+                ))
+                .first::<CourseInfoTuple>(conn_sync)
+        })
+        .await?;
+
+    let module_ids_result = helper::run_query(pool, move |conn_sync| {
+        modules_dsl::modules
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(modules_dsl::language.eq(language))
+            .select(modules_dsl::id)
+            .load::<i64>(conn_sync)
+    })
+    .await?;
+
+    Ok(CourseDataResponse {
+        gamification_rule_conditions: conditions,
+        gamification_complex_rules: complex_rules,
+        gamification_rule_results: results,
+        module_ids: module_ids_result,
+    })
+}
+
+/// Retrieves a game's course as a tree of modules and exercises in the requested language.
+/// Exercises are limited to the game's programming language; hidden exercises are left out
+/// unless the player has unlocked them. Lock state is available from `get_exercise_lock_states`.
+///
+/// Query Parameters:
+/// * `game_id`: The ID of the game.
+/// * `player_id`: The ID of the player.
+/// * `language`: The language code for filtering modules and exercises.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `StudentCourseTreeResponse`: The course with its modules and visible exercises, both ordered by `order` (200 OK).
+/// * `404 Not Found`: If the specified game ID does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_course_tree(
+    State(pool): State<Pool>,
+    Query(params): Query<GetCourseTreeParams>,
+) -> Result<ApiResponse<StudentCourseTreeResponse>, AppError> {
+    let game_id = params.game_id;
+    let player_id = params.player_id;
+
+    info!(
+        "Fetching course tree for game_id: {}, player_id: {}, language: {}",
+        game_id, player_id, params.language
+    );
+    debug!("Get course tree params: {:?}", params);
+
+    let game_info = helper::run_query(&pool, move |conn| {
+        games_dsl::games
+            .find(game_id)
+            .inner_join(courses_dsl::courses)
+            .select((
+                courses_dsl::id,
+                courses_dsl::title,
+                games_dsl::programming_language,
+            ))
+            .first::<(i64, String, String)>(conn)
+            .optional()
+    })
+    .await?;
+    let Some((course_id, course_title, programming_language)) = game_info else {
+        error!("Game with ID {} not found.", game_id);
+        return Err(AppError::NotFound(format!(
+            "Game with ID {} not found.",
+            game_id
+        )));
+    };
+
+    let language = params.language.clone();
+    let (modules, exercises) = helper::run_query(&pool, move |conn| {
+        let modules = modules_dsl::modules
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(modules_dsl::language.eq(&language))
+            .order_by((modules_dsl::order.asc(), modules_dsl::id.asc()))
+            .select((modules_dsl::id, modules_dsl::order, modules_dsl::title))
+            .load::<(i64, i32, String)>(conn)?;
+
+        let unlocked = pus_dsl::player_unlocks
+            .filter(pus_dsl::player_id.eq(player_id))
+            .select(pus_dsl::exercise_id);
+        let exercises = exercises_dsl::exercises
+            .inner_join(modules_dsl::modules)
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(modules_dsl::language.eq(&language))
+            .filter(exercises_dsl::language.eq(&language))
+            .filter(exercises_dsl::programming_language.eq(&programming_language))
+            .filter(
+                exercises_dsl::hidden
+                    .eq(false)
+                    .or(exercises_dsl::id.eq_any(unlocked)),
+            )
+            .order_by((exercises_dsl::order.asc(), exercises_dsl::id.asc()))
+            .select((
+                exercises_dsl::module_id,
+                exercises_dsl::id,
+                exercises_dsl::order,
+                exercises_dsl::title,
+            ))
+            .load::<(i64, i64, i32, String)>(conn)?;
+
+        Ok((modules, exercises))
+    })
+    .await?;
+
+    let mut exercises_by_module: HashMap<i64, Vec<StudentExerciseTreeNode>> = HashMap::new();
+    for (module_id, id, order, title) in exercises {
+        exercises_by_module
+            .entry(module_id)
+            .or_default()
+            .push(StudentExerciseTreeNode { id, order, title });
+    }
+
+    let response_data = StudentCourseTreeResponse {
+        course_id,
+        title: course_title,
+        modules: modules
+            .into_iter()
+            .map(|(id, order, title)| StudentModuleTreeNode {
+                id,
+                order,
+                title,
+                exercises: exercises_by_module.remove(&id).unwrap_or_default(),
+            })
+            .collect(),
+    };
+
+    info!(
+        "Successfully assembled course tree of {} modules for game_id: {}",
+        response_data.modules.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Lists the exercises of a game's course that carry a tag.
+///
+/// Query Parameters:
+/// * `game_id`: The ID of the game.
+/// * `tag`: The tag to filter by (case-insensitive).
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<i64>`: IDs of tagged exercises in the game's programming language, ordered by module and exercise order (200 OK).
+/// * `404 Not Found`: If the specified game ID does not exist.
+/// * `422 Unprocessable Entity`: If the tag is empty or longer than 50 characters.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn list_exercises_by_tag(
+    State(pool): State<Pool>,
+    Query(params): Query<ListExercisesByTagParams>,
+) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    let game_id = params.game_id;
+
+    info!(
+        "Listing exercises tagged '{}' for game_id: {}",
+        params.tag, game_id
+    );
+    debug!("List exercises by tag params: {:?}", params);
+
+    let tag = helper::normalize_exercise_tag(&params.tag)?;
+    let exercise_ids = helper::game_exercise_ids_by_tag(&pool, game_id, tag).await?;
+
+    info!(
+        "Found {} tagged exercises for game_id: {}",
+        exercise_ids.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(exercise_ids))
+}
+
+/// Retrieves module details and filtered exercise IDs.
+///
+/// Query Parameters:
+/// * `module_id`: The ID of the module.
+/// * `language`: The language to filter exercises by.
+/// * `programming_language`: The programming language to filter exercises by.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `ModuleDataResponse`: Module details and filtered exercise IDs (200 OK).
+/// * `404 Not Found`: If the specified module ID does not exist.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
 pub async fn get_module_data(
@@ -691,6 +1403,141 @@ pub async fn get_module_data(
     Ok(ApiResponse::ok(response_data))
 }
 
+/// Retrieves the data of every module of a game's course in the requested language, as
+/// `get_module_data` does for one module, with each module's accessibility for the player.
+/// Exercises are limited to the game's programming language. When the game sets a `module_lock`
+/// ratio, a module is locked until the player has solved at least that share of the exercises
+/// of the module before it; the first module is never locked.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+/// * `language`: The language code for filtering modules and exercises.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<CourseModuleData>`: The course's modules ordered by `order`, with their exercise IDs and `locked` flag (200 OK).
+/// * `403 Forbidden`: If the player is not registered in the game or has left it.
+/// * `404 Not Found`: If the game or player does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, acting_player, params))]
+pub async fn get_course_modules_data(
+    State(pool): State<Pool>,
+    acting_player: ActingPlayer,
+    Query(params): Query<GetCourseModulesDataParams>,
+) -> Result<ApiResponse<Vec<CourseModuleData>>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching module data for game_id: {}, player_id: {}, language: {}",
+        game_id, player_id, params.language
+    );
+    debug!("Get course modules data params: {:?}", params);
+
+    acting_player.ensure_acts_as(player_id)?;
+
+    type ModuleInfoTuple = (i64, i32, String, String, DateTime<Utc>, DateTime<Utc>); // id, order, title, desc, start, end
+    let language = params.language.clone();
+    let (game_module_lock, modules, exercises, solved) = helper::interact(&pool, move |conn| {
+        helper::ensure_player_registered(conn, player_id, game_id)?;
+
+        let (course_id, programming_language, game_module_lock) = games_dsl::games
+            .find(game_id)
+            .select((
+                games_dsl::course_id,
+                games_dsl::programming_language,
+                games_dsl::module_lock,
+            ))
+            .first::<(i64, String, f64)>(conn)?;
+
+        let modules = modules_dsl::modules
+            .filter(modules_dsl::course_id.eq(course_id))
+            .filter(modules_dsl::language.eq(&language))
+            .order_by((modules_dsl::order.asc(), modules_dsl::id.asc()))
+            .select((
+                modules_dsl::id,
+                modules_dsl::order,
+                modules_dsl::title,
+                modules_dsl::description,
+                modules_dsl::start_date,
+                modules_dsl::end_date,
+            ))
+            .load::<ModuleInfoTuple>(conn)?;
+        let module_ids: Vec<i64> = modules.iter().map(|module| module.0).collect();
+
+        let exercises = exercises_dsl::exercises
+            .filter(exercises_dsl::module_id.eq_any(&module_ids))
+            .filter(exercises_dsl::language.eq(&language))
+            .filter(exercises_dsl::programming_language.eq(&programming_language))
+            .order_by((exercises_dsl::order.asc(), exercises_dsl::id.asc()))
+            .select((exercises_dsl::module_id, exercises_dsl::id))
+            .load::<(i64, i64)>(conn)?;
+        let exercise_ids: Vec<i64> = exercises.iter().map(|exercise| exercise.1).collect();
+
+        let solved = sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq_any(&exercise_ids))
+            .filter(sub_dsl::result.ge(helper::load_success_threshold(conn, game_id)?))
+            .select(sub_dsl::exercise_id)
+            .distinct()
+            .load::<i64>(conn)?;
+
+        Ok::<_, AppError>((
+            game_module_lock,
+            modules,
+            exercises,
+            solved.into_iter().collect::<HashSet<i64>>(),
+        ))
+    })
+    .await??;
+
+    let mut exercises_by_module: HashMap<i64, Vec<i64>> = HashMap::new();
+    for (module_id, exercise_id) in exercises {
+        exercises_by_module
+            .entry(module_id)
+            .or_default()
+            .push(exercise_id);
+    }
+
+    let mut previous_solved_ratio: Option<f64> = None;
+    let response_data: Vec<CourseModuleData> = modules
+        .into_iter()
+        .map(
+            |(module_id, order, title, description, start_date, end_date)| {
+                let exercise_ids = exercises_by_module.remove(&module_id).unwrap_or_default();
+                let locked = game_module_lock > 0.0
+                    && previous_solved_ratio.is_some_and(|ratio| ratio < game_module_lock);
+                if !exercise_ids.is_empty() {
+                    let solved_count = exercise_ids.iter().filter(|id| solved.contains(id)).count();
+                    previous_solved_ratio = Some(solved_count as f64 / exercise_ids.len() as f64);
+                } else {
+                    previous_solved_ratio = None;
+                }
+                CourseModuleData {
+                    module_id,
+                    data: ModuleDataResponse {
+                        order,
+                        title,
+                        description,
+                        start_date,
+                        end_date,
+                        exercise_ids,
+                    },
+                    locked,
+                }
+            },
+        )
+        .collect();
+
+    info!(
+        "Successfully fetched data of {} modules for game_id: {}",
+        response_data.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
 /// Retrieves detailed exercise data, calculating context-dependent hidden/locked status.
 ///
 /// Query Parameters:
@@ -795,6 +1642,7 @@ pub async fn get_exercise_data(
     .await?;
 
     let hidden_flag = exercise_raw_hidden && !has_unlock;
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
 
     let mut is_locked_by_condition = exercise_raw_locked;
 
@@ -811,11 +1659,12 @@ pub async fn get_exercise_data(
 
         if total_module_exercises > 0 {
             let solved_in_module = helper::run_query(&pool, {
+                let success_threshold = success_threshold.clone();
                 move |conn| {
                     sub_dsl::submissions
                         .filter(sub_dsl::player_id.eq(player_id))
                         .filter(sub_dsl::game_id.eq(game_id))
-                        .filter(sub_dsl::result.gt(BigDecimal::from(50)))
+                        .filter(sub_dsl::result.ge(success_threshold))
                         .inner_join(
                             exercises_dsl::exercises.on(sub_dsl::exercise_id.eq(exercises_dsl::id)),
                         )
@@ -848,13 +1697,14 @@ pub async fn get_exercise_data(
 
         if let Some(prev_exercise_id) = prev_exercise_id_opt {
             let prev_solved = helper::run_query(&pool, {
+                let success_threshold = success_threshold.clone();
                 move |conn| {
                     diesel::dsl::select(diesel::dsl::exists(
                         sub_dsl::submissions
                             .filter(sub_dsl::player_id.eq(player_id))
                             .filter(sub_dsl::game_id.eq(game_id))
                             .filter(sub_dsl::exercise_id.eq(prev_exercise_id))
-                            .filter(sub_dsl::result.gt(BigDecimal::from(50))),
+                            .filter(sub_dsl::result.ge(success_threshold)),
                     ))
                     .get_result::<bool>(conn)
                 }
@@ -869,6 +1719,18 @@ pub async fn get_exercise_data(
 
     let locked_flag = is_locked_by_condition && !has_unlock;
 
+    let mode = ExerciseMode::parse(&mode).ok_or_else(|| {
+        error!(
+            "Exercise {} has unknown mode '{}' stored.",
+            exercise_id, mode
+        );
+        AppError::InternalServerError(anyhow!(
+            "Exercise {} has unknown mode '{}'.",
+            exercise_id,
+            mode
+        ))
+    })?;
+
     let response_data = ExerciseDataResponse {
         order,
         title,
@@ -892,56 +1754,283 @@ pub async fn get_exercise_data(
     Ok(ApiResponse::ok(response_data))
 }
 
+/// Lists the exercises of a module with each one's lock state for a player, for rendering a module map.
+/// Lock state is computed as in `get_exercise_data`: the exercise's own `locked` flag, the game's
+/// `module_lock` ratio and `exercise_lock` (previous exercise by order must be solved),
+/// overridden by any `player_unlocks` entry.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+/// * `module_id`: The ID of the module.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<ExerciseLockState>`: The module's exercises ordered by `order`, with their computed `locked` flag (200 OK).
+/// * `404 Not Found`: If the game or module does not exist, or the module is not part of the game's course.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_lock_states(
+    State(pool): State<Pool>,
+    Query(params): Query<GetExerciseLockStatesParams>,
+) -> Result<ApiResponse<Vec<ExerciseLockState>>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+    let module_id = params.module_id;
+
+    info!(
+        "Fetching exercise lock states for player_id: {}, game_id: {}, module_id: {}",
+        player_id, game_id, module_id
+    );
+    debug!("Get exercise lock states params: {:?}", params);
+
+    type GameInfoTuple = (i64, f64, bool); // course_id, module_lock, exercise_lock
+    let game_info = helper::run_query(&pool, move |conn| {
+        games_dsl::games
+            .find(game_id)
+            .select((
+                games_dsl::course_id,
+                games_dsl::module_lock,
+                games_dsl::exercise_lock,
+            ))
+            .first::<GameInfoTuple>(conn)
+            .optional()
+    })
+    .await?;
+    let Some((course_id, game_module_lock, game_exercise_lock)) = game_info else {
+        error!("Game with ID {} not found.", game_id);
+        return Err(AppError::NotFound(format!(
+            "Game with ID {} not found.",
+            game_id
+        )));
+    };
+
+    let module_course_id = helper::run_query(&pool, move |conn| {
+        modules_dsl::modules
+            .find(module_id)
+            .select(modules_dsl::course_id)
+            .first::<i64>(conn)
+            .optional()
+    })
+    .await?;
+    if module_course_id != Some(course_id) {
+        error!(
+            "Module with ID {} not found in course {} of game {}.",
+            module_id, course_id, game_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Module with ID {} not found in game {}.",
+            module_id, game_id
+        )));
+    }
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+    let (exercises, solved, unlocked) = helper::run_query(&pool, move |conn| {
+        let exercises = exercises_dsl::exercises
+            .filter(exercises_dsl::module_id.eq(module_id))
+            .order_by((exercises_dsl::order.asc(), exercises_dsl::id.asc()))
+            .select((
+                exercises_dsl::id,
+                exercises_dsl::title,
+                exercises_dsl::order,
+                exercises_dsl::locked,
+            ))
+            .load::<(i64, String, i32, bool)>(conn)?;
+        let exercise_ids: Vec<i64> = exercises.iter().map(|exercise| exercise.0).collect();
+
+        let solved = sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq_any(&exercise_ids))
+            .filter(sub_dsl::result.ge(success_threshold))
+            .select(sub_dsl::exercise_id)
+            .distinct()
+            .load::<i64>(conn)?;
+
+        let unlocked = pus_dsl::player_unlocks
+            .filter(pus_dsl::player_id.eq(player_id))
+            .filter(pus_dsl::exercise_id.eq_any(&exercise_ids))
+            .select(pus_dsl::exercise_id)
+            .load::<i64>(conn)?;
+
+        Ok((
+            exercises,
+            solved.into_iter().collect::<HashSet<i64>>(),
+            unlocked.into_iter().collect::<HashSet<i64>>(),
+        ))
+    })
+    .await?;
+
+    let module_locked = game_module_lock > 0.0
+        && !exercises.is_empty()
+        && (solved.len() as f64 / exercises.len() as f64) < game_module_lock;
+    let exercise_id_by_order: HashMap<i32, i64> = exercises
+        .iter()
+        .map(|(exercise_id, _, order, _)| (*order, *exercise_id))
+        .collect();
+
+    let lock_states: Vec<ExerciseLockState> = exercises
+        .into_iter()
+        .map(|(exercise_id, title, order, raw_locked)| {
+            let previous_unsolved = game_exercise_lock
+                && order > 1
+                && exercise_id_by_order
+                    .get(&(order - 1))
+                    .is_some_and(|previous_id| !solved.contains(previous_id));
+            let locked_by_condition = raw_locked || module_locked || previous_unsolved;
+            ExerciseLockState {
+                exercise_id,
+                title,
+                order,
+                locked: locked_by_condition && !unlocked.contains(&exercise_id),
+            }
+        })
+        .collect();
+
+    info!(
+        "Successfully computed lock states for {} exercises in module_id: {}",
+        lock_states.len(),
+        module_id
+    );
+    Ok(ApiResponse::ok(lock_states))
+}
+
 /// Submits a solution attempt for an exercise, updates progress, and grants rewards.
+/// When an evaluator is configured (`--evaluator-url`), the code is graded by it and its verdict
+/// replaces the client-supplied `result` and `result_description`.
+/// The resulting progress is read back within the same transaction as the submission insert.
+/// Submissions of the same player in the same game are serialised, so at most one is flagged as the first solution.
 ///
 /// Request Body: `SubmitSolutionPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if this was the first *correct* submission for the exercise/player/game, false otherwise (200 OK).
-/// * `404 Not Found`: If the player registration, game, exercise, or a specified reward ID does not exist.
+/// * `SubmitResult`: Whether this was the first *correct* submission for the exercise/player/game,
+///   the player's solved exercise count and progress percentage, and the IDs of rewards granted by this submission (200 OK).
+/// * `403 Forbidden`: If the player is not registered in the game or has left it, or has used up the game's
+///   `max_attempts_per_exercise` on an exercise they have not solved yet.
+/// * `404 Not Found`: If the game, player, exercise, or a specified reward ID does not exist.
+/// * `422 Unprocessable Entity`: If `time_spent_seconds` is negative or `submitted_code` exceeds the maximum code length.
 /// * `500 Internal Server Error`: If a database error or transaction failure occurs.
-#[instrument(skip(pool, payload))]
+/// * `502 Bad Gateway`: If the evaluator cannot be reached or returns an invalid verdict (including a result outside 0-100).
+#[instrument(skip(pool, evaluator, max_code_length, payload))]
 pub async fn submit_solution(
     State(pool): State<Pool>,
-    Json(payload): Json<SubmitSolutionPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
+    Extension(evaluator): Extension<Option<Arc<Evaluator>>>,
+    Extension(max_code_length): Extension<Arc<MaxCodeLength>>,
+    Json(mut payload): Json<SubmitSolutionPayload>,
+) -> Result<ApiResponse<SubmitResult>, AppError> {
     info!(
         "Attempting submission for exercise_id: {}, player_id: {}, game_id: {}",
         payload.exercise_id, payload.player_id, payload.game_id
     );
     debug!("Submit solution payload: {:?}", payload);
 
-    let conn = pool.get().await?;
-    let transaction_result: Result<bool, AppError> = conn.interact(move |conn_sync| {
+    if let Some(time_spent) = payload.time_spent_seconds
+        && time_spent < 0
+    {
+        warn!("Rejecting negative time_spent_seconds {}", time_spent);
+        return Err(AppError::UnprocessableEntity(format!(
+            "time_spent_seconds must not be negative, got {}.",
+            time_spent
+        )));
+    }
+
+    max_code_length.ensure_within(&payload.submitted_code)?;
+
+    if let Some(evaluator) = evaluator {
+        let (player_id, exercise_id, game_id) =
+            (payload.player_id, payload.exercise_id, payload.game_id);
+        // Reject submissions that would be refused anyway before spending an evaluator call on them.
+        // The checks are repeated inside the submission transaction.
+        helper::interact(&pool, move |conn| {
+            helper::ensure_player_registered(conn, player_id, game_id)?;
+            let success_threshold = helper::load_success_threshold(conn, game_id)?;
+            ensure_attempt_allowed(conn, player_id, exercise_id, game_id, &success_threshold)
+        })
+        .await??;
+
+        let exercise = helper::run_query(&pool, move |conn| {
+            exercises_dsl::exercises
+                .find(exercise_id)
+                .select((
+                    exercises_dsl::programming_language,
+                    exercises_dsl::mode,
+                    exercises_dsl::mode_parameters,
+                    exercises_dsl::pre_code,
+                    exercises_dsl::post_code,
+                    exercises_dsl::test_code,
+                    exercises_dsl::check_source,
+                ))
+                .first::<(String, String, JsonValue, String, String, String, String)>(conn)
+                .optional()
+        })
+        .await?;
+        let Some((
+            programming_language,
+            mode,
+            mode_parameters,
+            pre_code,
+            post_code,
+            test_code,
+            check_source,
+        )) = exercise
+        else {
+            error!("Exercise with ID {} not found.", exercise_id);
+            return Err(AppError::NotFound(format!(
+                "Exercise with ID {} not found.",
+                exercise_id
+            )));
+        };
+
+        let verdict = evaluator
+            .evaluate(&EvaluationRequest {
+                exercise_id,
+                programming_language,
+                mode,
+                mode_parameters,
+                submitted_code: payload.submitted_code.clone(),
+                pre_code,
+                post_code,
+                test_code,
+                check_source,
+            })
+            .await?;
+        info!(
+            "Evaluator graded exercise {} with result {} (client reported {})",
+            exercise_id, verdict.result, payload.result
+        );
+        payload.result = verdict.result;
+        payload.result_description = verdict.result_description;
+    }
+
+    let transaction_result: Result<SubmitResult, AppError> = helper::interact(&pool, move |conn_sync| {
         conn_sync.transaction(|transaction_conn| {
             let player_id = payload.player_id;
             let exercise_id = payload.exercise_id;
             let game_id = payload.game_id;
-            let current_result_is_correct = payload.result > BigDecimal::from(0);
 
-            let registration_exists = diesel::dsl::select(diesel::dsl::exists(
-                prs_dsl::player_registrations
-                    .filter(prs_dsl::player_id.eq(player_id))
-                    .filter(prs_dsl::game_id.eq(game_id))
-            )).get_result::<bool>(transaction_conn)?;
+            helper::ensure_player_registered(transaction_conn, player_id, game_id)?;
+            let success_threshold = helper::load_success_threshold(transaction_conn, game_id)?;
+            let current_result_is_correct = payload.result >= success_threshold;
 
-            if !registration_exists {
-                warn!("Player registration not found for player {} game {}. Cannot submit.", player_id, game_id);
-                return Err(AppError::NotFound(format!(
-                    "Player registration not found for player ID {} in game ID {}.",
-                    player_id, game_id
-                )));
-            }
+            // Lock the registration row so concurrent submissions of this player in this game are
+            // serialised: the next one only decides `first_solution` after this insert is committed.
+            prs_dsl::player_registrations
+                .filter(prs_dsl::player_id.eq(player_id))
+                .filter(prs_dsl::game_id.eq(game_id))
+                .select(prs_dsl::id)
+                .for_update()
+                .first::<i64>(transaction_conn)?;
 
-            let was_previously_solved = diesel::dsl::select(diesel::dsl::exists(
-                sub_dsl::submissions
-                    .filter(sub_dsl::player_id.eq(player_id))
-                    .filter(sub_dsl::exercise_id.eq(exercise_id))
-                    .filter(sub_dsl::game_id.eq(game_id))
-                    .filter(sub_dsl::result.gt(BigDecimal::from(50)))
-            )).get_result::<bool>(transaction_conn)?;
+            let was_previously_solved = ensure_attempt_allowed(
+                transaction_conn,
+                player_id,
+                exercise_id,
+                game_id,
+                &success_threshold,
+            )?;
 
             let is_first_correct = current_result_is_correct && !was_previously_solved;
+            let mut new_rewards: Vec<i64> = Vec::new();
 
             let new_submission = NewSubmission {
                 exercise_id,
@@ -956,6 +2045,7 @@ pub async fn submit_solution(
                 feedback: payload.feedback.clone(),
                 earned_rewards: payload.earned_rewards.clone(),
                 entered_at: payload.entered_at,
+                time_spent_seconds: payload.time_spent_seconds,
             };
 
             diesel::insert_into(sub_dsl::submissions)
@@ -974,20 +2064,25 @@ pub async fn submit_solution(
                 info!("First correct submission for exercise {}, player {}, game {}. Updating progress.",
                       exercise_id, player_id, game_id);
 
-                let rows_affected = diesel::update(
-                    prs_dsl::player_registrations
-                        .filter(prs_dsl::player_id.eq(player_id))
-                        .filter(prs_dsl::game_id.eq(game_id))
-                )
-                    .set(prs_dsl::progress.eq(prs_dsl::progress + 1))
-                    .execute(transaction_conn)?;
-
-                if rows_affected != 1 {
-                    error!("Failed to update progress for player {} game {}: Expected 1 row affected, got {}",
-                           player_id, game_id, rows_affected);
-                    return Err(AppError::InternalServerError(anyhow!(
-                        "Failed to update progress, inconsistent state."
-                    )));
+                if helper::exercise_counts_in_game(transaction_conn, game_id, exercise_id)? {
+                    let rows_affected = diesel::update(
+                        prs_dsl::player_registrations
+                            .filter(prs_dsl::player_id.eq(player_id))
+                            .filter(prs_dsl::game_id.eq(game_id))
+                    )
+                        .set(prs_dsl::progress.eq(prs_dsl::progress + 1))
+                        .execute(transaction_conn)?;
+
+                    if rows_affected != 1 {
+                        error!("Failed to update progress for player {} game {}: Expected 1 row affected, got {}",
+                               player_id, game_id, rows_affected);
+                        return Err(AppError::InternalServerError(anyhow!(
+                            "Failed to update progress, inconsistent state."
+                        )));
+                    }
+                } else {
+                    info!("Exercise {} is not part of the exercise subset of game {}; progress unchanged.",
+                          exercise_id, game_id);
                 }
 
                 if let Some(rewards_array) = payload.earned_rewards.as_array() {
@@ -1038,6 +2133,7 @@ pub async fn submit_solution(
                                 .set(crate::schema::player_rewards::count.eq(crate::schema::player_rewards::count + 1))
                                 .execute(transaction_conn)
                                 .map_err(AppError::from)?;
+                            new_rewards.push(reward_id);
 
                         } else {
                             warn!("Invalid non-integer reward ID found in earned_rewards: {:?}", reward_val);
@@ -1064,13 +2160,77 @@ pub async fn submit_solution(
                     internal_unlock_exercise(transaction_conn, player_id, exercise_id)?;
                 }
             }
-            Ok(is_first_correct)
+
+            let (solved_exercises, total_exercises) = prs_dsl::player_registrations
+                .inner_join(games_dsl::games.on(prs_dsl::game_id.eq(games_dsl::id)))
+                .filter(prs_dsl::player_id.eq(player_id))
+                .filter(prs_dsl::game_id.eq(game_id))
+                .select((prs_dsl::progress, games_dsl::total_exercises))
+                .first::<(i32, i32)>(transaction_conn)?;
+
+            let progress = if total_exercises > 0 {
+                (solved_exercises as f64 / total_exercises as f64) * 100.0
+            } else {
+                0.0
+            };
+
+            Ok(SubmitResult {
+                first_solution: is_first_correct,
+                solved_exercises,
+                progress,
+                new_rewards,
+            })
         })
     }).await?;
 
     transaction_result.map(ApiResponse::ok)
 }
 
+/// Checks that the player may submit another attempt on the exercise: once it is solved
+/// (a result reaching `success_threshold`), attempts are unlimited; until then, the game's
+/// `max_attempts_per_exercise` applies.
+/// Returns whether the exercise was already solved.
+/// Returns AppError::Forbidden if the attempt cap is reached.
+fn ensure_attempt_allowed(
+    conn: &mut PgConnection,
+    player_id: i64,
+    exercise_id: i64,
+    game_id: i64,
+    success_threshold: &BigDecimal,
+) -> Result<bool, AppError> {
+    let attempts = sub_dsl::submissions
+        .filter(sub_dsl::player_id.eq(player_id))
+        .filter(sub_dsl::exercise_id.eq(exercise_id))
+        .filter(sub_dsl::game_id.eq(game_id));
+
+    let was_previously_solved = diesel::dsl::select(diesel::dsl::exists(
+        attempts.filter(sub_dsl::result.ge(success_threshold)),
+    ))
+    .get_result::<bool>(conn)?;
+    if was_previously_solved {
+        return Ok(true);
+    }
+
+    let max_attempts = games_dsl::games
+        .find(game_id)
+        .select(games_dsl::max_attempts_per_exercise)
+        .first::<Option<i32>>(conn)?;
+    if let Some(max_attempts) = max_attempts {
+        let prior_attempts = attempts.count().get_result::<i64>(conn)?;
+        if prior_attempts >= i64::from(max_attempts) {
+            warn!(
+                "Player {} reached the cap of {} attempts on exercise {} in game {}",
+                player_id, max_attempts, exercise_id, game_id
+            );
+            return Err(AppError::Forbidden(format!(
+                "Maximum of {} attempts reached for this exercise.",
+                max_attempts
+            )));
+        }
+    }
+    Ok(false)
+}
+
 fn internal_unlock_exercise(
     conn: &mut PgConnection,
     player_id: i64,
@@ -1116,7 +2276,8 @@ fn internal_unlock_exercise(
 ///
 /// Returns (wrapped in `ApiResponse`)
 /// * `()`: Empty success response (200 OK).
-/// * `404 Not Found`: If the player or exercise does not exist (foreign key violation).
+/// * `403 Forbidden`: If the player is not registered in any game of the exercise's course, or has left them.
+/// * `404 Not Found`: If the player or exercise does not exist.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, payload))]
 pub async fn unlock(
@@ -1131,14 +2292,284 @@ pub async fn unlock(
         exercise_id, player_id
     );
 
-    let conn = pool.get().await?;
-    let unlock_result = conn
-        .interact(move |conn_sync| internal_unlock_exercise(conn_sync, player_id, exercise_id))
-        .await?;
+    let unlock_result = helper::interact(&pool, move |conn_sync| {
+        helper::ensure_player_registered_for_exercise(conn_sync, player_id, exercise_id)?;
+        internal_unlock_exercise(conn_sync, player_id, exercise_id)
+    })
+    .await?;
 
     unlock_result.map(|_| ApiResponse::ok(()))
 }
 
+/// Unlocks (and unhides) several exercises of a game's course for a player in one transaction.
+/// Exercises that are already unlocked are left untouched.
+///
+/// Request Body: `UnlockManyPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<i64>`: The IDs of the exercises newly unlocked by this call, in ascending order (200 OK).
+/// * `403 Forbidden`: If the player is not registered in the game, or has left it.
+/// * `404 Not Found`: If the player or game does not exist, or an exercise is not part of the game's course.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn unlock_many(
+    State(pool): State<Pool>,
+    Json(payload): Json<UnlockManyPayload>,
+) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    let player_id = payload.player_id;
+    let game_id = payload.game_id;
+    let exercise_ids: Vec<i64> = payload
+        .exercise_ids
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    info!(
+        "Attempting to unlock {} exercises for player {} in game {}",
+        exercise_ids.len(),
+        player_id,
+        game_id
+    );
+
+    let newly_unlocked = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction::<_, AppError, _>(|conn| {
+            helper::ensure_player_registered(conn, player_id, game_id)?;
+            if exercise_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let course_exercise_ids: HashSet<i64> = exercises_dsl::exercises
+                .inner_join(modules_dsl::modules)
+                .inner_join(games_dsl::games.on(games_dsl::course_id.eq(modules_dsl::course_id)))
+                .filter(games_dsl::id.eq(game_id))
+                .filter(exercises_dsl::id.eq_any(&exercise_ids))
+                .select(exercises_dsl::id)
+                .load::<i64>(conn)?
+                .into_iter()
+                .collect();
+            let mut foreign_ids: Vec<i64> = exercise_ids
+                .iter()
+                .filter(|id| !course_exercise_ids.contains(id))
+                .copied()
+                .collect();
+            if !foreign_ids.is_empty() {
+                foreign_ids.sort_unstable();
+                error!(
+                    "Exercises {:?} are not part of the course of game {}.",
+                    foreign_ids, game_id
+                );
+                return Err(AppError::NotFound(format!(
+                    "Exercises {:?} not found in the course of game {}.",
+                    foreign_ids, game_id
+                )));
+            }
+
+            let new_unlocks: Vec<NewPlayerUnlock> = exercise_ids
+                .iter()
+                .map(|&exercise_id| NewPlayerUnlock {
+                    player_id,
+                    exercise_id,
+                })
+                .collect();
+            let mut newly_unlocked = diesel::insert_into(pus_dsl::player_unlocks)
+                .values(&new_unlocks)
+                .on_conflict((pus_dsl::player_id, pus_dsl::exercise_id))
+                .do_nothing()
+                .returning(pus_dsl::exercise_id)
+                .get_results::<i64>(conn)?;
+            newly_unlocked.sort_unstable();
+            Ok(newly_unlocked)
+        })
+    })
+    .await??;
+
+    info!(
+        "Newly unlocked exercises {:?} for player {}",
+        newly_unlocked, player_id
+    );
+    Ok(ApiResponse::ok(newly_unlocked))
+}
+
+/// Retrieves the authenticated player's effective score in a game: points for solved exercises
+/// and obtained rewards, minus penalties for exercises unlocked before solving them.
+/// The point values come from the `score` object of the course's `gamification_rule_results`.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `PlayerScoreResponse`: The `base`, `rewards` and `penalties` points and their `total` (200 OK).
+/// * `403 Forbidden`: If the authenticated player does not match `player_id`, or the player is not registered in the game or has left it.
+/// * `404 Not Found`: If the player or game does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_player_score(
+    State(pool): State<Pool>,
+    acting_player: ActingPlayer,
+    Query(params): Query<GetPlayerScoreParams>,
+) -> Result<ApiResponse<PlayerScoreResponse>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching score for player_id: {} in game_id: {}",
+        player_id, game_id
+    );
+    debug!("Get player score params: {:?}", params);
+
+    acting_player.ensure_acts_as(player_id)?;
+
+    let score = helper::interact(&pool, move |conn| {
+        helper::ensure_player_registered(conn, player_id, game_id)?;
+        helper::compute_player_score(conn, player_id, game_id)
+    })
+    .await??;
+
+    info!(
+        "Player {} has a total score of {} in game {}",
+        player_id, score.total, game_id
+    );
+    Ok(ApiResponse::ok(score))
+}
+
+/// Retrieves the authenticated player's position among the active participants of a game.
+/// Participants are ranked by solved exercises, then by fewest submissions; tied players share a rank.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `PlayerRankResponse`: The player's `rank`, the `total_participants`, and the player's `solved_exercises` and `attempts` (200 OK).
+/// * `403 Forbidden`: If the authenticated player does not match `player_id`, or the player is not registered in the game or has left it.
+/// * `404 Not Found`: If the player or game does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_player_rank(
+    State(pool): State<Pool>,
+    acting_player: ActingPlayer,
+    Query(params): Query<GetPlayerRankParams>,
+) -> Result<ApiResponse<PlayerRankResponse>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching rank of player_id: {} in game_id: {}",
+        player_id, game_id
+    );
+    debug!("Get player rank params: {:?}", params);
+
+    acting_player.ensure_acts_as(player_id)?;
+
+    let rank = helper::interact(&pool, move |conn| {
+        helper::ensure_player_registered(conn, player_id, game_id)?;
+        // Ranked in the database so only the requesting player's row is returned.
+        diesel::sql_query(
+            "WITH standings AS ( \
+                 SELECT pr.player_id, pr.progress AS solved_exercises, \
+                        (SELECT COUNT(*) FROM submissions s \
+                          WHERE s.player_id = pr.player_id AND s.game_id = pr.game_id) AS attempts \
+                 FROM player_registrations pr \
+                 WHERE pr.game_id = $1 AND pr.left_at IS NULL \
+             ), ranked AS ( \
+                 SELECT player_id, solved_exercises, attempts, \
+                        RANK() OVER (ORDER BY solved_exercises DESC, attempts ASC) AS rank, \
+                        COUNT(*) OVER () AS total_participants \
+                 FROM standings \
+             ) \
+             SELECT rank, total_participants, solved_exercises, attempts \
+             FROM ranked WHERE player_id = $2",
+        )
+        .bind::<diesel::sql_types::BigInt, _>(game_id)
+        .bind::<diesel::sql_types::BigInt, _>(player_id)
+        .get_result::<PlayerRankResponse>(conn)
+        .map_err(AppError::from)
+    })
+    .await??;
+
+    info!(
+        "Player {} is ranked {} of {} in game {}",
+        player_id, rank.rank, rank.total_participants, game_id
+    );
+    Ok(ApiResponse::ok(rank))
+}
+
+/// Retrieves what a player needs to resume work on an exercise: its `init_code` and,
+/// if the player has submitted before in this game, the most recently submitted code.
+/// Only the authenticated player's own code is returned.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+/// * `exercise_id`: The ID of the exercise.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `ExerciseWorkspaceResponse`: The exercise's initial code and the player's last submitted code, if any (200 OK).
+/// * `403 Forbidden`: If the authenticated player does not match `player_id`.
+/// * `404 Not Found`: If the specified exercise does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_workspace(
+    State(pool): State<Pool>,
+    acting_player: ActingPlayer,
+    Query(params): Query<GetExerciseWorkspaceParams>,
+) -> Result<ApiResponse<ExerciseWorkspaceResponse>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+    let exercise_id = params.exercise_id;
+
+    info!(
+        "Fetching workspace for player_id: {}, game_id: {}, exercise_id: {}",
+        player_id, game_id, exercise_id
+    );
+    debug!("Get exercise workspace params: {:?}", params);
+
+    acting_player.ensure_acts_as(player_id)?;
+
+    let init_code = helper::run_query(&pool, move |conn| {
+        exercises_dsl::exercises
+            .find(exercise_id)
+            .select(exercises_dsl::init_code)
+            .first::<String>(conn)
+            .optional()
+    })
+    .await?;
+    let Some(init_code) = init_code else {
+        error!("Exercise with ID {} not found.", exercise_id);
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    };
+
+    let last_submission = helper::run_query(&pool, move |conn| {
+        sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq(exercise_id))
+            .order_by((sub_dsl::submitted_at.desc(), sub_dsl::id.desc()))
+            .select((sub_dsl::submitted_code, sub_dsl::submitted_at))
+            .first::<(String, DateTime<Utc>)>(conn)
+            .optional()
+    })
+    .await?;
+
+    let (last_submitted_code, last_submitted_at) = last_submission.unzip();
+    info!(
+        "Workspace for player {} on exercise {} has previous submission: {}",
+        player_id,
+        exercise_id,
+        last_submitted_code.is_some()
+    );
+    Ok(ApiResponse::ok(ExerciseWorkspaceResponse {
+        init_code,
+        last_submitted_code,
+        last_submitted_at,
+    }))
+}
+
 /// Retrieves the last relevant submission for a player and exercise.
 /// Prioritizes the last correct submission, falls back to the last submission overall.
 /// Returns `None` in data field if no submissions exist.
@@ -1204,63 +2635,231 @@ pub async fn get_last_solution(
         sub_dsl::submitted_at,
     );
 
-    let last_correct_result: Result<LastSolutionResponse, AppError> = helper::run_query(&pool, {
+    // Each submission is judged by the success threshold of the game it was made in.
+    let submissions = helper::run_query(&pool, {
         move |conn| {
             sub_dsl::submissions
+                .inner_join(games_dsl::games)
                 .filter(sub_dsl::player_id.eq(player_id))
                 .filter(sub_dsl::exercise_id.eq(exercise_id))
-                .filter(sub_dsl::result.gt(BigDecimal::from(50)))
                 .order(sub_dsl::submitted_at.desc())
-                .select(selection)
-                .first::<LastSolutionResponse>(conn)
+                .select((selection, games_dsl::success_threshold))
+                .load::<(LastSolutionResponse, Option<f64>)>(conn)
         }
     })
-    .await;
+    .await?;
 
-    match last_correct_result {
-        Ok(solution) => {
-            info!(
-                "Found last correct solution for player {}, exercise {}",
-                player_id, exercise_id
-            );
-            return Ok(ApiResponse::ok(Some(solution)));
-        }
-        Err(AppError::NotFound(_)) => {
-            info!(
-                "No correct solution found for player {}, exercise {}. Checking for any submission.",
-                player_id, exercise_id
-            );
-        }
-        Err(e) => return Err(e),
+    let last_correct = submissions
+        .iter()
+        .position(|(solution, threshold)| solution.result >= helper::success_threshold(*threshold));
+    if let Some(index) = last_correct {
+        info!(
+            "Found last correct solution for player {}, exercise {}",
+            player_id, exercise_id
+        );
+        return Ok(ApiResponse::ok(
+            submissions
+                .into_iter()
+                .nth(index)
+                .map(|(solution, _)| solution),
+        ));
     }
+    info!(
+        "No correct solution found for player {}, exercise {}. Checking for any submission.",
+        player_id, exercise_id
+    );
 
-    let last_any_result: Result<LastSolutionResponse, AppError> = helper::run_query(&pool, {
-        move |conn| {
-            sub_dsl::submissions
-                .filter(sub_dsl::player_id.eq(player_id))
-                .filter(sub_dsl::exercise_id.eq(exercise_id))
-                .order(sub_dsl::submitted_at.desc())
-                .select(selection)
-                .first::<LastSolutionResponse>(conn)
-        }
-    })
-    .await;
-
-    match last_any_result {
-        Ok(solution) => {
+    match submissions.into_iter().next() {
+        Some((solution, _)) => {
             info!(
                 "Found last overall submission for player {}, exercise {}",
                 player_id, exercise_id
             );
             Ok(ApiResponse::ok(Some(solution)))
         }
-        Err(AppError::NotFound(_)) => {
+        None => {
             info!(
                 "No submissions found at all for player {}, exercise {}",
                 player_id, exercise_id
             );
             Ok(ApiResponse::ok(None))
         }
-        Err(e) => Err(e),
     }
 }
+
+/// Computes a player's current daily-activity streak in a game.
+/// Counts consecutive UTC calendar days with at least one submission (by `entered_at`),
+/// ending today, or yesterday if the player has not submitted yet today.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `PlayerStreakResponse`: The current streak in days and the last active UTC date (200 OK).
+/// * `404 Not Found`: If the player is not registered in the game.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_player_streak(
+    State(pool): State<Pool>,
+    Query(params): Query<GetPlayerStreakParams>,
+) -> Result<ApiResponse<PlayerStreakResponse>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Computing streak for player_id: {}, game_id: {}",
+        player_id, game_id
+    );
+    debug!("Get player streak params: {:?}", params);
+
+    let registration_exists = helper::run_query(&pool, move |conn| {
+        diesel::dsl::select(diesel::dsl::exists(
+            prs_dsl::player_registrations
+                .filter(prs_dsl::player_id.eq(player_id))
+                .filter(prs_dsl::game_id.eq(game_id)),
+        ))
+        .get_result::<bool>(conn)
+    })
+    .await?;
+    if !registration_exists {
+        warn!(
+            "Player registration not found for player {} game {}.",
+            player_id, game_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player registration not found for player ID {} in game ID {}.",
+            player_id, game_id
+        )));
+    }
+
+    let active_dates = helper::run_query(&pool, move |conn| {
+        sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .select(diesel::dsl::sql::<diesel::sql_types::Date>(
+                "(entered_at AT TIME ZONE 'UTC')::date",
+            ))
+            .distinct()
+            .order_by(diesel::dsl::sql::<diesel::sql_types::Date>(
+                "(entered_at AT TIME ZONE 'UTC')::date DESC",
+            ))
+            .load::<NaiveDate>(conn)
+    })
+    .await?;
+
+    let today = Utc::now().date_naive();
+    let mut current_streak = 0;
+    if let Some(&latest) = active_dates.first()
+        && latest >= today - Duration::days(1)
+    {
+        let mut expected = latest;
+        for date in &active_dates {
+            if *date != expected {
+                break;
+            }
+            current_streak += 1;
+            expected -= Duration::days(1);
+        }
+    }
+
+    info!(
+        "Player {} has a {}-day streak in game {}",
+        player_id, current_streak, game_id
+    );
+    Ok(ApiResponse::ok(PlayerStreakResponse {
+        current_streak,
+        last_active_date: active_dates.first().copied(),
+    }))
+}
+
+/// Retrieves whether a player has completed a game, with the data needed to render a completion certificate.
+/// A game is complete once the player has a first solution for at least `total_exercises` distinct exercises.
+///
+/// Query Parameters:
+/// * `player_id`: The ID of the player.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `CompletionStatusResponse`: Whether the game is complete, the solved and total exercise counts, the completion
+///   timestamp (the latest first-solution `entered_at`, only when complete), and the player, game and course names,
+///   registration date, number of submissions and score (200 OK).
+/// * `403 Forbidden`: If the authenticated player does not match `player_id`, or the player is not registered in the game or has left it.
+/// * `404 Not Found`: If the player or game does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_completion_status(
+    State(pool): State<Pool>,
+    acting_player: ActingPlayer,
+    Query(params): Query<GetCompletionStatusParams>,
+) -> Result<ApiResponse<CompletionStatusResponse>, AppError> {
+    let player_id = params.player_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching completion status for player_id: {} in game_id: {}",
+        player_id, game_id
+    );
+    debug!("Get completion status params: {:?}", params);
+
+    acting_player.ensure_acts_as(player_id)?;
+
+    let status = helper::interact(&pool, move |conn| {
+        helper::ensure_player_registered(conn, player_id, game_id)?;
+
+        let (game_title, total_exercises, course_title) = games_dsl::games
+            .inner_join(courses_dsl::courses.on(courses_dsl::id.eq(games_dsl::course_id)))
+            .filter(games_dsl::id.eq(game_id))
+            .select((
+                games_dsl::title,
+                games_dsl::total_exercises,
+                courses_dsl::title,
+            ))
+            .first::<(String, i32, String)>(conn)?;
+        let player_display_name = players_dsl::players
+            .find(player_id)
+            .select(players_dsl::display_name)
+            .first::<String>(conn)?;
+        let joined_at = prs_dsl::player_registrations
+            .filter(prs_dsl::player_id.eq(player_id))
+            .filter(prs_dsl::game_id.eq(game_id))
+            .select(prs_dsl::joined_at)
+            .first::<DateTime<Utc>>(conn)?;
+
+        let player_submissions = sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id));
+        let attempts = player_submissions.count().get_result::<i64>(conn)?;
+        let (solved_exercises, last_first_solution) = player_submissions
+            .filter(sub_dsl::first_solution.eq(true))
+            .filter(helper::counts_in_game(game_id))
+            .select((
+                diesel::dsl::count_distinct(sub_dsl::exercise_id),
+                diesel::dsl::max(sub_dsl::entered_at),
+            ))
+            .first::<(i64, Option<DateTime<Utc>>)>(conn)?;
+
+        let complete = total_exercises > 0 && solved_exercises >= i64::from(total_exercises);
+        let score = helper::compute_player_score(conn, player_id, game_id)?.total;
+
+        Ok::<_, AppError>(CompletionStatusResponse {
+            complete,
+            solved_exercises,
+            total_exercises,
+            completed_at: last_first_solution.filter(|_| complete),
+            player_display_name,
+            game_title,
+            course_title,
+            joined_at,
+            attempts,
+            score,
+        })
+    })
+    .await??;
+
+    info!(
+        "Player {} solved {} of {} exercises in game {} (complete: {})",
+        player_id, status.solved_exercises, status.total_exercises, game_id, status.complete
+    );
+    Ok(ApiResponse::ok(status))
+}
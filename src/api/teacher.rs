@@ -1,54 +1,91 @@
 use super::helper;
+use crate::auth::ActingInstructor;
+use crate::config::{DefaultLanguages, MaxGroupSize, PublicBaseUrl};
+use crate::rate_limit::SlidingWindowLimiter;
+use crate::webhook::{GAME_ACTIVATED, GAME_STOPPED, Webhook};
 use anyhow::anyhow;
 
-use crate::model::student::NewPlayerRegistration;
+use crate::model::student::{
+    NewPlayerRegistration, NewPlayerReward, NewSubmission, PlayerScoreResponse,
+};
 use crate::model::teacher::{
-    ExerciseStatsResponse, GameChangeset, InstructorGameMetadataResponse, Invite,
-    InviteLinkResponse, NewGame, NewGameOwnership, NewGroup, NewGroupOwnership, NewInvite,
-    NewPlayer, NewPlayerGroup, StudentExercisesResponse, StudentProgressResponse,
-    SubmissionDataResponse,
+    CourseGameSummary, DisabledPlayerResponse, ExerciseStatsResponse, FirstSolver, GameChangeset,
+    GameEndSummaryResponse, GameEndingSoonSummary, GameExerciseStats, GameExercisesResponse,
+    GameInstructorResponse, GameTimelineResponse, GroupChangeset, GroupMemberResponse,
+    GroupMetadataResponse, GroupStatsResponse, ImportSubmissionsResponse,
+    InstructorGameMetadataResponse, IntegrityReportResponse, Invite, InviteDetailsResponse,
+    InviteLinkResponse, InviteSummary, NewGame, NewGameExercise, NewGameOwnership, NewGroup,
+    NewGroupOwnership, NewInvite, NewPlayer, NewPlayerGroup, PlayerActivityDay,
+    PlayerGroupResponse, ProcessInviteResult, ProgressDistributionBucket, RecentSubmission,
+    ScoreHistogramBucket, StrugglingStudent, StudentExercisesResponse, StudentProgressResponse,
+    StudentSubmissions, SubmissionDataResponse, TimelineCursor, TimelineEvent, TimelineEventType,
+    UnattemptedExerciseResponse,
 };
 use crate::payloads::teacher::{
-    ActivateGamePayload, AddGameInstructorPayload, AddGroupMemberPayload, CreateGamePayload,
-    CreateGroupPayload, CreatePlayerPayload, DeletePlayerPayload, DisablePlayerPayload,
-    DissolveGroupPayload, GenerateInviteLinkPayload, GetExerciseStatsParams,
-    GetExerciseSubmissionsParams, GetInstructorGameMetadataParams, GetStudentExercisesParams,
-    GetStudentProgressParams, GetStudentSubmissionsParams, GetSubmissionDataParams,
-    ListStudentsParams, ModifyGamePayload, ProcessInviteLinkPayload, RemoveGameInstructorPayload,
-    RemoveGameStudentPayload, RemoveGroupMemberPayload, StopGamePayload, TranslateEmailParams,
+    ActivateGamePayload, AddGameInstructorPayload, AddGroupMemberPayload, CheckIntegrityParams,
+    CloneGroupPayload, CreateGamePayload, CreateGroupPayload, CreatePlayerPayload,
+    DeletePlayerPayload, DisablePlayerPayload, DissolveGroupPayload, ExcludeGameExercisesPayload,
+    GenerateInviteLinkPayload, GetAllExerciseStatsParams, GetAllPlayerSubmissionsParams,
+    GetExerciseFirstSolversParams, GetExerciseScoreHistogramParams, GetExerciseStatsParams,
+    GetExerciseSubmissionsParams, GetGameEndSummaryParams, GetGameInstructorsParams,
+    GetGameTimelineParams, GetGamesByCourseParams, GetGamesEndingSoonParams,
+    GetGamesMetadataPayload, GetGroupMembersParams, GetGroupMetadataParams, GetGroupStatsParams,
+    GetInstructorGameMetadataParams, GetInviteDetailsParams, GetPlayerActivityByDayParams,
+    GetPlayerGroupsParams, GetProgressDistributionParams, GetRecentSubmissionsParams,
+    GetStrugglingStudentsParams, GetStudentBestSubmissionsParams, GetStudentExercisesParams,
+    GetStudentProgressParams, GetStudentScoreParams, GetStudentSubmissionsParams,
+    GetStudentUnattemptedExercisesParams, GetSubmissionDataParams, GetSubmissionsDataPayload,
+    GrantRewardPayload, ImportSubmissionsPayload, IncludeGameExercisesPayload,
+    ListDisabledPlayersParams, ListExercisesByTagParams, ListInvitesParams, ListStudentsParams,
+    MergeGroupsPayload, ModifyGamePayload, ModifyGroupPayload, ProcessInviteLinkPayload,
+    RemoveGameInstructorPayload, RemoveGameStudentPayload, RemoveGameStudentsPayload,
+    RemoveGroupMemberPayload, RestorePlayerPayload, SoftDeletePlayerPayload, StopGamePayload,
+    TransferGroupOwnershipPayload, TranslateEmailParams,
 };
 use crate::{
+    db,
     errors::AppError,
     payloads::teacher::GetInstructorGamesParams,
     response::ApiResponse,
     schema::{
         courses::dsl as courses_dsl, exercises::dsl as exercises_dsl,
+        game_end_summaries::dsl as ges_dsl, game_exercises::dsl as ge_dsl,
         game_ownership::dsl as go_dsl, games::dsl as games_dsl, group_ownership::dsl as gro_dsl,
         groups::dsl as groups_dsl, instructors::dsl as instructors_dsl,
         invites::dsl as invites_dsl, modules::dsl as modules_dsl, player_groups::dsl as pg_dsl,
         player_registrations::dsl as pr_dsl, player_rewards::dsl as prw_dsl,
-        player_unlocks::dsl as pu_dsl, players::dsl as players_dsl, submissions::dsl as sub_dsl,
+        player_unlocks::dsl as pu_dsl, players::dsl as players_dsl, rewards::dsl as rewards_dsl,
+        submissions::dsl as sub_dsl,
     },
 };
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Query, State},
+    http::HeaderMap,
+    response::Response,
 };
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, ToPrimitive};
 use chrono::{DateTime, Duration, Utc};
 use deadpool_diesel::postgres::Pool;
-use diesel::dsl::{exists, select};
+use diesel::define_sql_function;
+use diesel::dsl::{count_star, exists, select, sql};
+use diesel::pg::Pg;
 use diesel::prelude::*;
-use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use diesel::result::Error as DieselError;
+use diesel::sql_types::{BigInt, Bool, Date, Nullable, Numeric};
 use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::log::warn;
 use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
-/// Retrieves all game IDs associated with a specific instructor.
+/// Retrieves all game IDs associated with a specific instructor, with optional status filters.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor.
+/// * `active`: Optional, only return games whose `active` flag matches.
+/// * `public`: Optional, only return games whose `public` flag matches.
 ///
 /// Returns (wrapped in `ApiResponse`)
 /// * `Vec<i64>`: List of game IDs (200 OK).
@@ -84,11 +121,21 @@ pub async fn get_instructor_games(
         instructor_id
     );
 
+    let active_filter = params.active;
+    let public_filter = params.public;
     let game_ids = helper::run_query(&pool, move |conn_sync| {
-        go_dsl::game_ownership
+        let mut query = go_dsl::game_ownership
+            .inner_join(games_dsl::games)
             .filter(go_dsl::instructor_id.eq(instructor_id))
             .select(go_dsl::game_id)
-            .load::<i64>(conn_sync)
+            .into_boxed();
+        if let Some(active) = active_filter {
+            query = query.filter(games_dsl::active.eq(active));
+        }
+        if let Some(public) = public_filter {
+            query = query.filter(games_dsl::public.eq(public));
+        }
+        query.load::<i64>(conn_sync)
     })
     .await?;
 
@@ -107,15 +154,17 @@ pub async fn get_instructor_games(
 /// * `game_id`: The ID of the game.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `InstructorGameMetadataResponse`: Game details, ownership, and player count (200 OK).
+/// * `InstructorGameMetadataResponse`: Game details, ownership, and player count (200 OK, with an `ETag` header).
+/// * `304 Not Modified`: If `If-None-Match` matches the current `ETag`.
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
 /// * `404 Not Found`: If the game doesn't exist.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
 pub async fn get_instructor_game_metadata(
     State(pool): State<Pool>,
+    headers: HeaderMap,
     Query(params): Query<GetInstructorGameMetadataParams>,
-) -> Result<ApiResponse<InstructorGameMetadataResponse>, AppError> {
+) -> Result<Response, AppError> {
     let instructor_id = params.instructor_id;
     let game_id = params.game_id;
 
@@ -185,6 +234,7 @@ pub async fn get_instructor_game_metadata(
     .await?;
 
     let response_data = InstructorGameMetadataResponse {
+        id: game_id,
         title,
         description,
         active,
@@ -200,10 +250,358 @@ pub async fn get_instructor_game_metadata(
         "Successfully fetched metadata for game_id: {} for instructor_id: {}",
         game_id, instructor_id
     );
+    Ok(ApiResponse::ok(response_data).into_conditional_response(&headers))
+}
+
+/// Maximum number of game IDs accepted by `get_games_metadata` in one request.
+pub const MAX_GAMES_PER_BATCH: usize = 100;
+
+/// Retrieves the metadata of several games at once, for rendering dashboard cards.
+///
+/// Games, ownership and player counts are each loaded with a single query across all requested
+/// games. Games the instructor may not access either fail the whole request or are dropped
+/// from the result, depending on `skip_forbidden`.
+/// Results follow the order of `game_ids`; duplicate IDs are returned once.
+///
+/// Request Body: `GetGamesMetadataPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<InstructorGameMetadataResponse>`: Metadata of the accessible games (200 OK).
+/// * `400 Bad Request`: If more than `MAX_GAMES_PER_BATCH` IDs are requested.
+/// * `403 Forbidden`: If the instructor lacks permission for any game and `skip_forbidden` is false.
+/// * `404 Not Found`: If the instructor or any of the games does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn get_games_metadata(
+    State(pool): State<Pool>,
+    Json(payload): Json<GetGamesMetadataPayload>,
+) -> Result<ApiResponse<Vec<InstructorGameMetadataResponse>>, AppError> {
+    let instructor_id = payload.instructor_id;
+
+    info!(
+        "Fetching metadata for {} games requested by instructor_id: {}",
+        payload.game_ids.len(),
+        instructor_id
+    );
+    debug!("Get games metadata payload: {:?}", payload);
+
+    let mut game_ids = payload.game_ids;
+    let mut seen = HashSet::new();
+    game_ids.retain(|id| seen.insert(*id));
+
+    if game_ids.len() > MAX_GAMES_PER_BATCH {
+        warn!(
+            "Rejecting batch of {} game IDs (max {}).",
+            game_ids.len(),
+            MAX_GAMES_PER_BATCH
+        );
+        return Err(AppError::BadRequest(format!(
+            "At most {} game IDs can be requested at once.",
+            MAX_GAMES_PER_BATCH
+        )));
+    }
+
+    let instructor_exists = helper::run_query(&pool, move |conn| {
+        diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+            .get_result::<bool>(conn)
+    })
+    .await?;
+    if !instructor_exists {
+        error!("Instructor with ID {} not found.", instructor_id);
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            instructor_id
+        )));
+    }
+
+    if game_ids.is_empty() {
+        return Ok(ApiResponse::ok(Vec::new()));
+    }
+
+    type GameDetailsTuple = (
+        i64,
+        String,
+        DateTime<Utc>,
+        DateTime<Utc>,
+        bool,
+        bool,
+        i32,
+        String,
+    ); // id, title, start, end, active, public, total_ex, desc
+
+    let (games, ownership, player_counts) = helper::run_query(&pool, {
+        let game_ids = game_ids.clone();
+        move |conn| {
+            let games = games_dsl::games
+                .filter(games_dsl::id.eq_any(&game_ids))
+                .select((
+                    games_dsl::id,
+                    games_dsl::title,
+                    games_dsl::start_date,
+                    games_dsl::end_date,
+                    games_dsl::active,
+                    games_dsl::public,
+                    games_dsl::total_exercises,
+                    games_dsl::description,
+                ))
+                .load::<GameDetailsTuple>(conn)?;
+
+            let ownership = go_dsl::game_ownership
+                .filter(go_dsl::instructor_id.eq(instructor_id))
+                .filter(go_dsl::game_id.eq_any(&game_ids))
+                .select((go_dsl::game_id, go_dsl::owner))
+                .load::<(i64, bool)>(conn)?
+                .into_iter()
+                .collect::<HashMap<i64, bool>>();
+
+            let player_counts = pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq_any(&game_ids))
+                .group_by(pr_dsl::game_id)
+                .select((pr_dsl::game_id, count_star()))
+                .load::<(i64, i64)>(conn)?
+                .into_iter()
+                .collect::<HashMap<i64, i64>>();
+
+            Ok((games, ownership, player_counts))
+        }
+    })
+    .await?;
+
+    if games.len() != game_ids.len() {
+        let found: HashSet<i64> = games.iter().map(|game| game.0).collect();
+        let missing: Vec<i64> = game_ids
+            .iter()
+            .copied()
+            .filter(|id| !found.contains(id))
+            .collect();
+        error!("Games not found: {:?}", missing);
+        return Err(AppError::NotFound(format!(
+            "Games with IDs {:?} not found.",
+            missing
+        )));
+    }
+
+    let mut by_id: HashMap<i64, InstructorGameMetadataResponse> = HashMap::new();
+    for (id, title, start_date, end_date, active, public, total_exercises, description) in games {
+        let permitted = instructor_id == 0 || ownership.contains_key(&id);
+        if !permitted {
+            if payload.skip_forbidden {
+                info!(
+                    "Dropping game {} (not accessible to instructor {})",
+                    id, instructor_id
+                );
+                continue;
+            }
+            warn!(
+                "Permission denied for instructor {} on game {}.",
+                instructor_id, id
+            );
+            return Err(AppError::Forbidden(format!(
+                "Instructor {} does not have permission for game {}.",
+                instructor_id, id
+            )));
+        }
+
+        by_id.insert(
+            id,
+            InstructorGameMetadataResponse {
+                id,
+                title,
+                description,
+                active,
+                public,
+                total_exercises,
+                start_date,
+                end_date,
+                is_owner: ownership.get(&id).copied().unwrap_or(false),
+                player_count: player_counts.get(&id).copied().unwrap_or(0),
+            },
+        );
+    }
+
+    let results: Vec<InstructorGameMetadataResponse> =
+        game_ids.iter().filter_map(|id| by_id.remove(id)).collect();
+
+    info!(
+        "Successfully fetched metadata for {} games for instructor_id: {}",
+        results.len(),
+        instructor_id
+    );
+    Ok(ApiResponse::ok(results))
+}
+
+/// Lists every game instantiated from a course, with its player count.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor requesting the list.
+/// * `course_id`: The ID of the course.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<CourseGameSummary>`: Games referencing the course, ordered by ID (200 OK).
+/// * `403 Forbidden`: If the instructor does not own the course.
+/// * `404 Not Found`: If the course doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_games_by_course(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGamesByCourseParams>,
+) -> Result<ApiResponse<Vec<CourseGameSummary>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let course_id = params.course_id;
+
+    info!(
+        "Fetching games for course_id: {} by instructor_id: {}",
+        course_id, instructor_id
+    );
+    debug!("Get games by course params: {:?}", params);
+
+    helper::check_instructor_course_permission(&pool, instructor_id, course_id).await?;
+    info!(
+        "Permission check passed for instructor {} on course {}",
+        instructor_id, course_id
+    );
+
+    type CourseGameTuple = (i64, String, bool, bool, DateTime<Utc>, DateTime<Utc>); // id, title, active, public, start, end
+
+    let (games, player_counts) = helper::run_query(&pool, move |conn| {
+        let games = games_dsl::games
+            .filter(games_dsl::course_id.eq(course_id))
+            .order_by(games_dsl::id.asc())
+            .select((
+                games_dsl::id,
+                games_dsl::title,
+                games_dsl::active,
+                games_dsl::public,
+                games_dsl::start_date,
+                games_dsl::end_date,
+            ))
+            .load::<CourseGameTuple>(conn)?;
+
+        let game_ids: Vec<i64> = games.iter().map(|game| game.0).collect();
+        let player_counts = pr_dsl::player_registrations
+            .filter(pr_dsl::game_id.eq_any(game_ids))
+            .group_by(pr_dsl::game_id)
+            .select((pr_dsl::game_id, count_star()))
+            .load::<(i64, i64)>(conn)?
+            .into_iter()
+            .collect::<HashMap<i64, i64>>();
+
+        Ok((games, player_counts))
+    })
+    .await?;
+
+    let response_data: Vec<CourseGameSummary> = games
+        .into_iter()
+        .map(
+            |(id, title, active, public, start_date, end_date)| CourseGameSummary {
+                id,
+                title,
+                active,
+                public,
+                player_count: player_counts.get(&id).copied().unwrap_or(0),
+                start_date,
+                end_date,
+            },
+        )
+        .collect();
+
+    info!(
+        "Successfully fetched {} games for course_id: {}",
+        response_data.len(),
+        course_id
+    );
     Ok(ApiResponse::ok(response_data))
 }
 
+/// Lists the active games of an instructor whose end date falls within the next `within_days` days.
+///
+/// Only games owned by (or shared with) the instructor are considered; the admin (ID 0) sees all games.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `within_days`: Size of the window, in days from now.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<GameEndingSoonSummary>`: Matching games ordered by end date, soonest first (200 OK).
+/// * `404 Not Found`: If the specified instructor ID does not exist.
+/// * `422 Unprocessable Entity`: If `within_days` is negative.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_games_ending_soon(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGamesEndingSoonParams>,
+) -> Result<ApiResponse<Vec<GameEndingSoonSummary>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let within_days = params.within_days;
+
+    info!(
+        "Fetching games ending within {} days for instructor_id: {}",
+        within_days, instructor_id
+    );
+    debug!("Get games ending soon params: {:?}", params);
+
+    if within_days < 0 {
+        warn!("Rejecting negative within_days {}", within_days);
+        return Err(AppError::UnprocessableEntity(format!(
+            "within_days must not be negative, got {}.",
+            within_days
+        )));
+    }
+
+    let instructor_exists = helper::run_query(&pool, move |conn| {
+        diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+            .get_result::<bool>(conn)
+    })
+    .await?;
+
+    if !instructor_exists {
+        error!("Instructor with ID {} not found.", instructor_id);
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            instructor_id
+        )));
+    }
+
+    let now = Utc::now();
+    let window_end = now + Duration::days(within_days);
+    let games = helper::run_query(&pool, move |conn| {
+        let mut query = games_dsl::games
+            .filter(games_dsl::active.eq(true))
+            .filter(games_dsl::end_date.gt(now))
+            .filter(games_dsl::end_date.le(window_end))
+            .order_by((games_dsl::end_date.asc(), games_dsl::id.asc()))
+            .select((
+                games_dsl::id,
+                games_dsl::title,
+                games_dsl::course_id,
+                games_dsl::start_date,
+                games_dsl::end_date,
+            ))
+            .into_boxed();
+        if instructor_id != 0 {
+            query = query.filter(
+                games_dsl::id.eq_any(
+                    go_dsl::game_ownership
+                        .filter(go_dsl::instructor_id.eq(instructor_id))
+                        .select(go_dsl::game_id),
+                ),
+            );
+        }
+        query.load::<GameEndingSoonSummary>(conn)
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} games ending within {} days for instructor_id: {}",
+        games.len(),
+        within_days,
+        instructor_id
+    );
+    Ok(ApiResponse::ok(games))
+}
+
 /// Lists student IDs participating in a specific game, with optional filters.
+/// Soft-deleted players are never listed.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor requesting the list.
@@ -267,6 +665,7 @@ pub async fn list_students(
                 .inner_join(players_dsl::players.on(pr_dsl::player_id.eq(players_dsl::id)))
                 .inner_join(pg_dsl::player_groups.on(pg_dsl::player_id.eq(players_dsl::id)))
                 .filter(pg_dsl::group_id.eq(gid))
+                .filter(players_dsl::deleted_at.is_null())
                 .select(players_dsl::id)
                 .distinct()
                 .into_boxed();
@@ -281,6 +680,7 @@ pub async fn list_students(
             let mut query = pr_dsl::player_registrations
                 .filter(pr_dsl::game_id.eq(game_id))
                 .inner_join(players_dsl::players.on(pr_dsl::player_id.eq(players_dsl::id)))
+                .filter(players_dsl::deleted_at.is_null())
                 .select(players_dsl::id)
                 .distinct()
                 .into_boxed();
@@ -311,7 +711,7 @@ pub async fn list_students(
 /// * `player_id`: The ID of the student.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `StudentProgressResponse`: Attempts, solved count, and progress percentage (200 OK).
+/// * `StudentProgressResponse`: Attempts, solved count, progress percentage, and total recorded time spent (200 OK).
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
 /// * `404 Not Found`: If the game/player doesn't exist, or player not registered in game.
 /// * `500 Internal Server Error`: If a database error occurs.
@@ -386,6 +786,7 @@ pub async fn get_student_progress(
                 .filter(sub_dsl::player_id.eq(player_id))
                 .filter(sub_dsl::game_id.eq(game_id))
                 .filter(sub_dsl::first_solution.eq(true))
+                .filter(helper::counts_in_game(game_id))
                 .select(sub_dsl::exercise_id)
                 .distinct()
                 .count()
@@ -394,6 +795,18 @@ pub async fn get_student_progress(
     })
     .await?;
 
+    let time_spent_seconds = helper::run_query(&pool, {
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::player_id.eq(player_id))
+                .filter(sub_dsl::game_id.eq(game_id))
+                .select(diesel::dsl::sum(sub_dsl::time_spent_seconds))
+                .first::<Option<i64>>(conn)
+        }
+    })
+    .await?
+    .unwrap_or(0);
+
     let progress_percentage = if game_total_exercises > 0 {
         (solved_exercises_count as f64 / game_total_exercises as f64) * 100.0
     } else {
@@ -408,6 +821,7 @@ pub async fn get_student_progress(
         attempts: total_attempts,
         solved_exercises: solved_exercises_count,
         progress: progress_percentage,
+        time_spent_seconds,
     };
 
     info!(
@@ -517,16 +931,111 @@ pub async fn get_student_exercises(
     Ok(ApiResponse::ok(response_data))
 }
 
+/// Retrieves the non-hidden exercises counting towards a game (its exercise subset, or else the course's
+/// exercises in the game's programming language) that a specific student has not attempted yet.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `player_id`: The ID of the student.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<UnattemptedExerciseResponse>`: IDs and titles of unattempted exercises, in module/exercise order (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game/player doesn't exist, or player not registered in game.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_student_unattempted_exercises(
+    State(pool): State<Pool>,
+    Query(params): Query<GetStudentUnattemptedExercisesParams>,
+) -> Result<ApiResponse<Vec<UnattemptedExerciseResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let player_id = params.player_id;
+
+    info!(
+        "Fetching unattempted exercises for player_id: {} in game_id: {} requested by instructor_id: {}",
+        player_id, game_id, instructor_id
+    );
+    debug!("Get student unattempted exercises params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let is_registered = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(
+                pr_dsl::player_registrations
+                    .filter(pr_dsl::player_id.eq(player_id))
+                    .filter(pr_dsl::game_id.eq(game_id)),
+            ))
+            .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !is_registered {
+        warn!(
+            "Player {} is not registered in game {}. Cannot fetch unattempted exercises.",
+            player_id, game_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} is not registered in game with ID {}.",
+            player_id, game_id
+        )));
+    }
+    info!(
+        "Player {} confirmed registered in game {}.",
+        player_id, game_id
+    );
+
+    let unattempted_exercises = helper::run_query(&pool, {
+        move |conn| {
+            let game_exercise_ids = helper::game_exercise_ids(conn, game_id)?;
+
+            let attempted_exercise_ids = sub_dsl::submissions
+                .filter(sub_dsl::player_id.eq(player_id))
+                .filter(sub_dsl::game_id.eq(game_id))
+                .select(sub_dsl::exercise_id)
+                .distinct()
+                .load::<i64>(conn)?;
+
+            exercises_dsl::exercises
+                .inner_join(modules_dsl::modules.on(exercises_dsl::module_id.eq(modules_dsl::id)))
+                .filter(exercises_dsl::id.eq_any(game_exercise_ids))
+                .filter(exercises_dsl::hidden.eq(false))
+                .filter(exercises_dsl::id.ne_all(attempted_exercise_ids))
+                .order_by((modules_dsl::order.asc(), exercises_dsl::order.asc()))
+                .select((exercises_dsl::id, exercises_dsl::title))
+                .load::<UnattemptedExerciseResponse>(conn)
+        }
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} unattempted exercises for player_id: {} in game_id: {}",
+        unattempted_exercises.len(),
+        player_id,
+        game_id
+    );
+    Ok(ApiResponse::ok(unattempted_exercises))
+}
+
 /// Retrieves a list of submission IDs for a specific student within a game, with optional success filter.
+/// With `include_code`, the full submissions (as returned by `get_submission_data`) are returned instead.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor.
 /// * `game_id`: The ID of the game.
 /// * `player_id`: The ID of the student.
-/// * `success_only`: If true, filter for submissions with result >= 50.
+/// * `success_only`: If true, filter for submissions reaching the game's success threshold (50 by default).
+/// * `include_code`: If true, return full submissions including `submitted_code` rather than IDs.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `Vec<i64>`: List of submission IDs matching criteria (200 OK).
+/// * `StudentSubmissions`: List of submission IDs, or of full submissions, matching criteria, newest first (200 OK).
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
 /// * `404 Not Found`: If the game/player doesn't exist, or player not registered in game.
 /// * `500 Internal Server Error`: If a database error occurs.
@@ -534,15 +1043,16 @@ pub async fn get_student_exercises(
 pub async fn get_student_submissions(
     State(pool): State<Pool>,
     Query(params): Query<GetStudentSubmissionsParams>,
-) -> Result<ApiResponse<Vec<i64>>, AppError> {
+) -> Result<ApiResponse<StudentSubmissions>, AppError> {
     let instructor_id = params.instructor_id;
     let game_id = params.game_id;
     let player_id = params.player_id;
     let success_only_filter = params.success_only;
+    let include_code = params.include_code;
 
     info!(
-        "Fetching submissions for player_id: {} in game_id: {} requested by instructor_id: {}. Filter: success_only={}",
-        player_id, game_id, instructor_id, success_only_filter
+        "Fetching submissions for player_id: {} in game_id: {} requested by instructor_id: {}. Filter: success_only={}, include_code={}",
+        player_id, game_id, instructor_id, success_only_filter, include_code
     );
     debug!("Get student submissions params: {:?}", params);
 
@@ -579,7 +1089,9 @@ pub async fn get_student_submissions(
         player_id, game_id
     );
 
-    let submission_ids = helper::run_query(&pool, move |conn_sync| {
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+
+    let submissions = helper::run_query(&pool, move |conn_sync| {
         let player_id = player_id;
         let game_id = game_id;
         let success_only_filter = success_only_filter;
@@ -587,103 +1099,148 @@ pub async fn get_student_submissions(
         let mut query = sub_dsl::submissions
             .filter(sub_dsl::player_id.eq(player_id))
             .filter(sub_dsl::game_id.eq(game_id))
-            .select(sub_dsl::id)
             .order(sub_dsl::submitted_at.desc())
             .into_boxed();
 
         if success_only_filter {
-            info!("Applying filter: success_only = true (result >= 50)");
-            let success_threshold = BigDecimal::from(50);
+            info!(
+                "Applying filter: success_only = true (result >= {})",
+                success_threshold
+            );
             query = query.filter(sub_dsl::result.ge(success_threshold));
         }
 
-        query.load::<i64>(conn_sync)
+        if include_code {
+            query
+                .load::<SubmissionDataResponse>(conn_sync)
+                .map(StudentSubmissions::Submissions)
+        } else {
+            query
+                .select(sub_dsl::id)
+                .load::<i64>(conn_sync)
+                .map(StudentSubmissions::Ids)
+        }
     })
     .await?;
 
+    let submission_count = match &submissions {
+        StudentSubmissions::Ids(ids) => ids.len(),
+        StudentSubmissions::Submissions(submissions) => submissions.len(),
+    };
     info!(
-        "Successfully fetched {} submission IDs for player_id: {} in game_id: {} with applied filters.",
-        submission_ids.len(),
-        player_id,
-        game_id
+        "Successfully fetched {} submissions for player_id: {} in game_id: {} with applied filters.",
+        submission_count, player_id, game_id
     );
-    Ok(ApiResponse::ok(submission_ids))
+    Ok(ApiResponse::ok(submissions))
 }
 
-/// Retrieves the full data for a specific submission.
+/// Retrieves each attempted exercise's best submission for a student within a game, for grading.
+/// The best submission has the highest `result`; ties go to the earliest `entered_at`.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor.
-/// * `submission_id`: The ID of the submission.
+/// * `game_id`: The ID of the game.
+/// * `player_id`: The ID of the student.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `SubmissionDataResponse`: Full submission data (200 OK).
-/// * `403 Forbidden`: If the instructor lacks permission for the associated game.
-/// * `404 Not Found`: If the submission is not found or the associated game does not exist.
+/// * `Vec<SubmissionDataResponse>`: One submission per attempted exercise, ordered by exercise ID (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game/player doesn't exist, or player not registered in game.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
-pub async fn get_submission_data(
+pub async fn get_student_best_submissions(
     State(pool): State<Pool>,
-    Query(params): Query<GetSubmissionDataParams>,
-) -> Result<ApiResponse<SubmissionDataResponse>, AppError> {
+    Query(params): Query<GetStudentBestSubmissionsParams>,
+) -> Result<ApiResponse<Vec<SubmissionDataResponse>>, AppError> {
     let instructor_id = params.instructor_id;
-    let submission_id = params.submission_id;
+    let game_id = params.game_id;
+    let player_id = params.player_id;
 
     info!(
-        "Fetching data for submission_id: {} requested by instructor_id: {}",
-        submission_id, instructor_id
+        "Fetching best submissions for player_id: {} in game_id: {} requested by instructor_id: {}",
+        player_id, game_id, instructor_id
     );
-    debug!("Get submission data params: {:?}", params);
-
-    let submission_data = helper::run_query(&pool, {
-        move |conn| {
-            sub_dsl::submissions
-                .find(submission_id)
-                .first::<SubmissionDataResponse>(conn)
-        }
-    })
-    .await?;
+    debug!("Get student best submissions params: {:?}", params);
 
-    let game_id = submission_data.game_id;
     helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
-        "Permission check passed for instructor {} on game {} (associated with submission {})",
-        instructor_id, game_id, submission_id
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
     );
 
+    let is_registered = helper::run_query(&pool, move |conn| {
+        diesel::select(exists(
+            pr_dsl::player_registrations
+                .filter(pr_dsl::player_id.eq(player_id))
+                .filter(pr_dsl::game_id.eq(game_id)),
+        ))
+        .get_result::<bool>(conn)
+    })
+    .await?;
+
+    if !is_registered {
+        warn!(
+            "Player {} is not registered in game {}. Cannot fetch best submissions.",
+            player_id, game_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} is not registered in game with ID {}.",
+            player_id, game_id
+        )));
+    }
+
+    let submissions = helper::run_query(&pool, move |conn| {
+        sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .distinct_on(sub_dsl::exercise_id)
+            .order_by((
+                sub_dsl::exercise_id.asc(),
+                sub_dsl::result.desc(),
+                sub_dsl::entered_at.asc(),
+                sub_dsl::id.asc(),
+            ))
+            .load::<SubmissionDataResponse>(conn)
+    })
+    .await?;
+
     info!(
-        "Successfully fetched data for submission_id: {}",
-        submission_id
+        "Successfully fetched best submissions for {} exercises of player_id: {} in game_id: {}",
+        submissions.len(),
+        player_id,
+        game_id
     );
-    Ok(ApiResponse::ok(submission_data))
+    Ok(ApiResponse::ok(submissions))
 }
 
-/// Retrieves statistics for a specific exercise within a game.
+/// Retrieves the number of submissions a student entered per day within a game, for activity charts.
+///
+/// Submissions are grouped by the UTC date of `entered_at`; days without submissions are omitted.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor.
 /// * `game_id`: The ID of the game.
-/// * `exercise_id`: The ID of the exercise.
+/// * `player_id`: The ID of the student.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `ExerciseStatsResponse`: Calculated exercise statistics (200 OK).
+/// * `Vec<PlayerActivityDay>`: Submission counts per day in ascending date order, empty if there are none (200 OK).
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `404 Not Found`: If the game doesn't exist, or the player is not registered in the game.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
-pub async fn get_exercise_stats(
+pub async fn get_player_activity_by_day(
     State(pool): State<Pool>,
-    Query(params): Query<GetExerciseStatsParams>,
-) -> Result<ApiResponse<ExerciseStatsResponse>, AppError> {
+    Query(params): Query<GetPlayerActivityByDayParams>,
+) -> Result<ApiResponse<Vec<PlayerActivityDay>>, AppError> {
     let instructor_id = params.instructor_id;
     let game_id = params.game_id;
-    let exercise_id = params.exercise_id;
+    let player_id = params.player_id;
 
     info!(
-        "Fetching stats for exercise_id: {} in game_id: {} requested by instructor_id: {}",
-        exercise_id, game_id, instructor_id
+        "Fetching activity by day for player_id: {} in game_id: {} requested by instructor_id: {}",
+        player_id, game_id, instructor_id
     );
-    debug!("Get exercise stats params: {:?}", params);
+    debug!("Get player activity by day params: {:?}", params);
 
     helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
@@ -691,130 +1248,76 @@ pub async fn get_exercise_stats(
         instructor_id, game_id
     );
 
-    let exercise_exists = helper::run_query(&pool, {
+    let is_registered = helper::run_query(&pool, {
         move |conn| {
-            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
-                .get_result::<bool>(conn)
+            diesel::select(exists(
+                pr_dsl::player_registrations
+                    .filter(pr_dsl::player_id.eq(player_id))
+                    .filter(pr_dsl::game_id.eq(game_id)),
+            ))
+            .get_result::<bool>(conn)
         }
     })
     .await?;
 
-    if !exercise_exists {
-        error!(
-            "Cannot get stats: Exercise with ID {} not found.",
-            exercise_id
+    if !is_registered {
+        warn!(
+            "Player {} is not registered in game {}. Cannot fetch activity.",
+            player_id, game_id
         );
         return Err(AppError::NotFound(format!(
-            "Exercise with ID {} not found.",
-            exercise_id
+            "Player with ID {} is not registered in game with ID {}.",
+            player_id, game_id
         )));
     }
-    info!("Exercise {} confirmed to exist.", exercise_id);
-
-    let success_threshold = BigDecimal::from(50);
-
-    let total_attempts = helper::run_query(&pool, {
-        move |conn| {
-            sub_dsl::submissions
-                .filter(sub_dsl::game_id.eq(game_id))
-                .filter(sub_dsl::exercise_id.eq(exercise_id))
-                .count()
-                .get_result::<i64>(conn)
-        }
-    })
-    .await?;
-
-    let successful_attempts = helper::run_query(&pool, {
-        let success_threshold = success_threshold.clone();
-        move |conn| {
-            sub_dsl::submissions
-                .filter(sub_dsl::game_id.eq(game_id))
-                .filter(sub_dsl::exercise_id.eq(exercise_id))
-                .filter(sub_dsl::result.ge(success_threshold))
-                .count()
-                .get_result::<i64>(conn)
-        }
-    })
-    .await?;
-
-    let first_solutions_count = helper::run_query(&pool, {
-        move |conn| {
-            sub_dsl::submissions
-                .filter(sub_dsl::game_id.eq(game_id))
-                .filter(sub_dsl::exercise_id.eq(exercise_id))
-                .filter(sub_dsl::first_solution.eq(true))
-                .select(sub_dsl::player_id)
-                .distinct()
-                .count()
-                .get_result::<i64>(conn)
-        }
-    })
-    .await?;
 
-    let total_players_in_game = helper::run_query(&pool, {
-        move |conn| {
-            pr_dsl::player_registrations
-                .filter(pr_dsl::game_id.eq(game_id))
-                .count()
-                .get_result::<i64>(conn)
-        }
+    let activity = helper::run_query(&pool, move |conn| {
+        let day = sql::<Date>("(entered_at AT TIME ZONE 'UTC')::date");
+        sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .group_by(day.clone())
+            .select((day.clone(), count_star()))
+            .order_by(day)
+            .load::<PlayerActivityDay>(conn)
     })
     .await?;
 
-    let difficulty = if total_attempts > 0 {
-        100.0 - (successful_attempts as f64 / total_attempts as f64 * 100.0)
-    } else {
-        0.0
-    };
-
-    let solved_percentage = if total_players_in_game > 0 {
-        first_solutions_count as f64 / total_players_in_game as f64 * 100.0
-    } else {
-        0.0
-    };
-
-    let response_data = ExerciseStatsResponse {
-        attempts: total_attempts,
-        successful_attempts,
-        difficulty,
-        solved_percentage,
-    };
-
     info!(
-        "Successfully fetched stats for exercise_id: {} in game_id: {}. Attempts: {}, Success: {}, Difficulty: {:.2}, Solved%: {:.2}",
-        exercise_id, game_id, total_attempts, successful_attempts, difficulty, solved_percentage
+        "Successfully fetched activity on {} days for player_id: {} in game_id: {}",
+        activity.len(),
+        player_id,
+        game_id
     );
-    Ok(ApiResponse::ok(response_data))
+    Ok(ApiResponse::ok(activity))
 }
 
-/// Retrieves a list of submission IDs for a specific exercise within a game, with optional success filter.
+/// Retrieves a student's effective score in a game, as shown to the student by `get_player_score`.
 ///
 /// Query Parameters:
 /// * `instructor_id`: The ID of the instructor.
 /// * `game_id`: The ID of the game.
-/// * `exercise_id`: The ID of the exercise.
-/// * `success_only`: If true, filter for submissions with result >= 50.
+/// * `player_id`: The ID of the student.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `Vec<i64>`: List of submission IDs matching criteria (200 OK).
+/// * `PlayerScoreResponse`: The `base`, `rewards` and `penalties` points and their `total` (200 OK).
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `404 Not Found`: If the game doesn't exist, or the player is not registered in the game.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, params))]
-pub async fn get_exercise_submissions(
+pub async fn get_player_score(
     State(pool): State<Pool>,
-    Query(params): Query<GetExerciseSubmissionsParams>,
-) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    Query(params): Query<GetStudentScoreParams>,
+) -> Result<ApiResponse<PlayerScoreResponse>, AppError> {
     let instructor_id = params.instructor_id;
     let game_id = params.game_id;
-    let exercise_id = params.exercise_id;
-    let success_only_filter = params.success_only;
+    let player_id = params.player_id;
 
     info!(
-        "Fetching submissions for exercise_id: {} in game_id: {} requested by instructor_id: {}. Filter: success_only={}",
-        exercise_id, game_id, instructor_id, success_only_filter
+        "Fetching score for player_id: {} in game_id: {} requested by instructor_id: {}",
+        player_id, game_id, instructor_id
     );
-    debug!("Get exercise submissions params: {:?}", params);
+    debug!("Get player score params: {:?}", params);
 
     helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
@@ -822,254 +1325,489 @@ pub async fn get_exercise_submissions(
         instructor_id, game_id
     );
 
-    let exercise_exists = helper::run_query(&pool, {
-        move |conn| {
-            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
-                .get_result::<bool>(conn)
-        }
-    })
-    .await?;
-
-    if !exercise_exists {
-        error!(
-            "Cannot get submissions: Exercise with ID {} not found.",
-            exercise_id
-        );
-        return Err(AppError::NotFound(format!(
-            "Exercise with ID {} not found.",
-            exercise_id
-        )));
-    }
-    info!("Exercise {} confirmed to exist.", exercise_id);
-
-    let submission_ids = helper::run_query(&pool, move |conn_sync| {
-        let game_id = game_id;
-        let exercise_id = exercise_id;
-        let success_only_filter = success_only_filter;
-
-        let mut query = sub_dsl::submissions
-            .filter(sub_dsl::game_id.eq(game_id))
-            .filter(sub_dsl::exercise_id.eq(exercise_id))
-            .select(sub_dsl::id)
-            .order(sub_dsl::submitted_at.desc())
-            .into_boxed();
-
-        if success_only_filter {
-            info!("Applying filter: success_only = true (result >= 50)");
-            let success_threshold = BigDecimal::from(50);
-            query = query.filter(sub_dsl::result.ge(success_threshold));
-        }
-
-        query.load::<i64>(conn_sync)
+    let score = helper::interact(&pool, move |conn| {
+        helper::compute_player_score(conn, player_id, game_id)
     })
-    .await?;
+    .await??;
 
     info!(
-        "Successfully fetched {} submission IDs for exercise_id: {} in game_id: {} with applied filters.",
-        submission_ids.len(),
-        exercise_id,
-        game_id
+        "Player {} has a total score of {} in game {}",
+        player_id, score.total, game_id
     );
-    Ok(ApiResponse::ok(submission_ids))
+    Ok(ApiResponse::ok(score))
 }
 
-/// Creates a new game and assigns ownership to the requesting instructor.
+/// Retrieves the full data for a specific submission.
 ///
-/// Request Body: `CreateGamePayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `submission_id`: The ID of the submission.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `i64`: The ID of the newly created game (200 OK).
-/// * `404 Not Found`: If the specified instructor or course does not exist.
-/// * `422 Unprocessable Entity`: If the specified programming language is not allowed for the course.
-/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
-#[instrument(skip(pool, payload))]
-pub async fn create_game(
+/// * `SubmissionDataResponse`: Full submission data (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the associated game.
+/// * `404 Not Found`: If the submission is not found or the associated game does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_submission_data(
     State(pool): State<Pool>,
-    Json(payload): Json<CreateGamePayload>,
-) -> Result<ApiResponse<i64>, AppError> {
+    Query(params): Query<GetSubmissionDataParams>,
+) -> Result<ApiResponse<SubmissionDataResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let submission_id = params.submission_id;
+
     info!(
-        "Attempting to create game '{}' for course {} by instructor {}",
-        payload.title, payload.course_id, payload.instructor_id
+        "Fetching data for submission_id: {} requested by instructor_id: {}",
+        submission_id, instructor_id
     );
-    debug!("Create game payload: {:?}", payload);
+    debug!("Get submission data params: {:?}", params);
 
-    let instructor_exists = helper::run_query(&pool, {
-        let instructor_id = payload.instructor_id;
+    let submission_data = helper::run_query(&pool, {
         move |conn| {
-            diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
-                .get_result::<bool>(conn)
+            sub_dsl::submissions
+                .find(submission_id)
+                .first::<SubmissionDataResponse>(conn)
         }
     })
     .await?;
 
-    if !instructor_exists {
-        error!(
-            "Cannot create game: Instructor with ID {} not found.",
-            payload.instructor_id
-        );
+    let game_id = submission_data.game_id;
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {} (associated with submission {})",
+        instructor_id, game_id, submission_id
+    );
+
+    info!(
+        "Successfully fetched data for submission_id: {}",
+        submission_id
+    );
+    Ok(ApiResponse::ok(submission_data))
+}
+
+/// Maximum number of submission IDs accepted by `get_submissions_data` in one request.
+pub const MAX_SUBMISSIONS_PER_BATCH: usize = 100;
+
+/// Retrieves the full data for several submissions at once.
+///
+/// All submissions are loaded in a single query, then checked against the games the
+/// instructor may access. Submissions belonging to inaccessible games either fail the
+/// whole request or are dropped from the result, depending on `skip_forbidden`.
+/// Results follow the order of `submission_ids`; duplicate IDs are returned once.
+///
+/// Request Body: `GetSubmissionsDataPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<SubmissionDataResponse>`: Full data of the accessible submissions (200 OK).
+/// * `400 Bad Request`: If more than `MAX_SUBMISSIONS_PER_BATCH` IDs are requested.
+/// * `403 Forbidden`: If any submission belongs to a game the instructor lacks permission for and `skip_forbidden` is false.
+/// * `404 Not Found`: If the instructor or any of the submissions does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn get_submissions_data(
+    State(pool): State<Pool>,
+    Json(payload): Json<GetSubmissionsDataPayload>,
+) -> Result<ApiResponse<Vec<SubmissionDataResponse>>, AppError> {
+    let instructor_id = payload.instructor_id;
+
+    info!(
+        "Fetching data for {} submissions requested by instructor_id: {}",
+        payload.submission_ids.len(),
+        instructor_id
+    );
+    debug!("Get submissions data payload: {:?}", payload);
+
+    let mut submission_ids = payload.submission_ids;
+    let mut seen = HashSet::new();
+    submission_ids.retain(|id| seen.insert(*id));
+
+    if submission_ids.len() > MAX_SUBMISSIONS_PER_BATCH {
+        warn!(
+            "Rejecting batch of {} submission IDs (max {}).",
+            submission_ids.len(),
+            MAX_SUBMISSIONS_PER_BATCH
+        );
+        return Err(AppError::BadRequest(format!(
+            "At most {} submission IDs can be requested at once.",
+            MAX_SUBMISSIONS_PER_BATCH
+        )));
+    }
+
+    let instructor_exists = helper::run_query(&pool, move |conn| {
+        diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+            .get_result::<bool>(conn)
+    })
+    .await?;
+    if !instructor_exists {
+        error!("Instructor with ID {} not found.", instructor_id);
         return Err(AppError::NotFound(format!(
             "Instructor with ID {} not found.",
-            payload.instructor_id
+            instructor_id
         )));
     }
-    info!("Instructor {} confirmed to exist.", payload.instructor_id);
 
-    let course_languages = helper::run_query(&pool, {
-        let course_id = payload.course_id;
+    if submission_ids.is_empty() {
+        return Ok(ApiResponse::ok(Vec::new()));
+    }
+
+    let submissions = helper::run_query(&pool, {
+        let submission_ids = submission_ids.clone();
         move |conn| {
-            courses_dsl::courses
-                .find(course_id)
-                .select(courses_dsl::programming_languages)
-                .first::<String>(conn)
+            sub_dsl::submissions
+                .filter(sub_dsl::id.eq_any(submission_ids))
+                .load::<SubmissionDataResponse>(conn)
         }
     })
-    .await;
+    .await?;
 
-    let allowed_languages_str = match course_languages {
-        Ok(langs) => langs,
-        Err(AppError::NotFound(_)) => {
-            error!(
-                "Cannot create game: Course with ID {} not found.",
-                payload.course_id
+    if submissions.len() != submission_ids.len() {
+        let found: HashSet<i64> = submissions.iter().map(|s| s.id).collect();
+        let missing: Vec<i64> = submission_ids
+            .iter()
+            .copied()
+            .filter(|id| !found.contains(id))
+            .collect();
+        error!("Submissions not found: {:?}", missing);
+        return Err(AppError::NotFound(format!(
+            "Submissions with IDs {:?} not found.",
+            missing
+        )));
+    }
+
+    let accessible_games: Option<HashSet<i64>> = if instructor_id == 0 {
+        None
+    } else {
+        let game_ids: Vec<i64> = submissions
+            .iter()
+            .map(|s| s.game_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let owned = helper::run_query(&pool, move |conn| {
+            go_dsl::game_ownership
+                .filter(go_dsl::instructor_id.eq(instructor_id))
+                .filter(go_dsl::game_id.eq_any(game_ids))
+                .select(go_dsl::game_id)
+                .load::<i64>(conn)
+        })
+        .await?;
+        Some(owned.into_iter().collect())
+    };
+
+    let mut by_id: HashMap<i64, SubmissionDataResponse> = HashMap::new();
+    for submission in submissions {
+        let permitted = accessible_games
+            .as_ref()
+            .is_none_or(|games| games.contains(&submission.game_id));
+        if permitted {
+            by_id.insert(submission.id, submission);
+        } else if payload.skip_forbidden {
+            info!(
+                "Dropping submission {} (game {} not accessible to instructor {})",
+                submission.id, submission.game_id, instructor_id
             );
-            return Err(AppError::NotFound(format!(
-                "Course with ID {} not found.",
-                payload.course_id
+        } else {
+            warn!(
+                "Permission denied for instructor {} on submission {} (game {}).",
+                instructor_id, submission.id, submission.game_id
+            );
+            return Err(AppError::Forbidden(format!(
+                "Instructor {} does not have permission for submission {}.",
+                instructor_id, submission.id
             )));
         }
-        Err(e) => return Err(e),
-    };
+    }
 
-    let allowed_languages: Vec<&str> = allowed_languages_str
-        .split(',')
-        .map(|s| s.trim())
-        .filter(|s| !s.is_empty())
+    let results: Vec<SubmissionDataResponse> = submission_ids
+        .iter()
+        .filter_map(|id| by_id.remove(id))
         .collect();
 
-    if !allowed_languages.contains(&payload.programming_language.as_str()) {
+    info!(
+        "Successfully fetched data for {} submissions for instructor_id: {}",
+        results.len(),
+        instructor_id
+    );
+    Ok(ApiResponse::ok(results))
+}
+
+/// Maximum number of submissions accepted by `import_submissions` in one request.
+pub const MAX_IMPORTED_SUBMISSIONS: usize = 1000;
+/// `client` value recorded on submissions created by `import_submissions`.
+const IMPORTED_SUBMISSION_CLIENT: &str = "import";
+
+/// Imports externally graded submissions into a game in a single transaction.
+///
+/// Every player must be registered in the game (and not have left it) and every exercise must belong to the game's course.
+/// After inserting, `first_solution` is recomputed for each affected `(player_id, exercise_id)` pair
+/// so that only the earliest submission (by `entered_at`) reaching the game's success threshold carries it,
+/// and the affected players' `progress` is recomputed accordingly.
+/// Requires owner permission for the game (or admin).
+///
+/// Request Body: `ImportSubmissionsPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `ImportSubmissionsResponse`: Number of imported submissions and how many of them are first solutions (200 OK).
+/// * `400 Bad Request`: If no submissions or more than `MAX_IMPORTED_SUBMISSIONS` are supplied.
+/// * `403 Forbidden`: If the instructor is not an owner of the game.
+/// * `404 Not Found`: If the game does not exist.
+/// * `422 Unprocessable Entity`: If a result lies outside 0-100, a player is not registered in the game or has left it, or an exercise is not part of the game's course.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn import_submissions(
+    State(pool): State<Pool>,
+    Json(payload): Json<ImportSubmissionsPayload>,
+) -> Result<ApiResponse<ImportSubmissionsResponse>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to import {} submissions into game {} requested by instructor {}",
+        payload.submissions.len(),
+        game_id,
+        instructor_id
+    );
+    debug!("Import submissions payload: {:?}", payload);
+
+    if payload.submissions.is_empty() || payload.submissions.len() > MAX_IMPORTED_SUBMISSIONS {
         warn!(
-            "Invalid programming language '{}' for course {}. Allowed: {:?}",
-            payload.programming_language, payload.course_id, allowed_languages
+            "Rejecting import of {} submissions (allowed 1-{}).",
+            payload.submissions.len(),
+            MAX_IMPORTED_SUBMISSIONS
+        );
+        return Err(AppError::BadRequest(format!(
+            "Between 1 and {} submissions can be imported at once.",
+            MAX_IMPORTED_SUBMISSIONS
+        )));
+    }
+
+    let max_result = BigDecimal::from(100);
+    let zero = BigDecimal::from(0);
+    if let Some(record) = payload
+        .submissions
+        .iter()
+        .find(|record| record.result < zero || record.result > max_result)
+    {
+        warn!(
+            "Rejecting import: result {} for player {} on exercise {} is outside 0-100.",
+            record.result, record.player_id, record.exercise_id
         );
         return Err(AppError::UnprocessableEntity(format!(
-            "Programming language '{}' is not allowed for course {}. Allowed: {:?}",
-            payload.programming_language, payload.course_id, allowed_languages
+            "Submission result {} must be between 0 and 100.",
+            record.result
         )));
     }
+
+    helper::check_instructor_game_owner_permission(&pool, instructor_id, game_id).await?;
     info!(
-        "Programming language '{}' validated for course {}.",
-        payload.programming_language, payload.course_id
+        "Owner permission check passed for instructor {} on game {}",
+        instructor_id, game_id
     );
 
-    let total_exercises_count = helper::run_query(&pool, {
-        let course_id = payload.course_id;
-        let language = payload.programming_language.clone();
+    let threshold = helper::game_success_threshold(&pool, game_id).await?;
+
+    let player_ids: HashSet<i64> = payload.submissions.iter().map(|r| r.player_id).collect();
+    let exercise_ids: HashSet<i64> = payload.submissions.iter().map(|r| r.exercise_id).collect();
+
+    let (registered_players, course_exercises) = helper::run_query(&pool, {
+        let player_ids: Vec<i64> = player_ids.iter().copied().collect();
+        let exercise_ids: Vec<i64> = exercise_ids.iter().copied().collect();
         move |conn| {
-            exercises_dsl::exercises
-                .inner_join(modules_dsl::modules.on(exercises_dsl::module_id.eq(modules_dsl::id)))
+            let registered_players = pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::player_id.eq_any(player_ids))
+                .filter(pr_dsl::left_at.is_null())
+                .select(pr_dsl::player_id)
+                .load::<i64>(conn)?;
+
+            let course_id = games_dsl::games
+                .find(game_id)
+                .select(games_dsl::course_id)
+                .first::<i64>(conn)?;
+            let course_exercises = exercises_dsl::exercises
+                .inner_join(modules_dsl::modules)
                 .filter(modules_dsl::course_id.eq(course_id))
-                .filter(exercises_dsl::programming_language.eq(language))
-                .count()
-                .get_result::<i64>(conn)
+                .filter(exercises_dsl::id.eq_any(exercise_ids))
+                .select(exercises_dsl::id)
+                .load::<i64>(conn)?;
+
+            Ok((
+                registered_players.into_iter().collect::<HashSet<i64>>(),
+                course_exercises.into_iter().collect::<HashSet<i64>>(),
+            ))
         }
     })
     .await?;
-    info!(
-        "Calculated {} total exercises for course {} and language {}.",
-        total_exercises_count, payload.course_id, payload.programming_language
-    );
 
-    let conn = pool.get().await?;
-    let creation_result: Result<i64, AppError> = conn
-        .interact(move |conn_sync| {
-            let payload = payload;
-            conn_sync.transaction(|transaction_conn| {
-                let now = Utc::now();
-                let new_game = NewGame {
-                    title: payload.title,
-                    public: payload.public,
-                    active: payload.active,
-                    description: payload.description,
-                    course_id: payload.course_id,
-                    programming_language: payload.programming_language,
-                    module_lock: payload.module_lock,
-                    exercise_lock: payload.exercise_lock,
-                    total_exercises: total_exercises_count as i32,
-                    start_date: now,
-                    end_date: now + Duration::days(365),
-                };
+    let mut unregistered: Vec<i64> = player_ids
+        .difference(&registered_players)
+        .copied()
+        .collect();
+    if !unregistered.is_empty() {
+        unregistered.sort_unstable();
+        warn!(
+            "Rejecting import: players {:?} are not registered in game {}.",
+            unregistered, game_id
+        );
+        return Err(AppError::UnprocessableEntity(format!(
+            "Players {:?} are not registered in game {}.",
+            unregistered, game_id
+        )));
+    }
 
-                let inserted_game_id = diesel::insert_into(games_dsl::games)
-                    .values(&new_game)
-                    .returning(games_dsl::id)
-                    .get_result::<i64>(transaction_conn)
-                    .map_err(|e| {
-                        if let DieselError::DatabaseError(
-                            DatabaseErrorKind::ForeignKeyViolation,
-                            _,
-                        ) = e
-                        {
-                            AppError::NotFound(
-                                "Referenced course not found during transaction.".to_string(),
-                            )
-                        } else {
-                            AppError::from(e)
-                        }
-                    })?;
+    let mut foreign_exercises: Vec<i64> = exercise_ids
+        .difference(&course_exercises)
+        .copied()
+        .collect();
+    if !foreign_exercises.is_empty() {
+        foreign_exercises.sort_unstable();
+        warn!(
+            "Rejecting import: exercises {:?} are not part of game {}'s course.",
+            foreign_exercises, game_id
+        );
+        return Err(AppError::UnprocessableEntity(format!(
+            "Exercises {:?} do not belong to the course of game {}.",
+            foreign_exercises, game_id
+        )));
+    }
 
-                let new_ownership = NewGameOwnership {
-                    game_id: inserted_game_id,
-                    instructor_id: payload.instructor_id,
-                    owner: true,
-                };
+    let new_submissions: Vec<NewSubmission> = payload
+        .submissions
+        .into_iter()
+        .map(|record| NewSubmission {
+            exercise_id: record.exercise_id,
+            game_id,
+            player_id: record.player_id,
+            client: IMPORTED_SUBMISSION_CLIENT.to_string(),
+            submitted_code: String::new(),
+            metrics: json!({}),
+            result: record.result,
+            result_description: record.result_description,
+            first_solution: false,
+            feedback: String::new(),
+            earned_rewards: json!([]),
+            entered_at: record.entered_at,
+            time_spent_seconds: None,
+        })
+        .collect();
 
-                diesel::insert_into(go_dsl::game_ownership)
-                    .values(&new_ownership)
-                    .execute(transaction_conn)
-                    .map_err(|e| {
-                        if let DieselError::DatabaseError(
-                            DatabaseErrorKind::ForeignKeyViolation,
-                            _,
-                        ) = e
-                        {
-                            AppError::NotFound(
-                                "Referenced instructor not found during transaction.".to_string(),
-                            )
-                        } else {
-                            AppError::from(e)
-                        }
-                    })?;
+    let import_result = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            // Lock the registrations as `submit_solution` does, so `first_solution` is not decided
+            // concurrently for the same player; ordering by player avoids deadlocks between imports.
+            let mut locked_players: Vec<i64> = player_ids.into_iter().collect();
+            locked_players.sort_unstable();
+            pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::player_id.eq_any(&locked_players))
+                .order_by((pr_dsl::player_id.asc(), pr_dsl::id.asc()))
+                .select(pr_dsl::id)
+                .for_update()
+                .load::<i64>(tx_conn)?;
+
+            let inserted_ids = diesel::insert_into(sub_dsl::submissions)
+                .values(&new_submissions)
+                .returning(sub_dsl::id)
+                .get_results::<i64>(tx_conn)?;
+
+            let pairs: HashSet<(i64, i64)> = new_submissions
+                .iter()
+                .map(|s| (s.player_id, s.exercise_id))
+                .collect();
+            for (player_id, exercise_id) in pairs {
+                let pair_submissions = sub_dsl::submissions
+                    .filter(sub_dsl::game_id.eq(game_id))
+                    .filter(sub_dsl::player_id.eq(player_id))
+                    .filter(sub_dsl::exercise_id.eq(exercise_id));
+
+                let first_id = pair_submissions
+                    .filter(sub_dsl::result.ge(&threshold))
+                    .order_by((sub_dsl::entered_at.asc(), sub_dsl::id.asc()))
+                    .select(sub_dsl::id)
+                    .first::<i64>(tx_conn)
+                    .optional()?;
+
+                diesel::update(pair_submissions)
+                    .set(sub_dsl::first_solution.eq(false))
+                    .execute(tx_conn)?;
+                if let Some(first_id) = first_id {
+                    diesel::update(sub_dsl::submissions.find(first_id))
+                        .set(sub_dsl::first_solution.eq(true))
+                        .execute(tx_conn)?;
+                }
+            }
+
+            helper::sync_player_progress(tx_conn, game_id, &locked_players)?;
 
-                Ok(inserted_game_id)
+            let first_solutions = sub_dsl::submissions
+                .filter(sub_dsl::id.eq_any(&inserted_ids))
+                .filter(sub_dsl::first_solution.eq(true))
+                .count()
+                .get_result::<i64>(tx_conn)?;
+
+            Ok::<_, DieselError>(ImportSubmissionsResponse {
+                imported: inserted_ids.len() as i64,
+                first_solutions,
             })
         })
-        .await?;
+    })
+    .await?;
 
-    creation_result.map(ApiResponse::ok)
+    match import_result {
+        Ok(response) => {
+            info!(
+                "Imported {} submissions ({} first solutions) into game {}",
+                response.imported, response.first_solutions, game_id
+            );
+            Ok(ApiResponse::ok(response))
+        }
+        Err(diesel_err) => {
+            error!("Submission import transaction failed: {:?}", diesel_err);
+            Err(AppError::from(diesel_err))
+        }
+    }
 }
 
-/// Modifies settings of an existing game.
+/// Condition on `submissions` selecting the rows that count as attempts in exercise statistics:
+/// those reaching `min_meaningful_result`, or every row if the game sets none.
+fn meaningful_attempt_condition(
+    min_meaningful_result: Option<BigDecimal>,
+) -> Box<dyn BoxableExpression<sub_dsl::submissions, Pg, SqlType = Bool>> {
+    match min_meaningful_result {
+        Some(min_result) => Box::new(sub_dsl::result.ge(min_result)),
+        None => Box::new(true.into_sql::<Bool>()),
+    }
+}
+
+/// Retrieves statistics for a specific exercise within a game.
+/// Successful attempts are those reaching the game's success threshold (50 by default).
+/// Submissions below the game's `min_meaningful_result`, if set, are not counted as attempts.
+/// The average time to solve covers successful attempts that recorded `time_spent_seconds`.
 ///
-/// Request Body: `ModifyGamePayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `exercise_id`: The ID of the exercise.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the update was successful (200 OK).
+/// * `ExerciseStatsResponse`: Calculated exercise statistics (200 OK).
 /// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game doesn't exist.
-/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
-#[instrument(skip(pool, payload))]
-pub async fn modify_game(
+/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_stats(
     State(pool): State<Pool>,
-    Json(payload): Json<ModifyGamePayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let game_id = payload.game_id;
+    Query(params): Query<GetExerciseStatsParams>,
+) -> Result<ApiResponse<ExerciseStatsResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let exercise_id = params.exercise_id;
 
     info!(
-        "Attempting to modify game_id: {} requested by instructor_id: {}",
-        game_id, instructor_id
+        "Fetching stats for exercise_id: {} in game_id: {} requested by instructor_id: {}",
+        exercise_id, game_id, instructor_id
     );
-    debug!("Modify game payload: {:?}", payload);
+    debug!("Get exercise stats params: {:?}", params);
 
     helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
@@ -1077,48 +1815,1743 @@ pub async fn modify_game(
         instructor_id, game_id
     );
 
-    let changeset = GameChangeset {
-        title: payload.title,
-        public: payload.public,
-        active: payload.active,
-        description: payload.description,
-        module_lock: payload.module_lock,
-        exercise_lock: payload.exercise_lock,
-        updated_at: Some(Utc::now()),
-    };
-
-    let has_updates = changeset.title.is_some()
-        || changeset.public.is_some()
-        || changeset.active.is_some()
-        || changeset.description.is_some()
-        || changeset.module_lock.is_some()
-        || changeset.exercise_lock.is_some();
-
-    if !has_updates {
-        info!(
-            "No update fields provided for game {}. Returning success.",
-            game_id
-        );
-        return Ok(ApiResponse::ok(true));
-    }
-
-    let rows_affected = helper::run_query(&pool, {
+    let exercise_exists = helper::run_query(&pool, {
         move |conn| {
-            diesel::update(games_dsl::games.find(game_id))
-                .set(&changeset)
-                .execute(conn)
+            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
+                .get_result::<bool>(conn)
         }
     })
     .await?;
 
-    match rows_affected {
-        1 => {
-            info!("Successfully modified game {}", game_id);
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
+    if !exercise_exists {
+        error!(
+            "Cannot get stats: Exercise with ID {} not found.",
+            exercise_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    }
+    info!("Exercise {} confirmed to exist.", exercise_id);
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+    let min_meaningful_result = helper::game_min_meaningful_result(&pool, game_id).await?;
+
+    let total_attempts = helper::run_query(&pool, {
+        let min_meaningful_result = min_meaningful_result.clone();
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::exercise_id.eq(exercise_id))
+                .filter(meaningful_attempt_condition(min_meaningful_result))
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let successful_attempts = helper::run_query(&pool, {
+        let success_threshold = success_threshold.clone();
+        let min_meaningful_result = min_meaningful_result.clone();
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::exercise_id.eq(exercise_id))
+                .filter(sub_dsl::result.ge(success_threshold))
+                .filter(meaningful_attempt_condition(min_meaningful_result))
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let first_solutions_count = helper::run_query(&pool, {
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::exercise_id.eq(exercise_id))
+                .filter(sub_dsl::first_solution.eq(true))
+                .select(sub_dsl::player_id)
+                .distinct()
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let average_time_to_solve = helper::run_query(&pool, {
+        let success_threshold = success_threshold.clone();
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::exercise_id.eq(exercise_id))
+                .filter(sub_dsl::result.ge(success_threshold))
+                .filter(meaningful_attempt_condition(min_meaningful_result))
+                .select(diesel::dsl::avg(sub_dsl::time_spent_seconds))
+                .first::<Option<BigDecimal>>(conn)
+        }
+    })
+    .await?;
+
+    let total_players_in_game = helper::run_query(&pool, {
+        move |conn| {
+            pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let difficulty = if total_attempts > 0 {
+        100.0 - (successful_attempts as f64 / total_attempts as f64 * 100.0)
+    } else {
+        0.0
+    };
+
+    let solved_percentage = if total_players_in_game > 0 {
+        first_solutions_count as f64 / total_players_in_game as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    let response_data = ExerciseStatsResponse {
+        attempts: total_attempts,
+        successful_attempts,
+        difficulty,
+        solved_percentage,
+        average_time_to_solve_seconds: average_time_to_solve.and_then(|avg| avg.to_f64()),
+    };
+
+    info!(
+        "Successfully fetched stats for exercise_id: {} in game_id: {}. Attempts: {}, Success: {}, Difficulty: {:.2}, Solved%: {:.2}",
+        exercise_id, game_id, total_attempts, successful_attempts, difficulty, solved_percentage
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Retrieves the statistics of `get_exercise_stats` for every exercise of a game's course at once.
+/// The submissions are aggregated per exercise in a single grouped query.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<GameExerciseStats>`: Statistics per exercise, in module and exercise order (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_all_exercise_stats(
+    State(pool): State<Pool>,
+    Query(params): Query<GetAllExerciseStatsParams>,
+) -> Result<ApiResponse<Vec<GameExerciseStats>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching stats for all exercises of game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Get all exercise stats params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+    let min_meaningful_result = helper::game_min_meaningful_result(&pool, game_id).await?;
+
+    type ExerciseAggregates = (i64, i64, i64, i64, Option<BigDecimal>); // exercise_id, attempts, successful, first solvers, avg time
+    let (exercise_ids, aggregates, total_players_in_game) =
+        helper::run_query(&pool, move |conn| {
+            let course_id = games_dsl::games
+                .find(game_id)
+                .select(games_dsl::course_id)
+                .first::<i64>(conn)?;
+            let exercise_ids = exercises_dsl::exercises
+                .inner_join(modules_dsl::modules)
+                .filter(modules_dsl::course_id.eq(course_id))
+                .order_by((modules_dsl::order.asc(), exercises_dsl::order.asc()))
+                .select(exercises_dsl::id)
+                .load::<i64>(conn)?;
+
+            let meaningful = || meaningful_attempt_condition(min_meaningful_result.clone());
+            let successful = || sub_dsl::result.ge(success_threshold.clone()).and(meaningful());
+            let aggregates = sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .group_by(sub_dsl::exercise_id)
+                .select((
+                    sub_dsl::exercise_id,
+                    sql::<BigInt>("COUNT(*) FILTER (WHERE ")
+                        .bind::<Bool, _>(meaningful())
+                        .sql(")"),
+                    sql::<BigInt>("COUNT(*) FILTER (WHERE ")
+                        .bind::<Bool, _>(successful())
+                        .sql(")"),
+                    sql::<BigInt>(
+                        "COUNT(DISTINCT submissions.player_id) FILTER (WHERE submissions.first_solution)",
+                    ),
+                    sql::<Nullable<Numeric>>("AVG(submissions.time_spent_seconds) FILTER (WHERE ")
+                        .bind::<Bool, _>(successful())
+                        .sql(")"),
+                ))
+                .load::<ExerciseAggregates>(conn)?
+                .into_iter()
+                .map(|aggregate| (aggregate.0, aggregate))
+                .collect::<HashMap<i64, ExerciseAggregates>>();
+
+            let total_players_in_game = pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .count()
+                .get_result::<i64>(conn)?;
+
+            Ok((exercise_ids, aggregates, total_players_in_game))
+        })
+        .await?;
+
+    let response_data: Vec<GameExerciseStats> = exercise_ids
+        .into_iter()
+        .map(|exercise_id| {
+            let (_, attempts, successful_attempts, first_solutions_count, average_time_to_solve) =
+                aggregates
+                    .get(&exercise_id)
+                    .cloned()
+                    .unwrap_or((exercise_id, 0, 0, 0, None));
+            let difficulty = if attempts > 0 {
+                100.0 - (successful_attempts as f64 / attempts as f64 * 100.0)
+            } else {
+                0.0
+            };
+            let solved_percentage = if total_players_in_game > 0 {
+                first_solutions_count as f64 / total_players_in_game as f64 * 100.0
+            } else {
+                0.0
+            };
+            GameExerciseStats {
+                exercise_id,
+                stats: ExerciseStatsResponse {
+                    attempts,
+                    successful_attempts,
+                    difficulty,
+                    solved_percentage,
+                    average_time_to_solve_seconds: average_time_to_solve
+                        .and_then(|avg| avg.to_f64()),
+                },
+            }
+        })
+        .collect();
+
+    info!(
+        "Successfully fetched stats for {} exercises in game_id: {}",
+        response_data.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+define_sql_function! {
+    /// SQL `BOOL_AND` aggregate: whether a condition holds for every row of a group.
+    #[aggregate]
+    fn bool_and(condition: Bool) -> Bool;
+}
+
+/// Retrieves the students of a game who keep failing an exercise they have not solved yet.
+/// Students who left the game or whose account was soft-deleted are not listed. A failing attempt is a submission below the game's success threshold (50 by default); exercises
+/// with any successful attempt are ignored. The attempts are counted per player and exercise in a single grouped query.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `min_failed_attempts`: Minimum number of failing attempts on an exercise for a student to be listed.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<StrugglingStudent>`: Students ordered by player ID, each with the offending exercise IDs in ascending order (200 OK).
+/// * `400 Bad Request`: If `min_failed_attempts` is less than 1.
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_struggling_students(
+    State(pool): State<Pool>,
+    Query(params): Query<GetStrugglingStudentsParams>,
+) -> Result<ApiResponse<Vec<StrugglingStudent>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let min_failed_attempts = params.min_failed_attempts;
+
+    info!(
+        "Fetching students with at least {} failed attempts in game_id: {} requested by instructor_id: {}",
+        min_failed_attempts, game_id, instructor_id
+    );
+    debug!("Get struggling students params: {:?}", params);
+
+    if min_failed_attempts < 1 {
+        warn!(
+            "Rejecting min_failed_attempts {} for game {}.",
+            min_failed_attempts, game_id
+        );
+        return Err(AppError::BadRequest(
+            "min_failed_attempts must be at least 1.".to_string(),
+        ));
+    }
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+
+    let stuck_exercises = helper::run_query(&pool, move |conn| {
+        sub_dsl::submissions
+            .inner_join(
+                pr_dsl::player_registrations.on(pr_dsl::player_id
+                    .eq(sub_dsl::player_id)
+                    .and(pr_dsl::game_id.eq(sub_dsl::game_id))),
+            )
+            .inner_join(players_dsl::players.on(players_dsl::id.eq(sub_dsl::player_id)))
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(pr_dsl::left_at.is_null())
+            .filter(players_dsl::deleted_at.is_null())
+            .group_by((sub_dsl::player_id, sub_dsl::exercise_id))
+            .having(
+                bool_and(sub_dsl::result.lt(success_threshold))
+                    .and(count_star().ge(min_failed_attempts)),
+            )
+            .order_by((sub_dsl::player_id.asc(), sub_dsl::exercise_id.asc()))
+            .select((sub_dsl::player_id, sub_dsl::exercise_id))
+            .load::<(i64, i64)>(conn)
+    })
+    .await?;
+
+    let mut students: Vec<StrugglingStudent> = Vec::new();
+    for (player_id, exercise_id) in stuck_exercises {
+        match students.last_mut() {
+            Some(student) if student.player_id == player_id => {
+                student.exercise_ids.push(exercise_id)
+            }
+            _ => students.push(StrugglingStudent {
+                player_id,
+                exercise_ids: vec![exercise_id],
+            }),
+        }
+    }
+
+    info!(
+        "Found {} struggling students in game_id: {}",
+        students.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(students))
+}
+
+/// Retrieves the distribution of submission scores for an exercise within a game.
+///
+/// Scores are grouped into ten buckets of width 10 (0–9, 10–19, …, 80–89), the last
+/// bucket (90–100) also holding perfect scores. Every bucket is returned, empty ones with a
+/// count of 0.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `exercise_id`: The ID of the exercise.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<ScoreHistogramBucket>`: Submission counts per score range, ascending (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_score_histogram(
+    State(pool): State<Pool>,
+    Query(params): Query<GetExerciseScoreHistogramParams>,
+) -> Result<ApiResponse<Vec<ScoreHistogramBucket>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let exercise_id = params.exercise_id;
+
+    info!(
+        "Fetching score histogram for exercise_id: {} in game_id: {} requested by instructor_id: {}",
+        exercise_id, game_id, instructor_id
+    );
+    debug!("Get exercise score histogram params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let exercise_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
+                .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !exercise_exists {
+        error!(
+            "Cannot get score histogram: Exercise with ID {} not found.",
+            exercise_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    }
+    info!("Exercise {} confirmed to exist.", exercise_id);
+
+    let score_counts = helper::run_query(&pool, move |conn| {
+        let score = sql::<BigInt>("FLOOR(result)::BIGINT");
+        sub_dsl::submissions
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq(exercise_id))
+            .group_by(score.clone())
+            .select((score, count_star()))
+            .load::<(i64, i64)>(conn)
+    })
+    .await?;
+
+    let histogram: Vec<ScoreHistogramBucket> = helper::percentage_buckets(score_counts)
+        .into_iter()
+        .map(|bucket| ScoreHistogramBucket {
+            min_score: bucket.min,
+            max_score: bucket.max,
+            count: bucket.count,
+        })
+        .collect();
+
+    info!(
+        "Successfully calculated score histogram for exercise {} in game {}",
+        exercise_id, game_id
+    );
+    Ok(ApiResponse::ok(histogram))
+}
+
+/// Lists the players who solved an exercise within a game, fastest first.
+/// Players are ranked by the `entered_at` of their `first_solution` submission, ties broken by player ID.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `exercise_id`: The ID of the exercise.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<FirstSolver>`: Each solver's ID, display name and first solution timestamp (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_first_solvers(
+    State(pool): State<Pool>,
+    Query(params): Query<GetExerciseFirstSolversParams>,
+) -> Result<ApiResponse<Vec<FirstSolver>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let exercise_id = params.exercise_id;
+
+    info!(
+        "Fetching first solvers of exercise_id: {} in game_id: {} requested by instructor_id: {}",
+        exercise_id, game_id, instructor_id
+    );
+    debug!("Get exercise first solvers params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let exercise_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
+                .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !exercise_exists {
+        error!(
+            "Cannot get first solvers: Exercise with ID {} not found.",
+            exercise_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    }
+    info!("Exercise {} confirmed to exist.", exercise_id);
+
+    let solvers = helper::run_query(&pool, move |conn| {
+        let solved_at = diesel::dsl::min(sub_dsl::entered_at);
+        sub_dsl::submissions
+            .inner_join(players_dsl::players)
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq(exercise_id))
+            .filter(sub_dsl::first_solution.eq(true))
+            .filter(players_dsl::deleted_at.is_null())
+            .group_by((players_dsl::id, players_dsl::display_name))
+            .select((players_dsl::id, players_dsl::display_name, solved_at))
+            .order_by((solved_at.asc(), players_dsl::id.asc()))
+            .load::<(i64, String, Option<DateTime<Utc>>)>(conn)
+    })
+    .await?;
+    let solvers: Vec<FirstSolver> = solvers
+        .into_iter()
+        .filter_map(|(player_id, display_name, entered_at)| {
+            entered_at.map(|entered_at| FirstSolver {
+                player_id,
+                display_name,
+                entered_at,
+            })
+        })
+        .collect();
+
+    info!(
+        "Found {} first solvers of exercise {} in game {}",
+        solvers.len(),
+        exercise_id,
+        game_id
+    );
+    Ok(ApiResponse::ok(solvers))
+}
+
+/// Retrieves a list of submission IDs for a specific exercise within a game, with optional success filter.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `exercise_id`: The ID of the exercise.
+/// * `success_only`: If true, filter for submissions reaching the game's success threshold (50 by default).
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<i64>`: List of submission IDs matching criteria (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game or exercise doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_exercise_submissions(
+    State(pool): State<Pool>,
+    Query(params): Query<GetExerciseSubmissionsParams>,
+) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let exercise_id = params.exercise_id;
+    let success_only_filter = params.success_only;
+
+    info!(
+        "Fetching submissions for exercise_id: {} in game_id: {} requested by instructor_id: {}. Filter: success_only={}",
+        exercise_id, game_id, instructor_id, success_only_filter
+    );
+    debug!("Get exercise submissions params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let exercise_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(exercises_dsl::exercises.find(exercise_id)))
+                .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !exercise_exists {
+        error!(
+            "Cannot get submissions: Exercise with ID {} not found.",
+            exercise_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Exercise with ID {} not found.",
+            exercise_id
+        )));
+    }
+    info!("Exercise {} confirmed to exist.", exercise_id);
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+
+    let submission_ids = helper::run_query(&pool, move |conn_sync| {
+        let game_id = game_id;
+        let exercise_id = exercise_id;
+        let success_only_filter = success_only_filter;
+
+        let mut query = sub_dsl::submissions
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::exercise_id.eq(exercise_id))
+            .select(sub_dsl::id)
+            .order(sub_dsl::submitted_at.desc())
+            .into_boxed();
+
+        if success_only_filter {
+            info!(
+                "Applying filter: success_only = true (result >= {})",
+                success_threshold
+            );
+            query = query.filter(sub_dsl::result.ge(success_threshold));
+        }
+
+        query.load::<i64>(conn_sync)
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} submission IDs for exercise_id: {} in game_id: {} with applied filters.",
+        submission_ids.len(),
+        exercise_id,
+        game_id
+    );
+    Ok(ApiResponse::ok(submission_ids))
+}
+
+/// Retrieves aggregated statistics for the active members of a group within a game.
+/// Active members are non-disabled players currently in the group and registered in the game.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+/// * `group_id`: The ID of the group.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `GroupStatsResponse`: Member count, total submissions, distinct solved exercises and average progress (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game or group doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_group_stats(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGroupStatsParams>,
+) -> Result<ApiResponse<GroupStatsResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+    let group_id = params.group_id;
+
+    info!(
+        "Fetching stats for group_id: {} in game_id: {} requested by instructor_id: {}",
+        group_id, game_id, instructor_id
+    );
+    debug!("Get group stats params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let group_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(groups_dsl::groups.find(group_id))).get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !group_exists {
+        error!("Cannot get stats: Group with ID {} not found.", group_id);
+        return Err(AppError::NotFound(format!(
+            "Group with ID {} not found.",
+            group_id
+        )));
+    }
+    info!("Group {} confirmed to exist.", group_id);
+
+    let member_progress = helper::run_query(&pool, {
+        move |conn| {
+            pr_dsl::player_registrations
+                .inner_join(pg_dsl::player_groups.on(pg_dsl::player_id.eq(pr_dsl::player_id)))
+                .inner_join(players_dsl::players.on(players_dsl::id.eq(pr_dsl::player_id)))
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::left_at.is_null())
+                .filter(pg_dsl::group_id.eq(group_id))
+                .filter(pg_dsl::left_at.is_null())
+                .filter(players_dsl::disabled.eq(false))
+                .filter(players_dsl::deleted_at.is_null())
+                .select((pr_dsl::player_id, pr_dsl::progress))
+                .load::<(i64, i32)>(conn)
+        }
+    })
+    .await?;
+
+    let member_ids: Vec<i64> = member_progress.iter().map(|(id, _)| *id).collect();
+
+    let game_total_exercises = helper::run_query(&pool, {
+        move |conn| {
+            games_dsl::games
+                .find(game_id)
+                .select(games_dsl::total_exercises)
+                .first::<i32>(conn)
+        }
+    })
+    .await?;
+
+    let total_submissions = helper::run_query(&pool, {
+        let member_ids = member_ids.clone();
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::player_id.eq_any(member_ids))
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let success_threshold = helper::game_success_threshold(&pool, game_id).await?;
+    let solved_exercises = helper::run_query(&pool, {
+        let member_ids = member_ids.clone();
+        move |conn| {
+            sub_dsl::submissions
+                .filter(sub_dsl::game_id.eq(game_id))
+                .filter(sub_dsl::player_id.eq_any(member_ids))
+                .filter(sub_dsl::result.ge(success_threshold))
+                .select(sub_dsl::exercise_id)
+                .distinct()
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+
+    let average_progress = if !member_progress.is_empty() && game_total_exercises > 0 {
+        let total_progress: f64 = member_progress
+            .iter()
+            .map(|(_, progress)| *progress as f64 / game_total_exercises as f64 * 100.0)
+            .sum();
+        total_progress / member_progress.len() as f64
+    } else {
+        0.0
+    };
+
+    let response_data = GroupStatsResponse {
+        members: member_ids.len() as i64,
+        total_submissions,
+        solved_exercises,
+        average_progress,
+    };
+
+    info!(
+        "Successfully fetched stats for group_id: {} in game_id: {}. Members: {}, Submissions: {}, Solved: {}, Avg progress: {:.2}%",
+        group_id,
+        game_id,
+        response_data.members,
+        total_submissions,
+        solved_exercises,
+        average_progress
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Counts the active players of a game per progress range (0-9%, 10-19%, ..., 90-100%).
+/// A player's progress is their number of distinctly first-solved exercises relative to `total_exercises`.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<ProgressDistributionBucket>`: Player counts per progress range, ascending (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_progress_distribution(
+    State(pool): State<Pool>,
+    Query(params): Query<GetProgressDistributionParams>,
+) -> Result<ApiResponse<Vec<ProgressDistributionBucket>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching progress distribution for game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Get progress distribution params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let game_total_exercises = helper::run_query(&pool, {
+        move |conn| {
+            games_dsl::games
+                .find(game_id)
+                .select(games_dsl::total_exercises)
+                .first::<i32>(conn)
+        }
+    })
+    .await?;
+
+    let solved_per_player = helper::run_query(&pool, move |conn| {
+        pr_dsl::player_registrations
+            .inner_join(players_dsl::players.on(players_dsl::id.eq(pr_dsl::player_id)))
+            .left_join(
+                sub_dsl::submissions.on(sub_dsl::player_id
+                    .eq(pr_dsl::player_id)
+                    .and(sub_dsl::game_id.eq(pr_dsl::game_id))
+                    .and(sub_dsl::first_solution.eq(true))
+                    .and(helper::counts_in_game(game_id))),
+            )
+            .filter(pr_dsl::game_id.eq(game_id))
+            .filter(pr_dsl::left_at.is_null())
+            .filter(players_dsl::disabled.eq(false))
+            .filter(players_dsl::deleted_at.is_null())
+            .group_by(pr_dsl::player_id)
+            .select((
+                pr_dsl::player_id,
+                diesel::dsl::count_distinct(sub_dsl::exercise_id.nullable()),
+            ))
+            .load::<(i64, i64)>(conn)
+    })
+    .await?;
+
+    if game_total_exercises <= 0 {
+        warn!(
+            "Game {} has total_exercises <= 0. Counting every player at 0% progress.",
+            game_id
+        );
+    }
+
+    let progress_per_player = solved_per_player.iter().map(|(_, solved)| {
+        let progress = if game_total_exercises > 0 {
+            solved * 100 / i64::from(game_total_exercises)
+        } else {
+            0
+        };
+        (progress, 1)
+    });
+    let distribution: Vec<ProgressDistributionBucket> =
+        helper::percentage_buckets(progress_per_player)
+            .into_iter()
+            .map(|bucket| ProgressDistributionBucket {
+                min_progress: bucket.min,
+                max_progress: bucket.max,
+                players: bucket.count,
+            })
+            .collect();
+
+    info!(
+        "Successfully calculated progress distribution of {} players in game {}",
+        solved_per_player.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(distribution))
+}
+
+/// Retrieves the end summary recorded for a game once its end date has passed.
+/// Summaries are produced by the background game end scanner.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `GameEndSummaryResponse`: Player count and average progress at game end (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist or no summary has been recorded for it yet.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_game_end_summary(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGameEndSummaryParams>,
+) -> Result<ApiResponse<GameEndSummaryResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching end summary for game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Get game end summary params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let summary = helper::run_query(&pool, {
+        move |conn| {
+            ges_dsl::game_end_summaries
+                .filter(ges_dsl::game_id.eq(game_id))
+                .select((
+                    ges_dsl::game_id,
+                    ges_dsl::player_count,
+                    ges_dsl::average_progress,
+                    ges_dsl::created_at,
+                ))
+                .first::<GameEndSummaryResponse>(conn)
+                .optional()
+        }
+    })
+    .await?;
+
+    match summary {
+        Some(summary) => {
+            info!("Successfully fetched end summary for game_id: {}", game_id);
+            Ok(ApiResponse::ok(summary))
+        }
+        None => {
+            warn!("No end summary recorded yet for game_id: {}", game_id);
+            Err(AppError::NotFound(format!(
+                "No end summary recorded for game with ID {}.",
+                game_id
+            )))
+        }
+    }
+}
+
+/// Lists the instructors associated with a game, owners and non-owners alike, ordered by instructor ID.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+/// * `game_id`: The ID of the game.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<GameInstructorResponse>`: Each instructor's ID, email, display name and `owner` flag (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_game_instructors(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGameInstructorsParams>,
+) -> Result<ApiResponse<Vec<GameInstructorResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
+
+    info!(
+        "Fetching instructors of game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Get game instructors params: {:?}", params);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let instructors = helper::run_query(&pool, move |conn| {
+        go_dsl::game_ownership
+            .inner_join(instructors_dsl::instructors)
+            .filter(go_dsl::game_id.eq(game_id))
+            .order_by(instructors_dsl::id.asc())
+            .select((
+                instructors_dsl::id,
+                instructors_dsl::email,
+                instructors_dsl::display_name,
+                go_dsl::owner,
+            ))
+            .load::<GameInstructorResponse>(conn)
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} instructors of game_id: {}",
+        instructors.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(instructors))
+}
+
+/// Creates a new game and assigns ownership to the requesting instructor.
+///
+/// Request Body: `CreateGamePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The ID of the newly created game (200 OK).
+/// * `403 Forbidden`: If the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the specified instructor or course does not exist.
+/// * `422 Unprocessable Entity`: If the specified programming language is not allowed for the course,
+///   the success threshold or `min_meaningful_result` is outside 0-100, or `max_attempts_per_exercise` is below 1.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn create_game(
+    State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
+    Json(payload): Json<CreateGamePayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    info!(
+        "Attempting to create game '{}' for course {} by instructor {}",
+        payload.title, payload.course_id, payload.instructor_id
+    );
+    debug!("Create game payload: {:?}", payload);
+
+    acting_instructor.ensure_acts_as(payload.instructor_id)?;
+    helper::validate_success_threshold(payload.success_threshold)?;
+    helper::validate_max_attempts(payload.max_attempts_per_exercise)?;
+    helper::validate_min_meaningful_result(payload.min_meaningful_result)?;
+
+    let instructor_exists = helper::run_query(&pool, {
+        let instructor_id = payload.instructor_id;
+        move |conn| {
+            diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+                .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !instructor_exists {
+        error!(
+            "Cannot create game: Instructor with ID {} not found.",
+            payload.instructor_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            payload.instructor_id
+        )));
+    }
+    info!("Instructor {} confirmed to exist.", payload.instructor_id);
+
+    let course_languages = helper::run_query(&pool, {
+        let course_id = payload.course_id;
+        move |conn| {
+            courses_dsl::courses
+                .find(course_id)
+                .select(courses_dsl::programming_languages)
+                .first::<String>(conn)
+        }
+    })
+    .await;
+
+    let allowed_languages_str = match course_languages {
+        Ok(langs) => langs,
+        Err(AppError::NotFound(_)) => {
+            error!(
+                "Cannot create game: Course with ID {} not found.",
+                payload.course_id
+            );
+            return Err(AppError::NotFound(format!(
+                "Course with ID {} not found.",
+                payload.course_id
+            )));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let allowed_languages: Vec<&str> = allowed_languages_str
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if !allowed_languages.contains(&payload.programming_language.as_str()) {
+        warn!(
+            "Invalid programming language '{}' for course {}. Allowed: {:?}",
+            payload.programming_language, payload.course_id, allowed_languages
+        );
+        return Err(AppError::UnprocessableEntity(format!(
+            "Programming language '{}' is not allowed for course {}. Allowed: {:?}",
+            payload.programming_language, payload.course_id, allowed_languages
+        )));
+    }
+    info!(
+        "Programming language '{}' validated for course {}.",
+        payload.programming_language, payload.course_id
+    );
+
+    let total_exercises_count = helper::run_query(&pool, {
+        let course_id = payload.course_id;
+        let language = payload.programming_language.clone();
+        move |conn| {
+            exercises_dsl::exercises
+                .inner_join(modules_dsl::modules.on(exercises_dsl::module_id.eq(modules_dsl::id)))
+                .filter(modules_dsl::course_id.eq(course_id))
+                .filter(exercises_dsl::programming_language.eq(language))
+                .count()
+                .get_result::<i64>(conn)
+        }
+    })
+    .await?;
+    info!(
+        "Calculated {} total exercises for course {} and language {}.",
+        total_exercises_count, payload.course_id, payload.programming_language
+    );
+
+    let creation_result: Result<i64, AppError> = helper::interact(&pool, move |conn_sync| {
+        let payload = payload;
+        conn_sync.transaction(|transaction_conn| {
+            let now = Utc::now();
+            let new_game = NewGame {
+                title: payload.title,
+                public: payload.public,
+                active: payload.active,
+                description: payload.description,
+                course_id: payload.course_id,
+                programming_language: payload.programming_language,
+                module_lock: payload.module_lock,
+                exercise_lock: payload.exercise_lock,
+                total_exercises: total_exercises_count as i32,
+                success_threshold: payload.success_threshold,
+                max_attempts_per_exercise: payload.max_attempts_per_exercise,
+                min_meaningful_result: payload.min_meaningful_result,
+                start_date: now,
+                end_date: now + Duration::days(365),
+            };
+
+            let inserted_game_id = diesel::insert_into(games_dsl::games)
+                .values(&new_game)
+                .returning(games_dsl::id)
+                .get_result::<i64>(transaction_conn)
+                .map_err(|e| AppError::from_constraint_violation(e, "Could not create the game"))?;
+
+            let new_ownership = NewGameOwnership {
+                game_id: inserted_game_id,
+                instructor_id: payload.instructor_id,
+                owner: true,
+            };
+
+            diesel::insert_into(go_dsl::game_ownership)
+                .values(&new_ownership)
+                .execute(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(e, "Could not record the owner of the game")
+                })?;
+
+            Ok(inserted_game_id)
+        })
+    })
+    .await?;
+
+    creation_result.map(ApiResponse::ok)
+}
+
+/// Modifies settings of an existing game.
+///
+/// Request Body: `ModifyGamePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the update was successful (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game, or the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `422 Unprocessable Entity`: If the success threshold or `min_meaningful_result` is outside 0-100, or `max_attempts_per_exercise` is below 1.
+/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
+#[instrument(skip(pool, payload))]
+pub async fn modify_game(
+    State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
+    Json(payload): Json<ModifyGamePayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to modify game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Modify game payload: {:?}", payload);
+
+    acting_instructor.ensure_acts_as(instructor_id)?;
+    helper::validate_success_threshold(payload.success_threshold)?;
+    helper::validate_max_attempts(payload.max_attempts_per_exercise)?;
+    helper::validate_min_meaningful_result(payload.min_meaningful_result)?;
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let changeset = GameChangeset {
+        title: payload.title,
+        public: payload.public,
+        active: payload.active,
+        description: payload.description,
+        module_lock: payload.module_lock,
+        exercise_lock: payload.exercise_lock,
+        success_threshold: payload.success_threshold,
+        max_attempts_per_exercise: payload.max_attempts_per_exercise,
+        min_meaningful_result: payload.min_meaningful_result,
+        updated_at: Some(Utc::now()),
+    };
+
+    let has_updates = changeset.title.is_some()
+        || changeset.public.is_some()
+        || changeset.active.is_some()
+        || changeset.description.is_some()
+        || changeset.module_lock.is_some()
+        || changeset.exercise_lock.is_some()
+        || changeset.success_threshold.is_some()
+        || changeset.max_attempts_per_exercise.is_some()
+        || changeset.min_meaningful_result.is_some();
+
+    if !has_updates {
+        info!(
+            "No update fields provided for game {}. Returning success.",
+            game_id
+        );
+        return Ok(ApiResponse::ok(true));
+    }
+
+    let rows_affected = helper::run_query(&pool, {
+        move |conn| {
+            diesel::update(games_dsl::games.find(game_id))
+                .set(&changeset)
+                .execute(conn)
+        }
+    })
+    .await?;
+
+    match rows_affected {
+        1 => {
+            info!("Successfully modified game {}", game_id);
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
+            error!(
+                "Game {} modification failed: 0 rows affected (game not found after permission check).",
+                game_id
+            );
+            Err(AppError::NotFound(format!(
+                "Game with ID {} not found during update.",
+                game_id
+            )))
+        }
+        n => {
+            error!(
+                "Game {} modification failed: {} rows affected (unexpected state).",
+                game_id, n
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Game modification failed unexpectedly (multiple rows affected)."
+            )))
+        }
+    }
+}
+
+/// Adds an instructor to a game's ownership list or updates their owner status.
+///
+/// Request Body: `AddGameInstructorPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the instructor was successfully added or updated (200 OK).
+/// * `403 Forbidden`: If the requesting instructor lacks permission for the game.
+/// * `404 Not Found`: If the game or the instructor_to_add doesn't exist.
+/// * `409 Conflict`: If `is_owner` is false and the instructor is the game's last owner.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn add_game_instructor(
+    State(pool): State<Pool>,
+    Json(payload): Json<AddGameInstructorPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let requesting_instructor_id = payload.requesting_instructor_id;
+    let game_id = payload.game_id;
+    let instructor_to_add_id = payload.instructor_to_add_id;
+    let is_owner = payload.is_owner;
+
+    info!(
+        "Attempting to add instructor {} to game {} (owner={}) requested by instructor {}",
+        instructor_to_add_id, game_id, is_owner, requesting_instructor_id
+    );
+    debug!("Add game instructor payload: {:?}", payload);
+
+    helper::check_instructor_game_owner_permission(&pool, requesting_instructor_id, game_id)
+        .await?;
+    info!(
+        "Owner permission check passed for instructor {} on game {}",
+        requesting_instructor_id, game_id
+    );
+
+    let instructor_to_add_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(
+                instructors_dsl::instructors.find(instructor_to_add_id),
+            ))
+            .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !instructor_to_add_exists {
+        error!(
+            "Cannot add instructor: Instructor with ID {} not found.",
+            instructor_to_add_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            instructor_to_add_id
+        )));
+    }
+    info!(
+        "Instructor to add (ID {}) confirmed to exist.",
+        instructor_to_add_id
+    );
+
+    let operation_result: Result<usize, AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|transaction_conn| {
+            if !is_owner {
+                ensure_another_game_owner(transaction_conn, game_id, instructor_to_add_id)?;
+            }
+
+            let new_ownership = NewGameOwnership {
+                game_id,
+                instructor_id: instructor_to_add_id,
+                owner: is_owner,
+            };
+
+            diesel::insert_into(go_dsl::game_ownership)
+                .values(&new_ownership)
+                .on_conflict((go_dsl::game_id, go_dsl::instructor_id))
+                .do_update()
+                .set(go_dsl::owner.eq(is_owner))
+                .execute(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(
+                        e,
+                        "Could not add the instructor to the game",
+                    )
+                })
+        })
+    })
+    .await?;
+
+    let rows_affected = operation_result?;
+    info!(
+        "Successfully added/updated instructor {} for game {}. Owner set to: {}. Rows affected: {}",
+        instructor_to_add_id, game_id, is_owner, rows_affected
+    );
+    Ok(ApiResponse::ok(true))
+}
+
+/// Removes an instructor's association (ownership record) from a game.
+///
+/// Request Body: `RemoveGameInstructorPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the association was successfully removed (200 OK).
+/// * `403 Forbidden`: If the requesting instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist, or the instructor was not associated with the game.
+/// * `409 Conflict`: If the instructor is the game's last owner.
+/// * `500 Internal Server Error`: If a database error occurs or multiple records are deleted unexpectedly.
+#[instrument(skip(pool, payload))]
+pub async fn remove_game_instructor(
+    State(pool): State<Pool>,
+    Json(payload): Json<RemoveGameInstructorPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let requesting_instructor_id = payload.requesting_instructor_id;
+    let game_id = payload.game_id;
+    let instructor_to_remove_id = payload.instructor_to_remove_id;
+
+    info!(
+        "Attempting to remove instructor {} from game {} requested by instructor {}",
+        instructor_to_remove_id, game_id, requesting_instructor_id
+    );
+    debug!("Remove game instructor payload: {:?}", payload);
+
+    helper::check_instructor_game_owner_permission(&pool, requesting_instructor_id, game_id)
+        .await?;
+    info!(
+        "Owner permission check passed for instructor {} on game {}",
+        requesting_instructor_id, game_id
+    );
+
+    let removal_result: Result<usize, AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|transaction_conn| {
+            ensure_another_game_owner(transaction_conn, game_id, instructor_to_remove_id)?;
+            let rows_affected = diesel::delete(
+                go_dsl::game_ownership
+                    .filter(go_dsl::game_id.eq(game_id))
+                    .filter(go_dsl::instructor_id.eq(instructor_to_remove_id)),
+            )
+            .execute(transaction_conn)?;
+            Ok(rows_affected)
+        })
+    })
+    .await?;
+    let rows_affected = removal_result?;
+
+    match rows_affected {
+        1 => {
+            info!(
+                "Successfully removed instructor {} from game {}",
+                instructor_to_remove_id, game_id
+            );
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
+            warn!(
+                "Instructor {} was not associated with game {}. No record removed.",
+                instructor_to_remove_id, game_id
+            );
+            Err(AppError::NotFound(format!(
+                "Instructor {} is not associated with game {}.",
+                instructor_to_remove_id, game_id
+            )))
+        }
+        n => {
+            error!(
+                "Unexpected number of rows ({}) deleted when removing instructor {} from game {}",
+                n, instructor_to_remove_id, game_id
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Unexpected error during instructor removal."
+            )))
+        }
+    }
+}
+
+/// Refuses to take the owner flag away from `instructor_id` on `game_id` when they are
+/// its only owner, so a game always keeps someone able to manage it.
+/// Locks the game's owner rows; meant to run inside a transaction.
+fn ensure_another_game_owner(
+    transaction_conn: &mut PgConnection,
+    game_id: i64,
+    instructor_id: i64,
+) -> Result<(), AppError> {
+    let owner_ids = go_dsl::game_ownership
+        .filter(go_dsl::game_id.eq(game_id))
+        .filter(go_dsl::owner.eq(true))
+        .select(go_dsl::instructor_id)
+        .for_update()
+        .load::<i64>(transaction_conn)?;
+
+    if owner_ids.contains(&instructor_id) && owner_ids.len() == 1 {
+        warn!(
+            "Refusing to drop instructor {} as the last owner of game {}",
+            instructor_id, game_id
+        );
+        return Err(AppError::Conflict(format!(
+            "Cannot remove the last owner of game {}.",
+            game_id
+        )));
+    }
+    Ok(())
+}
+
+/// Lists a game's exercise subset, ordered by module and exercise order.
+fn load_game_exercise_subset(conn: &mut PgConnection, game_id: i64) -> QueryResult<Vec<i64>> {
+    ge_dsl::game_exercises
+        .inner_join(exercises_dsl::exercises.inner_join(modules_dsl::modules))
+        .filter(ge_dsl::game_id.eq(game_id))
+        .order_by((modules_dsl::order.asc(), exercises_dsl::order.asc()))
+        .select(ge_dsl::exercise_id)
+        .load::<i64>(conn)
+}
+
+/// Adds exercises of the game's course, in the game's programming language, to the game's exercise subset,
+/// in a single transaction.
+/// Once a game has a subset, only its exercises count towards `total_exercises` and player progress;
+/// the progress of every registration is recomputed accordingly. Exercises already included are skipped.
+///
+/// Request Body: `IncludeGameExercisesPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `GameExercisesResponse`: The game's new `total_exercises` and exercise subset (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `422 Unprocessable Entity`: If no exercises are given, or some are not part of the game's course
+///   or not in its programming language.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn include_game_exercises(
+    State(pool): State<Pool>,
+    Json(payload): Json<IncludeGameExercisesPayload>,
+) -> Result<ApiResponse<GameExercisesResponse>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to include {} exercises in game {} requested by instructor {}",
+        payload.exercise_ids.len(),
+        game_id,
+        instructor_id
+    );
+    debug!("Include game exercises payload: {:?}", payload);
+
+    helper::check_instructor_game_owner_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Owner permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let mut exercise_ids = payload.exercise_ids;
+    exercise_ids.sort_unstable();
+    exercise_ids.dedup();
+    if exercise_ids.is_empty() {
+        warn!("No exercises given to include in game {}", game_id);
+        return Err(AppError::UnprocessableEntity(
+            "At least one exercise must be given.".to_string(),
+        ));
+    }
+
+    let response_data = helper::interact(&pool, move |conn| {
+        conn.transaction::<_, AppError, _>(|transaction_conn| {
+            let (course_id, programming_language) = games_dsl::games
+                .find(game_id)
+                .select((games_dsl::course_id, games_dsl::programming_language))
+                .first::<(i64, String)>(transaction_conn)?;
+            let course_exercises = exercises_dsl::exercises
+                .inner_join(modules_dsl::modules)
+                .filter(modules_dsl::course_id.eq(course_id))
+                .filter(exercises_dsl::programming_language.eq(&programming_language))
+                .filter(exercises_dsl::id.eq_any(&exercise_ids))
+                .select(exercises_dsl::id)
+                .load::<i64>(transaction_conn)?
+                .into_iter()
+                .collect::<HashSet<i64>>();
+            let foreign: Vec<i64> = exercise_ids
+                .iter()
+                .copied()
+                .filter(|exercise_id| !course_exercises.contains(exercise_id))
+                .collect();
+            if !foreign.is_empty() {
+                warn!(
+                    "Cannot include exercises {:?} in game {}: not part of course {} in {}",
+                    foreign, game_id, course_id, programming_language
+                );
+                return Err(AppError::UnprocessableEntity(format!(
+                    "Exercises {:?} are not {} exercises of the course of game {}.",
+                    foreign, programming_language, game_id
+                )));
+            }
+
+            let new_game_exercises: Vec<NewGameExercise> = exercise_ids
+                .into_iter()
+                .map(|exercise_id| NewGameExercise {
+                    game_id,
+                    exercise_id,
+                })
+                .collect();
+            diesel::insert_into(ge_dsl::game_exercises)
+                .values(&new_game_exercises)
+                .on_conflict_do_nothing()
+                .execute(transaction_conn)?;
+
+            let total_exercises = helper::sync_game_exercise_totals(transaction_conn, game_id)?;
+            Ok(GameExercisesResponse {
+                total_exercises,
+                exercise_ids: load_game_exercise_subset(transaction_conn, game_id)?,
+            })
+        })
+    })
+    .await??;
+
+    info!(
+        "Game {} now runs {} exercises",
+        game_id, response_data.total_exercises
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Removes exercises from the game's exercise subset, in a single transaction.
+/// A game without a subset first gets one holding every course exercise in its programming language.
+/// Removing the last exercises of the subset makes the game run its whole course again.
+/// `total_exercises` and the progress of every registration are recomputed accordingly.
+///
+/// Request Body: `ExcludeGameExercisesPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `GameExercisesResponse`: The game's new `total_exercises` and exercise subset (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn exclude_game_exercises(
+    State(pool): State<Pool>,
+    Json(payload): Json<ExcludeGameExercisesPayload>,
+) -> Result<ApiResponse<GameExercisesResponse>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to exclude {} exercises from game {} requested by instructor {}",
+        payload.exercise_ids.len(),
+        game_id,
+        instructor_id
+    );
+    debug!("Exclude game exercises payload: {:?}", payload);
+
+    helper::check_instructor_game_owner_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Owner permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let exercise_ids = payload.exercise_ids;
+    let response_data = helper::run_query(&pool, move |conn| {
+        conn.transaction(|transaction_conn| {
+            let has_subset = diesel::select(exists(
+                ge_dsl::game_exercises.filter(ge_dsl::game_id.eq(game_id)),
+            ))
+            .get_result::<bool>(transaction_conn)?;
+            if !has_subset {
+                let (course_id, programming_language) = games_dsl::games
+                    .find(game_id)
+                    .select((games_dsl::course_id, games_dsl::programming_language))
+                    .first::<(i64, String)>(transaction_conn)?;
+                let new_game_exercises: Vec<NewGameExercise> = exercises_dsl::exercises
+                    .inner_join(modules_dsl::modules)
+                    .filter(modules_dsl::course_id.eq(course_id))
+                    .filter(exercises_dsl::programming_language.eq(programming_language))
+                    .select(exercises_dsl::id)
+                    .load::<i64>(transaction_conn)?
+                    .into_iter()
+                    .map(|exercise_id| NewGameExercise {
+                        game_id,
+                        exercise_id,
+                    })
+                    .collect();
+                diesel::insert_into(ge_dsl::game_exercises)
+                    .values(&new_game_exercises)
+                    .execute(transaction_conn)?;
+            }
+
+            diesel::delete(
+                ge_dsl::game_exercises
+                    .filter(ge_dsl::game_id.eq(game_id))
+                    .filter(ge_dsl::exercise_id.eq_any(exercise_ids)),
+            )
+            .execute(transaction_conn)?;
+
+            let total_exercises = helper::sync_game_exercise_totals(transaction_conn, game_id)?;
+            Ok(GameExercisesResponse {
+                total_exercises,
+                exercise_ids: load_game_exercise_subset(transaction_conn, game_id)?,
+            })
+        })
+    })
+    .await?;
+
+    info!(
+        "Game {} now runs {} exercises",
+        game_id, response_data.total_exercises
+    );
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Activates a specific game by setting its 'active' status to true.
+///
+/// Request Body: `ActivateGamePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the game was successfully activated (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
+#[instrument(skip(pool, payload))]
+pub async fn activate_game(
+    State(pool): State<Pool>,
+    Extension(webhook): Extension<Option<Arc<Webhook>>>,
+    Json(payload): Json<ActivateGamePayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to activate game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Activate game payload: {:?}", payload);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        let game_id = game_id;
+        diesel::update(games_dsl::games.find(game_id))
+            .set((
+                games_dsl::active.eq(true),
+                games_dsl::updated_at.eq(diesel::dsl::now),
+            ))
+            .execute(conn)
+    })
+    .await?;
+
+    match rows_affected {
+        1 => {
+            info!("Successfully activated game {}", game_id);
+            if let Some(webhook) = &webhook {
+                webhook.notify(GAME_ACTIVATED, game_id, instructor_id);
+            }
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
             error!(
-                "Game {} modification failed: 0 rows affected (game not found after permission check).",
+                "Game {} activation failed: 0 rows affected (game not found after permission check).",
+                game_id
+            );
+            Err(AppError::NotFound(format!(
+                "Game with ID {} not found during update.",
+                game_id
+            )))
+        }
+        n => {
+            error!(
+                "Game {} activation failed: {} rows affected (unexpected state).",
+                game_id, n
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Game activation failed unexpectedly (multiple rows affected)."
+            )))
+        }
+    }
+}
+
+/// Deactivates a specific game by setting its 'active' status to false.
+///
+/// Request Body: `StopGamePayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the game was successfully deactivated (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
+#[instrument(skip(pool, payload))]
+pub async fn stop_game(
+    State(pool): State<Pool>,
+    Extension(webhook): Extension<Option<Arc<Webhook>>>,
+    Json(payload): Json<StopGamePayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to stop (deactivate) game_id: {} requested by instructor_id: {}",
+        game_id, instructor_id
+    );
+    debug!("Stop game payload: {:?}", payload);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        let game_id = game_id;
+        diesel::update(games_dsl::games.find(game_id))
+            .set((
+                games_dsl::active.eq(false),
+                games_dsl::updated_at.eq(diesel::dsl::now),
+            ))
+            .execute(conn)
+    })
+    .await?;
+
+    match rows_affected {
+        1 => {
+            info!("Successfully stopped (deactivated) game {}", game_id);
+            if let Some(webhook) = &webhook {
+                webhook.notify(GAME_STOPPED, game_id, instructor_id);
+            }
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
+            error!(
+                "Game {} deactivation failed: 0 rows affected (game not found after permission check).",
                 game_id
             );
             Err(AppError::NotFound(format!(
@@ -1126,367 +3559,1257 @@ pub async fn modify_game(
                 game_id
             )))
         }
-        n => {
+        n => {
+            error!(
+                "Game {} deactivation failed: {} rows affected (unexpected state).",
+                game_id, n
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Game deactivation failed unexpectedly (multiple rows affected)."
+            )))
+        }
+    }
+}
+
+/// Removes a student's registration from a specific game.
+///
+/// Request Body: `RemoveGameStudentPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the registration was successfully removed (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist, or the student was not registered in the game.
+/// * `500 Internal Server Error`: If a database error occurs or multiple records are deleted unexpectedly.
+#[instrument(skip(pool, payload))]
+pub async fn remove_game_student(
+    State(pool): State<Pool>,
+    Json(payload): Json<RemoveGameStudentPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+    let student_id = payload.student_id;
+
+    info!(
+        "Attempting to remove student {} from game {} requested by instructor {}",
+        student_id, game_id, instructor_id
+    );
+    debug!("Remove game student payload: {:?}", payload);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        let game_id = game_id;
+        let student_id = student_id;
+        diesel::delete(
+            pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::player_id.eq(student_id)),
+        )
+        .execute(conn)
+    })
+    .await?;
+
+    match rows_affected {
+        1 => {
+            info!(
+                "Successfully removed student {} from game {}",
+                student_id, game_id
+            );
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
+            warn!(
+                "Student {} was not registered in game {}. No record removed.",
+                student_id, game_id
+            );
+            Err(AppError::NotFound(format!(
+                "Student {} is not registered in game {}.",
+                student_id, game_id
+            )))
+        }
+        n => {
+            error!(
+                "Unexpected number of rows ({}) deleted when removing student {} from game {}",
+                n, student_id, game_id
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Unexpected error during student removal."
+            )))
+        }
+    }
+}
+
+/// Removes several students' registrations from a specific game in a single statement.
+/// Student IDs that are not registered in the game are skipped.
+///
+/// Request Body: `RemoveGameStudentsPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The number of registrations removed (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn remove_game_students(
+    State(pool): State<Pool>,
+    Json(payload): Json<RemoveGameStudentsPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+
+    info!(
+        "Attempting to remove {} students from game {} requested by instructor {}",
+        payload.student_ids.len(),
+        game_id,
+        instructor_id
+    );
+    debug!("Remove game students payload: {:?}", payload);
+
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let student_ids = payload.student_ids;
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        diesel::delete(
+            pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::player_id.eq_any(student_ids)),
+        )
+        .execute(conn)
+    })
+    .await?;
+
+    info!(
+        "Successfully removed {} students from game {}",
+        rows_affected, game_id
+    );
+    Ok(ApiResponse::ok(rows_affected as i64))
+}
+
+/// Grants a reward of the game's course to several players registered in the game, in one transaction.
+/// Players who already hold the reward in this game are skipped.
+///
+/// Request Body: `GrantRewardPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The number of players newly granted the reward (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the game.
+/// * `404 Not Found`: If the game doesn't exist, or the reward doesn't exist in the game's course.
+/// * `422 Unprocessable Entity`: If a player is not registered in the game, or the reward has no `valid_period`.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn grant_reward(
+    State(pool): State<Pool>,
+    Json(payload): Json<GrantRewardPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let game_id = payload.game_id;
+    let reward_id = payload.reward_id;
+
+    info!(
+        "Attempting to grant reward {} to {} players in game {} requested by instructor {}",
+        reward_id,
+        payload.player_ids.len(),
+        game_id,
+        instructor_id
+    );
+    debug!("Grant reward payload: {:?}", payload);
+
+    helper::check_instructor_game_owner_permission(&pool, instructor_id, game_id).await?;
+    info!(
+        "Owner permission check passed for instructor {} on game {}",
+        instructor_id, game_id
+    );
+
+    let mut player_ids = payload.player_ids;
+    player_ids.sort_unstable();
+    player_ids.dedup();
+
+    let granted = helper::interact(&pool, move |conn| {
+        conn.transaction::<_, AppError, _>(|transaction_conn| {
+            let valid_period = rewards_dsl::rewards
+                .inner_join(games_dsl::games.on(games_dsl::course_id.eq(rewards_dsl::course_id)))
+                .filter(rewards_dsl::id.eq(reward_id))
+                .filter(games_dsl::id.eq(game_id))
+                .select(rewards_dsl::valid_period)
+                .first::<Option<Duration>>(transaction_conn)
+                .optional()?;
+            let Some(valid_period) = valid_period else {
+                error!(
+                    "Cannot grant reward: Reward {} not found in the course of game {}.",
+                    reward_id, game_id
+                );
+                return Err(AppError::NotFound(format!(
+                    "Reward with ID {} not found in the course of game {}.",
+                    reward_id, game_id
+                )));
+            };
+            let Some(valid_period) = valid_period else {
+                error!("Reward ID {} has invalid (NULL) valid_period.", reward_id);
+                return Err(AppError::UnprocessableEntity(format!(
+                    "Reward {} has no valid period configured.",
+                    reward_id
+                )));
+            };
+
+            let registered = pr_dsl::player_registrations
+                .filter(pr_dsl::game_id.eq(game_id))
+                .filter(pr_dsl::left_at.is_null())
+                .filter(pr_dsl::player_id.eq_any(&player_ids))
+                .select(pr_dsl::player_id)
+                .load::<i64>(transaction_conn)?
+                .into_iter()
+                .collect::<HashSet<i64>>();
+            let unregistered: Vec<i64> = player_ids
+                .iter()
+                .copied()
+                .filter(|player_id| !registered.contains(player_id))
+                .collect();
+            if !unregistered.is_empty() {
+                warn!(
+                    "Cannot grant reward {}: players {:?} are not registered in game {}",
+                    reward_id, unregistered, game_id
+                );
+                return Err(AppError::UnprocessableEntity(format!(
+                    "Players {:?} are not registered in game {}.",
+                    unregistered, game_id
+                )));
+            }
+
+            let now = Utc::now();
+            let new_rewards: Vec<NewPlayerReward> = player_ids
+                .into_iter()
+                .map(|player_id| NewPlayerReward {
+                    player_id,
+                    reward_id,
+                    game_id: Some(game_id),
+                    count: 1,
+                    used_count: 0,
+                    obtained_at: now,
+                    expires_at: now + valid_period,
+                })
+                .collect();
+            let granted = diesel::insert_into(prw_dsl::player_rewards)
+                .values(&new_rewards)
+                .on_conflict((prw_dsl::player_id, prw_dsl::reward_id, prw_dsl::game_id))
+                .do_nothing()
+                .execute(transaction_conn)?;
+            Ok(granted as i64)
+        })
+    })
+    .await??;
+
+    info!(
+        "Successfully granted reward {} to {} players in game {}",
+        reward_id, granted, game_id
+    );
+    Ok(ApiResponse::ok(granted))
+}
+
+/// Finds the player ID associated with a given email address.
+/// The email is trimmed and matched case-insensitively. Soft-deleted players are not found.
+///
+/// Query Parameters:
+/// * `email`: The email address to look up.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The player ID if found (200 OK).
+/// * `404 Not Found`: If no player with the given email exists.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn translate_email_to_player_id(
+    State(pool): State<Pool>,
+    Query(params): Query<TranslateEmailParams>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let email_to_find = db::normalize_email(&params.email);
+    let email_cloned = email_to_find.clone();
+
+    info!("Attempting to find player ID for email: {}", &email_to_find);
+    debug!("Translate email params: {:?}", &email_to_find);
+
+    let player_id = helper::run_query(&pool, move |conn| {
+        players_dsl::players
+            .filter(db::lower(players_dsl::email).eq(email_cloned))
+            .filter(players_dsl::deleted_at.is_null())
+            .select(players_dsl::id)
+            .first::<i64>(conn)
+    })
+    .await?;
+
+    info!(
+        "Successfully found player ID {} for email {}",
+        player_id, &email_to_find
+    );
+    Ok(ApiResponse::ok(player_id))
+}
+
+/// Creates a new group, assigns ownership, and adds initial members.
+///
+/// Request Body: `CreateGroupPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The ID of the newly created group (200 OK).
+/// * `404 Not Found`: If the requesting instructor or any specified member player does not exist.
+/// * `409 Conflict`: If the group display name is already taken, or the member list exceeds `--max-group-size`.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn create_group(
+    State(pool): State<Pool>,
+    Extension(max_group_size): Extension<Arc<MaxGroupSize>>,
+    Json(payload): Json<CreateGroupPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let display_name_cloned = payload.display_name.clone();
+    let instructor_id = payload.instructor_id;
+
+    info!(
+        "Attempting to create group '{}' by instructor {}",
+        &display_name_cloned, instructor_id
+    );
+    debug!("Create group payload: {:?}", payload);
+
+    let instructor_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+                .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+    if !instructor_exists {
+        error!(
+            "Cannot create group: Instructor with ID {} not found.",
+            instructor_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            instructor_id
+        )));
+    }
+
+    let name_taken = helper::run_query(&pool, {
+        let name = display_name_cloned.clone();
+        move |conn| {
+            diesel::select(exists(
+                groups_dsl::groups.filter(groups_dsl::display_name.eq(name)),
+            ))
+            .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+    if name_taken {
+        warn!("Group name '{}' is already taken.", &display_name_cloned);
+        return Err(AppError::Conflict(format!(
+            "Group name '{}' is already taken.",
+            display_name_cloned
+        )));
+    }
+
+    let members_to_add = payload.member_list.clone();
+    max_group_size.ensure_room(0, members_to_add.len())?;
+    if !members_to_add.is_empty() {
+        let existing_players_count = helper::run_query(&pool, {
+            let member_ids = members_to_add.clone();
+            move |conn| {
+                players_dsl::players
+                    .filter(players_dsl::id.eq_any(&member_ids))
+                    .count()
+                    .get_result::<i64>(conn)
+            }
+        })
+        .await?;
+
+        if existing_players_count != members_to_add.len() as i64 {
             error!(
-                "Game {} modification failed: {} rows affected (unexpected state).",
-                game_id, n
+                "Cannot create group: One or more player IDs in memberList do not exist. Expected {}, found {}.",
+                members_to_add.len(),
+                existing_players_count
             );
-            Err(AppError::InternalServerError(anyhow!(
-                "Game modification failed unexpectedly (multiple rows affected)."
-            )))
+            return Err(AppError::NotFound(
+                "One or more players listed as members do not exist.".to_string(),
+            ));
+        }
+        info!("All {} specified members validated.", members_to_add.len());
+    }
+
+    let creation_result: Result<i64, AppError> = helper::interact(&pool, move |conn_sync| {
+        let payload = payload;
+        let display_name_cloned = display_name_cloned;
+        conn_sync.transaction(|transaction_conn| {
+            let new_group = NewGroup {
+                display_name: payload.display_name,
+                display_avatar: payload.display_avatar,
+            };
+            let new_group_id = diesel::insert_into(groups_dsl::groups)
+                .values(&new_group)
+                .returning(groups_dsl::id)
+                .get_result::<i64>(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(
+                        e,
+                        format!("Could not create group '{}'", display_name_cloned),
+                    )
+                })?;
+
+            let new_ownership = NewGroupOwnership {
+                group_id: new_group_id,
+                instructor_id: payload.instructor_id,
+                owner: true,
+            };
+            diesel::insert_into(gro_dsl::group_ownership)
+                .values(&new_ownership)
+                .execute(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(
+                        e,
+                        "Could not record the owner of the group",
+                    )
+                })?;
+
+            if !payload.member_list.is_empty() {
+                let new_members: Vec<NewPlayerGroup> = payload
+                    .member_list
+                    .iter()
+                    .map(|&player_id| NewPlayerGroup {
+                        player_id,
+                        group_id: new_group_id,
+                    })
+                    .collect();
+
+                diesel::insert_into(pg_dsl::player_groups)
+                    .values(&new_members)
+                    .execute(transaction_conn)
+                    .map_err(|e| {
+                        AppError::from_constraint_violation(
+                            e,
+                            "Could not add the members to the group",
+                        )
+                    })?;
+            }
+
+            Ok(new_group_id)
+        })
+    })
+    .await?;
+
+    creation_result.map(ApiResponse::ok)
+}
+
+/// Retrieves metadata for a specific group, including its creation and last update timestamps.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor requesting the data.
+/// * `group_id`: The ID of the group.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `GroupMetadataResponse`: Group details with `created_at` and `updated_at` (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_group_metadata(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGroupMetadataParams>,
+) -> Result<ApiResponse<GroupMetadataResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let group_id = params.group_id;
+
+    info!(
+        "Fetching metadata for group_id: {} requested by instructor_id: {}",
+        group_id, instructor_id
+    );
+    debug!("Get group metadata params: {:?}", params);
+
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    info!(
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
+    );
+
+    let metadata = helper::run_query(&pool, {
+        move |conn| {
+            groups_dsl::groups
+                .find(group_id)
+                .select((
+                    groups_dsl::id,
+                    groups_dsl::display_name,
+                    groups_dsl::display_avatar,
+                    groups_dsl::created_at,
+                    groups_dsl::updated_at,
+                ))
+                .first::<GroupMetadataResponse>(conn)
+        }
+    })
+    .await?;
+
+    info!("Successfully fetched metadata for group_id: {}", group_id);
+    Ok(ApiResponse::ok(metadata))
+}
+
+/// Lists the active members of a group (those who have not left it), leaving out soft-deleted players.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor requesting the list.
+/// * `group_id`: The ID of the group.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<GroupMemberResponse>`: Active members with their display name and email, ordered by player ID (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_group_members(
+    State(pool): State<Pool>,
+    Query(params): Query<GetGroupMembersParams>,
+) -> Result<ApiResponse<Vec<GroupMemberResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let group_id = params.group_id;
+
+    info!(
+        "Fetching members of group_id: {} requested by instructor_id: {}",
+        group_id, instructor_id
+    );
+    debug!("Get group members params: {:?}", params);
+
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    info!(
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
+    );
+
+    let members = helper::run_query(&pool, move |conn| {
+        pg_dsl::player_groups
+            .inner_join(players_dsl::players)
+            .filter(pg_dsl::group_id.eq(group_id))
+            .filter(pg_dsl::left_at.is_null())
+            .filter(players_dsl::deleted_at.is_null())
+            .order_by(players_dsl::id.asc())
+            .select((
+                players_dsl::id,
+                players_dsl::display_name,
+                players_dsl::email,
+            ))
+            .load::<GroupMemberResponse>(conn)
+    })
+    .await?;
+
+    info!(
+        "Successfully fetched {} active members of group_id: {}",
+        members.len(),
+        group_id
+    );
+    Ok(ApiResponse::ok(members))
+}
+
+/// Lists the active groups a player is a member of.
+/// The admin (ID 0) sees every group; other instructors see only the groups they own.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor requesting the list.
+/// * `player_id`: The ID of the player.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<PlayerGroupResponse>`: The player's groups, ordered by group ID (200 OK).
+/// * `403 Forbidden`: If a non-admin instructor owns none of the player's groups.
+/// * `404 Not Found`: If the player doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_player_groups(
+    State(pool): State<Pool>,
+    Query(params): Query<GetPlayerGroupsParams>,
+) -> Result<ApiResponse<Vec<PlayerGroupResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let player_id = params.player_id;
+
+    info!(
+        "Fetching groups of player_id: {} requested by instructor_id: {}",
+        player_id, instructor_id
+    );
+    debug!("Get player groups params: {:?}", params);
+
+    let player_exists = helper::run_query(&pool, move |conn| {
+        diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
+    })
+    .await?;
+    if !player_exists {
+        error!(
+            "Cannot list groups: Player with ID {} not found.",
+            player_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} not found.",
+            player_id
+        )));
+    }
+
+    let groups = helper::run_query(&pool, move |conn| {
+        let mut query = pg_dsl::player_groups
+            .inner_join(groups_dsl::groups)
+            .filter(pg_dsl::player_id.eq(player_id))
+            .filter(pg_dsl::left_at.is_null())
+            .into_boxed();
+        if instructor_id != 0 {
+            let owned_group_ids = gro_dsl::group_ownership
+                .filter(gro_dsl::instructor_id.eq(instructor_id))
+                .filter(gro_dsl::owner.eq(true))
+                .select(gro_dsl::group_id);
+            query = query.filter(pg_dsl::group_id.eq_any(owned_group_ids));
         }
+        query
+            .order_by(groups_dsl::id.asc())
+            .select((groups_dsl::id, groups_dsl::display_name))
+            .load::<PlayerGroupResponse>(conn)
+    })
+    .await?;
+
+    if instructor_id != 0 && groups.is_empty() {
+        warn!(
+            "Permission denied: Instructor {} owns no group of player {}.",
+            instructor_id, player_id
+        );
+        return Err(AppError::Forbidden(format!(
+            "Instructor {} does not own any group of player {}.",
+            instructor_id, player_id
+        )));
     }
+
+    info!(
+        "Successfully fetched {} groups of player_id: {}",
+        groups.len(),
+        player_id
+    );
+    Ok(ApiResponse::ok(groups))
 }
 
-/// Adds an instructor to a game's ownership list or updates their owner status.
+/// Modifies the display name and/or avatar of an existing group, refreshing its `updated_at`.
 ///
-/// Request Body: `AddGameInstructorPayload`
+/// Request Body: `ModifyGroupPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the instructor was successfully added or updated (200 OK).
-/// * `403 Forbidden`: If the requesting instructor lacks permission for the game.
-/// * `404 Not Found`: If the game or the instructor_to_add doesn't exist.
+/// * `bool`: true if the update was successful (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group doesn't exist.
+/// * `409 Conflict`: If the new display name is already taken by another group.
 /// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, payload))]
-pub async fn add_game_instructor(
+pub async fn modify_group(
     State(pool): State<Pool>,
-    Json(payload): Json<AddGameInstructorPayload>,
+    Json(payload): Json<ModifyGroupPayload>,
 ) -> Result<ApiResponse<bool>, AppError> {
-    let requesting_instructor_id = payload.requesting_instructor_id;
-    let game_id = payload.game_id;
-    let instructor_to_add_id = payload.instructor_to_add_id;
-    let is_owner = payload.is_owner;
+    let instructor_id = payload.instructor_id;
+    let group_id = payload.group_id;
 
     info!(
-        "Attempting to add instructor {} to game {} (owner={}) requested by instructor {}",
-        instructor_to_add_id, game_id, is_owner, requesting_instructor_id
+        "Attempting to modify group_id: {} requested by instructor_id: {}",
+        group_id, instructor_id
     );
-    debug!("Add game instructor payload: {:?}", payload);
+    debug!("Modify group payload: {:?}", payload);
 
-    helper::check_instructor_game_owner_permission(&pool, requesting_instructor_id, game_id)
-        .await?;
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
     info!(
-        "Owner permission check passed for instructor {} on game {}",
-        requesting_instructor_id, game_id
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
     );
 
-    let instructor_to_add_exists = helper::run_query(&pool, {
+    if payload.display_name.is_none() && payload.display_avatar.is_none() {
+        info!(
+            "No update fields provided for group {}. Returning success.",
+            group_id
+        );
+        return Ok(ApiResponse::ok(true));
+    }
+
+    if let Some(name) = payload.display_name.clone() {
+        let name_taken = helper::run_query(&pool, {
+            let name = name.clone();
+            move |conn| {
+                diesel::select(exists(
+                    groups_dsl::groups
+                        .filter(groups_dsl::display_name.eq(name))
+                        .filter(groups_dsl::id.ne(group_id)),
+                ))
+                .get_result::<bool>(conn)
+            }
+        })
+        .await?;
+        if name_taken {
+            warn!("Group name '{}' is already taken.", &name);
+            return Err(AppError::Conflict(format!(
+                "Group name '{}' is already taken.",
+                name
+            )));
+        }
+    }
+
+    let changeset = GroupChangeset {
+        display_name: payload.display_name,
+        display_avatar: payload.display_avatar,
+        updated_at: Some(Utc::now()),
+    };
+
+    let rows_affected = helper::run_query(&pool, {
         move |conn| {
-            diesel::select(exists(
-                instructors_dsl::instructors.find(instructor_to_add_id),
-            ))
-            .get_result::<bool>(conn)
+            diesel::update(groups_dsl::groups.find(group_id))
+                .set(&changeset)
+                .execute(conn)
         }
     })
     .await?;
 
-    if !instructor_to_add_exists {
+    if rows_affected == 0 {
         error!(
-            "Cannot add instructor: Instructor with ID {} not found.",
-            instructor_to_add_id
+            "Group {} modification failed: 0 rows affected (group not found after permission check).",
+            group_id
         );
         return Err(AppError::NotFound(format!(
-            "Instructor with ID {} not found.",
-            instructor_to_add_id
+            "Group with ID {} not found during update.",
+            group_id
         )));
     }
+
+    info!("Successfully modified group {}", group_id);
+    Ok(ApiResponse::ok(true))
+}
+
+/// Dissolves a group, removing all members and ownership records.
+///
+/// Request Body: `DissolveGroupPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the group was successfully dissolved (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group doesn't exist.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn dissolve_group(
+    State(pool): State<Pool>,
+    Json(payload): Json<DissolveGroupPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let group_id = payload.group_id;
+
     info!(
-        "Instructor to add (ID {}) confirmed to exist.",
-        instructor_to_add_id
+        "Attempting to dissolve group {} requested by instructor {}",
+        group_id, instructor_id
     );
+    debug!("Dissolve group payload: {:?}", payload);
 
-    let operation_result = helper::run_query(&pool, move |conn| {
-        let game_id = game_id;
-        let instructor_to_add_id = instructor_to_add_id;
-        let is_owner = is_owner;
-
-        let new_ownership = NewGameOwnership {
-            game_id,
-            instructor_id: instructor_to_add_id,
-            owner: is_owner,
-        };
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    info!(
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
+    );
 
-        diesel::insert_into(go_dsl::game_ownership)
-            .values(&new_ownership)
-            .on_conflict((go_dsl::game_id, go_dsl::instructor_id))
-            .do_update()
-            .set(go_dsl::owner.eq(is_owner))
-            .execute(conn)
+    let deletion_result: Result<(), AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|transaction_conn| delete_group(transaction_conn, group_id))
     })
-    .await;
+    .await?;
 
-    match operation_result {
-        Ok(rows_affected) => {
-            info!(
-                "Successfully added/updated instructor {} for game {}. Owner set to: {}. Rows affected: {}",
-                instructor_to_add_id, game_id, is_owner, rows_affected
-            );
-            Ok(ApiResponse::ok(true))
-        }
-        Err(AppError::InternalServerError(ref err)) => {
-            if let Some(db_err) = err.downcast_ref::<DieselError>() {
-                if let DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) =
-                    db_err
-                {
-                    error!(
-                        "Database constraint violation during instructor addition: {:?}",
-                        err
-                    );
-                    return Err(AppError::NotFound(
-                        "Game or Instructor not found (foreign key violation).".to_string(),
-                    ));
-                }
-            }
-            Err(operation_result.unwrap_err())
-        }
-        Err(e) => Err(e),
+    deletion_result.map(|_| ApiResponse::ok(true))
+}
+
+/// Deletes a group together with its member and ownership records.
+/// Meant to run inside a transaction.
+fn delete_group(transaction_conn: &mut PgConnection, group_id: i64) -> Result<(), AppError> {
+    info!(
+        "Deleting member records from player_groups for group {}",
+        group_id
+    );
+    let members_deleted =
+        diesel::delete(pg_dsl::player_groups.filter(pg_dsl::group_id.eq(group_id)))
+            .execute(transaction_conn)?;
+    info!(
+        "Deleted {} member records from player_groups for group {}",
+        members_deleted, group_id
+    );
+
+    info!(
+        "Deleting ownership records from group_ownership for group {}",
+        group_id
+    );
+    let owners_deleted =
+        diesel::delete(gro_dsl::group_ownership.filter(gro_dsl::group_id.eq(group_id)))
+            .execute(transaction_conn)?;
+    info!(
+        "Deleted {} ownership records from group_ownership for group {}",
+        owners_deleted, group_id
+    );
+
+    info!("Deleting group record for group {}", group_id);
+    let group_deleted =
+        diesel::delete(groups_dsl::groups.find(group_id)).execute(transaction_conn)?;
+
+    if group_deleted == 1 {
+        Ok(())
+    } else {
+        error!(
+            "Failed to delete group {} itself after deleting dependencies ({} rows affected).",
+            group_id, group_deleted
+        );
+        Err(AppError::NotFound(format!(
+            "Group {} not found during final delete step.",
+            group_id
+        )))
     }
 }
 
-/// Removes an instructor's association (ownership record) from a game.
+/// Merges one group into another: active members of the source join the target, then the source is dissolved.
 ///
-/// Request Body: `RemoveGameInstructorPayload`
+/// Requires owner permission on both groups (or admin). Members already in the target are left as they are.
+/// All changes happen in a single transaction.
+///
+/// Request Body: `MergeGroupsPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the association was successfully removed (200 OK).
-/// * `403 Forbidden`: If the requesting instructor lacks permission for the game.
-/// * `404 Not Found`: If the game doesn't exist, or the instructor was not associated with the game.
-/// * `500 Internal Server Error`: If a database error occurs or multiple records are deleted unexpectedly.
-#[instrument(skip(pool, payload))]
-pub async fn remove_game_instructor(
+/// * `i64`: The number of active members of the target group after the merge (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for either group.
+/// * `404 Not Found`: If either group doesn't exist.
+/// * `409 Conflict`: If the target group would exceed `--max-group-size` active members.
+/// * `422 Unprocessable Entity`: If the source and target are the same group.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, max_group_size, payload))]
+pub async fn merge_groups(
     State(pool): State<Pool>,
-    Json(payload): Json<RemoveGameInstructorPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let requesting_instructor_id = payload.requesting_instructor_id;
-    let game_id = payload.game_id;
-    let instructor_to_remove_id = payload.instructor_to_remove_id;
+    Extension(max_group_size): Extension<Arc<MaxGroupSize>>,
+    Json(payload): Json<MergeGroupsPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let source_group_id = payload.source_group_id;
+    let target_group_id = payload.target_group_id;
 
     info!(
-        "Attempting to remove instructor {} from game {} requested by instructor {}",
-        instructor_to_remove_id, game_id, requesting_instructor_id
+        "Attempting to merge group {} into group {} requested by instructor {}",
+        source_group_id, target_group_id, instructor_id
     );
-    debug!("Remove game instructor payload: {:?}", payload);
+    debug!("Merge groups payload: {:?}", payload);
 
-    helper::check_instructor_game_owner_permission(&pool, requesting_instructor_id, game_id)
-        .await?;
+    if source_group_id == target_group_id {
+        warn!("Rejecting merge of group {} into itself", source_group_id);
+        return Err(AppError::UnprocessableEntity(format!(
+            "Cannot merge group {} into itself.",
+            source_group_id
+        )));
+    }
+
+    helper::check_instructor_group_permission(&pool, instructor_id, source_group_id).await?;
+    helper::check_instructor_group_permission(&pool, instructor_id, target_group_id).await?;
     info!(
-        "Owner permission check passed for instructor {} on game {}",
-        requesting_instructor_id, game_id
+        "Permission check passed for instructor {} on groups {} and {}",
+        instructor_id, source_group_id, target_group_id
     );
 
-    let rows_affected = helper::run_query(&pool, move |conn| {
-        let game_id = game_id;
-        let instructor_to_remove_id = instructor_to_remove_id;
-        diesel::delete(
-            go_dsl::game_ownership
-                .filter(go_dsl::game_id.eq(game_id))
-                .filter(go_dsl::instructor_id.eq(instructor_to_remove_id)),
-        )
-        .execute(conn)
+    let merge_result: Result<i64, AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|transaction_conn| {
+            // locking the target serializes the merge with concurrent additions against the size limit
+            groups_dsl::groups
+                .find(target_group_id)
+                .select(groups_dsl::id)
+                .for_update()
+                .first::<i64>(transaction_conn)?;
+
+            let source_members = pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(source_group_id))
+                .filter(pg_dsl::left_at.is_null())
+                .select(pg_dsl::player_id)
+                .load::<i64>(transaction_conn)?;
+            let target_players = pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(target_group_id))
+                .select((pg_dsl::player_id, pg_dsl::left_at.is_null()))
+                .load::<(i64, bool)>(transaction_conn)?;
+            let target_active = target_players.iter().filter(|(_, active)| *active).count();
+            // existing rows, including former members, are left untouched by the insert below
+            let new_memberships: Vec<NewPlayerGroup> = source_members
+                .iter()
+                .copied()
+                .filter(|player_id| !target_players.iter().any(|(id, _)| id == player_id))
+                .map(|player_id| NewPlayerGroup {
+                    player_id,
+                    group_id: target_group_id,
+                })
+                .collect();
+            max_group_size.ensure_room(target_active as i64, new_memberships.len())?;
+
+            let moved = diesel::insert_into(pg_dsl::player_groups)
+                .values(&new_memberships)
+                .on_conflict((pg_dsl::player_id, pg_dsl::group_id))
+                .do_nothing()
+                .execute(transaction_conn)?;
+            info!(
+                "Moved {} of {} members of group {} into group {}",
+                moved,
+                source_members.len(),
+                source_group_id,
+                target_group_id
+            );
+
+            delete_group(transaction_conn, source_group_id)?;
+
+            let member_count = pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(target_group_id))
+                .filter(pg_dsl::left_at.is_null())
+                .count()
+                .get_result::<i64>(transaction_conn)?;
+            Ok(member_count)
+        })
     })
     .await?;
 
-    match rows_affected {
-        1 => {
+    let member_count = merge_result?;
+    info!(
+        "Successfully merged group {} into group {} ({} active members)",
+        source_group_id, target_group_id, member_count
+    );
+    Ok(ApiResponse::ok(member_count))
+}
+
+/// Creates a copy of a group under a new display name, with the caller as its owner
+/// and every active member of the source group as a member.
+///
+/// Requires owner permission on the source group (or admin). All changes happen in a single transaction.
+///
+/// Request Body: `CloneGroupPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The ID of the newly created group (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the source group.
+/// * `404 Not Found`: If the source group or the requesting instructor doesn't exist.
+/// * `409 Conflict`: If the new display name is already taken, or the members exceed `--max-group-size`.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn clone_group(
+    State(pool): State<Pool>,
+    Extension(max_group_size): Extension<Arc<MaxGroupSize>>,
+    Json(payload): Json<CloneGroupPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let source_group_id = payload.source_group_id;
+
+    info!(
+        "Attempting to clone group {} as '{}' requested by instructor {}",
+        source_group_id, payload.display_name, instructor_id
+    );
+    debug!("Clone group payload: {:?}", payload);
+
+    helper::check_instructor_group_permission(&pool, instructor_id, source_group_id).await?;
+    info!(
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, source_group_id
+    );
+
+    let clone_result: Result<i64, AppError> = helper::interact(&pool, move |conn_sync| {
+        let display_name = payload.display_name;
+        conn_sync.transaction(|transaction_conn| {
+            let name_taken = diesel::select(exists(
+                groups_dsl::groups.filter(groups_dsl::display_name.eq(&display_name)),
+            ))
+            .get_result::<bool>(transaction_conn)?;
+            if name_taken {
+                warn!("Group name '{}' is already taken.", &display_name);
+                return Err(AppError::Conflict(format!(
+                    "Group name '{}' is already taken.",
+                    display_name
+                )));
+            }
+
+            let display_avatar = groups_dsl::groups
+                .find(source_group_id)
+                .select(groups_dsl::display_avatar)
+                .first::<Option<String>>(transaction_conn)?;
+            let source_members = pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(source_group_id))
+                .filter(pg_dsl::left_at.is_null())
+                .select(pg_dsl::player_id)
+                .load::<i64>(transaction_conn)?;
+            max_group_size.ensure_room(0, source_members.len())?;
+
+            let new_group_id = diesel::insert_into(groups_dsl::groups)
+                .values(&NewGroup {
+                    display_name,
+                    display_avatar,
+                })
+                .returning(groups_dsl::id)
+                .get_result::<i64>(transaction_conn)?;
+
+            diesel::insert_into(gro_dsl::group_ownership)
+                .values(&NewGroupOwnership {
+                    group_id: new_group_id,
+                    instructor_id,
+                    owner: true,
+                })
+                .execute(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(
+                        e,
+                        "Could not record the owner of the cloned group",
+                    )
+                })?;
+
+            let new_members: Vec<NewPlayerGroup> = source_members
+                .into_iter()
+                .map(|player_id| NewPlayerGroup {
+                    player_id,
+                    group_id: new_group_id,
+                })
+                .collect();
+            let copied = diesel::insert_into(pg_dsl::player_groups)
+                .values(&new_members)
+                .execute(transaction_conn)?;
             info!(
-                "Successfully removed instructor {} from game {}",
-                instructor_to_remove_id, game_id
-            );
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
-            warn!(
-                "Instructor {} was not associated with game {}. No record removed.",
-                instructor_to_remove_id, game_id
-            );
-            Err(AppError::NotFound(format!(
-                "Instructor {} is not associated with game {}.",
-                instructor_to_remove_id, game_id
-            )))
-        }
-        n => {
-            error!(
-                "Unexpected number of rows ({}) deleted when removing instructor {} from game {}",
-                n, instructor_to_remove_id, game_id
+                "Copied {} members of group {} into new group {}",
+                copied, source_group_id, new_group_id
             );
-            Err(AppError::InternalServerError(anyhow!(
-                "Unexpected error during instructor removal."
-            )))
-        }
-    }
+
+            Ok(new_group_id)
+        })
+    })
+    .await?;
+
+    let new_group_id = clone_result?;
+    info!(
+        "Successfully cloned group {} into group {}",
+        source_group_id, new_group_id
+    );
+    Ok(ApiResponse::ok(new_group_id))
 }
 
-/// Activates a specific game by setting its 'active' status to true.
+/// Makes another instructor an owner of a group, optionally demoting the current owner
+/// to a non-owner association in the same transaction.
 ///
-/// Request Body: `ActivateGamePayload`
+/// Request Body: `TransferGroupOwnershipPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the game was successfully activated (200 OK).
-/// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game doesn't exist.
-/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
+/// * `bool`: true if the ownership was transferred (200 OK).
+/// * `403 Forbidden`: If the current owner lacks owner permission for the group.
+/// * `404 Not Found`: If the group or the new owner doesn't exist.
+/// * `422 Unprocessable Entity`: If the current and new owner are the same instructor.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
 #[instrument(skip(pool, payload))]
-pub async fn activate_game(
+pub async fn transfer_group_ownership(
     State(pool): State<Pool>,
-    Json(payload): Json<ActivateGamePayload>,
+    Json(payload): Json<TransferGroupOwnershipPayload>,
 ) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let game_id = payload.game_id;
+    let current_owner_id = payload.current_owner_id;
+    let group_id = payload.group_id;
+    let new_owner_id = payload.new_owner_id;
+    let relinquish = payload.relinquish;
 
     info!(
-        "Attempting to activate game_id: {} requested by instructor_id: {}",
-        game_id, instructor_id
+        "Attempting to transfer ownership of group {} from instructor {} to instructor {} (relinquish={})",
+        group_id, current_owner_id, new_owner_id, relinquish
     );
-    debug!("Activate game payload: {:?}", payload);
+    debug!("Transfer group ownership payload: {:?}", payload);
 
-    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
-    info!(
-        "Permission check passed for instructor {} on game {}",
-        instructor_id, game_id
+    if current_owner_id == new_owner_id {
+        warn!(
+            "Rejecting transfer of group {} ownership to its current owner {}",
+            group_id, current_owner_id
+        );
+        return Err(AppError::UnprocessableEntity(format!(
+            "Instructor {} cannot transfer group {} to themselves.",
+            current_owner_id, group_id
+        )));
+    }
+
+    helper::check_instructor_group_permission(&pool, current_owner_id, group_id).await?;
+    info!(
+        "Permission check passed for instructor {} on group {}",
+        current_owner_id, group_id
     );
 
-    let rows_affected = helper::run_query(&pool, move |conn| {
-        let game_id = game_id;
-        diesel::update(games_dsl::games.find(game_id))
-            .set((
-                games_dsl::active.eq(true),
-                games_dsl::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(conn)
+    let transfer_result: Result<(), AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|transaction_conn| {
+            let new_owner_exists =
+                diesel::select(exists(instructors_dsl::instructors.find(new_owner_id)))
+                    .get_result::<bool>(transaction_conn)?;
+            if !new_owner_exists {
+                error!(
+                    "Cannot transfer group {}: Instructor with ID {} not found.",
+                    group_id, new_owner_id
+                );
+                return Err(AppError::NotFound(format!(
+                    "Instructor with ID {} not found.",
+                    new_owner_id
+                )));
+            }
+
+            diesel::insert_into(gro_dsl::group_ownership)
+                .values(&NewGroupOwnership {
+                    group_id,
+                    instructor_id: new_owner_id,
+                    owner: true,
+                })
+                .on_conflict((gro_dsl::group_id, gro_dsl::instructor_id))
+                .do_update()
+                .set(gro_dsl::owner.eq(true))
+                .execute(transaction_conn)?;
+
+            if relinquish {
+                let demoted = diesel::update(
+                    gro_dsl::group_ownership
+                        .filter(gro_dsl::group_id.eq(group_id))
+                        .filter(gro_dsl::instructor_id.eq(current_owner_id)),
+                )
+                .set(gro_dsl::owner.eq(false))
+                .execute(transaction_conn)?;
+                info!(
+                    "Instructor {} relinquished ownership of group {} ({} rows updated)",
+                    current_owner_id, group_id, demoted
+                );
+            }
+            Ok(())
+        })
     })
     .await?;
 
-    match rows_affected {
-        1 => {
-            info!("Successfully activated game {}", game_id);
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
-            error!(
-                "Game {} activation failed: 0 rows affected (game not found after permission check).",
-                game_id
-            );
-            Err(AppError::NotFound(format!(
-                "Game with ID {} not found during update.",
-                game_id
-            )))
-        }
-        n => {
-            error!(
-                "Game {} activation failed: {} rows affected (unexpected state).",
-                game_id, n
-            );
-            Err(AppError::InternalServerError(anyhow!(
-                "Game activation failed unexpectedly (multiple rows affected)."
-            )))
-        }
-    }
+    transfer_result?;
+    info!(
+        "Successfully made instructor {} an owner of group {}",
+        new_owner_id, group_id
+    );
+    Ok(ApiResponse::ok(true))
 }
 
-/// Deactivates a specific game by setting its 'active' status to false.
+/// Adds a student (player) to a specific group.
 ///
-/// Request Body: `StopGamePayload`
+/// Request Body: `AddGroupMemberPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the game was successfully deactivated (200 OK).
-/// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game doesn't exist.
-/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
+/// * `bool`: true if the student is now a member (either newly added or already present) (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group or player doesn't exist.
+/// * `409 Conflict`: If the group already has `--max-group-size` active members.
+/// * `500 Internal Server Error`: If a database error occurs.
 #[instrument(skip(pool, payload))]
-pub async fn stop_game(
+pub async fn add_group_member(
     State(pool): State<Pool>,
-    Json(payload): Json<StopGamePayload>,
+    Extension(max_group_size): Extension<Arc<MaxGroupSize>>,
+    Json(payload): Json<AddGroupMemberPayload>,
 ) -> Result<ApiResponse<bool>, AppError> {
     let instructor_id = payload.instructor_id;
-    let game_id = payload.game_id;
+    let group_id = payload.group_id;
+    let player_id = payload.player_id;
 
     info!(
-        "Attempting to stop (deactivate) game_id: {} requested by instructor_id: {}",
-        game_id, instructor_id
+        "Attempting to add player {} to group {} requested by instructor {}",
+        player_id, group_id, instructor_id
     );
-    debug!("Stop game payload: {:?}", payload);
+    debug!("Add group member payload: {:?}", payload);
 
-    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
     info!(
-        "Permission check passed for instructor {} on game {}",
-        instructor_id, game_id
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
     );
 
-    let rows_affected = helper::run_query(&pool, move |conn| {
-        let game_id = game_id;
-        diesel::update(games_dsl::games.find(game_id))
-            .set((
-                games_dsl::active.eq(false),
-                games_dsl::updated_at.eq(diesel::dsl::now),
-            ))
-            .execute(conn)
+    let player_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
+        }
     })
     .await?;
 
-    match rows_affected {
-        1 => {
-            info!("Successfully stopped (deactivated) game {}", game_id);
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
-            error!(
-                "Game {} deactivation failed: 0 rows affected (game not found after permission check).",
-                game_id
-            );
-            Err(AppError::NotFound(format!(
-                "Game with ID {} not found during update.",
-                game_id
-            )))
-        }
-        n => {
-            error!(
-                "Game {} deactivation failed: {} rows affected (unexpected state).",
-                game_id, n
-            );
-            Err(AppError::InternalServerError(anyhow!(
-                "Game deactivation failed unexpectedly (multiple rows affected)."
-            )))
-        }
+    if !player_exists {
+        error!("Cannot add member: Player with ID {} not found.", player_id);
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} not found.",
+            player_id
+        )));
+    }
+    info!("Player to add (ID {}) confirmed to exist.", player_id);
+
+    let rows_affected = helper::interact(&pool, move |conn| {
+        conn.transaction(|tx_conn| {
+            // locking the group serializes concurrent additions against the size limit
+            groups_dsl::groups
+                .find(group_id)
+                .select(groups_dsl::id)
+                .for_update()
+                .first::<i64>(tx_conn)?;
+
+            let active_members = pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(group_id))
+                .filter(pg_dsl::left_at.is_null());
+            let already_member: bool = select(exists(
+                active_members.filter(pg_dsl::player_id.eq(player_id)),
+            ))
+            .get_result(tx_conn)?;
+            if !already_member {
+                let member_count = active_members.count().get_result::<i64>(tx_conn)?;
+                max_group_size.ensure_room(member_count, 1)?;
+            }
+
+            let new_membership = NewPlayerGroup {
+                player_id,
+                group_id,
+            };
+            diesel::insert_into(pg_dsl::player_groups)
+                .values(&new_membership)
+                .on_conflict((pg_dsl::player_id, pg_dsl::group_id))
+                .do_nothing()
+                .execute(tx_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(e, "Could not add the player to the group")
+                })
+        })
+    })
+    .await??;
+
+    if rows_affected == 1 {
+        info!(
+            "Successfully added player {} to group {}",
+            player_id, group_id
+        );
+    } else {
+        info!(
+            "Player {} was already a member of group {}. No changes made.",
+            player_id, group_id
+        );
     }
+    Ok(ApiResponse::ok(true))
 }
 
-/// Removes a student's registration from a specific game.
+/// Removes a student (player) from a specific group.
 ///
-/// Request Body: `RemoveGameStudentPayload`
+/// Request Body: `RemoveGroupMemberPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the registration was successfully removed (200 OK).
-/// * `403 Forbidden`: If the instructor lacks permission for the game.
-/// * `404 Not Found`: If the game doesn't exist, or the student was not registered in the game.
+/// * `bool`: true if the student was successfully removed (200 OK).
+/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
+/// * `404 Not Found`: If the group doesn't exist, or the student was not a member.
 /// * `500 Internal Server Error`: If a database error occurs or multiple records are deleted unexpectedly.
 #[instrument(skip(pool, payload))]
-pub async fn remove_game_student(
+pub async fn remove_group_member(
     State(pool): State<Pool>,
-    Json(payload): Json<RemoveGameStudentPayload>,
+    Json(payload): Json<RemoveGroupMemberPayload>,
 ) -> Result<ApiResponse<bool>, AppError> {
     let instructor_id = payload.instructor_id;
-    let game_id = payload.game_id;
-    let student_id = payload.student_id;
+    let group_id = payload.group_id;
+    let player_id = payload.player_id;
 
     info!(
-        "Attempting to remove student {} from game {} requested by instructor {}",
-        student_id, game_id, instructor_id
+        "Attempting to remove player {} from group {} requested by instructor {}",
+        player_id, group_id, instructor_id
     );
-    debug!("Remove game student payload: {:?}", payload);
+    debug!("Remove group member payload: {:?}", payload);
 
-    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
+    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
     info!(
-        "Permission check passed for instructor {} on game {}",
-        instructor_id, game_id
+        "Permission check passed for instructor {} on group {}",
+        instructor_id, group_id
     );
 
     let rows_affected = helper::run_query(&pool, move |conn| {
-        let game_id = game_id;
-        let student_id = student_id;
+        let group_id = group_id;
+        let player_id = player_id;
         diesel::delete(
-            pr_dsl::player_registrations
-                .filter(pr_dsl::game_id.eq(game_id))
-                .filter(pr_dsl::player_id.eq(student_id)),
+            pg_dsl::player_groups
+                .filter(pg_dsl::group_id.eq(group_id))
+                .filter(pg_dsl::player_id.eq(player_id)),
         )
         .execute(conn)
     })
@@ -1495,704 +4818,872 @@ pub async fn remove_game_student(
     match rows_affected {
         1 => {
             info!(
-                "Successfully removed student {} from game {}",
-                student_id, game_id
+                "Successfully removed player {} from group {}",
+                player_id, group_id
             );
             Ok(ApiResponse::ok(true))
         }
         0 => {
             warn!(
-                "Student {} was not registered in game {}. No record removed.",
-                student_id, game_id
+                "Player {} was not a member of group {}. No record removed.",
+                player_id, group_id
             );
             Err(AppError::NotFound(format!(
-                "Student {} is not registered in game {}.",
-                student_id, game_id
+                "Player {} is not a member of group {}.",
+                player_id, group_id
             )))
         }
         n => {
             error!(
-                "Unexpected number of rows ({}) deleted when removing student {} from game {}",
-                n, student_id, game_id
+                "Unexpected number of rows ({}) deleted when removing player {} from group {}",
+                n, player_id, group_id
             );
             Err(AppError::InternalServerError(anyhow!(
-                "Unexpected error during student removal."
+                "Unexpected error during member removal."
             )))
         }
     }
-}
+}
+
+/// Creates a new player and optionally adds them to a game and/or group.
+/// Without a `language`, the game registration uses the first of `--default-languages` the
+/// game's course offers, or else the course's first language.
+/// The email is stored trimmed and lowercased.
+///
+/// Request Body: `CreatePlayerPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `i64`: The ID of the newly created player (200 OK).
+/// * `403 Forbidden`: If a non-admin instructor tries to create a player without game/group context, or lacks permission for the specified game/group.
+/// * `404 Not Found`: If the specified game or group does not exist.
+/// * `409 Conflict`: If the player email address is already taken, ignoring case.
+/// * `429 Too Many Requests`: If a non-admin instructor exceeded the hourly player creation limit.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, limiter, default_languages, payload))]
+pub async fn create_player(
+    State(pool): State<Pool>,
+    Extension(limiter): Extension<Arc<SlidingWindowLimiter>>,
+    Extension(default_languages): Extension<Arc<DefaultLanguages>>,
+    Json(mut payload): Json<CreatePlayerPayload>,
+) -> Result<ApiResponse<i64>, AppError> {
+    payload.email = db::normalize_email(&payload.email);
+    info!(
+        "Attempting to create player with email '{}' requested by instructor {}",
+        payload.email, payload.instructor_id
+    );
+    debug!("Create player payload: {:?}", payload);
+
+    if let Some(game_id) = payload.game_id {
+        helper::check_instructor_game_permission(&pool, payload.instructor_id, game_id).await?;
+        info!(
+            "Instructor {} has permission for game {}",
+            payload.instructor_id, game_id
+        );
+    }
+    if let Some(group_id) = payload.group_id {
+        helper::check_instructor_group_permission(&pool, payload.instructor_id, group_id).await?;
+        info!(
+            "Instructor {} has permission for group {}",
+            payload.instructor_id, group_id
+        );
+    }
+    if payload.game_id.is_none() && payload.group_id.is_none() && payload.instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} cannot create player without game/group context.",
+            payload.instructor_id
+        );
+        return Err(AppError::Forbidden(
+            "Instructor lacks permission to create player without game/group context.".to_string(),
+        ));
+    }
+
+    let email_taken = helper::run_query(&pool, {
+        let email = payload.email.clone();
+        move |conn| {
+            diesel::select(exists(
+                players_dsl::players.filter(db::lower(players_dsl::email).eq(email)),
+            ))
+            .get_result::<bool>(conn)
+        }
+    })
+    .await?;
+    if email_taken {
+        warn!("Player email '{}' is already taken.", payload.email);
+        return Err(AppError::Conflict(
+            "Player email is already taken.".to_string(),
+        ));
+    }
+
+    if payload.instructor_id != 0 && !limiter.try_acquire(payload.instructor_id) {
+        warn!(
+            "Instructor {} exceeded the player creation limit of {} per hour.",
+            payload.instructor_id,
+            limiter.limit()
+        );
+        return Err(AppError::TooManyRequests(format!(
+            "Player creation limit of {} per hour reached.",
+            limiter.limit()
+        )));
+    }
 
-/// Finds the player ID associated with a given email address.
-///
-/// Query Parameters:
-/// * `email`: The email address to look up.
-///
-/// Returns (wrapped in `ApiResponse`)
-/// * `i64`: The player ID if found (200 OK).
-/// * `404 Not Found`: If no player with the given email exists.
-/// * `500 Internal Server Error`: If a database error occurs.
-#[instrument(skip(pool, params))]
-pub async fn translate_email_to_player_id(
-    State(pool): State<Pool>,
-    Query(params): Query<TranslateEmailParams>,
-) -> Result<ApiResponse<i64>, AppError> {
-    let email_to_find = params.email;
-    let email_cloned = email_to_find.clone();
+    let creation_result: Result<i64, AppError> = helper::interact(&pool, move |conn_sync| {
+        let payload = payload;
+        conn_sync.transaction(|transaction_conn| {
+            let new_player = NewPlayer {
+                email: payload.email,
+                display_name: payload.display_name,
+                display_avatar: payload.display_avatar,
+            };
+            let new_player_id = diesel::insert_into(players_dsl::players)
+                .values(&new_player)
+                .returning(players_dsl::id)
+                .get_result::<i64>(transaction_conn)
+                .map_err(|e| {
+                    AppError::from_constraint_violation(e, "Could not create the player")
+                })?;
+
+            if let Some(game_id) = payload.game_id {
+                let language = match payload.language {
+                    Some(language) => language,
+                    None => helper::default_registration_language(
+                        transaction_conn,
+                        game_id,
+                        &default_languages,
+                    )?,
+                };
+                let new_registration = NewPlayerRegistration {
+                    player_id: new_player_id,
+                    game_id,
+                    language,
+                    progress: 0,
+                    game_state: json!({}),
+                };
+                diesel::insert_into(pr_dsl::player_registrations)
+                    .values(&new_registration)
+                    .execute(transaction_conn)
+                    .map_err(|e| {
+                        AppError::from_constraint_violation(
+                            e,
+                            "Could not register the player in the game",
+                        )
+                    })?;
+            }
 
-    info!("Attempting to find player ID for email: {}", &email_to_find);
-    debug!("Translate email params: {:?}", &email_to_find);
+            if let Some(group_id) = payload.group_id {
+                let new_membership = NewPlayerGroup {
+                    player_id: new_player_id,
+                    group_id,
+                };
+                diesel::insert_into(pg_dsl::player_groups)
+                    .values(&new_membership)
+                    .on_conflict((pg_dsl::player_id, pg_dsl::group_id))
+                    .do_nothing()
+                    .execute(transaction_conn)
+                    .map_err(|e| {
+                        AppError::from_constraint_violation(
+                            e,
+                            "Could not add the player to the group",
+                        )
+                    })?;
+            }
 
-    let player_id = helper::run_query(&pool, move |conn| {
-        players_dsl::players
-            .filter(players_dsl::email.eq(email_cloned))
-            .select(players_dsl::id)
-            .first::<i64>(conn)
+            Ok(new_player_id)
+        })
     })
     .await?;
 
-    info!(
-        "Successfully found player ID {} for email {}",
-        player_id, &email_to_find
-    );
-    Ok(ApiResponse::ok(player_id))
+    creation_result.map(ApiResponse::ok)
 }
 
-/// Creates a new group, assigns ownership, and adds initial members.
+/// Disables a specific player account by setting their 'disabled' status to true.
 ///
-/// Request Body: `CreateGroupPayload`
+/// Request Body: `DisablePlayerPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `i64`: The ID of the newly created group (200 OK).
-/// * `404 Not Found`: If the requesting instructor or any specified member player does not exist.
-/// * `409 Conflict`: If the group display name is already taken.
-/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+/// * `bool`: true if the player was successfully disabled (200 OK).
+/// * `403 Forbidden`: If requesting instructor is not admin (ID 0).
+/// * `404 Not Found`: If the target player doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
 #[instrument(skip(pool, payload))]
-pub async fn create_group(
+pub async fn disable_player(
     State(pool): State<Pool>,
-    Json(payload): Json<CreateGroupPayload>,
-) -> Result<ApiResponse<i64>, AppError> {
-    let display_name_cloned = payload.display_name.clone();
+    Json(payload): Json<DisablePlayerPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
     let instructor_id = payload.instructor_id;
+    let player_id = payload.player_id;
 
     info!(
-        "Attempting to create group '{}' by instructor {}",
-        &display_name_cloned, instructor_id
+        "Attempting to disable player {} requested by instructor {}",
+        player_id, instructor_id
     );
-    debug!("Create group payload: {:?}", payload);
+    debug!("Disable player payload: {:?}", payload);
 
-    let instructor_exists = helper::run_query(&pool, {
+    if instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot disable players.",
+            instructor_id
+        );
+        return Err(AppError::Forbidden(
+            "Only admin users can disable players.".to_string(),
+        ));
+    }
+    info!(
+        "Admin permission confirmed for instructor {}",
+        instructor_id
+    );
+
+    let player_exists = helper::run_query(&pool, {
         move |conn| {
-            diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
-                .get_result::<bool>(conn)
+            diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
         }
     })
     .await?;
-    if !instructor_exists {
+
+    if !player_exists {
         error!(
-            "Cannot create group: Instructor with ID {} not found.",
-            instructor_id
+            "Cannot disable player: Player with ID {} not found.",
+            player_id
         );
         return Err(AppError::NotFound(format!(
-            "Instructor with ID {} not found.",
-            instructor_id
+            "Player with ID {} not found.",
+            player_id
         )));
     }
+    info!("Player {} confirmed to exist.", player_id);
 
-    let name_taken = helper::run_query(&pool, {
-        let name = display_name_cloned.clone();
-        move |conn| {
-            diesel::select(exists(
-                groups_dsl::groups.filter(groups_dsl::display_name.eq(name)),
-            ))
-            .get_result::<bool>(conn)
-        }
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        let player_id = player_id;
+        diesel::update(players_dsl::players.find(player_id))
+            .set(players_dsl::disabled.eq(true))
+            .execute(conn)
     })
     .await?;
-    if name_taken {
-        warn!("Group name '{}' is already taken.", &display_name_cloned);
-        return Err(AppError::Conflict(format!(
-            "Group name '{}' is already taken.",
-            display_name_cloned
-        )));
-    }
-
-    let members_to_add = payload.member_list.clone();
-    if !members_to_add.is_empty() {
-        let existing_players_count = helper::run_query(&pool, {
-            let member_ids = members_to_add.clone();
-            move |conn| {
-                players_dsl::players
-                    .filter(players_dsl::id.eq_any(&member_ids))
-                    .count()
-                    .get_result::<i64>(conn)
-            }
-        })
-        .await?;
 
-        if existing_players_count != members_to_add.len() as i64 {
+    match rows_affected {
+        1 => {
+            info!("Successfully disabled player {}", player_id);
+            Ok(ApiResponse::ok(true))
+        }
+        0 => {
             error!(
-                "Cannot create group: One or more player IDs in memberList do not exist. Expected {}, found {}.",
-                members_to_add.len(),
-                existing_players_count
+                "Player {} disable failed: 0 rows affected (player not found during update).",
+                player_id
             );
-            return Err(AppError::NotFound(
-                "One or more players listed as members do not exist.".to_string(),
-            ));
+            Err(AppError::NotFound(format!(
+                "Player with ID {} not found during update.",
+                player_id
+            )))
+        }
+        n => {
+            error!(
+                "Player {} disable failed: {} rows affected (unexpected state).",
+                player_id, n
+            );
+            Err(AppError::InternalServerError(anyhow!(
+                "Player disable failed unexpectedly (multiple rows affected)."
+            )))
         }
-        info!("All {} specified members validated.", members_to_add.len());
     }
-
-    let conn = pool.get().await?;
-    let creation_result: Result<i64, AppError> = conn
-        .interact(move |conn_sync| {
-            let payload = payload;
-            let display_name_cloned = display_name_cloned;
-            conn_sync.transaction(|transaction_conn| {
-                let new_group = NewGroup {
-                    display_name: payload.display_name,
-                    display_avatar: payload.display_avatar,
-                };
-                let new_group_id = diesel::insert_into(groups_dsl::groups)
-                    .values(&new_group)
-                    .returning(groups_dsl::id)
-                    .get_result::<i64>(transaction_conn)
-                    .map_err(|e| {
-                        if let DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) = e
-                        {
-                            AppError::Conflict(format!(
-                                "Group name '{}' is already taken (race condition).",
-                                display_name_cloned
-                            ))
-                        } else {
-                            AppError::from(e)
-                        }
-                    })?;
-
-                let new_ownership = NewGroupOwnership {
-                    group_id: new_group_id,
-                    instructor_id: payload.instructor_id,
-                    owner: true,
-                };
-                diesel::insert_into(gro_dsl::group_ownership)
-                    .values(&new_ownership)
-                    .execute(transaction_conn)
-                    .map_err(|e| {
-                        if let DieselError::DatabaseError(
-                            DatabaseErrorKind::ForeignKeyViolation,
-                            _,
-                        ) = e
-                        {
-                            AppError::NotFound(
-                                "Referenced instructor not found during transaction.".to_string(),
-                            )
-                        } else {
-                            AppError::from(e)
-                        }
-                    })?;
-
-                if !payload.member_list.is_empty() {
-                    let new_members: Vec<NewPlayerGroup> = payload
-                        .member_list
-                        .iter()
-                        .map(|&player_id| NewPlayerGroup {
-                            player_id,
-                            group_id: new_group_id,
-                        })
-                        .collect();
-
-                    diesel::insert_into(pg_dsl::player_groups)
-                        .values(&new_members)
-                        .execute(transaction_conn)
-                        .map_err(|e| {
-                            if let DieselError::DatabaseError(
-                                DatabaseErrorKind::ForeignKeyViolation,
-                                _,
-                            ) = e
-                            {
-                                AppError::NotFound(
-                                    "Referenced player not found during transaction.".to_string(),
-                                )
-                            } else {
-                                AppError::from(e)
-                            }
-                        })?;
-                }
-
-                Ok(new_group_id)
-            })
-        })
-        .await?;
-
-    creation_result.map(ApiResponse::ok)
 }
 
-/// Dissolves a group, removing all members and ownership records.
+/// Default number of events returned per page by `get_game_timeline`.
+const DEFAULT_TIMELINE_PAGE_SIZE: i64 = 100;
+/// Maximum number of events returned per page by `get_game_timeline`.
+const MAX_TIMELINE_PAGE_SIZE: i64 = 500;
+
+/// Retrieves a chronological feed of events in a game across all players.
+/// Merges submissions (by `entered_at`), joins (by `joined_at`) and leaves (by `left_at`).
+/// Events are ordered by `(occurred_at, event_type, id)`, so events sharing a timestamp
+/// are never skipped or repeated across pages.
 ///
-/// Request Body: `DissolveGroupPayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor.
+/// * `game_id`: The ID of the game.
+/// * `after`, `after_type`, `after_id`: Optional cursor taken from the previous page's `next_cursor`;
+///   only events strictly after it are returned. `after` alone returns events strictly after that timestamp.
+/// * `limit`: Optional page size (default 100, capped at 500).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the group was successfully dissolved (200 OK).
-/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
-/// * `404 Not Found`: If the group doesn't exist.
-/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
-#[instrument(skip(pool, payload))]
-pub async fn dissolve_group(
+/// * `GameTimelineResponse`: Events in ascending order, plus `next_cursor` when more events may follow (200 OK).
+/// * `400 Bad Request`: If `after_type` or `after_id` is given without the rest of the cursor.
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_game_timeline(
     State(pool): State<Pool>,
-    Json(payload): Json<DissolveGroupPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let group_id = payload.group_id;
+    Query(params): Query<GetGameTimelineParams>,
+) -> Result<ApiResponse<GameTimelineResponse>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
 
     info!(
-        "Attempting to dissolve group {} requested by instructor {}",
-        group_id, instructor_id
+        "Fetching timeline for game {} requested by instructor {}",
+        game_id, instructor_id
     );
-    debug!("Dissolve group payload: {:?}", payload);
+    debug!("Get game timeline params: {:?}", params);
+
+    let cursor = match (params.after, params.after_type, params.after_id) {
+        (Some(occurred_at), Some(event_type), Some(id)) => Some(TimelineCursor {
+            occurred_at,
+            event_type,
+            id,
+        }),
+        (_, None, None) => None,
+        _ => {
+            warn!("Incomplete timeline cursor: {:?}", params);
+            return Err(AppError::BadRequest(
+                "after_type and after_id must be given together with after".to_string(),
+            ));
+        }
+    };
 
-    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
-        "Permission check passed for instructor {} on group {}",
-        instructor_id, group_id
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
     );
 
-    let conn = pool.get().await?;
-    let deletion_result: Result<(), AppError> = conn.interact(move |conn_sync| {
-        let group_id = group_id;
-        conn_sync.transaction(|transaction_conn| {
-            info!("Deleting member records from player_groups for group {}", group_id);
-            let members_deleted = diesel::delete(pg_dsl::player_groups.filter(pg_dsl::group_id.eq(group_id)))
-                .execute(transaction_conn)
-                .map_err(AppError::from)?;
-            info!("Deleted {} member records from player_groups for group {}", members_deleted, group_id);
+    let after = params.after.unwrap_or(DateTime::UNIX_EPOCH);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_TIMELINE_PAGE_SIZE)
+        .clamp(1, MAX_TIMELINE_PAGE_SIZE);
+
+    // Smallest id still returned for an event of the given type at exactly `after`.
+    // Event types ordered before the cursor's type were fully returned at that timestamp,
+    // those ordered after it were not returned at all.
+    let min_id_at_cursor = move |event_type: TimelineEventType| match cursor {
+        Some(cursor) if event_type < cursor.event_type => i64::MAX,
+        Some(cursor) if event_type == cursor.event_type => cursor.id,
+        Some(_) => i64::MIN,
+        None if params.after.is_some() => i64::MAX,
+        None => i64::MIN,
+    };
+    let submission_min_id = min_id_at_cursor(TimelineEventType::Submission);
+    let join_min_id = min_id_at_cursor(TimelineEventType::Join);
+    let leave_min_id = min_id_at_cursor(TimelineEventType::Leave);
 
-            info!("Deleting ownership records from group_ownership for group {}", group_id);
-            let owners_deleted = diesel::delete(gro_dsl::group_ownership.filter(gro_dsl::group_id.eq(group_id)))
-                .execute(transaction_conn)
-                .map_err(AppError::from)?;
-            info!("Deleted {} ownership records from group_ownership for group {}", owners_deleted, group_id);
+    type SubmissionEventTuple = (i64, i64, i64, BigDecimal, bool, DateTime<Utc>); // id, player, exercise, result, first_solution, entered_at
+    type RegistrationEventTuple = (i64, i64, DateTime<Utc>); // id, player, timestamp
 
-            info!("Deleting group record for group {}", group_id);
-            let group_deleted = diesel::delete(groups_dsl::groups.find(group_id))
-                .execute(transaction_conn)
-                .map_err(AppError::from)?;
+    let (submissions, joins, leaves) = helper::run_query(&pool, move |conn| {
+        let submissions = sub_dsl::submissions
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(
+                sub_dsl::entered_at.gt(after).or(sub_dsl::entered_at
+                    .eq(after)
+                    .and(sub_dsl::id.gt(submission_min_id))),
+            )
+            .order_by((sub_dsl::entered_at.asc(), sub_dsl::id.asc()))
+            .limit(limit)
+            .select((
+                sub_dsl::id,
+                sub_dsl::player_id,
+                sub_dsl::exercise_id,
+                sub_dsl::result,
+                sub_dsl::first_solution,
+                sub_dsl::entered_at,
+            ))
+            .load::<SubmissionEventTuple>(conn)?;
+
+        let joins = pr_dsl::player_registrations
+            .filter(pr_dsl::game_id.eq(game_id))
+            .filter(
+                pr_dsl::joined_at
+                    .gt(after)
+                    .or(pr_dsl::joined_at.eq(after).and(pr_dsl::id.gt(join_min_id))),
+            )
+            .order_by((pr_dsl::joined_at.asc(), pr_dsl::id.asc()))
+            .limit(limit)
+            .select((pr_dsl::id, pr_dsl::player_id, pr_dsl::joined_at))
+            .load::<RegistrationEventTuple>(conn)?;
+
+        let leaves = pr_dsl::player_registrations
+            .filter(pr_dsl::game_id.eq(game_id))
+            .filter(
+                pr_dsl::left_at
+                    .gt(after)
+                    .or(pr_dsl::left_at.eq(after).and(pr_dsl::id.gt(leave_min_id))),
+            )
+            .order_by((pr_dsl::left_at.asc(), pr_dsl::id.asc()))
+            .limit(limit)
+            .select((
+                pr_dsl::id,
+                pr_dsl::player_id,
+                pr_dsl::left_at.assume_not_null(),
+            ))
+            .load::<RegistrationEventTuple>(conn)?;
 
-            if group_deleted == 1 {
-                Ok(())
-            } else {
-                error!("Failed to delete group {} itself after deleting dependencies ({} rows affected).", group_id, group_deleted);
-                Err(AppError::NotFound(format!("Group {} not found during final delete step.", group_id)))
-            }
-        })
-    }).await?;
+        Ok((submissions, joins, leaves))
+    })
+    .await?;
 
-    deletion_result.map(|_| ApiResponse::ok(true))
+    let mut events: Vec<(TimelineCursor, TimelineEvent)> = submissions
+        .into_iter()
+        .map(
+            |(submission_id, player_id, exercise_id, result, first_solution, entered_at)| {
+                (
+                    TimelineCursor {
+                        occurred_at: entered_at,
+                        event_type: TimelineEventType::Submission,
+                        id: submission_id,
+                    },
+                    TimelineEvent {
+                        event_type: TimelineEventType::Submission,
+                        player_id,
+                        occurred_at: entered_at,
+                        payload: json!({
+                            "submission_id": submission_id,
+                            "exercise_id": exercise_id,
+                            "result": result.to_f64(),
+                            "first_solution": first_solution,
+                        }),
+                    },
+                )
+            },
+        )
+        .chain(
+            joins
+                .into_iter()
+                .map(|(registration_id, player_id, joined_at)| {
+                    (
+                        TimelineCursor {
+                            occurred_at: joined_at,
+                            event_type: TimelineEventType::Join,
+                            id: registration_id,
+                        },
+                        TimelineEvent {
+                            event_type: TimelineEventType::Join,
+                            player_id,
+                            occurred_at: joined_at,
+                            payload: json!({ "registration_id": registration_id }),
+                        },
+                    )
+                }),
+        )
+        .chain(
+            leaves
+                .into_iter()
+                .map(|(registration_id, player_id, left_at)| {
+                    (
+                        TimelineCursor {
+                            occurred_at: left_at,
+                            event_type: TimelineEventType::Leave,
+                            id: registration_id,
+                        },
+                        TimelineEvent {
+                            event_type: TimelineEventType::Leave,
+                            player_id,
+                            occurred_at: left_at,
+                            payload: json!({ "registration_id": registration_id }),
+                        },
+                    )
+                }),
+        )
+        .collect();
+
+    events.sort_by_key(|(position, _)| *position);
+    events.truncate(limit as usize);
+
+    let next_cursor = if events.len() as i64 == limit {
+        events.last().map(|(position, _)| *position)
+    } else {
+        None
+    };
+    let events: Vec<TimelineEvent> = events.into_iter().map(|(_, event)| event).collect();
+
+    info!(
+        "Returning {} timeline events for game {}",
+        events.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(GameTimelineResponse {
+        events,
+        next_cursor,
+    }))
 }
 
-/// Adds a student (player) to a specific group.
+/// Maximum number of submissions returned per call by `get_recent_submissions`.
+const MAX_RECENT_SUBMISSIONS: i64 = 500;
+
+/// Retrieves the submissions of a game entered after a timestamp, for polling an activity feed.
+/// At most 500 submissions are returned; poll again with the last `entered_at` to get the rest.
 ///
-/// Request Body: `AddGroupMemberPayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor.
+/// * `game_id`: The ID of the game.
+/// * `since`: Optional timestamp; only submissions entered strictly after it are returned.
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the student is now a member (either newly added or already present) (200 OK).
-/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
-/// * `404 Not Found`: If the group or player doesn't exist.
+/// * `Vec<RecentSubmission>`: Submissions ordered by ascending `entered_at` (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game does not exist.
 /// * `500 Internal Server Error`: If a database error occurs.
-#[instrument(skip(pool, payload))]
-pub async fn add_group_member(
+#[instrument(skip(pool, params))]
+pub async fn get_recent_submissions(
     State(pool): State<Pool>,
-    Json(payload): Json<AddGroupMemberPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let group_id = payload.group_id;
-    let player_id = payload.player_id;
+    Query(params): Query<GetRecentSubmissionsParams>,
+) -> Result<ApiResponse<Vec<RecentSubmission>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
 
     info!(
-        "Attempting to add player {} to group {} requested by instructor {}",
-        player_id, group_id, instructor_id
+        "Fetching recent submissions for game {} requested by instructor {}",
+        game_id, instructor_id
     );
-    debug!("Add group member payload: {:?}", payload);
+    debug!("Get recent submissions params: {:?}", params);
 
-    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
-        "Permission check passed for instructor {} on group {}",
-        instructor_id, group_id
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
     );
 
-    let player_exists = helper::run_query(&pool, {
-        move |conn| {
-            diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
-        }
+    let since = params.since.unwrap_or(DateTime::UNIX_EPOCH);
+    let submissions = helper::run_query(&pool, move |conn| {
+        sub_dsl::submissions
+            .filter(sub_dsl::game_id.eq(game_id))
+            .filter(sub_dsl::entered_at.gt(since))
+            .order_by((sub_dsl::entered_at.asc(), sub_dsl::id.asc()))
+            .limit(MAX_RECENT_SUBMISSIONS)
+            .select((
+                sub_dsl::id,
+                sub_dsl::player_id,
+                sub_dsl::exercise_id,
+                sub_dsl::result,
+                sub_dsl::first_solution,
+                sub_dsl::entered_at,
+            ))
+            .load::<RecentSubmission>(conn)
     })
     .await?;
 
-    if !player_exists {
-        error!("Cannot add member: Player with ID {} not found.", player_id);
-        return Err(AppError::NotFound(format!(
-            "Player with ID {} not found.",
-            player_id
-        )));
-    }
-    info!("Player to add (ID {}) confirmed to exist.", player_id);
-
-    let operation_result = helper::run_query(&pool, move |conn| {
-        let player_id = player_id;
-        let group_id = group_id;
-        let new_membership = NewPlayerGroup {
-            player_id,
-            group_id,
-        };
-
-        diesel::insert_into(pg_dsl::player_groups)
-            .values(&new_membership)
-            .on_conflict((pg_dsl::player_id, pg_dsl::group_id))
-            .do_nothing()
-            .execute(conn)
-    })
-    .await;
-
-    match operation_result {
-        Ok(rows_affected) => {
-            if rows_affected == 1 {
-                info!(
-                    "Successfully added player {} to group {}",
-                    player_id, group_id
-                );
-            } else {
-                info!(
-                    "Player {} was already a member of group {}. No changes made.",
-                    player_id, group_id
-                );
-            }
-            Ok(ApiResponse::ok(true))
-        }
-        Err(AppError::InternalServerError(ref err)) => {
-            if let Some(db_err) = err.downcast_ref::<DieselError>() {
-                if let DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) =
-                    db_err
-                {
-                    error!(
-                        "Database constraint violation during member addition: {:?}",
-                        err
-                    );
-                    return Err(AppError::NotFound(
-                        "Group or Player not found (foreign key violation).".to_string(),
-                    ));
-                }
-            }
-            Err(operation_result.unwrap_err())
-        }
-        Err(e) => Err(e),
-    }
+    info!(
+        "Returning {} submissions entered after {} in game {}",
+        submissions.len(),
+        since,
+        game_id
+    );
+    Ok(ApiResponse::ok(submissions))
 }
 
-/// Removes a student (player) from a specific group.
+/// Lists the exercises of a game's course that carry a tag.
 ///
-/// Request Body: `RemoveGroupMemberPayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor.
+/// * `game_id`: The ID of the game.
+/// * `tag`: The tag to filter by (case-insensitive).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the student was successfully removed (200 OK).
-/// * `403 Forbidden`: If the instructor lacks owner permission for the group.
-/// * `404 Not Found`: If the group doesn't exist, or the student was not a member.
-/// * `500 Internal Server Error`: If a database error occurs or multiple records are deleted unexpectedly.
-#[instrument(skip(pool, payload))]
-pub async fn remove_group_member(
+/// * `Vec<i64>`: IDs of tagged exercises in the game's programming language, ordered by module and exercise order (200 OK).
+/// * `403 Forbidden`: If the instructor lacks permission for the game.
+/// * `404 Not Found`: If the game does not exist.
+/// * `422 Unprocessable Entity`: If the tag is empty or longer than 50 characters.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn list_exercises_by_tag(
     State(pool): State<Pool>,
-    Json(payload): Json<RemoveGroupMemberPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let group_id = payload.group_id;
-    let player_id = payload.player_id;
+    Query(params): Query<ListExercisesByTagParams>,
+) -> Result<ApiResponse<Vec<i64>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let game_id = params.game_id;
 
     info!(
-        "Attempting to remove player {} from group {} requested by instructor {}",
-        player_id, group_id, instructor_id
+        "Listing exercises tagged '{}' in game {} requested by instructor {}",
+        params.tag, game_id, instructor_id
     );
-    debug!("Remove group member payload: {:?}", payload);
+    debug!("List exercises by tag params: {:?}", params);
 
-    helper::check_instructor_group_permission(&pool, instructor_id, group_id).await?;
+    let tag = helper::normalize_exercise_tag(&params.tag)?;
+    helper::check_instructor_game_permission(&pool, instructor_id, game_id).await?;
     info!(
-        "Permission check passed for instructor {} on group {}",
-        instructor_id, group_id
+        "Permission check passed for instructor {} on game {}",
+        instructor_id, game_id
     );
 
-    let rows_affected = helper::run_query(&pool, move |conn| {
-        let group_id = group_id;
-        let player_id = player_id;
-        diesel::delete(
-            pg_dsl::player_groups
-                .filter(pg_dsl::group_id.eq(group_id))
-                .filter(pg_dsl::player_id.eq(player_id)),
-        )
-        .execute(conn)
-    })
-    .await?;
+    let exercise_ids = helper::game_exercise_ids_by_tag(&pool, game_id, tag).await?;
 
-    match rows_affected {
-        1 => {
-            info!(
-                "Successfully removed player {} from group {}",
-                player_id, group_id
-            );
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
-            warn!(
-                "Player {} was not a member of group {}. No record removed.",
-                player_id, group_id
-            );
-            Err(AppError::NotFound(format!(
-                "Player {} is not a member of group {}.",
-                player_id, group_id
-            )))
-        }
-        n => {
-            error!(
-                "Unexpected number of rows ({}) deleted when removing player {} from group {}",
-                n, player_id, group_id
-            );
-            Err(AppError::InternalServerError(anyhow!(
-                "Unexpected error during member removal."
-            )))
-        }
-    }
+    info!(
+        "Found {} tagged exercises in game {}",
+        exercise_ids.len(),
+        game_id
+    );
+    Ok(ApiResponse::ok(exercise_ids))
 }
 
-/// Creates a new player and optionally adds them to a game and/or group.
+/// Default number of players returned per page by `list_disabled_players`.
+const DEFAULT_PLAYERS_PAGE_SIZE: i64 = 50;
+/// Maximum number of players returned per page by `list_disabled_players`.
+const MAX_PLAYERS_PAGE_SIZE: i64 = 100;
+
+/// Lists disabled player accounts for auditing, leaving out soft-deleted ones. Requires admin permission (ID 0).
 ///
-/// Request Body: `CreatePlayerPayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor (must be 0).
+/// * `offset`: Optional number of players to skip (default 0).
+/// * `limit`: Optional page size (default 50, capped at 100).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `i64`: The ID of the newly created player (200 OK).
-/// * `403 Forbidden`: If a non-admin instructor tries to create a player without game/group context, or lacks permission for the specified game/group.
-/// * `404 Not Found`: If the specified game or group does not exist.
-/// * `409 Conflict`: If the player email address is already taken.
-/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
-#[instrument(skip(pool, payload))]
-pub async fn create_player(
+/// * `Vec<DisabledPlayerResponse>`: Disabled players ordered by ID (200 OK).
+/// * `403 Forbidden`: If the requesting instructor is not admin, or the authenticated instructor does not match `instructor_id`.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn list_disabled_players(
     State(pool): State<Pool>,
-    Json(payload): Json<CreatePlayerPayload>,
-) -> Result<ApiResponse<i64>, AppError> {
+    acting_instructor: ActingInstructor,
+    Query(params): Query<ListDisabledPlayersParams>,
+) -> Result<ApiResponse<Vec<DisabledPlayerResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+
     info!(
-        "Attempting to create player with email '{}' requested by instructor {}",
-        payload.email, payload.instructor_id
+        "Listing disabled players requested by instructor {}",
+        instructor_id
     );
-    debug!("Create player payload: {:?}", payload);
+    debug!("List disabled players params: {:?}", params);
 
-    if let Some(game_id) = payload.game_id {
-        helper::check_instructor_game_permission(&pool, payload.instructor_id, game_id).await?;
-        info!(
-            "Instructor {} has permission for game {}",
-            payload.instructor_id, game_id
-        );
-    }
-    if let Some(group_id) = payload.group_id {
-        helper::check_instructor_group_permission(&pool, payload.instructor_id, group_id).await?;
-        info!(
-            "Instructor {} has permission for group {}",
-            payload.instructor_id, group_id
-        );
-    }
-    if payload.game_id.is_none() && payload.group_id.is_none() && payload.instructor_id != 0 {
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
+    if instructor_id != 0 {
         warn!(
-            "Permission denied: Instructor {} cannot create player without game/group context.",
-            payload.instructor_id
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot list disabled players.",
+            instructor_id
         );
         return Err(AppError::Forbidden(
-            "Instructor lacks permission to create player without game/group context.".to_string(),
+            "Only admin users can list disabled players.".to_string(),
         ));
     }
 
-    let email_taken = helper::run_query(&pool, {
-        let email = payload.email.clone();
-        move |conn| {
-            diesel::select(exists(
-                players_dsl::players.filter(players_dsl::email.eq(email)),
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PLAYERS_PAGE_SIZE)
+        .clamp(1, MAX_PLAYERS_PAGE_SIZE);
+
+    let players = helper::run_query(&pool, move |conn| {
+        players_dsl::players
+            .filter(players_dsl::disabled.eq(true))
+            .filter(players_dsl::deleted_at.is_null())
+            .order_by(players_dsl::id.asc())
+            .offset(offset)
+            .limit(limit)
+            .select((
+                players_dsl::id,
+                players_dsl::email,
+                players_dsl::display_name,
+                players_dsl::last_active,
             ))
-            .get_result::<bool>(conn)
-        }
+            .load::<DisabledPlayerResponse>(conn)
     })
     .await?;
-    if email_taken {
-        warn!("Player email '{}' is already taken.", payload.email);
-        return Err(AppError::Conflict(
-            "Player email is already taken.".to_string(),
-        ));
-    }
-
-    let conn = pool.get().await?;
-    let creation_result: Result<i64, AppError> = conn
-        .interact(move |conn_sync| {
-            let payload = payload;
-            conn_sync.transaction(|transaction_conn| {
-                let new_player = NewPlayer {
-                    email: payload.email,
-                    display_name: payload.display_name,
-                    display_avatar: payload.display_avatar,
-                };
-                let new_player_id = diesel::insert_into(players_dsl::players)
-                    .values(&new_player)
-                    .returning(players_dsl::id)
-                    .get_result::<i64>(transaction_conn)
-                    .map_err(|e| {
-                        if let DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) = e
-                        {
-                            AppError::Conflict(
-                                "Player email is already taken (race condition).".to_string(),
-                            )
-                        } else {
-                            AppError::from(e)
-                        }
-                    })?;
-
-                if let Some(game_id) = payload.game_id {
-                    let language = payload.language.as_deref().unwrap_or("en").to_string();
-                    let new_registration = NewPlayerRegistration {
-                        player_id: new_player_id,
-                        game_id,
-                        language,
-                        progress: 0,
-                        game_state: json!({}),
-                    };
-                    diesel::insert_into(pr_dsl::player_registrations)
-                        .values(&new_registration)
-                        .execute(transaction_conn)
-                        .map_err(|e| {
-                            if let DieselError::DatabaseError(
-                                DatabaseErrorKind::ForeignKeyViolation,
-                                _,
-                            ) = e
-                            {
-                                AppError::NotFound(
-                                    "Referenced game not found during transaction.".to_string(),
-                                )
-                            } else {
-                                AppError::from(e)
-                            }
-                        })?;
-                }
-
-                if let Some(group_id) = payload.group_id {
-                    let new_membership = NewPlayerGroup {
-                        player_id: new_player_id,
-                        group_id,
-                    };
-                    diesel::insert_into(pg_dsl::player_groups)
-                        .values(&new_membership)
-                        .on_conflict((pg_dsl::player_id, pg_dsl::group_id))
-                        .do_nothing()
-                        .execute(transaction_conn)
-                        .map_err(|e| {
-                            if let DieselError::DatabaseError(
-                                DatabaseErrorKind::ForeignKeyViolation,
-                                _,
-                            ) = e
-                            {
-                                AppError::NotFound(
-                                    "Referenced group not found during transaction.".to_string(),
-                                )
-                            } else {
-                                AppError::from(e)
-                            }
-                        })?;
-                }
-
-                Ok(new_player_id)
-            })
-        })
-        .await?;
 
-    creation_result.map(ApiResponse::ok)
+    info!(
+        "Found {} disabled players (offset {}, limit {})",
+        players.len(),
+        offset,
+        limit
+    );
+    Ok(ApiResponse::ok(players))
 }
 
-/// Disables a specific player account by setting their 'disabled' status to true.
+/// Default number of submissions returned per page by `get_all_player_submissions`.
+const DEFAULT_SUBMISSIONS_PAGE_SIZE: i64 = 100;
+/// Maximum number of submissions returned per page by `get_all_player_submissions`.
+const MAX_SUBMISSIONS_PAGE_SIZE: i64 = 500;
+
+/// Lists a player's submissions across every game, for debugging a student's activity.
+/// Requires admin permission (ID 0).
 ///
-/// Request Body: `DisablePlayerPayload`
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor (must be 0).
+/// * `player_id`: The ID of the player whose submissions are listed.
+/// * `offset`: Optional number of submissions to skip (default 0).
+/// * `limit`: Optional page size (default 100, capped at 500).
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the player was successfully disabled (200 OK).
-/// * `403 Forbidden`: If requesting instructor is not admin (ID 0).
-/// * `404 Not Found`: If the target player doesn't exist.
-/// * `500 Internal Server Error`: If a database error occurs or the update affects an unexpected number of rows.
-#[instrument(skip(pool, payload))]
-pub async fn disable_player(
+/// * `Vec<SubmissionDataResponse>`: The player's submissions in all games, each carrying its `game_id`, ordered by ID (200 OK).
+/// * `403 Forbidden`: If the requesting instructor is not admin, or the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the player doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_all_player_submissions(
     State(pool): State<Pool>,
-    Json(payload): Json<DisablePlayerPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let instructor_id = payload.instructor_id;
-    let player_id = payload.player_id;
+    acting_instructor: ActingInstructor,
+    Query(params): Query<GetAllPlayerSubmissionsParams>,
+) -> Result<ApiResponse<Vec<SubmissionDataResponse>>, AppError> {
+    let instructor_id = params.instructor_id;
+    let player_id = params.player_id;
 
     info!(
-        "Attempting to disable player {} requested by instructor {}",
+        "Fetching all submissions of player {} requested by instructor {}",
         player_id, instructor_id
     );
-    debug!("Disable player payload: {:?}", payload);
+    debug!("Get all player submissions params: {:?}", params);
+
+    acting_instructor.ensure_acts_as(instructor_id)?;
 
     if instructor_id != 0 {
         warn!(
-            "Permission denied: Instructor {} is not admin (ID 0) and cannot disable players.",
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot list submissions across games.",
             instructor_id
         );
         return Err(AppError::Forbidden(
-            "Only admin users can disable players.".to_string(),
+            "Only admin users can list a player's submissions across all games.".to_string(),
         ));
     }
+
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_SUBMISSIONS_PAGE_SIZE)
+        .clamp(1, MAX_SUBMISSIONS_PAGE_SIZE);
+
+    let submissions = helper::interact(&pool, move |conn_sync| {
+        let player_exists = select(exists(
+            players_dsl::players.filter(players_dsl::id.eq(player_id)),
+        ))
+        .get_result::<bool>(conn_sync)?;
+        if !player_exists {
+            warn!("Player {} not found", player_id);
+            return Err(AppError::NotFound(format!(
+                "Player {} not found.",
+                player_id
+            )));
+        }
+
+        sub_dsl::submissions
+            .filter(sub_dsl::player_id.eq(player_id))
+            .order_by(sub_dsl::id.asc())
+            .offset(offset)
+            .limit(limit)
+            .load::<SubmissionDataResponse>(conn_sync)
+            .map_err(AppError::from)
+    })
+    .await??;
+
     info!(
-        "Admin permission confirmed for instructor {}",
-        instructor_id
+        "Found {} submissions of player {} (offset {}, limit {})",
+        submissions.len(),
+        player_id,
+        offset,
+        limit
     );
+    Ok(ApiResponse::ok(submissions))
+}
+
+/// Reports rows whose parent records no longer exist, as a consistency check after
+/// manual database maintenance. Read-only. Requires admin permission (ID 0).
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the requesting instructor (must be 0).
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `IntegrityReportResponse`: Counts of orphaned submissions, registrations, group memberships, unlocks and rewards (200 OK).
+/// * `403 Forbidden`: If the requesting instructor is not admin, or the authenticated instructor does not match `instructor_id`.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn check_integrity(
+    State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
+    Query(params): Query<CheckIntegrityParams>,
+) -> Result<ApiResponse<IntegrityReportResponse>, AppError> {
+    let instructor_id = params.instructor_id;
 
-    let player_exists = helper::run_query(&pool, {
-        move |conn| {
-            diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
-        }
-    })
-    .await?;
+    info!("Integrity check requested by instructor {}", instructor_id);
+    debug!("Check integrity params: {:?}", params);
 
-    if !player_exists {
-        error!(
-            "Cannot disable player: Player with ID {} not found.",
-            player_id
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
+    if instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot run the integrity check.",
+            instructor_id
         );
-        return Err(AppError::NotFound(format!(
-            "Player with ID {} not found.",
-            player_id
-        )));
+        return Err(AppError::Forbidden(
+            "Only admin users can run the integrity check.".to_string(),
+        ));
     }
-    info!("Player {} confirmed to exist.", player_id);
 
-    let rows_affected = helper::run_query(&pool, move |conn| {
-        let player_id = player_id;
-        diesel::update(players_dsl::players.find(player_id))
-            .set(players_dsl::disabled.eq(true))
-            .execute(conn)
+    let report = helper::run_query(&pool, move |conn| {
+        let orphaned_submissions = sub_dsl::submissions
+            .left_join(exercises_dsl::exercises.on(exercises_dsl::id.eq(sub_dsl::exercise_id)))
+            .left_join(games_dsl::games.on(games_dsl::id.eq(sub_dsl::game_id)))
+            .left_join(players_dsl::players.on(players_dsl::id.eq(sub_dsl::player_id)))
+            .filter(
+                exercises_dsl::id
+                    .nullable()
+                    .is_null()
+                    .or(games_dsl::id.nullable().is_null())
+                    .or(players_dsl::id.nullable().is_null()),
+            )
+            .count()
+            .get_result::<i64>(conn)?;
+
+        let orphaned_registrations = pr_dsl::player_registrations
+            .left_join(games_dsl::games.on(games_dsl::id.eq(pr_dsl::game_id)))
+            .left_join(players_dsl::players.on(players_dsl::id.eq(pr_dsl::player_id)))
+            .filter(
+                games_dsl::id
+                    .nullable()
+                    .is_null()
+                    .or(players_dsl::id.nullable().is_null()),
+            )
+            .count()
+            .get_result::<i64>(conn)?;
+
+        let orphaned_memberships = pg_dsl::player_groups
+            .left_join(groups_dsl::groups.on(groups_dsl::id.eq(pg_dsl::group_id)))
+            .left_join(players_dsl::players.on(players_dsl::id.eq(pg_dsl::player_id)))
+            .filter(
+                groups_dsl::id
+                    .nullable()
+                    .is_null()
+                    .or(players_dsl::id.nullable().is_null()),
+            )
+            .count()
+            .get_result::<i64>(conn)?;
+
+        let orphaned_unlocks = pu_dsl::player_unlocks
+            .left_join(exercises_dsl::exercises.on(exercises_dsl::id.eq(pu_dsl::exercise_id)))
+            .left_join(players_dsl::players.on(players_dsl::id.eq(pu_dsl::player_id)))
+            .filter(
+                exercises_dsl::id
+                    .nullable()
+                    .is_null()
+                    .or(players_dsl::id.nullable().is_null()),
+            )
+            .count()
+            .get_result::<i64>(conn)?;
+
+        // `game_id` is optional on rewards, so only a dangling non-null game counts.
+        let orphaned_rewards = prw_dsl::player_rewards
+            .left_join(rewards_dsl::rewards.on(rewards_dsl::id.eq(prw_dsl::reward_id)))
+            .left_join(players_dsl::players.on(players_dsl::id.eq(prw_dsl::player_id)))
+            .left_join(games_dsl::games.on(games_dsl::id.nullable().eq(prw_dsl::game_id)))
+            .filter(
+                rewards_dsl::id
+                    .nullable()
+                    .is_null()
+                    .or(players_dsl::id.nullable().is_null())
+                    .or(prw_dsl::game_id
+                        .is_not_null()
+                        .and(games_dsl::id.nullable().is_null())),
+            )
+            .count()
+            .get_result::<i64>(conn)?;
+
+        Ok(IntegrityReportResponse {
+            orphaned_submissions,
+            orphaned_registrations,
+            orphaned_memberships,
+            orphaned_unlocks,
+            orphaned_rewards,
+        })
     })
     .await?;
 
-    match rows_affected {
-        1 => {
-            info!("Successfully disabled player {}", player_id);
-            Ok(ApiResponse::ok(true))
-        }
-        0 => {
-            error!(
-                "Player {} disable failed: 0 rows affected (player not found during update).",
-                player_id
-            );
-            Err(AppError::NotFound(format!(
-                "Player with ID {} not found during update.",
-                player_id
-            )))
-        }
-        n => {
-            error!(
-                "Player {} disable failed: {} rows affected (unexpected state).",
-                player_id, n
-            );
-            Err(AppError::InternalServerError(anyhow!(
-                "Player disable failed unexpectedly (multiple rows affected)."
-            )))
-        }
-    }
+    info!("Integrity check completed: {:?}", report);
+    Ok(ApiResponse::ok(report))
 }
 
 /// Completely deletes a player and all associated data from the platform.
@@ -2201,12 +5692,13 @@ pub async fn disable_player(
 ///
 /// Returns (wrapped in `ApiResponse`)
 /// * `bool`: true if the player and all associated data were successfully deleted (200 OK).
-/// * `403 Forbidden`: If requesting instructor is not admin (ID 0).
+/// * `403 Forbidden`: If requesting instructor is not admin (ID 0), or the authenticated instructor does not match `instructor_id`.
 /// * `404 Not Found`: If the target player doesn't exist.
 /// * `500 Internal Server Error`: If a database error or transaction failure occurs.
 #[instrument(skip(pool, payload))]
 pub async fn delete_player(
     State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
     Json(payload): Json<DeletePlayerPayload>,
 ) -> Result<ApiResponse<bool>, AppError> {
     let instructor_id = payload.instructor_id;
@@ -2218,6 +5710,8 @@ pub async fn delete_player(
     );
     debug!("Delete player payload: {:?}", payload);
 
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
     if instructor_id != 0 {
         warn!(
             "Permission denied: Instructor {} is not admin (ID 0) and cannot delete players.",
@@ -2254,8 +5748,7 @@ pub async fn delete_player(
         player_id
     );
 
-    let conn = pool.get().await?;
-    let deletion_result: Result<(), AppError> = conn.interact(move |conn_sync| {
+    let deletion_result: Result<(), AppError> = helper::interact(&pool, move |conn_sync| {
         let player_id = player_id;
         conn_sync.transaction(|tx_conn| {
             info!("Deleting submissions for player {}", player_id);
@@ -2294,6 +5787,192 @@ pub async fn delete_player(
     deletion_result.map(|_| ApiResponse::ok(true))
 }
 
+/// Soft-deletes a player account: sets `deleted_at` and detaches the player's active game
+/// registrations and group memberships (by setting their `left_at`), in a single transaction.
+/// Soft-deleted players are left out of player listings until `restore_player` is called;
+/// their submissions and other history are kept. Requires admin permission (ID 0).
+///
+/// Request Body: `SoftDeletePlayerPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the player was soft-deleted (200 OK).
+/// * `403 Forbidden`: If the requesting instructor is not admin, or the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the player doesn't exist.
+/// * `409 Conflict`: If the player is already soft-deleted.
+/// * `500 Internal Server Error`: If a database error or transaction failure occurs.
+#[instrument(skip(pool, payload))]
+pub async fn soft_delete_player(
+    State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
+    Json(payload): Json<SoftDeletePlayerPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let player_id = payload.player_id;
+
+    info!(
+        "Attempting to soft-delete player {} requested by instructor {}",
+        player_id, instructor_id
+    );
+    debug!("Soft delete player payload: {:?}", payload);
+
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
+    if instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot delete players.",
+            instructor_id
+        );
+        return Err(AppError::Forbidden(
+            "Only admin users can delete players.".to_string(),
+        ));
+    }
+
+    let soft_delete_result: Result<(), AppError> = helper::interact(&pool, move |conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            let deleted_at = players_dsl::players
+                .find(player_id)
+                .select(players_dsl::deleted_at)
+                .first::<Option<DateTime<Utc>>>(tx_conn)
+                .optional()?;
+            match deleted_at {
+                None => {
+                    error!(
+                        "Cannot soft-delete player: Player with ID {} not found.",
+                        player_id
+                    );
+                    return Err(AppError::NotFound(format!(
+                        "Player with ID {} not found.",
+                        player_id
+                    )));
+                }
+                Some(Some(_)) => {
+                    warn!("Player {} is already soft-deleted.", player_id);
+                    return Err(AppError::Conflict(format!(
+                        "Player {} is already deleted.",
+                        player_id
+                    )));
+                }
+                Some(None) => {}
+            }
+
+            let now = Utc::now();
+            diesel::update(players_dsl::players.find(player_id))
+                .set(players_dsl::deleted_at.eq(Some(now)))
+                .execute(tx_conn)?;
+
+            let registrations_left = diesel::update(
+                pr_dsl::player_registrations
+                    .filter(pr_dsl::player_id.eq(player_id))
+                    .filter(pr_dsl::left_at.is_null()),
+            )
+            .set(pr_dsl::left_at.eq(Some(now)))
+            .execute(tx_conn)?;
+
+            let groups_left = diesel::update(
+                pg_dsl::player_groups
+                    .filter(pg_dsl::player_id.eq(player_id))
+                    .filter(pg_dsl::left_at.is_null()),
+            )
+            .set(pg_dsl::left_at.eq(Some(now)))
+            .execute(tx_conn)?;
+
+            info!(
+                "Detached player {} from {} games and {} groups",
+                player_id, registrations_left, groups_left
+            );
+            Ok(())
+        })
+    })
+    .await?;
+
+    soft_delete_result?;
+    info!("Successfully soft-deleted player {}", player_id);
+    Ok(ApiResponse::ok(true))
+}
+
+/// Restores a soft-deleted player account by clearing `deleted_at`, so the player shows up in
+/// listings again. Registrations and memberships detached by `soft_delete_player` are not
+/// re-attached. Requires admin permission (ID 0).
+///
+/// Request Body: `RestorePlayerPayload`
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `bool`: true if the player was restored (200 OK).
+/// * `403 Forbidden`: If the requesting instructor is not admin, or the authenticated instructor does not match `instructor_id`.
+/// * `404 Not Found`: If the player doesn't exist.
+/// * `409 Conflict`: If the player is not soft-deleted.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, payload))]
+pub async fn restore_player(
+    State(pool): State<Pool>,
+    acting_instructor: ActingInstructor,
+    Json(payload): Json<RestorePlayerPayload>,
+) -> Result<ApiResponse<bool>, AppError> {
+    let instructor_id = payload.instructor_id;
+    let player_id = payload.player_id;
+
+    info!(
+        "Attempting to restore player {} requested by instructor {}",
+        player_id, instructor_id
+    );
+    debug!("Restore player payload: {:?}", payload);
+
+    acting_instructor.ensure_acts_as(instructor_id)?;
+
+    if instructor_id != 0 {
+        warn!(
+            "Permission denied: Instructor {} is not admin (ID 0) and cannot restore players.",
+            instructor_id
+        );
+        return Err(AppError::Forbidden(
+            "Only admin users can restore players.".to_string(),
+        ));
+    }
+
+    let player_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(players_dsl::players.find(player_id))).get_result::<bool>(conn)
+        }
+    })
+    .await?;
+
+    if !player_exists {
+        error!(
+            "Cannot restore player: Player with ID {} not found.",
+            player_id
+        );
+        return Err(AppError::NotFound(format!(
+            "Player with ID {} not found.",
+            player_id
+        )));
+    }
+
+    let rows_affected = helper::run_query(&pool, move |conn| {
+        diesel::update(
+            players_dsl::players
+                .find(player_id)
+                .filter(players_dsl::deleted_at.is_not_null()),
+        )
+        .set(players_dsl::deleted_at.eq(None::<DateTime<Utc>>))
+        .execute(conn)
+    })
+    .await?;
+
+    if rows_affected == 0 {
+        warn!(
+            "Player {} is not soft-deleted, nothing to restore.",
+            player_id
+        );
+        return Err(AppError::Conflict(format!(
+            "Player {} is not deleted.",
+            player_id
+        )));
+    }
+
+    info!("Successfully restored player {}", player_id);
+    Ok(ApiResponse::ok(true))
+}
+
 /// Generates a unique invite link (UUID), optionally associated with a game and/or group.
 ///
 /// Requires the requesting instructor to be an admin (ID 0) OR be listed (owner or not)
@@ -2303,12 +5982,14 @@ pub async fn delete_player(
 /// Request Body: `GenerateInviteLinkPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `InviteLinkResponse`: Contains the newly generated UUID (200).
-/// * `None`: If validation or permission checks fail (404/403).
-/// * `None`: If a database error occurs (500).
-#[instrument(skip(pool, payload))]
+/// * `InviteLinkResponse`: The newly generated UUID and, if `--public-base-url` is set, the full invite URL (200).
+/// * `403 Forbidden`: If the instructor is not listed for the group, or is not an admin and gives no group.
+/// * `404 Not Found`: If the instructor, game or group doesn't exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, public_base_url, payload))]
 pub async fn generate_invite_link(
     State(pool): State<Pool>,
+    Extension(public_base_url): Extension<Arc<PublicBaseUrl>>,
     Json(payload): Json<GenerateInviteLinkPayload>,
 ) -> Result<ApiResponse<InviteLinkResponse>, AppError> {
     let instructor_id = payload.instructor_id;
@@ -2377,7 +6058,7 @@ pub async fn generate_invite_link(
                 "Permission denied: Instructor {} cannot generate invite for group {}.",
                 instructor_id, gid
             );
-            return Err(AppError::NotFound(
+            return Err(AppError::Forbidden(
                 "Instructor lacks permission for the specified group.".to_string(),
             ));
         }
@@ -2391,7 +6072,7 @@ pub async fn generate_invite_link(
                 "Permission denied: Instructor {} cannot generate invite without group context.",
                 instructor_id
             );
-            return Err(AppError::NotFound(
+            return Err(AppError::Forbidden(
                 "Instructor lacks permission to generate invite without group context.".to_string(),
             ));
         }
@@ -2416,7 +6097,7 @@ pub async fn generate_invite_link(
     let new_uuid = Uuid::new_v4();
     info!("Generated new invite UUID: {}", new_uuid);
 
-    let insert_result = helper::run_query(&pool, move |conn| {
+    let rows_affected = helper::interact(&pool, move |conn| {
         let new_invite = NewInvite {
             uuid: new_uuid,
             instructor_id,
@@ -2427,68 +6108,145 @@ pub async fn generate_invite_link(
         diesel::insert_into(invites_dsl::invites)
             .values(&new_invite)
             .execute(conn)
+            .map_err(|e| AppError::from_constraint_violation(e, "Could not create the invite"))
     })
-    .await;
+    .await??;
 
-    match insert_result {
-        Ok(rows_affected) => {
-            if rows_affected == 1 {
-                info!(
-                    "Successfully inserted invite record with UUID: {}",
-                    new_uuid
-                );
-                let response_data = InviteLinkResponse {
-                    invite_uuid: new_uuid,
-                };
-                Ok(ApiResponse::ok(response_data))
-            } else {
-                error!(
-                    "Invite link generation failed: Insert query affected {} rows (expected 1) for UUID {}",
-                    rows_affected, new_uuid
-                );
-                Err(AppError::InternalServerError(anyhow!(
-                    "Database insert for invite link returned unexpected row count: {}",
-                    rows_affected
-                )))
-            }
+    if rows_affected != 1 {
+        error!(
+            "Invite link generation failed: Insert query affected {} rows (expected 1) for UUID {}",
+            rows_affected, new_uuid
+        );
+        return Err(AppError::InternalServerError(anyhow!(
+            "Database insert for invite link returned unexpected row count: {}",
+            rows_affected
+        )));
+    }
+
+    info!(
+        "Successfully inserted invite record with UUID: {}",
+        new_uuid
+    );
+    let response_data = InviteLinkResponse {
+        invite_uuid: new_uuid,
+        invite_url: public_base_url.invite_url(new_uuid),
+    };
+    Ok(ApiResponse::ok(response_data))
+}
+
+/// Lists the invites created by an instructor, newest first.
+///
+/// The admin instructor (ID 0) sees the invites of all instructors.
+///
+/// Query Parameters:
+/// * `instructor_id`: The ID of the instructor.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `Vec<InviteSummary>`: The invites with their targets and `created_at` (200 OK).
+/// * `404 Not Found`: If the specified instructor ID does not exist.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn list_invites(
+    State(pool): State<Pool>,
+    Query(params): Query<ListInvitesParams>,
+) -> Result<ApiResponse<Vec<InviteSummary>>, AppError> {
+    let instructor_id = params.instructor_id;
+
+    info!("Listing invites for instructor_id: {}", instructor_id);
+    debug!("List invites params: {:?}", params);
+
+    let instructor_exists = helper::run_query(&pool, {
+        move |conn| {
+            diesel::select(exists(instructors_dsl::instructors.find(instructor_id)))
+                .get_result::<bool>(conn)
         }
-        Err(AppError::InternalServerError(ref err)) => {
-            if let Some(db_err) = err.downcast_ref::<DieselError>() {
-                if let DieselError::DatabaseError(kind, info) = db_err {
-                    return match kind {
-                        DatabaseErrorKind::ForeignKeyViolation => {
-                            warn!(
-                                "Failed to insert invite link due to foreign key violation (UUID: {}). Details: {}",
-                                new_uuid,
-                                info.message()
-                            );
-                            Err(AppError::NotFound(format!(
-                                "Referenced instructor, game, or group not found during invite creation (likely deleted concurrently). Details: {}",
-                                info.message()
-                            )))
-                        }
-                        _ => {
-                            error!(
-                                "Database error during invite link insertion (UUID: {}): {:?}",
-                                new_uuid, err
-                            );
-                            Err(insert_result.unwrap_err())
-                        }
-                    };
-                }
+    })
+    .await?;
+    if !instructor_exists {
+        error!("Instructor with ID {} not found.", instructor_id);
+        return Err(AppError::NotFound(format!(
+            "Instructor with ID {} not found.",
+            instructor_id
+        )));
+    }
+
+    let invites = helper::run_query(&pool, {
+        move |conn| {
+            let mut query = invites_dsl::invites
+                .select((
+                    invites_dsl::id,
+                    invites_dsl::uuid,
+                    invites_dsl::game_id,
+                    invites_dsl::group_id,
+                    invites_dsl::created_at,
+                ))
+                .order_by((invites_dsl::created_at.desc(), invites_dsl::id.desc()))
+                .into_boxed();
+            if instructor_id != 0 {
+                query = query.filter(invites_dsl::instructor_id.eq(instructor_id));
             }
-            error!(
-                "Unhandled internal server error during invite link insertion (UUID: {}): {:?}",
-                new_uuid, err
-            );
-            Err(insert_result.unwrap_err())
+            query.load::<InviteSummary>(conn)
         }
-        Err(e) => {
-            error!(
-                "Unexpected error during invite link insertion (UUID: {}): {:?}",
-                new_uuid, e
-            );
-            Err(e)
+    })
+    .await?;
+
+    info!(
+        "Found {} invites for instructor_id: {}",
+        invites.len(),
+        instructor_id
+    );
+    Ok(ApiResponse::ok(invites))
+}
+
+/// Retrieves what an invite link leads to, without processing it.
+///
+/// Query Parameters:
+/// * `uuid`: The UUID of the invite.
+///
+/// Returns (wrapped in `ApiResponse`)
+/// * `InviteDetailsResponse`: The invite's game title and/or group name (200 OK).
+///   A target deleted since the invite was created is reported as `null`.
+/// * `404 Not Found`: If no invite with the given UUID exists.
+/// * `500 Internal Server Error`: If a database error occurs.
+#[instrument(skip(pool, params))]
+pub async fn get_invite_details(
+    State(pool): State<Pool>,
+    Query(params): Query<GetInviteDetailsParams>,
+) -> Result<ApiResponse<InviteDetailsResponse>, AppError> {
+    let invite_uuid = params.uuid;
+
+    info!("Fetching details of invite {}", invite_uuid);
+    debug!("Get invite details params: {:?}", params);
+
+    let details = helper::run_query(&pool, move |conn| {
+        invites_dsl::invites
+            .left_join(games_dsl::games)
+            .left_join(groups_dsl::groups)
+            .filter(invites_dsl::uuid.eq(invite_uuid))
+            .select((
+                invites_dsl::uuid,
+                invites_dsl::game_id,
+                games_dsl::title.nullable(),
+                invites_dsl::group_id,
+                groups_dsl::display_name.nullable(),
+                invites_dsl::created_at,
+            ))
+            .first::<InviteDetailsResponse>(conn)
+            .optional()
+    })
+    .await?;
+
+    match details {
+        Some(details) => {
+            info!("Successfully retrieved details of invite {}", invite_uuid);
+            Ok(ApiResponse::ok(details))
+        }
+        None => {
+            error!("Invite with UUID {} not found.", invite_uuid);
+            Err(AppError::NotFound(format!(
+                "Invite with UUID {} not found.",
+                invite_uuid
+            )))
         }
     }
 }
@@ -2497,28 +6255,46 @@ pub async fn generate_invite_link(
 ///
 /// Finds the invite by UUID, validates the player exists, adds the player
 /// to the associated game and/or group (if specified in the invite and not already present).
+/// If the player does not exist and an `email` is supplied, a new player is created first
+/// (the invite grants the authority to do so). Everything happens in one transaction.
+/// Game registrations use the first of `--default-languages` the game's course offers,
+/// or else the course's first language.
 ///
 /// Request Body: `ProcessInviteLinkPayload`
 ///
 /// Returns (wrapped in `ApiResponse`)
-/// * `bool`: true if the invite was successfully processed (200 OK).
-/// * `404 Not Found`: If the invite UUID, player ID, or associated game/group ID (at time of use) is invalid.
+/// * `ProcessInviteResult`: The player's ID, which of the invite's game and group the player newly joined
+///   and which they were already in (200 OK).
+/// * `404 Not Found`: If the invite UUID, player ID (without `email`), or associated game/group ID (at time of use) is invalid, or the player is disabled.
+/// * `409 Conflict`: If a new player would be created but the email is already taken,
+///   or the invite's group already has `--max-group-size` active members.
 /// * `500 Internal Server Error`: If a database error occurs.
-#[instrument(skip(pool, payload))]
+#[instrument(skip(pool, default_languages, max_group_size, payload))]
 pub async fn process_invite_link(
     State(pool): State<Pool>,
+    Extension(default_languages): Extension<Arc<DefaultLanguages>>,
+    Extension(max_group_size): Extension<Arc<MaxGroupSize>>,
     Json(payload): Json<ProcessInviteLinkPayload>,
-) -> Result<ApiResponse<bool>, AppError> {
-    let player_id = payload.player_id;
+) -> Result<ApiResponse<ProcessInviteResult>, AppError> {
+    let requested_player_id = payload.player_id;
     let invite_uuid = payload.uuid;
-    info!(player_id, %invite_uuid, "[Handler] Received request to process invite link");
+    info!(player_id = requested_player_id, %invite_uuid, "[Handler] Received request to process invite link");
+
+    let new_player = payload.email.map(|email| {
+        let email = db::normalize_email(&email);
+        let display_name = payload
+            .display_name
+            .unwrap_or_else(|| email.split('@').next().unwrap_or_default().to_string());
+        NewPlayer {
+            email,
+            display_name,
+            display_avatar: None,
+        }
+    });
 
-    pool
-        .get()
-        .await?
-        .interact(move |conn| {
+    let (player_id, result) = helper::interact(&pool, move |conn| {
             info!("[Handler] Starting database transaction");
-            conn.transaction::<_, DieselError, _>(|tx_conn| {
+            conn.transaction::<_, AppError, _>(|tx_conn| {
                 info!(uuid = %invite_uuid, "[Handler Tx] Attempting to find invite by UUID");
                 let invite = invites_dsl::invites
                     .filter(invites_dsl::uuid.eq(invite_uuid))
@@ -2533,22 +6309,57 @@ pub async fn process_invite_link(
                     })?;
                 info!(invite_id = invite.id, "[Handler Tx] Invite found");
 
-                debug!(player_id, "[Handler Tx] Validating player existence and status");
-                let player_exists: bool = select(exists(
-                    players_dsl::players
-                        .filter(players_dsl::id.eq(player_id))
-                        .filter(players_dsl::disabled.eq(false)),
-                ))
-                    .get_result(tx_conn)?;
-
-                if !player_exists {
-                    error!(player_id, "[Handler Tx] Player not found or is disabled");
-                    return Err(DieselError::NotFound);
-                }
+                debug!(player_id = requested_player_id, "[Handler Tx] Validating player existence and status");
+                // A soft-deleted player is rejected like a disabled one.
+                let player_disabled = players_dsl::players
+                    .find(requested_player_id)
+                    .select(players_dsl::disabled.or(players_dsl::deleted_at.is_not_null()))
+                    .first::<bool>(tx_conn)
+                    .optional()?;
+
+                let player_id = match (player_disabled, new_player) {
+                    (Some(false), _) => requested_player_id,
+                    (None, Some(new_player)) => {
+                        let email_taken: bool = select(exists(
+                            players_dsl::players.filter(db::lower(players_dsl::email).eq(&new_player.email)),
+                        ))
+                            .get_result(tx_conn)?;
+                        if email_taken {
+                            warn!("[Handler Tx] Cannot provision player, email {} already taken", new_player.email);
+                            return Err(AppError::Conflict(format!(
+                                "A player with email '{}' already exists.",
+                                new_player.email
+                            )));
+                        }
+                        let new_id = diesel::insert_into(players_dsl::players)
+                            .values(&new_player)
+                            .returning(players_dsl::id)
+                            .get_result::<i64>(tx_conn)
+                            .map_err(|e| {
+                                AppError::from_constraint_violation(
+                                    e,
+                                    "Could not create the invited player",
+                                )
+                            })?;
+                        info!(player_id = new_id, "[Handler Tx] Provisioned new player from invite");
+                        new_id
+                    }
+                    _ => {
+                        error!(player_id = requested_player_id, "[Handler Tx] Player not found or is disabled");
+                        return Err(AppError::NotFound(format!(
+                            "Player with ID {} not found or is disabled.",
+                            requested_player_id
+                        )));
+                    }
+                };
                 debug!(player_id, "[Handler Tx] Player validation successful");
 
                 let target_game_id = invite.game_id;
                 let target_group_id = invite.group_id;
+                let mut result = ProcessInviteResult {
+                    player_id,
+                    ..ProcessInviteResult::default()
+                };
 
                 if let Some(game_id) = target_game_id {
                     info!(game_id, "[Handler Tx] Checking existence of associated game");
@@ -2556,7 +6367,10 @@ pub async fn process_invite_link(
                         .get_result(tx_conn)?;
                     if !game_exists {
                         error!(game_id, "[Handler Tx] Associated game determined NOT FOUND during pre-check");
-                        return Err(DieselError::NotFound);
+                        return Err(AppError::NotFound(format!(
+                            "Game with ID {} not found.",
+                            game_id
+                        )));
                     }
                     info!(game_id, "[Handler Tx] Associated game determined FOUND during pre-check");
                 }
@@ -2566,7 +6380,10 @@ pub async fn process_invite_link(
                         .get_result(tx_conn)?;
                     if !group_exists {
                         error!(group_id, "[Handler Tx] Associated group determined NOT FOUND during pre-check");
-                        return Err(DieselError::NotFound);
+                        return Err(AppError::NotFound(format!(
+                            "Group with ID {} not found.",
+                            group_id
+                        )));
                     }
                     info!(group_id, "[Handler Tx] Associated group determined FOUND during pre-check");
                 }
@@ -2586,7 +6403,11 @@ pub async fn process_invite_link(
                         let new_registration = NewPlayerRegistration {
                             player_id,
                             game_id,
-                            language: "en".to_string(),
+                            language: helper::default_registration_language(
+                                tx_conn,
+                                game_id,
+                                &default_languages,
+                            )?,
                             progress: 0,
                             game_state: json!({}),
                         };
@@ -2594,22 +6415,33 @@ pub async fn process_invite_link(
                             .values(&new_registration)
                             .execute(tx_conn)?;
                         info!(player_id, game_id, "[Handler Tx] Player successfully registered in game");
+                        result.joined_game = true;
                     } else {
                         info!(player_id, game_id, "[Handler Tx] Player already registered in game, skipping registration");
+                        result.already_in_game = true;
                     }
                 }
 
                 if let Some(group_id) = target_group_id {
                     info!(group_id, player_id, "[Handler Tx] Processing group association for invite");
+                    // locking the group serializes concurrent additions against the size limit
+                    groups_dsl::groups
+                        .find(group_id)
+                        .select(groups_dsl::id)
+                        .for_update()
+                        .first::<i64>(tx_conn)?;
+
+                    let active_members = pg_dsl::player_groups
+                        .filter(pg_dsl::group_id.eq(group_id))
+                        .filter(pg_dsl::left_at.is_null());
                     let already_member: bool = select(exists(
-                        pg_dsl::player_groups
-                            .filter(pg_dsl::player_id.eq(player_id))
-                            .filter(pg_dsl::group_id.eq(group_id))
-                            .filter(pg_dsl::left_at.is_null()),
+                        active_members.filter(pg_dsl::player_id.eq(player_id)),
                     ))
                         .get_result(tx_conn)?;
 
                     if !already_member {
+                        let member_count = active_members.count().get_result::<i64>(tx_conn)?;
+                        max_group_size.ensure_room(member_count, 1)?;
                         info!(player_id, group_id, "[Handler Tx] Player not member of group, adding membership");
                         let new_player_group = NewPlayerGroup {
                             player_id,
@@ -2622,17 +6454,19 @@ pub async fn process_invite_link(
                             .set(pg_dsl::left_at.eq(None::<chrono::NaiveDateTime>))
                             .execute(tx_conn)?;
                         info!(player_id, group_id, "[Handler Tx] Player successfully added to group");
+                        result.added_to_group = true;
                     } else {
                         info!(player_id, group_id, "[Handler Tx] Player already member of group, skipping membership update");
+                        result.already_in_group = true;
                     }
                 }
 
                 info!(uuid = %invite_uuid, player_id, "[Handler Tx] Invite processing completed successfully within transaction");
-                Ok(())
+                Ok((player_id, result))
             })
         })
         .await??;
 
-    info!(player_id, %invite_uuid, "[Handler] Invite processed successfully, returning 200 OK");
-    Ok(ApiResponse::ok(true))
+    info!(player_id, %invite_uuid, ?result, "[Handler] Invite processed successfully, returning 200 OK");
+    Ok(ApiResponse::ok(result))
 }
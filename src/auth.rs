@@ -0,0 +1,286 @@
+use crate::config::ApiKeys;
+use crate::db;
+use crate::errors::AppError;
+use crate::schema::{instructors::dsl as instructors_dsl, players::dsl as players_dsl};
+use axum::extract::{FromRef, FromRequestParts, Request, State};
+use axum::http::HeaderMap;
+use axum::http::request::Parts;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum_keycloak_auth::KeycloakAuthStatus;
+use axum_keycloak_auth::decode::{KeycloakToken, ProfileAndEmail};
+use deadpool_diesel::postgres::Pool;
+use diesel::prelude::*;
+use std::sync::Arc;
+use tracing::log::{debug, warn};
+
+/// Header carrying a static API key of a service account, see `AppConfig::api_keys`.
+pub const API_KEY_HEADER: &str = "x-api-key";
+/// Header read by the test router to set the subject of the acting user.
+pub const TEST_SUBJECT_HEADER: &str = "x-test-subject";
+/// Header read by the test router to set the email of the acting user.
+pub const TEST_EMAIL_HEADER: &str = "x-test-email";
+/// Header read by the test router to send a request without any identity, as if no token was given.
+pub const TEST_ANONYMOUS_HEADER: &str = "x-test-anonymous";
+
+/// The caller on whose behalf a request is executed.
+#[derive(Debug, Clone)]
+pub enum Principal {
+    /// An end user authenticated through validated Keycloak claims.
+    User { subject: String, email: String },
+    /// A trusted caller (e.g. the test harness) acting on behalf of the IDs supplied in the request.
+    Trusted,
+    /// A service account authenticated through a static API key, acting as the admin.
+    ServiceAccount,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Principal {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Principal>()
+            .cloned()
+            .ok_or_else(|| AppError::Unauthorized("No authenticated identity.".to_string()))
+    }
+}
+
+/// The local instructor on whose behalf the authenticated caller acts.
+/// Resolved by matching the token's email claim against `instructors`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ActingInstructor {
+    /// The caller is the local instructor with this ID.
+    Instructor(i64),
+    /// Trusted caller, the client-supplied instructor ID is taken as-is.
+    Trusted,
+}
+
+impl ActingInstructor {
+    /// Cross-checks a client-supplied instructor ID against the authenticated identity.
+    /// The admin (ID 0) may act on behalf of any instructor.
+    pub(crate) fn ensure_acts_as(&self, claimed_instructor_id: i64) -> Result<(), AppError> {
+        match *self {
+            ActingInstructor::Trusted => Ok(()),
+            ActingInstructor::Instructor(id) if id == claimed_instructor_id || id == 0 => Ok(()),
+            ActingInstructor::Instructor(id) => {
+                warn!(
+                    "Instructor {} attempted to act as instructor {}",
+                    id, claimed_instructor_id
+                );
+                Err(AppError::Forbidden(format!(
+                    "Authenticated instructor {} cannot act as instructor {}.",
+                    id, claimed_instructor_id
+                )))
+            }
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ActingInstructor
+where
+    Pool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let email = match Principal::from_request_parts(parts, state).await? {
+            Principal::Trusted => return Ok(ActingInstructor::Trusted),
+            Principal::ServiceAccount => return Ok(ActingInstructor::Instructor(0)),
+            Principal::User { email, .. } => email,
+        };
+
+        let pool = Pool::from_ref(state);
+        let conn = pool.get().await?;
+        let instructor_id = conn
+            .interact(move |conn| {
+                instructors_dsl::instructors
+                    .filter(instructors_dsl::email.eq(email))
+                    .select(instructors_dsl::id)
+                    .first::<i64>(conn)
+                    .optional()
+            })
+            .await??;
+
+        match instructor_id {
+            Some(id) => Ok(ActingInstructor::Instructor(id)),
+            None => {
+                warn!("Authenticated user is not a registered instructor");
+                Err(AppError::Forbidden(
+                    "Authenticated user is not a registered instructor.".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+/// The local player on whose behalf the authenticated caller acts.
+/// Resolved by matching the token's email claim against `players`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ActingPlayer {
+    /// The caller is the local player with this ID.
+    Player(i64),
+    /// Trusted caller, the client-supplied player ID is taken as-is.
+    Trusted,
+}
+
+impl ActingPlayer {
+    /// Cross-checks a client-supplied player ID against the authenticated identity.
+    pub(crate) fn ensure_acts_as(&self, claimed_player_id: i64) -> Result<(), AppError> {
+        match *self {
+            ActingPlayer::Trusted => Ok(()),
+            ActingPlayer::Player(id) if id == claimed_player_id => Ok(()),
+            ActingPlayer::Player(id) => {
+                warn!(
+                    "Player {} attempted to act as player {}",
+                    id, claimed_player_id
+                );
+                Err(AppError::Forbidden(format!(
+                    "Authenticated player {} cannot act as player {}.",
+                    id, claimed_player_id
+                )))
+            }
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ActingPlayer
+where
+    Pool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let email = match Principal::from_request_parts(parts, state).await? {
+            Principal::Trusted => return Ok(ActingPlayer::Trusted),
+            Principal::ServiceAccount => {
+                warn!("Service account attempted to act as a player");
+                return Err(AppError::Forbidden(
+                    "Service accounts cannot act as a player.".to_string(),
+                ));
+            }
+            Principal::User { email, .. } => email,
+        };
+
+        let pool = Pool::from_ref(state);
+        let conn = pool.get().await?;
+        let player_id = conn
+            .interact(move |conn| {
+                players_dsl::players
+                    .filter(db::lower(players_dsl::email).eq(db::normalize_email(&email)))
+                    .filter(players_dsl::deleted_at.is_null())
+                    .select(players_dsl::id)
+                    .first::<i64>(conn)
+                    .optional()
+            })
+            .await??;
+
+        match player_id {
+            Some(id) => Ok(ActingPlayer::Player(id)),
+            None => {
+                warn!("Authenticated user is not a registered player");
+                Err(AppError::Forbidden(
+                    "Authenticated user is not a registered player.".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+/// Derives the `Principal` from the Keycloak token validated by the auth layer.
+/// Must run inside the Keycloak layer. In passthrough mode, requests without a valid token
+/// continue without a `Principal`.
+pub(crate) async fn principal_from_token(mut request: Request, next: Next) -> Response {
+    let extensions = request.extensions();
+    let token = extensions.get::<KeycloakToken<String>>().or_else(|| {
+        match extensions.get::<KeycloakAuthStatus<String, ProfileAndEmail>>() {
+            Some(KeycloakAuthStatus::Success(token)) => Some(token),
+            _ => None,
+        }
+    });
+    if let Some(token) = token {
+        let principal = Principal::User {
+            subject: token.subject.clone(),
+            email: token.extra.email.email.clone(),
+        };
+        debug!("Resolved principal from token: {:?}", principal);
+        request.extensions_mut().insert(principal);
+    }
+    next.run(request).await
+}
+
+/// Authenticates service accounts presenting `API_KEY_HEADER`, as a fallback to Keycloak tokens.
+/// Must run after `principal_from_token`: a caller already identified by a token keeps that identity.
+/// Otherwise a configured key yields `Principal::ServiceAccount`, and any other key is rejected with 401.
+pub(crate) async fn principal_from_api_key(
+    State(api_keys): State<Arc<ApiKeys>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(API_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    if matches!(
+        request.extensions().get::<Principal>(),
+        Some(Principal::User { .. })
+    ) {
+        debug!("Ignoring API key of a request authenticated by token");
+        return next.run(request).await;
+    }
+
+    if !key.to_str().is_ok_and(|key| api_keys.accepts(key)) {
+        warn!(
+            "Rejecting request to {} with an unknown API key",
+            request.uri()
+        );
+        return AppError::Unauthorized("Invalid API key.".to_string()).into_response();
+    }
+    debug!("Resolved service account principal from API key");
+    request.extensions_mut().insert(Principal::ServiceAccount);
+    next.run(request).await
+}
+
+/// Counterpart of the blocking Keycloak layer on protected routes, used by the test router
+/// and whenever API keys let requests without a token past Keycloak.
+/// Rejects requests that carry no `Principal` with 401.
+pub(crate) async fn require_principal(request: Request, next: Next) -> Response {
+    if request.extensions().get::<Principal>().is_none() {
+        warn!("Rejecting unauthenticated request to {}", request.uri());
+        return AppError::Unauthorized("No authenticated identity.".to_string()).into_response();
+    }
+    next.run(request).await
+}
+
+/// Test router counterpart of `principal_from_token`.
+/// Builds a `Principal::User` from the test identity headers, or `Principal::Trusted` if they are absent.
+/// Requests carrying `TEST_ANONYMOUS_HEADER` get no `Principal` at all.
+pub(crate) async fn principal_from_test_headers(mut request: Request, next: Next) -> Response {
+    if let Some(principal) = test_principal(request.headers()) {
+        request.extensions_mut().insert(principal);
+    }
+    next.run(request).await
+}
+
+fn test_principal(headers: &HeaderMap) -> Option<Principal> {
+    if headers.contains_key(TEST_ANONYMOUS_HEADER) {
+        return None;
+    }
+
+    let header_value = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+
+    let principal = match header_value(TEST_EMAIL_HEADER) {
+        Some(email) => Principal::User {
+            subject: header_value(TEST_SUBJECT_HEADER).unwrap_or_else(|| email.clone()),
+            email,
+        },
+        None => Principal::Trusted,
+    };
+    Some(principal)
+}
@@ -1,4 +1,10 @@
-use clap::Parser;
+use crate::config::{
+    DEFAULT_EVALUATOR_TIMEOUT_SECS, DEFAULT_IMPORT_MAX_BODY_SIZE, DEFAULT_IP_RATE_LIMIT_BURST,
+    DEFAULT_LANGUAGE, DEFAULT_MAX_BODY_SIZE, DEFAULT_MAX_CHECKPOINTS_PER_REGISTRATION,
+    DEFAULT_MAX_CODE_LENGTH, DEFAULT_PLAYER_CREATION_HOURLY_LIMIT, DEFAULT_REQUEST_TIMEOUT_SECS,
+};
+use crate::db::{DEFAULT_POOL_TIMEOUT_MS, DEFAULT_SLOW_QUERY_THRESHOLD_MS};
+use clap::{ArgAction, Parser};
 use std::net::SocketAddr;
 use url::Url;
 
@@ -16,6 +22,13 @@ pub struct Args {
     #[arg(long, env = "DB_POOL_MAX_SIZE", default_value = "10")]
     pub db_pool_max_size: u32,
 
+    /// Time in milliseconds a request waits for a free database connection before it is answered
+    /// with 503 Service Unavailable. Set to 0 to wait indefinitely.
+    /// Can also be set using the DB_POOL_TIMEOUT_MS environment variable.
+    /// Default value: 5000
+    #[arg(long, env = "DB_POOL_TIMEOUT_MS", default_value_t = DEFAULT_POOL_TIMEOUT_MS)]
+    pub db_pool_timeout_ms: u64,
+
     /// Server listen address and port (e.g., "127.0.0.1:3000")
     /// Can also be set using the SERVER_ADDRESS environment variable.
     /// Default value: 127.0.0.1:3000
@@ -44,6 +57,226 @@ pub struct Args {
     #[arg(long, env = "KEYCLOAK_AUDIENCES", default_value = "fgpe-backend")]
     pub keycloak_audiences: String,
 
+    /// Maximum accepted request body size in bytes
+    /// Can also be set using the MAX_BODY_SIZE environment variable.
+    /// Default value: 2097152 (2 MiB)
+    #[arg(long, env = "MAX_BODY_SIZE", default_value_t = DEFAULT_MAX_BODY_SIZE)]
+    pub max_body_size: usize,
+
+    /// Maximum accepted request body size in bytes for course imports
+    /// Can also be set using the IMPORT_MAX_BODY_SIZE environment variable.
+    /// Default value: 1048576 (1 MiB)
+    #[arg(
+        long,
+        env = "IMPORT_MAX_BODY_SIZE",
+        default_value_t = DEFAULT_IMPORT_MAX_BODY_SIZE
+    )]
+    pub import_max_body_size: usize,
+
+    /// Interval in seconds between scans for games whose end date has passed
+    /// Set to 0 to disable the scanner.
+    /// Can also be set using the GAME_END_SCAN_INTERVAL_SECS environment variable.
+    /// Default value: 60
+    #[arg(long, env = "GAME_END_SCAN_INTERVAL_SECS", default_value = "60")]
+    pub game_end_scan_interval_secs: u64,
+
+    /// Age in days after which submissions of ended games are moved to the archived_submissions table
+    /// Each player's latest first solution of an exercise is never archived. Set to 0 to disable archiving.
+    /// Can also be set using the SUBMISSION_RETENTION_DAYS environment variable.
+    /// Default value: 0
+    #[arg(long, env = "SUBMISSION_RETENTION_DAYS", default_value = "0")]
+    pub submission_retention_days: u64,
+
+    /// Compress responses (gzip/brotli) for clients sending Accept-Encoding
+    /// Can also be set using the RESPONSE_COMPRESSION environment variable.
+    /// Default value: true
+    #[arg(
+        long,
+        env = "RESPONSE_COMPRESSION",
+        default_value_t = true,
+        action = ArgAction::Set
+    )]
+    pub response_compression: bool,
+
+    /// Answer with MessagePack instead of JSON when the request's Accept header lists "application/msgpack"
+    /// Can also be set using the MSGPACK_RESPONSES environment variable.
+    /// Default value: true
+    #[arg(
+        long,
+        env = "MSGPACK_RESPONSES",
+        default_value_t = true,
+        action = ArgAction::Set
+    )]
+    pub msgpack_responses: bool,
+
+    /// Serve the content editor API under /editor; when disabled, its paths answer 404
+    /// Can also be set using the ENABLE_EDITOR environment variable.
+    /// Default value: true
+    #[arg(
+        long,
+        env = "ENABLE_EDITOR",
+        default_value_t = true,
+        action = ArgAction::Set
+    )]
+    pub enable_editor: bool,
+
+    /// Duration in milliseconds above which a database call is logged as a slow query
+    /// Set to 0 to disable slow query logging.
+    /// Can also be set using the SLOW_QUERY_THRESHOLD_MS environment variable.
+    /// Default value: 500
+    #[arg(
+        long,
+        env = "SLOW_QUERY_THRESHOLD_MS",
+        default_value_t = DEFAULT_SLOW_QUERY_THRESHOLD_MS
+    )]
+    pub slow_query_threshold_ms: u64,
+
+    /// Time in seconds a request may take before it is answered with 504 Gateway Timeout
+    /// Database statements running longer than this are cancelled as well. Set to 0 to disable the timeout.
+    /// Can also be set using the REQUEST_TIMEOUT_SECS environment variable.
+    /// Default value: 30
+    #[arg(
+        long,
+        env = "REQUEST_TIMEOUT_SECS",
+        default_value_t = DEFAULT_REQUEST_TIMEOUT_SECS
+    )]
+    pub request_timeout_secs: u64,
+
+    /// Maximum length in characters of the code sent to submit_solution
+    /// Set to 0 to disable the check.
+    /// Can also be set using the MAX_CODE_LENGTH environment variable.
+    /// Default value: 65536
+    #[arg(long, env = "MAX_CODE_LENGTH", default_value_t = DEFAULT_MAX_CODE_LENGTH)]
+    pub max_code_length: usize,
+
+    /// Maximum number of players a non-admin instructor may create within a sliding hour
+    /// Set to 0 to disable the limit. The admin (instructor ID 0) is exempt.
+    /// Can also be set using the PLAYER_CREATION_HOURLY_LIMIT environment variable.
+    /// Default value: 200
+    #[arg(
+        long,
+        env = "PLAYER_CREATION_HOURLY_LIMIT",
+        default_value_t = DEFAULT_PLAYER_CREATION_HOURLY_LIMIT
+    )]
+    pub player_creation_hourly_limit: usize,
+
+    /// Maximum number of active members of a group, enforced by create_group, clone_group, add_group_member, merge_groups and process_invite_link
+    /// Set to 0 to disable the limit.
+    /// Can also be set using the MAX_GROUP_SIZE environment variable.
+    /// Default value: 0
+    #[arg(long, env = "MAX_GROUP_SIZE", default_value_t = 0)]
+    pub max_group_size: usize,
+
+    /// Maximum number of named checkpoints a player may keep per game registration, enforced by save_checkpoint
+    /// Set to 0 to disable the limit.
+    /// Can also be set using the MAX_CHECKPOINTS_PER_REGISTRATION environment variable.
+    /// Default value: 10
+    #[arg(
+        long,
+        env = "MAX_CHECKPOINTS_PER_REGISTRATION",
+        default_value_t = DEFAULT_MAX_CHECKPOINTS_PER_REGISTRATION
+    )]
+    pub max_checkpoints_per_registration: usize,
+
+    /// URL of an external evaluation service grading submit_solution calls (e.g. "http://127.0.0.1:8080/evaluate")
+    /// When set, the submitted code is sent there and its verdict replaces the client-supplied result.
+    /// Can also be set using the EVALUATOR_URL environment variable.
+    /// Default value: none (the client-supplied result is stored)
+    #[arg(long, env = "EVALUATOR_URL")]
+    pub evaluator_url: Option<Url>,
+
+    /// Timeout in seconds of a call to the evaluation service; keep it below the request timeout
+    /// Can also be set using the EVALUATOR_TIMEOUT_SECS environment variable.
+    /// Default value: 20
+    #[arg(
+        long,
+        env = "EVALUATOR_TIMEOUT_SECS",
+        default_value_t = DEFAULT_EVALUATOR_TIMEOUT_SECS
+    )]
+    pub evaluator_timeout_secs: u64,
+
+    /// URL notified with a JSON POST when a game is activated, stopped or archived (e.g. "http://127.0.0.1:9000/hooks")
+    /// Events are delivered in the background after the change is committed.
+    /// Can also be set using the WEBHOOK_URL environment variable.
+    /// Default value: none (no notifications are sent)
+    #[arg(long, env = "WEBHOOK_URL")]
+    pub webhook_url: Option<Url>,
+
+    /// Comma-separated events sent to the webhook, out of "game.activated", "game.stopped" and "game.archived"
+    /// Can also be set using the WEBHOOK_EVENTS environment variable.
+    /// Default value: game.activated,game.stopped,game.archived
+    #[arg(
+        long,
+        env = "WEBHOOK_EVENTS",
+        value_delimiter = ',',
+        default_value = "game.activated,game.stopped,game.archived"
+    )]
+    pub webhook_events: Vec<String>,
+
+    /// Public base URL of the client application (e.g. "https://play.example.org")
+    /// Invite links are returned as "{base}/join?invite={uuid}" in addition to the bare UUID.
+    /// Can also be set using the PUBLIC_BASE_URL environment variable.
+    /// Default value: none (only the UUID is returned)
+    #[arg(long, env = "PUBLIC_BASE_URL")]
+    pub public_base_url: Option<Url>,
+
+    /// Comma-separated student routes reachable without a token (e.g. "get_available_games,get_public_courses")
+    /// A token, if sent, is still validated and identifies the caller.
+    /// Can also be set using the PUBLIC_STUDENT_ROUTES environment variable.
+    /// Default value: none (every student route requires a token)
+    #[arg(long, env = "PUBLIC_STUDENT_ROUTES", value_delimiter = ',')]
+    pub public_student_routes: Vec<String>,
+
+    /// Comma-separated static API keys accepted in the X-Api-Key header as an alternative to a Keycloak token
+    /// Callers presenting a valid key act as the admin (instructor ID 0); a valid token takes precedence.
+    /// Can also be set using the API_KEYS environment variable.
+    /// Default value: none (only Keycloak tokens are accepted)
+    #[arg(long, env = "API_KEYS", value_delimiter = ',', hide_env_values = true)]
+    pub api_keys: Vec<String>,
+
+    /// Comma-separated languages in order of preference (e.g. "pt,en"), used to register players in a
+    /// game when no language is specified. The first one offered by the game's course is picked,
+    /// falling back to the course's first language if it offers none of them.
+    /// Can also be set using the DEFAULT_LANGUAGES environment variable.
+    /// Default value: en
+    #[arg(
+        long,
+        env = "DEFAULT_LANGUAGES",
+        value_delimiter = ',',
+        default_value = DEFAULT_LANGUAGE
+    )]
+    pub default_languages: Vec<String>,
+
+    /// Requests per second each client IP address may make, refilling a token bucket of ip_rate_limit_burst
+    /// Requests beyond it are answered with 429 Too Many Requests. Set to 0 to disable the limit.
+    /// Can also be set using the IP_RATE_LIMIT_PER_SEC environment variable.
+    /// Default value: 0
+    #[arg(long, env = "IP_RATE_LIMIT_PER_SEC", default_value_t = 0)]
+    pub ip_rate_limit_per_sec: u32,
+
+    /// Requests a client IP address may make in a burst before ip_rate_limit_per_sec applies
+    /// Can also be set using the IP_RATE_LIMIT_BURST environment variable.
+    /// Default value: 20
+    #[arg(
+        long,
+        env = "IP_RATE_LIMIT_BURST",
+        default_value_t = DEFAULT_IP_RATE_LIMIT_BURST
+    )]
+    pub ip_rate_limit_burst: u32,
+
+    /// Rate limit clients by the last address of the X-Forwarded-For header instead of the peer address
+    /// The last address is the one appended by the reverse proxy; only enable this behind a proxy that appends it,
+    /// as clients can forge the header otherwise.
+    /// Can also be set using the TRUST_FORWARDED_FOR environment variable.
+    /// Default value: false
+    #[arg(
+        long,
+        env = "TRUST_FORWARDED_FOR",
+        default_value_t = false,
+        action = ArgAction::Set
+    )]
+    pub trust_forwarded_for: bool,
+
     /// Log level (e.g., "info")
     /// Can also be set using the RUST_LOG environment variable.
     /// Default value: info
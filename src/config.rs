@@ -0,0 +1,268 @@
+use crate::cli::Args;
+use crate::db::DEFAULT_SLOW_QUERY_THRESHOLD_MS;
+use crate::errors::AppError;
+use crate::webhook::GAME_LIFECYCLE_EVENTS;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use subtle::{Choice, ConstantTimeEq};
+use url::Url;
+use uuid::Uuid;
+
+/// Default maximum request body size in bytes (2 MiB).
+pub const DEFAULT_MAX_BODY_SIZE: usize = 2 * 1024 * 1024;
+/// Default maximum request body size in bytes for course imports (1 MiB).
+pub const DEFAULT_IMPORT_MAX_BODY_SIZE: usize = 1024 * 1024;
+/// Default maximum length of `submitted_code` in characters (64 Ki).
+pub const DEFAULT_MAX_CODE_LENGTH: usize = 64 * 1024;
+/// Default number of players a (non-admin) instructor may create per hour.
+pub const DEFAULT_PLAYER_CREATION_HOURLY_LIMIT: usize = 200;
+/// Default number of named checkpoints a player may keep per game registration.
+pub const DEFAULT_MAX_CHECKPOINTS_PER_REGISTRATION: usize = 10;
+/// Default time in seconds a request may take before it is answered with 504 Gateway Timeout.
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+/// Default timeout in seconds of a call to the external evaluator.
+/// Shorter than `DEFAULT_REQUEST_TIMEOUT_SECS`, so a hanging evaluator is answered with 502 before the request times out.
+pub const DEFAULT_EVALUATOR_TIMEOUT_SECS: u64 = 20;
+/// Default number of requests a client IP address may make in a burst.
+pub const DEFAULT_IP_RATE_LIMIT_BURST: u32 = 20;
+/// Language players are registered in when none is specified or configured.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Runtime settings shared by the router layers, derived from `cli::Args`.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub max_body_size: usize,
+    pub import_max_body_size: usize,
+    pub response_compression: bool,
+    /// Whether clients may request MessagePack responses through `Accept: application/msgpack`.
+    pub msgpack_responses: bool,
+    /// Whether the `/editor` routes are mounted.
+    pub enable_editor: bool,
+    pub slow_query_threshold: Duration,
+    /// Time a request may take before it is answered with 504 Gateway Timeout (zero disables the timeout).
+    pub request_timeout: Duration,
+    /// Maximum `submitted_code` length in characters accepted by `submit_solution` (0 disables the check).
+    pub max_code_length: usize,
+    /// Players a non-admin instructor may create within a sliding hour (0 disables the limit).
+    pub player_creation_hourly_limit: usize,
+    /// Active members a group may have (0 disables the limit).
+    pub max_group_size: usize,
+    /// Named checkpoints a player may keep per game registration (0 disables the limit).
+    pub max_checkpoints_per_registration: usize,
+    /// External evaluator grading `submit_solution` calls; the client's verdict is trusted when unset.
+    pub evaluator_url: Option<Url>,
+    pub evaluator_timeout: Duration,
+    /// Receiver of game lifecycle events; none are sent when unset.
+    pub webhook_url: Option<Url>,
+    /// Events sent to `webhook_url`, out of `webhook::GAME_LIFECYCLE_EVENTS`.
+    pub webhook_events: Vec<String>,
+    /// Base URL invite links are built on; `generate_invite_link` returns only the UUID when unset.
+    pub public_base_url: Option<Url>,
+    /// Names of student routes (path without the leading `/student/`) served without authentication.
+    pub public_student_routes: Vec<String>,
+    /// Languages in order of preference for registrations that specify none.
+    pub default_languages: Vec<String>,
+    /// Static keys accepted in the `X-Api-Key` header for service accounts (empty disables API key auth).
+    pub api_keys: Vec<String>,
+    /// Requests per second each client IP address may make (0 disables the limit).
+    pub ip_rate_limit_per_sec: u32,
+    pub ip_rate_limit_burst: u32,
+    /// Whether clients are identified by the last `X-Forwarded-For` address instead of the peer address.
+    pub trust_forwarded_for: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            import_max_body_size: DEFAULT_IMPORT_MAX_BODY_SIZE,
+            response_compression: true,
+            msgpack_responses: true,
+            enable_editor: true,
+            slow_query_threshold: Duration::from_millis(DEFAULT_SLOW_QUERY_THRESHOLD_MS),
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            max_code_length: DEFAULT_MAX_CODE_LENGTH,
+            player_creation_hourly_limit: DEFAULT_PLAYER_CREATION_HOURLY_LIMIT,
+            max_group_size: 0,
+            max_checkpoints_per_registration: DEFAULT_MAX_CHECKPOINTS_PER_REGISTRATION,
+            evaluator_url: None,
+            evaluator_timeout: Duration::from_secs(DEFAULT_EVALUATOR_TIMEOUT_SECS),
+            webhook_url: None,
+            webhook_events: GAME_LIFECYCLE_EVENTS
+                .iter()
+                .map(|event| event.to_string())
+                .collect(),
+            public_base_url: None,
+            public_student_routes: Vec::new(),
+            default_languages: vec![DEFAULT_LANGUAGE.to_string()],
+            api_keys: Vec::new(),
+            ip_rate_limit_per_sec: 0,
+            ip_rate_limit_burst: DEFAULT_IP_RATE_LIMIT_BURST,
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+impl From<&Args> for AppConfig {
+    fn from(args: &Args) -> Self {
+        Self {
+            max_body_size: args.max_body_size,
+            import_max_body_size: args.import_max_body_size,
+            response_compression: args.response_compression,
+            msgpack_responses: args.msgpack_responses,
+            enable_editor: args.enable_editor,
+            slow_query_threshold: Duration::from_millis(args.slow_query_threshold_ms),
+            request_timeout: Duration::from_secs(args.request_timeout_secs),
+            max_code_length: args.max_code_length,
+            player_creation_hourly_limit: args.player_creation_hourly_limit,
+            max_group_size: args.max_group_size,
+            max_checkpoints_per_registration: args.max_checkpoints_per_registration,
+            evaluator_url: args.evaluator_url.clone(),
+            evaluator_timeout: Duration::from_secs(args.evaluator_timeout_secs),
+            webhook_url: args.webhook_url.clone(),
+            webhook_events: args
+                .webhook_events
+                .iter()
+                .map(|event| event.trim().to_string())
+                .filter(|event| !event.is_empty())
+                .collect(),
+            public_base_url: args.public_base_url.clone(),
+            public_student_routes: args
+                .public_student_routes
+                .iter()
+                .map(|route| route.trim().to_string())
+                .filter(|route| !route.is_empty())
+                .collect(),
+            default_languages: args
+                .default_languages
+                .iter()
+                .map(|language| language.trim().to_string())
+                .filter(|language| !language.is_empty())
+                .collect(),
+            api_keys: args
+                .api_keys
+                .iter()
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty())
+                .collect(),
+            ip_rate_limit_per_sec: args.ip_rate_limit_per_sec,
+            ip_rate_limit_burst: args.ip_rate_limit_burst,
+            trust_forwarded_for: args.trust_forwarded_for,
+        }
+    }
+}
+
+/// `AppConfig::public_base_url`, shared with the teacher handlers that build invite links.
+#[derive(Debug, Clone)]
+pub(crate) struct PublicBaseUrl(pub Option<Url>);
+
+impl PublicBaseUrl {
+    /// `{base}/join?invite={uuid}`, or `None` if no base URL is configured.
+    pub(crate) fn invite_url(&self, invite_uuid: Uuid) -> Option<String> {
+        self.0.as_ref().map(|base| {
+            format!(
+                "{}/join?invite={}",
+                base.as_str().trim_end_matches('/'),
+                invite_uuid
+            )
+        })
+    }
+}
+
+/// `AppConfig::max_group_size`, shared with the teacher handlers that add group members.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MaxGroupSize(pub usize);
+
+impl MaxGroupSize {
+    /// Fails with 409 Conflict if adding `adding` members to a group with `active_members`
+    /// active members would exceed the limit.
+    pub(crate) fn ensure_room(&self, active_members: i64, adding: usize) -> Result<(), AppError> {
+        if self.0 == 0 || active_members as usize + adding <= self.0 {
+            return Ok(());
+        }
+        Err(AppError::Conflict(format!(
+            "Groups are limited to {} active members.",
+            self.0
+        )))
+    }
+}
+
+/// `AppConfig::max_checkpoints_per_registration`, shared with the student checkpoint handlers.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MaxCheckpoints(pub usize);
+
+impl MaxCheckpoints {
+    /// Fails with 409 Conflict if a registration holding `existing` checkpoints may not get another one.
+    pub(crate) fn ensure_room(&self, existing: i64) -> Result<(), AppError> {
+        if self.0 == 0 || (existing as usize) < self.0 {
+            return Ok(());
+        }
+        Err(AppError::Conflict(format!(
+            "At most {} checkpoints can be saved per game registration.",
+            self.0
+        )))
+    }
+}
+
+/// `AppConfig::max_code_length`, shared with `submit_solution`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MaxCodeLength(pub usize);
+
+impl MaxCodeLength {
+    /// Fails with 422 Unprocessable Entity if `code` has more characters than the limit.
+    pub(crate) fn ensure_within(&self, code: &str) -> Result<(), AppError> {
+        let code_length = code.chars().count();
+        if self.0 == 0 || code_length <= self.0 {
+            return Ok(());
+        }
+        Err(AppError::UnprocessableEntity(format!(
+            "submitted_code must not exceed {} characters, got {}.",
+            self.0, code_length
+        )))
+    }
+}
+
+/// `AppConfig::default_languages`, shared with the teacher handlers that register players.
+#[derive(Debug, Clone)]
+pub(crate) struct DefaultLanguages(pub Vec<String>);
+
+impl DefaultLanguages {
+    /// Picks the registration language for a course offering `course_languages` (comma-separated):
+    /// the first preferred language the course offers, else the course's first language.
+    /// A course listing no languages gets the first preference (or `DEFAULT_LANGUAGE`).
+    pub(crate) fn resolve(&self, course_languages: &str) -> String {
+        let offered: Vec<&str> = course_languages
+            .split(',')
+            .map(|language| language.trim())
+            .filter(|language| !language.is_empty())
+            .collect();
+
+        self.0
+            .iter()
+            .map(String::as_str)
+            .find(|language| offered.contains(language))
+            .or_else(|| offered.first().copied())
+            .or_else(|| self.0.first().map(String::as_str))
+            .unwrap_or(DEFAULT_LANGUAGE)
+            .to_string()
+    }
+}
+
+/// `AppConfig::api_keys`, shared with the middleware authenticating service accounts.
+#[derive(Debug, Clone)]
+pub(crate) struct ApiKeys(pub Vec<String>);
+
+impl ApiKeys {
+    /// Whether `key` is one of the configured keys.
+    /// Compares SHA-256 digests in constant time and checks every key, so the response time
+    /// reveals neither how much of a key matched nor which key did.
+    pub(crate) fn accepts(&self, key: &str) -> bool {
+        let digest = Sha256::digest(key.as_bytes());
+        self.0
+            .iter()
+            .fold(Choice::from(0), |accepted, configured| {
+                accepted | Sha256::digest(configured.as_bytes()).ct_eq(&digest)
+            })
+            .into()
+    }
+}
@@ -0,0 +1,127 @@
+use deadpool_diesel::postgres::{Hook, HookError, Manager, Pool, PoolError};
+use deadpool_diesel::{InteractError, Runtime};
+use diesel::sql_types::Text;
+use diesel::{PgConnection, RunQueryDsl, define_sql_function};
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::log::debug;
+
+/// Default duration (in milliseconds) above which a database call is logged as slow.
+pub const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 500;
+/// Default time (in milliseconds) to wait for a free pooled connection.
+pub const DEFAULT_POOL_TIMEOUT_MS: u64 = 5000;
+
+tokio::task_local! {
+    /// Set by `middleware::scope_slow_query_threshold` for the duration of a request.
+    static SLOW_QUERY_THRESHOLD: Duration;
+}
+
+define_sql_function! {
+    /// SQL `LOWER`, used to match emails against the case-insensitive `players` email index.
+    fn lower(x: Text) -> Text;
+}
+
+/// Normalizes an email address (trimmed, lowercase) so lookups and uniqueness are case-insensitive.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Runs `future` with `threshold` as the slow query threshold of the `interact` calls it makes.
+/// A zero threshold disables slow query logging.
+pub async fn with_slow_query_threshold<F: Future>(threshold: Duration, future: F) -> F::Output {
+    SLOW_QUERY_THRESHOLD.scope(threshold, future).await
+}
+
+/// The slow query threshold of the running request, `DEFAULT_SLOW_QUERY_THRESHOLD_MS` outside of one.
+pub fn slow_query_threshold() -> Duration {
+    SLOW_QUERY_THRESHOLD
+        .try_with(|threshold| *threshold)
+        .unwrap_or(Duration::from_millis(DEFAULT_SLOW_QUERY_THRESHOLD_MS))
+}
+
+/// Builds the connection pool. Waiting for a free connection gives up after `acquire_timeout`,
+/// so a saturated pool answers 503 instead of queueing requests; a zero duration disables
+/// either timeout.
+pub fn init_pool(
+    conn_str: &str,
+    max_size: u32,
+    statement_timeout: Duration,
+    acquire_timeout: Duration,
+) -> anyhow::Result<Pool> {
+    let manager = Manager::new(conn_str, Runtime::Tokio1);
+    let mut builder = Pool::builder(manager)
+        .max_size(max_size as usize)
+        .runtime(Runtime::Tokio1);
+    if !statement_timeout.is_zero() {
+        builder = builder.post_create(statement_timeout_hook(statement_timeout));
+    }
+    if !acquire_timeout.is_zero() {
+        builder = builder.wait_timeout(Some(acquire_timeout));
+    }
+    let pool = builder.build()?;
+    Ok(pool)
+}
+
+/// Acquires a connection from the pool and runs `query` on it, timing both steps.
+/// Emits a warning (within the caller's span) if the combined time exceeds the slow query threshold,
+/// broken down into time spent waiting for a connection and time spent executing.
+pub async fn interact<T, E, F>(pool: &Pool, query: F) -> Result<T, E>
+where
+    F: FnOnce(&mut PgConnection) -> T + Send + 'static,
+    T: Send + 'static,
+    E: From<PoolError> + From<InteractError>,
+{
+    let started = Instant::now();
+    let conn = pool.get().await.inspect_err(|err| {
+        if let PoolError::Timeout(_) = err {
+            let status = pool.status();
+            tracing::warn!(
+                waited_ms = started.elapsed().as_millis() as u64,
+                max_size = status.max_size,
+                waiting = status.waiting,
+                "Database pool saturated"
+            );
+        }
+    })?;
+    let acquired = started.elapsed();
+    debug!("DB connection object obtained from pool for interaction");
+
+    let result = conn.interact(query).await;
+    report_timing(acquired, started.elapsed() - acquired);
+
+    Ok(result?)
+}
+
+/// Builds a pool hook making Postgres abort any statement that runs longer than `timeout`
+/// on each new connection. A request cut off by the request timeout only drops the future
+/// waiting on `interact`; this ends the query it left behind and hands the connection back.
+pub fn statement_timeout_hook(timeout: Duration) -> Hook {
+    let millis = u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX);
+    Hook::async_fn(move |conn, _| {
+        Box::pin(async move {
+            conn.interact(move |conn| {
+                diesel::sql_query(format!("SET statement_timeout = {}", millis)).execute(conn)
+            })
+            .await
+            .map_err(|e| HookError::message(e.to_string()))?
+            .map_err(|e| HookError::message(format!("Failed to set statement_timeout: {}", e)))?;
+            Ok(())
+        })
+    })
+}
+
+fn report_timing(acquire: Duration, execute: Duration) {
+    let threshold = slow_query_threshold();
+    let total = acquire + execute;
+    if threshold.is_zero() || total <= threshold {
+        return;
+    }
+
+    tracing::warn!(
+        total_ms = total.as_millis() as u64,
+        acquire_ms = acquire.as_millis() as u64,
+        execute_ms = execute.as_millis() as u64,
+        threshold_ms = threshold.as_millis() as u64,
+        "Slow database query"
+    );
+}
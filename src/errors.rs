@@ -4,16 +4,15 @@ use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use deadpool_diesel::InteractError;
 use deadpool_diesel::postgres::PoolError;
+use diesel::result::DatabaseErrorKind;
 use thiserror::Error;
-use tracing::error;
+use tracing::{error, warn};
 
 #[derive(Debug, Error)]
 pub enum AppError {
-    #[allow(dead_code)]
     #[error("Bad Request: {0}")]
     BadRequest(String), // 400
 
-    #[allow(dead_code)]
     #[error("Unauthorized: {0}")]
     Unauthorized(String), // 401
 
@@ -23,18 +22,42 @@ pub enum AppError {
     #[error("Not Found: {0}")]
     NotFound(String), // 404
 
+    #[error("Method Not Allowed: {0}")]
+    MethodNotAllowed(String), // 405
+
     #[error("Conflict: {0}")]
     Conflict(String), // 409
 
+    #[error("Payload Too Large: {0}")]
+    PayloadTooLarge(String), // 413
+
     #[error("Unprocessable Entity: {0}")]
     UnprocessableEntity(String), // 422
 
+    #[error("Too Many Requests: {0}")]
+    TooManyRequests(String), // 429
+
+    #[error("Bad Gateway: {0}")]
+    BadGateway(String), // 502
+
+    #[error("Service Unavailable: {0}")]
+    ServiceUnavailable(String), // 503
+
+    #[error("Gateway Timeout: {0}")]
+    GatewayTimeout(String), // 504
+
     #[error("Internal Server Error: {0}")]
     InternalServerError(#[from] anyhow::Error), // 500
 }
 
 impl From<PoolError> for AppError {
     fn from(err: PoolError) -> Self {
+        if let PoolError::Timeout(_) = err {
+            warn!("No database connection became available in time: {:?}", err);
+            return AppError::ServiceUnavailable(
+                "The server is busy, please retry shortly.".to_string(),
+            );
+        }
         error!("Database pool error encountered: {:?}", err);
         AppError::InternalServerError(anyhow::Error::new(err).context("Database pool error"))
     }
@@ -67,25 +90,93 @@ impl From<diesel::result::Error> for AppError {
     }
 }
 
+impl AppError {
+    /// Maps a Diesel error raised by a write to the variant a client can act on: a foreign-key
+    /// violation (a referenced row is missing) becomes `NotFound`, a unique violation `Conflict`,
+    /// and a check or not-null violation `UnprocessableEntity`. The response text describes the
+    /// kind of violation after `context` (what the write was doing, e.g. "Could not create the game")
+    /// and names the violated constraint. Other errors go through the generic `From` conversion.
+    pub fn from_constraint_violation(
+        err: diesel::result::Error,
+        context: impl std::fmt::Display,
+    ) -> AppError {
+        let diesel::result::Error::DatabaseError(kind, info) = &err else {
+            return AppError::from(err);
+        };
+        let constraint = info
+            .constraint_name()
+            .map(|name| format!(" ({})", name))
+            .unwrap_or_default();
+        match kind {
+            DatabaseErrorKind::ForeignKeyViolation => {
+                warn!("Foreign key violation: {}", info.message());
+                AppError::NotFound(format!(
+                    "{}: a referenced record does not exist{}.",
+                    context, constraint
+                ))
+            }
+            DatabaseErrorKind::UniqueViolation => {
+                warn!("Unique violation: {}", info.message());
+                AppError::Conflict(format!(
+                    "{}: a record with the same values already exists{}.",
+                    context, constraint
+                ))
+            }
+            DatabaseErrorKind::CheckViolation | DatabaseErrorKind::NotNullViolation => {
+                warn!("Check violation: {}", info.message());
+                AppError::UnprocessableEntity(format!(
+                    "{}: a value is not allowed{}.",
+                    context, constraint
+                ))
+            }
+            _ => AppError::from(err),
+        }
+    }
+
+    /// The HTTP status every response built from this error carries.
+    /// This is the only place errors are mapped to statuses; handlers pick the variant.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnprocessableEntity(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::BadGateway(_) => StatusCode::BAD_GATEWAY,
+            AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            AppError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AppError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
-            AppError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message),
-            AppError::Forbidden(message) => (StatusCode::FORBIDDEN, message),
-            AppError::NotFound(message) => (StatusCode::NOT_FOUND, message),
-            AppError::Conflict(message) => (StatusCode::CONFLICT, message),
-            AppError::UnprocessableEntity(message) => (StatusCode::UNPROCESSABLE_ENTITY, message),
+        let status = self.status_code();
+        let error_message = match self {
+            AppError::BadRequest(message)
+            | AppError::Unauthorized(message)
+            | AppError::Forbidden(message)
+            | AppError::NotFound(message)
+            | AppError::MethodNotAllowed(message)
+            | AppError::Conflict(message)
+            | AppError::PayloadTooLarge(message)
+            | AppError::UnprocessableEntity(message)
+            | AppError::TooManyRequests(message)
+            | AppError::BadGateway(message)
+            | AppError::ServiceUnavailable(message)
+            | AppError::GatewayTimeout(message) => message,
 
             AppError::InternalServerError(source) => {
                 error!(
                     "Responding with 500 Internal Server Error. Source: {:?}",
                     source
                 );
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "An internal server error occurred".to_string(),
-                )
+                "An internal server error occurred".to_string()
             }
         };
 
@@ -0,0 +1,80 @@
+use crate::errors::AppError;
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::time::Duration;
+use tracing::{error, info};
+use url::Url;
+
+/// What `submit_solution` sends to the external evaluation service.
+#[derive(Serialize, Debug)]
+pub(crate) struct EvaluationRequest {
+    pub exercise_id: i64,
+    pub programming_language: String,
+    pub mode: String,
+    pub mode_parameters: JsonValue,
+    pub submitted_code: String,
+    pub pre_code: String,
+    pub post_code: String,
+    pub test_code: String,
+    pub check_source: String,
+}
+
+/// The evaluator's verdict, stored in place of the client-supplied one.
+#[derive(Deserialize, Debug)]
+pub(crate) struct EvaluationVerdict {
+    /// Score as a percentage on a 0-100 scale.
+    pub result: BigDecimal,
+    #[serde(default)]
+    pub result_description: JsonValue,
+}
+
+/// HTTP client of the external evaluation service configured with `--evaluator-url`.
+#[derive(Debug)]
+pub(crate) struct Evaluator {
+    client: reqwest::Client,
+    url: Url,
+}
+
+impl Evaluator {
+    pub(crate) fn new(url: Url, timeout: Duration) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(Self { client, url })
+    }
+
+    /// POSTs the request as JSON and parses the verdict.
+    /// Any transport failure, non-2xx status, malformed body or result outside 0-100
+    /// is reported as `502 Bad Gateway`.
+    pub(crate) async fn evaluate(
+        &self,
+        request: &EvaluationRequest,
+    ) -> Result<EvaluationVerdict, AppError> {
+        info!(
+            "Sending exercise {} submission to evaluator {}",
+            request.exercise_id, self.url
+        );
+        let response = self
+            .client
+            .post(self.url.clone())
+            .json(request)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                error!("Evaluator request failed: {:?}", e);
+                AppError::BadGateway("The evaluation service could not be reached.".to_string())
+            })?;
+
+        let verdict = response.json::<EvaluationVerdict>().await.map_err(|e| {
+            error!("Evaluator returned an invalid verdict: {:?}", e);
+            AppError::BadGateway("The evaluation service returned an invalid verdict.".to_string())
+        })?;
+        if verdict.result < BigDecimal::from(0) || verdict.result > BigDecimal::from(100) {
+            error!("Evaluator returned result {} outside 0-100", verdict.result);
+            return Err(AppError::BadGateway(
+                "The evaluation service returned an invalid verdict.".to_string(),
+            ));
+        }
+        Ok(verdict)
+    }
+}
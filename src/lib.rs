@@ -1,64 +1,256 @@
 use crate::cli::Args;
+use crate::config::{
+    ApiKeys, AppConfig, DefaultLanguages, MaxCheckpoints, MaxCodeLength, MaxGroupSize,
+    PublicBaseUrl,
+};
+use crate::evaluator::Evaluator;
+use crate::rate_limit::{IpRateLimiter, SlidingWindowLimiter};
+use crate::webhook::Webhook;
 use anyhow::Context;
-use axum::Router;
-use axum::routing::{get, post};
+use axum::extract::DefaultBodyLimit;
+use axum::http::StatusCode;
+use axum::middleware::{from_fn, from_fn_with_state};
+use axum::routing::{MethodRouter, get, post};
+use axum::{Extension, Router};
 use axum_keycloak_auth::PassthroughMode;
 use axum_keycloak_auth::instance::{KeycloakAuthInstance, KeycloakConfig};
 use axum_keycloak_auth::layer::KeycloakAuthLayer;
-use deadpool_diesel::Runtime;
-use deadpool_diesel::postgres::{Manager, Pool};
+use deadpool_diesel::postgres::Pool;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::compression::CompressionLayer;
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::timeout::TimeoutLayer;
 use tracing::log::info;
 
+pub mod auth;
 pub mod cli;
+pub mod config;
+pub mod db;
+pub mod errors;
 pub mod model;
 pub mod payloads;
 pub mod response;
+pub mod scheduler;
 pub mod schema;
 
 mod api;
-mod errors;
+mod evaluator;
+mod middleware;
+mod rate_limit;
+mod webhook;
 
 pub fn init_router(args: &Args) -> anyhow::Result<Router> {
+    let config = AppConfig::from(args);
+    validate_public_student_routes(&config)?;
+    let evaluator = init_evaluator(&config).context("Failed to initialize evaluator client")?;
+    let webhook = init_webhook(&config).context("Failed to initialize webhook")?;
+
     info!("Initializing database pool...");
-    let pool = init_pool(&args.connection_str, args.db_pool_max_size)
-        .context("Failed to initialize database pool")?;
+    let pool = db::init_pool(
+        &args.connection_str,
+        args.db_pool_max_size,
+        config.request_timeout,
+        Duration::from_millis(args.db_pool_timeout_ms),
+    )
+    .context("Failed to initialize database pool")?;
 
     info!("Initializing Keycloak authentication layer...");
     let keycloak_layer =
         init_protection_layer(args).context("Failed to initialize Keycloak layer")?;
 
+    info!("Initializing background tasks...");
+    scheduler::spawn_game_end_scanner(
+        pool.clone(),
+        Duration::from_secs(args.game_end_scan_interval_secs),
+    );
+    scheduler::spawn_submission_archiver(
+        pool.clone(),
+        Duration::from_secs(args.submission_retention_days * 24 * 60 * 60),
+    );
+
     info!("Initializing router...");
-    Ok(init_router_internal(pool, keycloak_layer))
+    Ok(init_router_internal(
+        pool,
+        keycloak_layer,
+        evaluator,
+        webhook,
+        &config,
+    ))
 }
 
 pub fn init_test_router(pool: Pool) -> Router {
-    let student_api = student_routes();
-    let teacher_api = teacher_routes();
-    let editor_api = editor_routes();
+    init_test_router_with_config(pool, AppConfig::default())
+}
 
-    Router::new()
+pub fn init_test_router_with_config(pool: Pool, config: AppConfig) -> Router {
+    let evaluator = init_evaluator(&config).expect("Failed to initialize evaluator client");
+    let webhook = init_webhook(&config).expect("Failed to initialize webhook");
+    let identity_api = identity_routes().layer(from_fn(auth::require_principal));
+    let (public_student_api, protected_student_api) = student_routes(&config, evaluator);
+    let student_api =
+        public_student_api.merge(protected_student_api.layer(from_fn(auth::require_principal)));
+    let teacher_api =
+        teacher_routes(&config, webhook.clone()).layer(from_fn(auth::require_principal));
+
+    let mut router = Router::new()
+        .merge(identity_api)
         .nest("/student", student_api)
-        .nest("/teacher", teacher_api)
-        .nest("/editor", editor_api)
-        .with_state(pool)
+        .nest("/teacher", teacher_api);
+    if config.enable_editor {
+        let editor_api = editor_routes(&config, webhook).layer(from_fn(auth::require_principal));
+        router = router.nest("/editor", editor_api);
+    }
+    if !config.api_keys.is_empty() {
+        router = router.layer(from_fn_with_state(
+            Arc::new(ApiKeys(config.api_keys.clone())),
+            auth::principal_from_api_key,
+        ));
+    }
+    let router = router
+        .layer(from_fn(auth::principal_from_test_headers))
+        .with_state(pool);
+    apply_common_layers(router, &config)
 }
 
-fn init_router_internal(pool: Pool, keycloak_layer: KeycloakAuthLayer<String>) -> Router {
-    let student_api = student_routes().layer(keycloak_layer.clone());
-    let teacher_api = teacher_routes().layer(keycloak_layer.clone());
-    let editor_api = editor_routes().layer(keycloak_layer.clone());
+fn init_router_internal(
+    pool: Pool,
+    keycloak_layer: KeycloakAuthLayer<String>,
+    evaluator: Option<Arc<Evaluator>>,
+    webhook: Option<Arc<Webhook>>,
+    config: &AppConfig,
+) -> Router {
+    let identity_api = protect(identity_routes(), &keycloak_layer, config);
+    let (public_student_api, protected_student_api) = student_routes(config, evaluator);
+    let mut optional_keycloak_layer = keycloak_layer.clone();
+    optional_keycloak_layer.passthrough_mode = PassthroughMode::Pass;
+    let student_api = public_student_api
+        .layer(from_fn(auth::principal_from_token))
+        .layer(optional_keycloak_layer)
+        .merge(protect(protected_student_api, &keycloak_layer, config));
+    let teacher_api = protect(
+        teacher_routes(config, webhook.clone()),
+        &keycloak_layer,
+        config,
+    );
 
-    Router::new()
+    let mut router = Router::new()
+        .merge(identity_api)
         .nest("/student", student_api)
-        .nest("/teacher", teacher_api)
-        .nest("/editor", editor_api)
-        .with_state(pool)
+        .nest("/teacher", teacher_api);
+    if config.enable_editor {
+        let editor_api = protect(editor_routes(config, webhook), &keycloak_layer, config);
+        router = router.nest("/editor", editor_api);
+    } else {
+        info!("Editor routes are disabled");
+    }
+    let router = router.with_state(pool);
+    apply_common_layers(router, config)
+}
+
+/// Requires a valid Keycloak token on `routes`, or a valid `X-Api-Key` if `AppConfig::api_keys` is set.
+/// With API keys configured, the Keycloak layer lets requests without a token through
+/// and `auth::require_principal` rejects those that neither a token nor a key identified.
+fn protect(
+    routes: Router<Pool>,
+    keycloak_layer: &KeycloakAuthLayer<String>,
+    config: &AppConfig,
+) -> Router<Pool> {
+    if config.api_keys.is_empty() {
+        return routes
+            .layer(from_fn(auth::principal_from_token))
+            .layer(keycloak_layer.clone());
+    }
+
+    let mut passthrough_keycloak_layer = keycloak_layer.clone();
+    passthrough_keycloak_layer.passthrough_mode = PassthroughMode::Pass;
+    routes
+        .layer(from_fn(auth::require_principal))
+        .layer(from_fn_with_state(
+            Arc::new(ApiKeys(config.api_keys.clone())),
+            auth::principal_from_api_key,
+        ))
+        .layer(from_fn(auth::principal_from_token))
+        .layer(passthrough_keycloak_layer)
+}
+
+fn apply_common_layers(router: Router, config: &AppConfig) -> Router {
+    // axum only adds the `Allow` header once the matched route has answered, so the 405
+    // envelope wraps the whole router instead of being layered onto its routes
+    let router = router
+        .fallback(middleware::route_not_found)
+        .layer(from_fn_with_state(
+            config.slow_query_threshold,
+            middleware::scope_slow_query_threshold,
+        ));
+    let router = if config.msgpack_responses {
+        router.layer(from_fn(middleware::negotiate_response_format))
+    } else {
+        router
+    };
+    let router = Router::new()
+        .fallback_service(router)
+        .layer(axum::middleware::map_response(
+            middleware::envelope_method_not_allowed,
+        ));
+    let router = if config.request_timeout.is_zero() {
+        router
+    } else {
+        router.layer(TimeoutLayer::with_status_code(
+            StatusCode::GATEWAY_TIMEOUT,
+            config.request_timeout,
+        ))
+    };
+    let router = router
+        .layer(axum::middleware::map_response(
+            middleware::envelope_gateway_timeout,
+        ))
+        .layer(DefaultBodyLimit::disable())
+        .layer(RequestBodyLimitLayer::new(config.max_body_size))
+        .layer(axum::middleware::map_response(
+            middleware::envelope_payload_too_large,
+        ));
+    let router = if config.ip_rate_limit_per_sec == 0 {
+        router
+    } else {
+        router.layer(from_fn_with_state(
+            Arc::new(middleware::ClientRateLimit {
+                limiter: IpRateLimiter::new(
+                    config.ip_rate_limit_per_sec,
+                    config.ip_rate_limit_burst,
+                ),
+                trust_forwarded_for: config.trust_forwarded_for,
+            }),
+            middleware::limit_requests_per_ip,
+        ))
+    };
+
+    if !config.response_compression {
+        return router;
+    }
+    router.layer(CompressionLayer::new())
 }
 
-fn init_pool(conn_str: &str, max_size: u32) -> anyhow::Result<Pool> {
-    let manager = Manager::new(conn_str, Runtime::Tokio1);
-    let pool = Pool::builder(manager).max_size(max_size as usize).build()?;
-    Ok(pool)
+fn init_evaluator(config: &AppConfig) -> anyhow::Result<Option<Arc<Evaluator>>> {
+    config
+        .evaluator_url
+        .clone()
+        .map(|url| {
+            info!("Grading submissions with the evaluator at {}", url);
+            Evaluator::new(url, config.evaluator_timeout).map(Arc::new)
+        })
+        .transpose()
+}
+
+fn init_webhook(config: &AppConfig) -> anyhow::Result<Option<Arc<Webhook>>> {
+    config
+        .webhook_url
+        .clone()
+        .map(|url| {
+            info!("Sending game lifecycle events to the webhook at {}", url);
+            Webhook::spawn(url, config.webhook_events.clone()).map(Arc::new)
+        })
+        .transpose()
 }
 
 fn init_protection_layer(args: &Args) -> anyhow::Result<KeycloakAuthLayer<String>> {
@@ -79,33 +271,140 @@ fn init_protection_layer(args: &Args) -> anyhow::Result<KeycloakAuthLayer<String
     Ok(layer)
 }
 
-fn student_routes() -> Router<Pool> {
+fn identity_routes() -> Router<Pool> {
     Router::new()
         // protected routes go here
-        .route(
+        .route("/whoami", get(api::identity::whoami))
+    // public routes go here
+}
+
+/// Every student route as a (path, handler) pair; `student_routes` decides which ones are public.
+fn student_route_table() -> Vec<(&'static str, MethodRouter<Pool>)> {
+    vec![
+        (
             "/get_available_games",
             get(api::student::get_available_games),
-        )
-        .route("/join_game", post(api::student::join_game))
-        .route("/save_game", post(api::student::save_game))
-        .route("/load_game", post(api::student::load_game))
-        .route("/leave_game", post(api::student::leave_game))
-        .route("/set_game_lang", post(api::student::set_game_lang))
-        .route("/get_player_games", get(api::student::get_player_games))
-        .route(
+        ),
+        ("/get_public_courses", get(api::student::get_public_courses)),
+        ("/join_game", post(api::student::join_game)),
+        ("/save_game", post(api::student::save_game)),
+        ("/load_game", post(api::student::load_game)),
+        ("/save_checkpoint", post(api::student::save_checkpoint)),
+        ("/load_checkpoint", post(api::student::load_checkpoint)),
+        ("/list_checkpoints", get(api::student::list_checkpoints)),
+        ("/leave_game", post(api::student::leave_game)),
+        ("/set_game_lang", post(api::student::set_game_lang)),
+        ("/get_player_games", get(api::student::get_player_games)),
+        (
+            "/get_player_registrations",
+            get(api::student::get_player_registrations),
+        ),
+        (
             "/get_game_metadata/{registration_id}",
             get(api::student::get_game_metadata),
-        )
-        .route("/get_course_data", get(api::student::get_course_data))
-        .route("/get_module_data", get(api::student::get_module_data))
-        .route("/get_exercise_data", get(api::student::get_exercise_data))
-        .route("/submit_solution", post(api::student::submit_solution))
-        .route("/unlock", post(api::student::unlock))
-        .route("/get_last_solution", get(api::student::get_last_solution))
-    // public routes go here
+        ),
+        ("/get_course_data", get(api::student::get_course_data)),
+        (
+            "/get_course_data_parsed",
+            get(api::student::get_course_data_parsed),
+        ),
+        ("/get_course_tree", get(api::student::get_course_tree)),
+        (
+            "/list_exercises_by_tag",
+            get(api::student::list_exercises_by_tag),
+        ),
+        ("/get_module_data", get(api::student::get_module_data)),
+        (
+            "/get_course_modules_data",
+            get(api::student::get_course_modules_data),
+        ),
+        ("/get_exercise_data", get(api::student::get_exercise_data)),
+        (
+            "/get_exercise_lock_states",
+            get(api::student::get_exercise_lock_states),
+        ),
+        (
+            "/get_exercise_workspace",
+            get(api::student::get_exercise_workspace),
+        ),
+        ("/get_player_score", get(api::student::get_player_score)),
+        ("/get_player_rank", get(api::student::get_player_rank)),
+        ("/submit_solution", post(api::student::submit_solution)),
+        ("/unlock", post(api::student::unlock)),
+        ("/unlock_many", post(api::student::unlock_many)),
+        ("/get_last_solution", get(api::student::get_last_solution)),
+        ("/get_player_streak", get(api::student::get_player_streak)),
+        (
+            "/get_completion_status",
+            get(api::student::get_completion_status),
+        ),
+    ]
 }
 
-fn teacher_routes() -> Router<Pool> {
+/// Name of a student route as used in `AppConfig::public_student_routes`: its first path segment.
+fn student_route_name(path: &str) -> &str {
+    path.trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Fails if `AppConfig::public_student_routes` names a route that does not exist.
+fn validate_public_student_routes(config: &AppConfig) -> anyhow::Result<()> {
+    let known: Vec<&str> = student_route_table()
+        .iter()
+        .map(|(path, _)| student_route_name(path))
+        .collect();
+    if let Some(unknown) = config
+        .public_student_routes
+        .iter()
+        .find(|route| !known.contains(&route.as_str()))
+    {
+        anyhow::bail!("Unknown public student route '{}'", unknown);
+    }
+    Ok(())
+}
+
+/// Returns the (public, protected) student routers, split by `AppConfig::public_student_routes`.
+fn student_routes(
+    config: &AppConfig,
+    evaluator: Option<Arc<Evaluator>>,
+) -> (Router<Pool>, Router<Pool>) {
+    let mut public = Router::new();
+    let mut protected = Router::new();
+    for (path, handler) in student_route_table() {
+        let name = student_route_name(path);
+        if config
+            .public_student_routes
+            .iter()
+            .any(|route| route == name)
+        {
+            info!("Serving /student/{} without authentication", name);
+            public = public.route(path, handler);
+        } else {
+            protected = protected.route(path, handler);
+        }
+    }
+    let max_checkpoints = Arc::new(MaxCheckpoints(config.max_checkpoints_per_registration));
+    let max_code_length = Arc::new(MaxCodeLength(config.max_code_length));
+    (
+        public
+            .layer(Extension(evaluator.clone()))
+            .layer(Extension(max_checkpoints.clone()))
+            .layer(Extension(max_code_length.clone())),
+        protected
+            .layer(Extension(evaluator))
+            .layer(Extension(max_checkpoints))
+            .layer(Extension(max_code_length)),
+    )
+}
+
+fn teacher_routes(config: &AppConfig, webhook: Option<Arc<Webhook>>) -> Router<Pool> {
+    let player_creation_limiter = Arc::new(SlidingWindowLimiter::new(
+        config.player_creation_hourly_limit,
+        Duration::from_secs(60 * 60),
+    ));
+
     Router::new()
         // protected routes go here
         .route(
@@ -116,6 +415,18 @@ fn teacher_routes() -> Router<Pool> {
             "/get_instructor_game_metadata",
             get(api::teacher::get_instructor_game_metadata),
         )
+        .route(
+            "/get_games_metadata",
+            post(api::teacher::get_games_metadata),
+        )
+        .route(
+            "/get_games_by_course",
+            get(api::teacher::get_games_by_course),
+        )
+        .route(
+            "/get_games_ending_soon",
+            get(api::teacher::get_games_ending_soon),
+        )
         .route("/list_students", get(api::teacher::list_students))
         .route(
             "/get_student_progress",
@@ -125,19 +436,68 @@ fn teacher_routes() -> Router<Pool> {
             "/get_student_exercises",
             get(api::teacher::get_student_exercises),
         )
+        .route(
+            "/get_student_unattempted_exercises",
+            get(api::teacher::get_student_unattempted_exercises),
+        )
         .route(
             "/get_student_submissions",
             get(api::teacher::get_student_submissions),
         )
+        .route(
+            "/get_student_best_submissions",
+            get(api::teacher::get_student_best_submissions),
+        )
+        .route(
+            "/get_struggling_students",
+            get(api::teacher::get_struggling_students),
+        )
+        .route(
+            "/get_player_activity_by_day",
+            get(api::teacher::get_player_activity_by_day),
+        )
+        .route("/get_player_score", get(api::teacher::get_player_score))
         .route(
             "/get_submission_data",
             get(api::teacher::get_submission_data),
         )
+        .route(
+            "/get_submissions_data",
+            post(api::teacher::get_submissions_data),
+        )
         .route("/get_exercise_stats", get(api::teacher::get_exercise_stats))
+        .route(
+            "/get_all_exercise_stats",
+            get(api::teacher::get_all_exercise_stats),
+        )
+        .route(
+            "/get_exercise_score_histogram",
+            get(api::teacher::get_exercise_score_histogram),
+        )
+        .route(
+            "/get_exercise_first_solvers",
+            get(api::teacher::get_exercise_first_solvers),
+        )
         .route(
             "/get_exercise_submissions",
             get(api::teacher::get_exercise_submissions),
         )
+        .route("/get_group_stats", get(api::teacher::get_group_stats))
+        .route(
+            "/get_progress_distribution",
+            get(api::teacher::get_progress_distribution),
+        )
+        .route("/get_group_metadata", get(api::teacher::get_group_metadata))
+        .route("/get_group_members", get(api::teacher::get_group_members))
+        .route("/get_player_groups", get(api::teacher::get_player_groups))
+        .route(
+            "/get_game_end_summary",
+            get(api::teacher::get_game_end_summary),
+        )
+        .route(
+            "/get_game_instructors",
+            get(api::teacher::get_game_instructors),
+        )
         .route("/create_game", post(api::teacher::create_game))
         .route("/modify_game", post(api::teacher::modify_game))
         .route(
@@ -148,18 +508,38 @@ fn teacher_routes() -> Router<Pool> {
             "/remove_game_instructor",
             post(api::teacher::remove_game_instructor),
         )
+        .route(
+            "/include_game_exercises",
+            post(api::teacher::include_game_exercises),
+        )
+        .route(
+            "/exclude_game_exercises",
+            post(api::teacher::exclude_game_exercises),
+        )
         .route("/activate_game", post(api::teacher::activate_game))
         .route("/stop_game", post(api::teacher::stop_game))
         .route(
             "/remove_game_student",
             post(api::teacher::remove_game_student),
         )
+        .route("/grant_reward", post(api::teacher::grant_reward))
+        .route(
+            "/remove_game_students",
+            post(api::teacher::remove_game_students),
+        )
         .route(
             "/translate_email_to_player_id",
             get(api::teacher::translate_email_to_player_id),
         )
         .route("/create_group", post(api::teacher::create_group))
+        .route("/modify_group", post(api::teacher::modify_group))
         .route("/dissolve_group", post(api::teacher::dissolve_group))
+        .route("/merge_groups", post(api::teacher::merge_groups))
+        .route("/clone_group", post(api::teacher::clone_group))
+        .route(
+            "/transfer_group_ownership",
+            post(api::teacher::transfer_group_ownership),
+        )
         .route("/add_group_member", post(api::teacher::add_group_member))
         .route(
             "/remove_group_member",
@@ -167,22 +547,94 @@ fn teacher_routes() -> Router<Pool> {
         )
         .route("/create_player", post(api::teacher::create_player))
         .route("/disable_player", post(api::teacher::disable_player))
+        .route(
+            "/import_submissions",
+            post(api::teacher::import_submissions),
+        )
+        .route("/get_game_timeline", get(api::teacher::get_game_timeline))
+        .route(
+            "/get_recent_submissions",
+            get(api::teacher::get_recent_submissions),
+        )
+        .route(
+            "/list_exercises_by_tag",
+            get(api::teacher::list_exercises_by_tag),
+        )
+        .route(
+            "/list_disabled_players",
+            get(api::teacher::list_disabled_players),
+        )
+        .route(
+            "/get_all_player_submissions",
+            get(api::teacher::get_all_player_submissions),
+        )
+        .route("/check_integrity", get(api::teacher::check_integrity))
         .route("/delete_player", post(api::teacher::delete_player))
+        .route(
+            "/soft_delete_player",
+            post(api::teacher::soft_delete_player),
+        )
+        .route("/restore_player", post(api::teacher::restore_player))
         .route(
             "/generate_invite_link",
             post(api::teacher::generate_invite_link),
         )
+        .route("/list_invites", get(api::teacher::list_invites))
+        .route("/get_invite_details", get(api::teacher::get_invite_details))
         .route(
             "/process_invite_link",
             post(api::teacher::process_invite_link),
         )
+        .layer(Extension(player_creation_limiter))
+        .layer(Extension(Arc::new(PublicBaseUrl(
+            config.public_base_url.clone(),
+        ))))
+        .layer(Extension(Arc::new(DefaultLanguages(
+            config.default_languages.clone(),
+        ))))
+        .layer(Extension(Arc::new(MaxGroupSize(config.max_group_size))))
+        .layer(Extension(webhook))
     // public routes go here
 }
 
-fn editor_routes() -> Router<Pool> {
+fn editor_routes(config: &AppConfig, webhook: Option<Arc<Webhook>>) -> Router<Pool> {
     Router::new()
         // protected routes go here
-        .route("/import_course", post(api::editor::import_course))
+        .route(
+            "/import_course",
+            post(api::editor::import_course)
+                .layer(RequestBodyLimitLayer::new(config.import_max_body_size)),
+        )
         .route("/export_course", get(api::editor::export_course))
+        .route(
+            "/import_module",
+            post(api::editor::import_module)
+                .layer(RequestBodyLimitLayer::new(config.import_max_body_size)),
+        )
+        .route("/export_module", get(api::editor::export_module))
+        .route("/add_exercise_tag", post(api::editor::add_exercise_tag))
+        .route(
+            "/remove_exercise_tag",
+            post(api::editor::remove_exercise_tag),
+        )
+        .route("/reorder_modules", post(api::editor::reorder_modules))
+        .route("/reorder_exercises", post(api::editor::reorder_exercises))
+        .route("/renumber_course", post(api::editor::renumber_course))
+        .route(
+            "/recalculate_course_games",
+            post(api::editor::recalculate_course_games),
+        )
+        .route("/delete_course", post(api::editor::delete_course))
+        .route(
+            "/get_course_exercise_difficulty",
+            get(api::editor::get_course_exercise_difficulty),
+        )
+        .route("/get_exercise_games", get(api::editor::get_exercise_games))
+        .route(
+            "/get_course_enrollment_summary",
+            get(api::editor::get_course_enrollment_summary),
+        )
+        .route("/get_course_tree", get(api::editor::get_course_tree))
+        .layer(Extension(webhook))
     // public routes go here
 }
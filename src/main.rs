@@ -32,8 +32,12 @@ async fn run(router: Router, addr: SocketAddr) -> anyhow::Result<()> {
     let listener = tokio::net::TcpListener::bind(addr)
         .await
         .with_context(|| format!("Failed to bind to address {}", addr))?;
-    axum::serve(listener, router.into_make_service())
-        .await
-        .context("Axum server error")?;
+    // the peer address identifies clients for the per-IP rate limit
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .context("Axum server error")?;
     Ok(())
 }
@@ -0,0 +1,163 @@
+use crate::db;
+use crate::errors::AppError;
+use crate::rate_limit::IpRateLimiter;
+use crate::response::{MSGPACK_CONTENT_TYPE, RESPONSE_FORMAT, ResponseFormat};
+use axum::extract::{ConnectInfo, OriginalUri, Request, State};
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::log::{debug, warn};
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// State of `limit_requests_per_ip`.
+#[derive(Debug)]
+pub(crate) struct ClientRateLimit {
+    pub(crate) limiter: IpRateLimiter,
+    /// Whether the last `X-Forwarded-For` address identifies the client instead of the peer address.
+    pub(crate) trust_forwarded_for: bool,
+}
+
+/// Answers 429 Too Many Requests once the client's IP address has used up its token bucket.
+/// Requests whose address cannot be determined are let through.
+pub(crate) async fn limit_requests_per_ip(
+    State(rate_limit): State<Arc<ClientRateLimit>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(ip) = client_ip(&request, rate_limit.trust_forwarded_for) else {
+        debug!("Could not determine the client address, skipping the rate limit");
+        return next.run(request).await;
+    };
+
+    if let Err(retry_after) = rate_limit.limiter.try_acquire(ip) {
+        warn!("Rate limited requests from {}", ip);
+        let mut response =
+            AppError::TooManyRequests("Too many requests, please slow down.".to_string())
+                .into_response();
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from(retry_after.as_secs_f64().ceil() as u64),
+        );
+        return response;
+    }
+    next.run(request).await
+}
+
+/// The last `X-Forwarded-For` address if trusted and valid, else the peer address of the connection.
+/// The last entry is the one appended by the trusted proxy; earlier ones are supplied by the client.
+fn client_ip(request: &Request, trust_forwarded_for: bool) -> Option<IpAddr> {
+    if trust_forwarded_for && let Some(ip) = forwarded_for(request.headers()) {
+        return Some(ip);
+    }
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
+
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .next_back()?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Applies the configured slow query threshold to the database calls made while handling the request.
+pub(crate) async fn scope_slow_query_threshold(
+    State(threshold): State<Duration>,
+    request: Request,
+    next: Next,
+) -> Response {
+    db::with_slow_query_threshold(threshold, next.run(request)).await
+}
+
+/// Serializes the `ApiResponse` bodies of the request as MessagePack if its `Accept` header asks for it.
+pub(crate) async fn negotiate_response_format(request: Request, next: Next) -> Response {
+    let format = ResponseFormat::from_accept(request.headers());
+    RESPONSE_FORMAT.scope(format, next.run(request)).await
+}
+
+/// Rewrites plain 413 responses (produced by body limit layers or extractors)
+/// into the standard `ApiResponse` envelope.
+pub(crate) async fn envelope_payload_too_large(response: Response) -> Response {
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE || is_enveloped(&response) {
+        return response;
+    }
+
+    warn!("Rejected request with body exceeding the configured size limit");
+    AppError::PayloadTooLarge("Request body exceeds the maximum allowed size.".to_string())
+        .into_response()
+}
+
+/// Rewrites the plain 504 responses of the request timeout layer into the standard `ApiResponse` envelope.
+pub(crate) async fn envelope_gateway_timeout(response: Response) -> Response {
+    if response.status() != StatusCode::GATEWAY_TIMEOUT || is_enveloped(&response) {
+        return response;
+    }
+
+    warn!("Request exceeded the configured request timeout");
+    AppError::GatewayTimeout("The request took too long to complete.".to_string()).into_response()
+}
+
+/// Rewrites the plain 405 responses of method routers into the standard `ApiResponse` envelope.
+/// The `Allow` header is kept and its methods are listed in the message.
+pub(crate) async fn envelope_method_not_allowed(
+    method: Method,
+    OriginalUri(uri): OriginalUri,
+    response: Response,
+) -> Response {
+    if response.status() != StatusCode::METHOD_NOT_ALLOWED || is_enveloped(&response) {
+        return response;
+    }
+
+    let allow = response.headers().get(header::ALLOW).cloned();
+    let allowed = allow
+        .as_ref()
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    warn!(
+        "Rejected {} {}, allowed methods: {}",
+        method,
+        uri.path(),
+        allowed
+    );
+    let mut enveloped = AppError::MethodNotAllowed(format!(
+        "Method {} is not allowed for {}. Allowed methods: {}.",
+        method,
+        uri.path(),
+        allowed
+    ))
+    .into_response();
+    if let Some(allow) = allow {
+        enveloped.headers_mut().insert(header::ALLOW, allow);
+    }
+    enveloped
+}
+
+/// Router fallback answering unknown paths with a 404 in the standard `ApiResponse` envelope.
+pub(crate) async fn route_not_found(method: Method, OriginalUri(uri): OriginalUri) -> AppError {
+    warn!("No route for {} {}", method, uri.path());
+    AppError::NotFound(format!("No route for {} {}.", method, uri.path()))
+}
+
+/// Whether the response already carries an `ApiResponse` envelope, in either response format.
+fn is_enveloped(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/json") || value.starts_with(MSGPACK_CONTENT_TYPE)
+        })
+}
@@ -1,3 +1,5 @@
 pub mod editor;
+pub mod identity;
+pub mod score;
 pub mod student;
 pub mod teacher;
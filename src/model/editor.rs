@@ -1,4 +1,4 @@
-use crate::schema::{course_ownership, courses, exercises, modules};
+use crate::schema::{archived_games, course_ownership, courses, exercise_tags, exercises, modules};
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
@@ -39,6 +39,38 @@ pub struct NewModule {
     pub end_date: DateTime<Utc>,
 }
 
+#[derive(Insertable, Debug)]
+#[diesel(table_name = exercise_tags)]
+pub struct NewExerciseTag {
+    pub exercise_id: i64,
+    pub tag: String,
+    // created_at has DB default
+}
+
+/// How a player interacts with an exercise, stored as `exercises.mode`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExerciseMode {
+    /// Free-form programming exercise; `mode_parameters` is an arbitrary object.
+    Code,
+    /// Multiple choice; `mode_parameters.options` lists the (non-empty) answer options.
+    Quiz,
+    /// Fill in the blanks; `mode_parameters.blanks` lists the (non-empty) blank identifiers.
+    FillIn,
+}
+
+impl ExerciseMode {
+    /// Parses the stored representation of a mode, returning `None` for unknown modes.
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "code" => Some(ExerciseMode::Code),
+            "quiz" => Some(ExerciseMode::Quiz),
+            "fill_in" => Some(ExerciseMode::FillIn),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = exercises)]
 pub struct NewExercise {
@@ -98,6 +130,14 @@ pub struct ExportModuleResponse {
     pub exercises: Vec<ExportExerciseResponse>,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ImportDryRunSummary {
+    pub course_title: String,
+    pub module_count: i64,
+    pub exercise_count: i64,
+    pub warnings: Vec<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ExportCourseResponse {
     pub title: String,
@@ -157,3 +197,115 @@ pub struct ExerciseQueryResult {
     pub mode_parameters: JsonValue,
     pub difficulty: String,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CourseTreeResponse {
+    pub id: i64,
+    pub title: String,
+    pub description: String,
+    pub languages: String,
+    pub programming_languages: String,
+    pub public: bool,
+    pub modules: Vec<ModuleTreeNode>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ModuleTreeNode {
+    pub id: i64,
+    pub order: i32,
+    pub title: String,
+    pub description: String,
+    pub language: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub exercises: Vec<ExerciseTreeNode>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct ExerciseTreeNode {
+    pub id: i64,
+    pub order: i32,
+    pub title: String,
+    pub language: String,
+    pub programming_language: String,
+    pub mode: String,
+    pub difficulty: String,
+    pub hidden: bool,
+    pub locked: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CourseExerciseDifficulty {
+    pub exercise_id: i64,
+    pub title: String,
+    pub attempts: i64,
+    pub successful_attempts: i64,
+    pub success_rate: f64,
+    pub difficulty: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExerciseGameSummary {
+    pub game_id: i64,
+    pub title: String,
+    pub active: bool,
+    /// Players currently registered in the game (excluding those who left).
+    pub player_count: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CourseEnrollmentSummary {
+    pub course_id: i64,
+    /// Players registered in at least one game of the course, each counted once.
+    pub distinct_players: i64,
+    /// Registrations summed over all games of the course.
+    pub registrations: i64,
+    pub games: Vec<GameEnrollment>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GameEnrollment {
+    pub game_id: i64,
+    pub title: String,
+    pub active: bool,
+    /// Players currently registered in the game (excluding those who left).
+    pub registrations: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GameExerciseCount {
+    pub game_id: i64,
+    pub total_exercises: i32,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = archived_games)]
+pub struct NewArchivedGame {
+    pub game_id: i64,
+    pub course_id: i64,
+    pub title: String,
+    pub snapshot: JsonValue,
+    // archived_at has a DB default (CURRENT_TIMESTAMP)
+}
+
+impl From<ExerciseQueryResult> for ExportExerciseResponse {
+    fn from(exercise: ExerciseQueryResult) -> Self {
+        Self {
+            order: exercise.order,
+            title: exercise.title,
+            description: exercise.description,
+            language: exercise.language,
+            programming_language: exercise.programming_language,
+            init_code: exercise.init_code,
+            pre_code: exercise.pre_code,
+            post_code: exercise.post_code,
+            test_code: exercise.test_code,
+            check_source: exercise.check_source,
+            hidden: exercise.hidden,
+            locked: exercise.locked,
+            mode: exercise.mode,
+            mode_parameters: exercise.mode_parameters,
+            difficulty: exercise.difficulty,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityRole {
+    Admin,
+    Instructor,
+    Player,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WhoAmIResponse {
+    pub id: i64,
+    pub role: IdentityRole,
+    pub email: String,
+}
@@ -0,0 +1,18 @@
+use bigdecimal::{BigDecimal, ToPrimitive};
+use serde::Serializer;
+use serde::ser::Error;
+
+/// Serializes a submission score as a JSON number.
+///
+/// Scores are stored in `submissions.result` as a percentage on a 0-100 scale
+/// (e.g. `75` when three quarters of the tests passed) and are sent on the wire
+/// on the same scale as a float, instead of the string form `BigDecimal` uses by default.
+pub fn serialize<S>(score: &BigDecimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let value = score
+        .to_f64()
+        .ok_or_else(|| S::Error::custom(format!("score {} is not representable as f64", score)))?;
+    serializer.serialize_f64(value)
+}
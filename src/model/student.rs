@@ -1,9 +1,11 @@
+use crate::model::editor::ExerciseMode;
+use crate::schema::game_checkpoints;
 use crate::schema::player_registrations;
 use crate::schema::player_rewards;
 use crate::schema::player_unlocks;
 use crate::schema::submissions;
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -35,6 +37,7 @@ pub struct NewSubmission {
     pub feedback: String,
     pub earned_rewards: JsonValue,
     pub entered_at: DateTime<Utc>,
+    pub time_spent_seconds: Option<i32>,
     // submitted_at has a DB default (CURRENT_TIMESTAMP)
 }
 
@@ -58,10 +61,26 @@ pub struct NewPlayerUnlock {
     // unlocked_at has a DB default (CURRENT_TIMESTAMP)
 }
 
+#[derive(Insertable, Debug)]
+#[diesel(table_name = game_checkpoints)]
+pub struct NewGameCheckpoint {
+    pub player_registrations_id: i64,
+    pub name: String,
+    pub game_state: JsonValue,
+    // saved_at has a DB default (CURRENT_TIMESTAMP)
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct CheckpointSummary {
+    pub name: String,
+    pub saved_at: DateTime<Utc>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GameMetadata {
     pub registration_id: i64,
     pub progress: i32,
+    pub saved_at: DateTime<Utc>,
     pub joined_at: DateTime<Utc>,
     pub left_at: Option<DateTime<Utc>>,
     pub language: String,
@@ -76,6 +95,17 @@ pub struct GameMetadata {
     pub game_end_date: DateTime<Utc>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct PlayerRegistrationResponse {
+    pub registration_id: i64,
+    pub game_id: i64,
+    pub game_title: String,
+    pub language: String,
+    pub progress: i32,
+    pub joined_at: DateTime<Utc>,
+    pub left_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct CourseDataResponse {
     pub gamification_rule_conditions: String,
@@ -84,6 +114,15 @@ pub struct CourseDataResponse {
     pub module_ids: Vec<i64>,
 }
 
+/// `CourseDataResponse` with the gamification rule blobs parsed as JSON.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ParsedCourseDataResponse {
+    pub gamification_rule_conditions: JsonValue,
+    pub gamification_complex_rules: JsonValue,
+    pub gamification_rule_results: JsonValue,
+    pub module_ids: Vec<i64>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ModuleDataResponse {
     pub order: i32,
@@ -95,6 +134,14 @@ pub struct ModuleDataResponse {
     pub exercise_ids: Vec<i64>,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CourseModuleData {
+    pub module_id: i64,
+    #[serde(flatten)]
+    pub data: ModuleDataResponse,
+    pub locked: bool,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ExerciseDataResponse {
     // exercises fields
@@ -106,7 +153,7 @@ pub struct ExerciseDataResponse {
     pub post_code: String,
     pub test_code: String,
     pub check_source: String,
-    pub mode: String,
+    pub mode: ExerciseMode,
     pub mode_parameters: JsonValue,
     pub difficulty: String,
     // calculated fields
@@ -118,8 +165,102 @@ pub struct ExerciseDataResponse {
 pub struct LastSolutionResponse {
     pub submitted_code: String,
     pub metrics: JsonValue,
+    /// Score as a percentage on a 0-100 scale.
+    #[serde(serialize_with = "crate::model::score::serialize")]
     pub result: BigDecimal,
     pub result_description: JsonValue,
     pub feedback: String,
     pub submitted_at: DateTime<Utc>,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubmitResult {
+    pub first_solution: bool,
+    pub solved_exercises: i32,
+    pub progress: f64,
+    pub new_rewards: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StudentCourseTreeResponse {
+    pub course_id: i64,
+    pub title: String,
+    pub modules: Vec<StudentModuleTreeNode>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StudentModuleTreeNode {
+    pub id: i64,
+    pub order: i32,
+    pub title: String,
+    pub exercises: Vec<StudentExerciseTreeNode>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StudentExerciseTreeNode {
+    pub id: i64,
+    pub order: i32,
+    pub title: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExerciseLockState {
+    pub exercise_id: i64,
+    pub title: String,
+    pub order: i32,
+    pub locked: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PlayerScoreResponse {
+    pub base: i64,
+    pub rewards: i64,
+    pub penalties: i64,
+    pub total: i64,
+}
+
+#[derive(QueryableByName, Deserialize, Serialize, Debug)]
+pub struct PlayerRankResponse {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub rank: i64,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub total_participants: i64,
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    pub solved_exercises: i32,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub attempts: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExerciseWorkspaceResponse {
+    pub init_code: String,
+    pub last_submitted_code: Option<String>,
+    pub last_submitted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PlayerStreakResponse {
+    pub current_streak: i64,
+    pub last_active_date: Option<NaiveDate>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CompletionStatusResponse {
+    pub complete: bool,
+    pub solved_exercises: i64,
+    pub total_exercises: i32,
+    /// When the last exercise was first solved; `None` until the game is complete.
+    pub completed_at: Option<DateTime<Utc>>,
+    pub player_display_name: String,
+    pub game_title: String,
+    pub course_title: String,
+    pub joined_at: DateTime<Utc>,
+    pub attempts: i64,
+    pub score: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CatalogPageResponse {
+    pub ids: Vec<i64>,
+    pub next_cursor: Option<i64>,
+}
@@ -1,3 +1,5 @@
+use crate::schema::game_end_summaries;
+use crate::schema::game_exercises;
 use crate::schema::game_ownership;
 use crate::schema::games;
 use crate::schema::group_ownership;
@@ -7,7 +9,7 @@ use crate::schema::invites;
 use crate::schema::player_groups;
 use crate::schema::players;
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use diesel::{AsChangeset, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -25,11 +27,21 @@ pub struct NewGame {
     pub module_lock: f64,
     pub exercise_lock: bool,
     pub total_exercises: i32,
+    pub success_threshold: Option<f64>,
+    pub max_attempts_per_exercise: Option<i32>,
+    pub min_meaningful_result: Option<f64>,
     pub start_date: DateTime<Utc>,
     pub end_date: DateTime<Utc>,
     // created_at, updated_at have DB defaults
 }
 
+#[derive(Insertable, Debug)]
+#[diesel(table_name = game_exercises)]
+pub struct NewGameExercise {
+    pub game_id: i64,
+    pub exercise_id: i64,
+}
+
 #[derive(Insertable, Debug)]
 #[diesel(table_name = game_ownership)]
 pub struct NewGameOwnership {
@@ -71,6 +83,7 @@ pub struct NewPlayer {
     // points defaults to 0 in DB
     // created_at, last_active have DB defaults
     // disabled defaults to false in DB
+    // deleted_at is NULL until the player is soft-deleted
 }
 
 #[derive(Insertable)]
@@ -90,11 +103,90 @@ pub struct GameChangeset {
     pub description: Option<String>,
     pub module_lock: Option<f64>,
     pub exercise_lock: Option<bool>,
+    pub success_threshold: Option<f64>,
+    pub max_attempts_per_exercise: Option<i32>,
+    pub min_meaningful_result: Option<f64>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
+#[derive(AsChangeset, Debug, Default)]
+#[diesel(table_name = groups)]
+pub struct GroupChangeset {
+    pub display_name: Option<String>,
+    pub display_avatar: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ImportSubmissionsResponse {
+    pub imported: i64,
+    pub first_solutions: i64,
+}
+
+/// Variant order is the tie-breaker between events sharing a timestamp.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineEventType {
+    Submission,
+    Join,
+    Leave,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TimelineEvent {
+    pub event_type: TimelineEventType,
+    pub player_id: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub payload: JsonValue,
+}
+
+/// Position of an event in the timeline; fields are compared in declaration order.
+/// `id` is the submission id or the registration id, depending on `event_type`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimelineCursor {
+    pub occurred_at: DateTime<Utc>,
+    pub event_type: TimelineEventType,
+    pub id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GameTimelineResponse {
+    pub events: Vec<TimelineEvent>,
+    pub next_cursor: Option<TimelineCursor>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct RecentSubmission {
+    pub id: i64,
+    pub player_id: i64,
+    pub exercise_id: i64,
+    /// Score as a percentage on a 0-100 scale.
+    #[serde(serialize_with = "crate::model::score::serialize")]
+    pub result: BigDecimal,
+    pub first_solution: bool,
+    pub entered_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct DisabledPlayerResponse {
+    pub id: i64,
+    pub email: String,
+    pub display_name: String,
+    pub last_active: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IntegrityReportResponse {
+    pub orphaned_submissions: i64,
+    pub orphaned_registrations: i64,
+    pub orphaned_memberships: i64,
+    pub orphaned_unlocks: i64,
+    pub orphaned_rewards: i64,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct InstructorGameMetadataResponse {
+    pub id: i64,
     pub title: String,
     pub description: String,
     pub active: bool,
@@ -106,11 +198,32 @@ pub struct InstructorGameMetadataResponse {
     pub player_count: i64,
 }
 
+#[derive(Queryable, Deserialize, Serialize, Debug)]
+pub struct GameEndingSoonSummary {
+    pub id: i64,
+    pub title: String,
+    pub course_id: i64,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CourseGameSummary {
+    pub id: i64,
+    pub title: String,
+    pub active: bool,
+    pub public: bool,
+    pub player_count: i64,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct StudentProgressResponse {
     pub attempts: i64,
     pub solved_exercises: i64,
     pub progress: f64,
+    pub time_spent_seconds: i64,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -119,6 +232,12 @@ pub struct StudentExercisesResponse {
     pub solved_exercises: Vec<i64>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct UnattemptedExerciseResponse {
+    pub id: i64,
+    pub title: String,
+}
+
 #[derive(Deserialize, Serialize, Debug, Queryable)]
 pub struct SubmissionDataResponse {
     pub id: i64,
@@ -128,6 +247,8 @@ pub struct SubmissionDataResponse {
     pub client: String,
     pub submitted_code: String,
     pub metrics: JsonValue,
+    /// Score as a percentage on a 0-100 scale.
+    #[serde(serialize_with = "crate::model::score::serialize")]
     pub result: BigDecimal,
     pub result_description: JsonValue,
     pub first_solution: bool,
@@ -135,6 +256,15 @@ pub struct SubmissionDataResponse {
     pub earned_rewards: JsonValue,
     pub entered_at: DateTime<Utc>,
     pub submitted_at: DateTime<Utc>,
+    pub time_spent_seconds: Option<i32>,
+}
+
+/// Submissions of `get_student_submissions`: bare IDs, or full submissions when `include_code` is set.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(untagged)]
+pub enum StudentSubmissions {
+    Ids(Vec<i64>),
+    Submissions(Vec<SubmissionDataResponse>),
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -143,6 +273,78 @@ pub struct ExerciseStatsResponse {
     pub successful_attempts: i64,
     pub difficulty: f64,
     pub solved_percentage: f64,
+    pub average_time_to_solve_seconds: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GameExerciseStats {
+    pub exercise_id: i64,
+    #[serde(flatten)]
+    pub stats: ExerciseStatsResponse,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StrugglingStudent {
+    pub player_id: i64,
+    pub exercise_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct FirstSolver {
+    pub player_id: i64,
+    pub display_name: String,
+    /// `entered_at` of the player's first solution.
+    pub entered_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ScoreHistogramBucket {
+    pub min_score: i32,
+    pub max_score: i32,
+    pub count: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ProgressDistributionBucket {
+    pub min_progress: i32,
+    pub max_progress: i32,
+    pub players: i64,
+}
+
+#[derive(Queryable, Deserialize, Serialize, Debug)]
+pub struct PlayerActivityDay {
+    pub date: NaiveDate,
+    pub submission_count: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GroupStatsResponse {
+    pub members: i64,
+    pub total_submissions: i64,
+    pub solved_exercises: i64,
+    pub average_progress: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct GroupMemberResponse {
+    pub player_id: i64,
+    pub display_name: String,
+    pub email: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct PlayerGroupResponse {
+    pub group_id: i64,
+    pub display_name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct GroupMetadataResponse {
+    pub id: i64,
+    pub display_name: String,
+    pub display_avatar: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Insertable, Debug)]
@@ -157,6 +359,8 @@ pub struct NewInvite {
 #[derive(Deserialize, Serialize, Debug)]
 pub struct InviteLinkResponse {
     pub invite_uuid: Uuid,
+    /// Full join link built on `--public-base-url`, if configured.
+    pub invite_url: Option<String>,
 }
 
 #[derive(Queryable, Debug)]
@@ -167,4 +371,66 @@ pub struct Invite {
     pub instructor_id: i64,
     pub game_id: Option<i64>,
     pub group_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct InviteSummary {
+    pub id: i64,
+    pub uuid: Uuid,
+    pub game_id: Option<i64>,
+    pub group_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessInviteResult {
+    /// The invited player, newly created if the invite provisioned them.
+    pub player_id: i64,
+    pub joined_game: bool,
+    pub added_to_group: bool,
+    pub already_in_game: bool,
+    pub already_in_group: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct InviteDetailsResponse {
+    pub uuid: Uuid,
+    pub game_id: Option<i64>,
+    pub game_title: Option<String>,
+    pub group_id: Option<i64>,
+    pub group_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Insertable, Debug)]
+#[diesel(table_name = game_end_summaries)]
+pub struct NewGameEndSummary {
+    pub game_id: i64,
+    pub player_count: i32,
+    pub average_progress: f64,
+    // created_at has a DB default (CURRENT_TIMESTAMP)
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct GameEndSummaryResponse {
+    pub game_id: i64,
+    pub player_count: i32,
+    pub average_progress: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GameExercisesResponse {
+    pub total_exercises: i32,
+    /// The game's exercise subset; empty if the game runs every exercise of its course.
+    pub exercise_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Queryable)]
+pub struct GameInstructorResponse {
+    pub instructor_id: i64,
+    pub email: String,
+    pub display_name: String,
+    pub owner: bool,
 }
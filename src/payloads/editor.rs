@@ -6,6 +6,8 @@ use std::vec::Vec;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ImportExerciseData {
+    /// Ignored on import; exported bundles leave it out.
+    #[serde(default)]
     pub version: BigDecimal,
     pub order: i32,
     pub title: String,
@@ -70,6 +72,9 @@ pub struct ImportCoursePayload {
     pub instructor_id: i64,
     #[serde(default)]
     pub public: bool,
+    /// Runs the import in a transaction that is rolled back, returning a summary instead.
+    #[serde(default)]
+    pub dry_run: bool,
     pub course_data: ImportCourseData,
 }
 
@@ -82,3 +87,81 @@ pub struct ExportCourseParams {
     pub instructor_id: i64,
     pub course_id: i64,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct ExportModuleParams {
+    pub instructor_id: i64,
+    pub module_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ImportModulePayload {
+    pub instructor_id: i64,
+    pub course_id: i64,
+    pub module_data: ImportModuleData,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetCourseTreeParams {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetCourseExerciseDifficultyParams {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetExerciseGamesParams {
+    pub instructor_id: i64,
+    pub exercise_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetCourseEnrollmentSummaryParams {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct DeleteCoursePayload {
+    pub instructor_id: i64,
+    pub course_id: i64,
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExerciseTagPayload {
+    pub instructor_id: i64,
+    pub exercise_id: i64,
+    pub tag: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ReorderModulesPayload {
+    pub instructor_id: i64,
+    pub course_id: i64,
+    pub module_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ReorderExercisesPayload {
+    pub instructor_id: i64,
+    pub module_id: i64,
+    pub exercise_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RenumberCoursePayload {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RecalculateCourseGamesPayload {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
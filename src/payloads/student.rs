@@ -14,6 +14,9 @@ pub struct JoinGamePayload {
 pub struct SaveGamePayload {
     pub player_registrations_id: i64,
     pub game_state: JsonValue,
+    /// The `saved_at` the client last read; the save is rejected if the stored state is newer.
+    #[serde(default)]
+    pub if_saved_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -21,6 +24,24 @@ pub struct LoadGamePayload {
     pub player_registrations_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SaveCheckpointPayload {
+    pub player_registrations_id: i64,
+    pub name: String,
+    pub game_state: JsonValue,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct LoadCheckpointPayload {
+    pub player_registrations_id: i64,
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListCheckpointsParams {
+    pub player_registrations_id: i64,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct LeaveGamePayload {
     pub player_id: i64,
@@ -38,6 +59,15 @@ pub struct SetGameLangPayload {
 pub struct GetPlayerGamesParams {
     pub player_id: i64,
     pub active: bool,
+    #[serde(default)]
+    pub include_left: bool,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerRegistrationsParams {
+    pub player_id: i64,
 }
 
 #[derive(Deserialize, Debug)]
@@ -46,6 +76,33 @@ pub struct GetCourseDataParams {
     pub language: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetCourseTreeParams {
+    pub game_id: i64,
+    pub player_id: i64,
+    pub language: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CatalogPageParams {
+    pub after_id: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetAvailableGamesParams {
+    /// Also include the private games this player is eligible for.
+    pub player_id: Option<i64>,
+    pub after_id: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListExercisesByTagParams {
+    pub game_id: i64,
+    pub tag: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct GetModuleDataParams {
     pub module_id: i64,
@@ -68,11 +125,14 @@ pub struct SubmitSolutionPayload {
     pub client: String,
     pub submitted_code: String,
     pub metrics: JsonValue,
+    /// Score as a percentage on a 0-100 scale.
     pub result: BigDecimal,
     pub result_description: JsonValue,
     pub feedback: String,
     pub entered_at: DateTime<Utc>,
     pub earned_rewards: JsonValue,
+    #[serde(default)]
+    pub time_spent_seconds: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -81,8 +141,60 @@ pub struct UnlockPayload {
     pub exercise_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UnlockManyPayload {
+    pub player_id: i64,
+    pub game_id: i64,
+    pub exercise_ids: Vec<i64>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct GetLastSolutionParams {
     pub player_id: i64,
     pub exercise_id: i64,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct GetCourseModulesDataParams {
+    pub player_id: i64,
+    pub game_id: i64,
+    pub language: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetExerciseLockStatesParams {
+    pub player_id: i64,
+    pub game_id: i64,
+    pub module_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerScoreParams {
+    pub player_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerRankParams {
+    pub player_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetExerciseWorkspaceParams {
+    pub player_id: i64,
+    pub game_id: i64,
+    pub exercise_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerStreakParams {
+    pub player_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetCompletionStatusParams {
+    pub player_id: i64,
+    pub game_id: i64,
+}
@@ -1,9 +1,15 @@
+use crate::model::teacher::TimelineEventType;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use uuid::Uuid;
 
 #[derive(Deserialize, Debug)]
 pub struct GetInstructorGamesParams {
     pub instructor_id: i64,
+    pub active: Option<bool>,
+    pub public: Option<bool>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -12,6 +18,26 @@ pub struct GetInstructorGameMetadataParams {
     pub game_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetGamesMetadataPayload {
+    pub instructor_id: i64,
+    pub game_ids: Vec<i64>,
+    #[serde(default)]
+    pub skip_forbidden: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGamesByCourseParams {
+    pub instructor_id: i64,
+    pub course_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGamesEndingSoonParams {
+    pub instructor_id: i64,
+    pub within_days: i64,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct ListStudentsParams {
     pub instructor_id: i64,
@@ -35,6 +61,13 @@ pub struct GetStudentExercisesParams {
     pub player_id: i64,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetStudentUnattemptedExercisesParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub player_id: i64,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct GetStudentSubmissionsParams {
     pub instructor_id: i64,
@@ -42,6 +75,22 @@ pub struct GetStudentSubmissionsParams {
     pub player_id: i64,
     #[serde(default)]
     pub success_only: bool,
+    #[serde(default)]
+    pub include_code: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetStudentBestSubmissionsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub player_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetStrugglingStudentsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub min_failed_attempts: i64,
 }
 
 #[derive(Deserialize, Debug)]
@@ -50,6 +99,14 @@ pub struct GetSubmissionDataParams {
     pub submission_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetSubmissionsDataPayload {
+    pub instructor_id: i64,
+    pub submission_ids: Vec<i64>,
+    #[serde(default)]
+    pub skip_forbidden: bool,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct GetExerciseStatsParams {
     pub instructor_id: i64,
@@ -57,6 +114,46 @@ pub struct GetExerciseStatsParams {
     pub exercise_id: i64,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetAllExerciseStatsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetExerciseScoreHistogramParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub exercise_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetProgressDistributionParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetExerciseFirstSolversParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub exercise_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetStudentScoreParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub player_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerActivityByDayParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub player_id: i64,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct GetExerciseSubmissionsParams {
     pub instructor_id: i64,
@@ -66,6 +163,25 @@ pub struct GetExerciseSubmissionsParams {
     pub success_only: bool,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetGroupStatsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub group_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGameEndSummaryParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGameInstructorsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct CreateGamePayload {
     pub instructor_id: i64,
@@ -82,6 +198,13 @@ pub struct CreateGamePayload {
     pub module_lock: f64,
     #[serde(default)]
     pub exercise_lock: bool,
+    #[serde(default)]
+    pub success_threshold: Option<f64>,
+    #[serde(default)]
+    pub max_attempts_per_exercise: Option<i32>,
+    /// Submissions below this result are left out of attempt statistics; all count when unset.
+    #[serde(default)]
+    pub min_meaningful_result: Option<f64>,
     // start_date and end_date are not in payload, will be defaulted
 }
 
@@ -96,6 +219,10 @@ pub struct ModifyGamePayload {
     pub description: Option<String>,
     pub module_lock: Option<f64>,
     pub exercise_lock: Option<bool>,
+    pub success_threshold: Option<f64>,
+    pub max_attempts_per_exercise: Option<i32>,
+    #[serde(default)]
+    pub min_meaningful_result: Option<f64>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -133,6 +260,35 @@ pub struct RemoveGameStudentPayload {
     pub student_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IncludeGameExercisesPayload {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub exercise_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExcludeGameExercisesPayload {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub exercise_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RemoveGameStudentsPayload {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub student_ids: Vec<i64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GrantRewardPayload {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub reward_id: i64,
+    pub player_ids: Vec<i64>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct TranslateEmailParams {
     pub email: String,
@@ -147,12 +303,61 @@ pub struct CreateGroupPayload {
     pub member_list: Vec<i64>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetGroupMetadataParams {
+    pub instructor_id: i64,
+    pub group_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGroupMembersParams {
+    pub instructor_id: i64,
+    pub group_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetPlayerGroupsParams {
+    pub instructor_id: i64,
+    pub player_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ModifyGroupPayload {
+    pub instructor_id: i64,
+    pub group_id: i64,
+    pub display_name: Option<String>,
+    pub display_avatar: Option<String>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct DissolveGroupPayload {
     pub instructor_id: i64,
     pub group_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MergeGroupsPayload {
+    pub instructor_id: i64,
+    pub source_group_id: i64,
+    pub target_group_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CloneGroupPayload {
+    pub instructor_id: i64,
+    pub source_group_id: i64,
+    pub display_name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct TransferGroupOwnershipPayload {
+    pub current_owner_id: i64,
+    pub group_id: i64,
+    pub new_owner_id: i64,
+    #[serde(default)]
+    pub relinquish: bool,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct AddGroupMemberPayload {
     pub instructor_id: i64,
@@ -176,7 +381,7 @@ pub struct CreatePlayerPayload {
     pub game_id: Option<i64>,
     pub group_id: Option<i64>,
     // Optional language if adding to game (needed for NewPlayerRegistration)
-    // Defaulting to "en" if game_id is Some and language is None
+    // Defaults to the configured `--default-languages` the game's course offers
     pub language: Option<String>,
 }
 
@@ -186,12 +391,86 @@ pub struct DisablePlayerPayload {
     pub player_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ImportedSubmissionRecord {
+    pub player_id: i64,
+    pub exercise_id: i64,
+    /// Score as a percentage on a 0-100 scale.
+    pub result: BigDecimal,
+    #[serde(default = "default_json_object")]
+    pub result_description: JsonValue,
+    pub entered_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ImportSubmissionsPayload {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub submissions: Vec<ImportedSubmissionRecord>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetGameTimelineParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub after: Option<DateTime<Utc>>,
+    pub after_type: Option<TimelineEventType>,
+    pub after_id: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetRecentSubmissionsParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub since: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListExercisesByTagParams {
+    pub instructor_id: i64,
+    pub game_id: i64,
+    pub tag: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ListDisabledPlayersParams {
+    pub instructor_id: i64,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetAllPlayerSubmissionsParams {
+    pub instructor_id: i64,
+    pub player_id: i64,
+    pub offset: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CheckIntegrityParams {
+    pub instructor_id: i64,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct DeletePlayerPayload {
     pub instructor_id: i64,
     pub player_id: i64,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SoftDeletePlayerPayload {
+    pub instructor_id: i64,
+    pub player_id: i64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RestorePlayerPayload {
+    pub instructor_id: i64,
+    pub player_id: i64,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GenerateInviteLinkPayload {
     pub instructor_id: i64,
@@ -199,8 +478,28 @@ pub struct GenerateInviteLinkPayload {
     pub group_id: Option<i64>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct ListInvitesParams {
+    pub instructor_id: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetInviteDetailsParams {
+    pub uuid: Uuid,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ProcessInviteLinkPayload {
     pub player_id: i64,
     pub uuid: Uuid,
+    /// Email for provisioning a new player when `player_id` does not exist yet.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Display name of the provisioned player; defaults to the part of `email` before `@`.
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
+
+fn default_json_object() -> JsonValue {
+    serde_json::json!({})
 }
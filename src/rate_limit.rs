@@ -0,0 +1,135 @@
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// In-memory sliding window limiter counting events per key (e.g. per instructor).
+#[derive(Debug)]
+pub(crate) struct SlidingWindowLimiter {
+    limit: usize,
+    window: Duration,
+    events: Mutex<HashMap<i64, VecDeque<Instant>>>,
+}
+
+impl SlidingWindowLimiter {
+    /// Creates a limiter allowing `limit` events per key within `window`. A zero limit disables it.
+    pub(crate) fn new(limit: usize, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            events: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records an event for `key` if it is still under the limit.
+    /// Returns false, without recording anything, if the limit has been reached.
+    pub(crate) fn try_acquire(&self, key: i64) -> bool {
+        if self.limit == 0 {
+            return true;
+        }
+
+        let now = Instant::now();
+        let mut events = self.events.lock().unwrap_or_else(|e| e.into_inner());
+        let timestamps = events.entry(key).or_default();
+        while timestamps
+            .front()
+            .is_some_and(|oldest| now.duration_since(*oldest) >= self.window)
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= self.limit {
+            return false;
+        }
+        timestamps.push_back(now);
+        true
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Maximum number of client addresses tracked at once.
+const MAX_TRACKED_ADDRESSES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// In-memory token bucket limiter per client IP address.
+/// At most `MAX_TRACKED_ADDRESSES` buckets are kept; see `evict`.
+#[derive(Debug)]
+pub(crate) struct IpRateLimiter {
+    rate_per_sec: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+}
+
+impl IpRateLimiter {
+    /// Creates a limiter refilling `rate_per_sec` tokens per second, up to `burst` tokens per address.
+    /// A zero rate disables it; a zero burst is treated as one request.
+    pub(crate) fn new(rate_per_sec: u32, burst: u32) -> Self {
+        Self {
+            rate_per_sec: f64::from(rate_per_sec),
+            burst: f64::from(burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Takes a token from the bucket of `ip`.
+    /// Returns the time until the next token is available, without taking anything, if it is empty.
+    pub(crate) fn try_acquire(&self, ip: IpAddr) -> Result<(), Duration> {
+        if self.rate_per_sec == 0.0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if !buckets.contains_key(&ip) && buckets.len() >= MAX_TRACKED_ADDRESSES {
+            self.evict(&mut buckets, now);
+        }
+
+        let bucket = buckets.entry(ip).or_insert(TokenBucket {
+            tokens: self.burst,
+            refilled_at: now,
+        });
+        *bucket = self.refill(*bucket, now);
+        if bucket.tokens < 1.0 {
+            return Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.rate_per_sec,
+            ));
+        }
+        bucket.tokens -= 1.0;
+        Ok(())
+    }
+
+    /// Shrinks a full map to half its capacity, so eviction runs once per `MAX_TRACKED_ADDRESSES / 2`
+    /// new addresses. Buckets that refilled completely go first, as dropping them loses nothing;
+    /// if that is not enough, the least recently seen addresses are dropped as well.
+    fn evict(&self, buckets: &mut HashMap<IpAddr, TokenBucket>, now: Instant) {
+        buckets.retain(|_, bucket| self.refill(*bucket, now).tokens < self.burst);
+
+        let keep = MAX_TRACKED_ADDRESSES / 2;
+        if buckets.len() > keep {
+            let mut last_seen: Vec<(Instant, IpAddr)> = buckets
+                .iter()
+                .map(|(ip, bucket)| (bucket.refilled_at, *ip))
+                .collect();
+            last_seen.sort_unstable();
+            for (_, ip) in &last_seen[..last_seen.len() - keep] {
+                buckets.remove(ip);
+            }
+        }
+    }
+
+    fn refill(&self, bucket: TokenBucket, now: Instant) -> TokenBucket {
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        TokenBucket {
+            tokens: (bucket.tokens + elapsed * self.rate_per_sec).min(self.burst),
+            refilled_at: now,
+        }
+    }
+}
@@ -1,7 +1,54 @@
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use tracing::log::warn;
+
+/// Media type of MessagePack-encoded responses.
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Encoding of `ApiResponse` bodies, negotiated from the request's `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResponseFormat {
+    Json,
+    MessagePack,
+}
+
+impl ResponseFormat {
+    /// MessagePack if `Accept` lists `application/msgpack` (or `application/x-msgpack`), JSON otherwise.
+    pub(crate) fn from_accept(request_headers: &HeaderMap) -> Self {
+        let wants_msgpack = request_headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|range| range.split(';').next())
+            .map(str::trim)
+            .any(|media_type| {
+                media_type.eq_ignore_ascii_case(MSGPACK_CONTENT_TYPE)
+                    || media_type.eq_ignore_ascii_case("application/x-msgpack")
+            });
+        if wants_msgpack {
+            ResponseFormat::MessagePack
+        } else {
+            ResponseFormat::Json
+        }
+    }
+
+    /// The format negotiated for the request being handled, JSON outside of `negotiate_response_format`.
+    fn current() -> Self {
+        RESPONSE_FORMAT
+            .try_with(|format| *format)
+            .unwrap_or(ResponseFormat::Json)
+    }
+}
+
+tokio::task_local! {
+    /// Set by `middleware::negotiate_response_format` for the duration of a request.
+    pub(crate) static RESPONSE_FORMAT: ResponseFormat;
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ApiResponse<T: Serialize> {
@@ -24,6 +71,65 @@ impl<T: Serialize> ApiResponse<T> {
             data: Some(data),
         }
     }
+
+    /// Converts the response into one carrying a weak `ETag` derived from its serialized body.
+    /// Returns an empty 304 Not Modified if the request's `If-None-Match` already lists that tag.
+    pub fn into_conditional_response(self, request_headers: &HeaderMap) -> Response {
+        let Some((content_type, body)) = self.encode() else {
+            return self.into_response();
+        };
+
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        let etag = format!("W/\"{:016x}\"", hasher.finish());
+        let Ok(etag_value) = HeaderValue::from_str(&etag) else {
+            return self.into_response();
+        };
+
+        if if_none_match_contains(request_headers, &etag) {
+            return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_value)]).into_response();
+        }
+
+        let status =
+            StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (
+            status,
+            [
+                (header::CONTENT_TYPE, content_type),
+                (header::ETAG, etag_value),
+            ],
+            body,
+        )
+            .into_response()
+    }
+
+    /// Serializes the response in the negotiated `ResponseFormat`, returning its content type and body.
+    fn encode(&self) -> Option<(HeaderValue, Vec<u8>)> {
+        match ResponseFormat::current() {
+            ResponseFormat::Json => serde_json::to_vec(self)
+                .ok()
+                .map(|body| (HeaderValue::from_static("application/json"), body)),
+            ResponseFormat::MessagePack => match rmp_serde::to_vec_named(self) {
+                Ok(body) => Some((HeaderValue::from_static(MSGPACK_CONTENT_TYPE), body)),
+                Err(e) => {
+                    warn!("Failed to encode response as MessagePack: {}", e);
+                    None
+                }
+            },
+        }
+    }
+}
+
+/// Checks `If-None-Match` against an ETag using weak comparison, as required for GET requests.
+fn if_none_match_contains(request_headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let wanted = opaque(etag);
+    request_headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == wanted)
 }
 
 impl<T: Serialize> IntoResponse for ApiResponse<T> {
@@ -31,6 +137,12 @@ impl<T: Serialize> IntoResponse for ApiResponse<T> {
         let status =
             StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
+        if ResponseFormat::current() == ResponseFormat::MessagePack
+            && let Some((content_type, body)) = self.encode()
+        {
+            return (status, [(header::CONTENT_TYPE, content_type)], body).into_response();
+        }
+
         let body = Json(self);
 
         (status, body).into_response()
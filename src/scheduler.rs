@@ -0,0 +1,155 @@
+use crate::model::teacher::NewGameEndSummary;
+use crate::schema::{
+    game_end_summaries::dsl as ges_dsl, games::dsl as games_dsl,
+    player_registrations::dsl as pr_dsl,
+};
+use anyhow::anyhow;
+use chrono::Utc;
+use deadpool_diesel::postgres::Pool;
+use diesel::prelude::*;
+use diesel::sql_types::Timestamptz;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::log::{debug, error, info};
+
+/// Interval between runs of the submission archiver.
+const SUBMISSION_ARCHIVE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Spawns a background task that periodically records end summaries for finished games.
+/// Returns `None` if the interval is zero (scanner disabled).
+pub fn spawn_game_end_scanner(pool: Pool, interval: Duration) -> Option<JoinHandle<()>> {
+    if interval.is_zero() {
+        info!("Game end scanner disabled (interval set to 0).");
+        return None;
+    }
+
+    info!("Starting game end scanner with interval {:?}", interval);
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match scan_ended_games(&pool).await {
+                Ok(0) => debug!("Game end scan finished, no new summaries."),
+                Ok(created) => info!("Game end scan recorded {} new summaries.", created),
+                Err(e) => error!("Game end scan failed: {:?}", e),
+            }
+        }
+    }))
+}
+
+/// Performs a single scan tick.
+/// Records an end summary (player count, average progress) for every game whose end date
+/// has passed and which has no summary yet.
+/// Returns the number of summaries created.
+pub async fn scan_ended_games(pool: &Pool) -> anyhow::Result<usize> {
+    let conn = pool.get().await?;
+    conn.interact(|conn_sync| {
+        conn_sync.transaction(|tx_conn| {
+            let ended_games = games_dsl::games
+                .left_join(ges_dsl::game_end_summaries)
+                .filter(games_dsl::end_date.le(Utc::now()))
+                .filter(ges_dsl::id.is_null())
+                .select((games_dsl::id, games_dsl::total_exercises))
+                .load::<(i64, i32)>(tx_conn)?;
+
+            let mut created = 0;
+            for (game_id, total_exercises) in ended_games {
+                let progresses = pr_dsl::player_registrations
+                    .filter(pr_dsl::game_id.eq(game_id))
+                    .select(pr_dsl::progress)
+                    .load::<i32>(tx_conn)?;
+
+                let average_progress = if !progresses.is_empty() && total_exercises > 0 {
+                    let total: f64 = progresses
+                        .iter()
+                        .map(|progress| *progress as f64 / total_exercises as f64 * 100.0)
+                        .sum();
+                    total / progresses.len() as f64
+                } else {
+                    0.0
+                };
+
+                let new_summary = NewGameEndSummary {
+                    game_id,
+                    player_count: progresses.len() as i32,
+                    average_progress,
+                };
+                created += diesel::insert_into(ges_dsl::game_end_summaries)
+                    .values(&new_summary)
+                    .on_conflict(ges_dsl::game_id)
+                    .do_nothing()
+                    .execute(tx_conn)?;
+                info!(
+                    "Recorded end summary for game {}: {} players, average progress {:.2}%",
+                    game_id, new_summary.player_count, average_progress
+                );
+            }
+            Ok::<usize, diesel::result::Error>(created)
+        })
+    })
+    .await
+    .map_err(|e| anyhow!("Interaction error during game end scan: {}", e))?
+    .map_err(anyhow::Error::from)
+}
+
+/// Spawns a background task that hourly moves submissions older than `retention` to
+/// `archived_submissions` (see `archive_old_submissions`).
+/// Returns `None` if the retention is zero (archiver disabled).
+pub fn spawn_submission_archiver(pool: Pool, retention: Duration) -> Option<JoinHandle<()>> {
+    if retention.is_zero() {
+        info!("Submission archiver disabled (retention set to 0).");
+        return None;
+    }
+
+    info!(
+        "Starting submission archiver with retention {:?}",
+        retention
+    );
+    Some(tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(SUBMISSION_ARCHIVE_INTERVAL);
+        loop {
+            ticker.tick().await;
+            match archive_old_submissions(&pool, retention).await {
+                Ok(0) => debug!("Submission archiving finished, nothing to archive."),
+                Ok(archived) => info!("Archived {} old submissions.", archived),
+                Err(e) => error!("Submission archiving failed: {:?}", e),
+            }
+        }
+    }))
+}
+
+/// Performs a single archiving run.
+/// Moves every submission entered more than `retention` ago in a game whose end date has passed
+/// to `archived_submissions`, storing the full row as a JSONB snapshot. A player's latest
+/// first solution of each exercise is kept in place so grades stay intact.
+/// Returns the number of submissions archived.
+pub async fn archive_old_submissions(pool: &Pool, retention: Duration) -> anyhow::Result<usize> {
+    let cutoff = Utc::now() - chrono::Duration::from_std(retention)?;
+    let conn = pool.get().await?;
+    conn.interact(move |conn_sync| {
+        diesel::sql_query(
+            "WITH moved AS ( \
+                DELETE FROM submissions s USING games g \
+                WHERE g.id = s.game_id \
+                  AND g.end_date <= NOW() \
+                  AND s.entered_at < $1 \
+                  AND NOT (s.first_solution AND NOT EXISTS ( \
+                      SELECT 1 FROM submissions later \
+                      WHERE later.player_id = s.player_id \
+                        AND later.game_id = s.game_id \
+                        AND later.exercise_id = s.exercise_id \
+                        AND later.first_solution \
+                        AND (later.entered_at, later.id) > (s.entered_at, s.id))) \
+                RETURNING s.* \
+            ) \
+            INSERT INTO archived_submissions \
+                (submission_id, game_id, player_id, exercise_id, entered_at, snapshot) \
+            SELECT id, game_id, player_id, exercise_id, entered_at, to_jsonb(moved) FROM moved",
+        )
+        .bind::<Timestamptz, _>(cutoff)
+        .execute(conn_sync)
+    })
+    .await
+    .map_err(|e| anyhow!("Interaction error during submission archiving: {}", e))?
+    .map_err(anyhow::Error::from)
+}
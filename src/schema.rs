@@ -1,5 +1,30 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    archived_games (id) {
+        id -> Int8,
+        game_id -> Int8,
+        course_id -> Int8,
+        #[max_length = 255]
+        title -> Varchar,
+        snapshot -> Jsonb,
+        archived_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    archived_submissions (id) {
+        id -> Int8,
+        submission_id -> Int8,
+        game_id -> Int8,
+        player_id -> Int8,
+        exercise_id -> Int8,
+        entered_at -> Timestamptz,
+        snapshot -> Jsonb,
+        archived_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     course_ownership (course_id, instructor_id) {
         course_id -> Int8,
@@ -25,6 +50,15 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    exercise_tags (exercise_id, tag) {
+        exercise_id -> Int8,
+        #[max_length = 50]
+        tag -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     exercises (id) {
         id -> Int8,
@@ -55,6 +89,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    game_checkpoints (id) {
+        id -> Int8,
+        player_registrations_id -> Int8,
+        #[max_length = 100]
+        name -> Varchar,
+        game_state -> Jsonb,
+        saved_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    game_end_summaries (id) {
+        id -> Int8,
+        game_id -> Int8,
+        player_count -> Int4,
+        average_progress -> Float8,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    game_exercises (game_id, exercise_id) {
+        game_id -> Int8,
+        exercise_id -> Int8,
+    }
+}
+
 diesel::table! {
     game_ownership (game_id, instructor_id) {
         game_id -> Int8,
@@ -77,6 +139,9 @@ diesel::table! {
         module_lock -> Float8,
         exercise_lock -> Bool,
         total_exercises -> Int4,
+        success_threshold -> Nullable<Float8>,
+        max_attempts_per_exercise -> Nullable<Int4>,
+        min_meaningful_result -> Nullable<Float8>,
         start_date -> Timestamptz,
         end_date -> Timestamptz,
         created_at -> Timestamptz,
@@ -98,6 +163,8 @@ diesel::table! {
         #[max_length = 100]
         display_name -> Varchar,
         display_avatar -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -121,6 +188,7 @@ diesel::table! {
         instructor_id -> Int8,
         game_id -> Nullable<Int8>,
         group_id -> Nullable<Int8>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -196,6 +264,7 @@ diesel::table! {
         created_at -> Timestamptz,
         last_active -> Timestamptz,
         disabled -> Bool,
+        deleted_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -229,12 +298,18 @@ diesel::table! {
         earned_rewards -> Jsonb,
         entered_at -> Timestamptz,
         submitted_at -> Timestamptz,
+        time_spent_seconds -> Nullable<Int4>,
     }
 }
 
 diesel::joinable!(course_ownership -> courses (course_id));
 diesel::joinable!(course_ownership -> instructors (instructor_id));
+diesel::joinable!(exercise_tags -> exercises (exercise_id));
 diesel::joinable!(exercises -> modules (module_id));
+diesel::joinable!(game_checkpoints -> player_registrations (player_registrations_id));
+diesel::joinable!(game_end_summaries -> games (game_id));
+diesel::joinable!(game_exercises -> exercises (exercise_id));
+diesel::joinable!(game_exercises -> games (game_id));
 diesel::joinable!(game_ownership -> games (game_id));
 diesel::joinable!(game_ownership -> instructors (instructor_id));
 diesel::joinable!(games -> courses (course_id));
@@ -259,9 +334,15 @@ diesel::joinable!(submissions -> games (game_id));
 diesel::joinable!(submissions -> players (player_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    archived_games,
+    archived_submissions,
     course_ownership,
     courses,
+    exercise_tags,
     exercises,
+    game_checkpoints,
+    game_end_summaries,
+    game_exercises,
     game_ownership,
     games,
     group_ownership,
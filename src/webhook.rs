@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tracing::{debug, error, info, warn};
+use url::Url;
+
+/// Sent after a game is activated by `activate_game`.
+pub const GAME_ACTIVATED: &str = "game.activated";
+/// Sent after a game is deactivated by `stop_game`.
+pub const GAME_STOPPED: &str = "game.stopped";
+/// Sent for each game archived by a forced `delete_course`.
+pub const GAME_ARCHIVED: &str = "game.archived";
+/// Every event the webhook can be subscribed to.
+pub const GAME_LIFECYCLE_EVENTS: [&str; 3] = [GAME_ACTIVATED, GAME_STOPPED, GAME_ARCHIVED];
+
+/// Events queued for delivery at most; further events are dropped until the queue drains.
+const WEBHOOK_QUEUE_CAPACITY: usize = 256;
+/// Timeout of a single delivery.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Body POSTed to the webhook URL.
+#[derive(Serialize, Debug)]
+pub(crate) struct GameLifecycleEvent {
+    pub game_id: i64,
+    pub event: &'static str,
+    pub timestamp: DateTime<Utc>,
+    pub instructor_id: i64,
+}
+
+/// Notifies the URL configured with `--webhook-url` of game lifecycle changes.
+/// Handlers only enqueue events; a background task delivers them in order, so a slow or
+/// unreachable receiver never delays a response.
+#[derive(Debug)]
+pub(crate) struct Webhook {
+    sender: mpsc::Sender<GameLifecycleEvent>,
+    events: Vec<String>,
+}
+
+impl Webhook {
+    /// Starts the delivery task. Must be called within a Tokio runtime.
+    pub(crate) fn spawn(url: Url, events: Vec<String>) -> anyhow::Result<Self> {
+        if let Some(unknown) = events
+            .iter()
+            .find(|event| !GAME_LIFECYCLE_EVENTS.contains(&event.as_str()))
+        {
+            anyhow::bail!("Unknown webhook event '{}'", unknown);
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()?;
+        let (sender, mut receiver) = mpsc::channel::<GameLifecycleEvent>(WEBHOOK_QUEUE_CAPACITY);
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let delivery = client
+                    .post(url.clone())
+                    .json(&event)
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status());
+                match delivery {
+                    Ok(_) => info!(
+                        "Delivered webhook event {} for game {}",
+                        event.event, event.game_id
+                    ),
+                    Err(e) => error!(
+                        "Webhook delivery of {} for game {} failed: {:?}",
+                        event.event, event.game_id, e
+                    ),
+                }
+            }
+        });
+
+        Ok(Self { sender, events })
+    }
+
+    /// Queues `event` for delivery if it is subscribed to. Call only after the change is committed.
+    pub(crate) fn notify(&self, event: &'static str, game_id: i64, instructor_id: i64) {
+        if !self.events.iter().any(|subscribed| subscribed == event) {
+            debug!("Webhook event {} is not subscribed to, skipping", event);
+            return;
+        }
+
+        let payload = GameLifecycleEvent {
+            game_id,
+            event,
+            timestamp: Utc::now(),
+            instructor_id,
+        };
+        match self.sender.try_send(payload) {
+            Ok(()) => {}
+            Err(TrySendError::Full(payload)) => warn!(
+                "Webhook queue is full, dropping event {} for game {}",
+                payload.event, payload.game_id
+            ),
+            Err(TrySendError::Closed(payload)) => error!(
+                "Webhook delivery task has stopped, dropping event {} for game {}",
+                payload.event, payload.game_id
+            ),
+        }
+    }
+}
@@ -1,9 +1,15 @@
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use bigdecimal::BigDecimal;
 use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
-use lightweight_fgpe_server::model::editor::ExportCourseResponse;
+use lightweight_fgpe_server::config::AppConfig;
+use lightweight_fgpe_server::model::editor::{
+    CourseEnrollmentSummary, CourseExerciseDifficulty, CourseTreeResponse, ExerciseGameSummary,
+    ExportCourseResponse, GameExerciseCount, ImportDryRunSummary,
+};
 use lightweight_fgpe_server::payloads::editor::{
-    ImportCourseData, ImportCoursePayload, ImportExerciseData, ImportModuleData,
+    DeleteCoursePayload, ExerciseTagPayload, ImportCourseData, ImportCoursePayload,
+    ImportExerciseData, ImportModuleData, RecalculateCourseGamesPayload, RenumberCoursePayload,
+    ReorderExercisesPayload, ReorderModulesPayload,
 };
 use lightweight_fgpe_server::response::ApiResponse;
 use serde_json::{Value, json};
@@ -11,8 +17,11 @@ use serde_json::{Value, json};
 mod helpers;
 use helpers::{
     check_course_ownership, count_courses, count_exercises_for_module, count_modules_for_course,
-    create_test_course, create_test_course_ownership, create_test_exercise, create_test_instructor,
-    create_test_module, setup_test_environment,
+    create_test_course, create_test_course_ownership, create_test_exercise, create_test_game,
+    create_test_instructor, create_test_module, create_test_player,
+    create_test_player_registration, create_test_submission, get_exercise_ids_in_order,
+    get_exercise_tags, get_module_ids_in_order, setup_test_environment,
+    setup_test_environment_with_config, update_game_status,
 };
 
 // import_course
@@ -21,6 +30,7 @@ fn create_valid_import_payload(instructor_id: i64) -> ImportCoursePayload {
     ImportCoursePayload {
         instructor_id,
         public: false,
+        dry_run: false,
         course_data: ImportCourseData {
             title: "Imported Course".to_string(),
             description: "A course imported via test".to_string(),
@@ -173,6 +183,33 @@ async fn test_import_course_instructor_not_found() {
     );
 }
 
+#[tokio::test]
+async fn test_import_course_unreachable_when_editor_disabled() {
+    let config = AppConfig {
+        enable_editor: false,
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+    create_test_player(&pool, 2681, "no_editor@test.com", "No Editor").await;
+
+    let initial_course_count = count_courses(&pool).await;
+    let response = server
+        .post("/editor/import_course")
+        .json(&create_valid_import_payload(0))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(count_courses(&pool).await, initial_course_count);
+
+    let response = server
+        .get("/teacher/translate_email_to_player_id")
+        .add_query_param("email", "no_editor@test.com")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    assert_eq!(body.data.unwrap(), 2681);
+}
+
 #[tokio::test]
 async fn test_import_course_minimal_payload() {
     let (server, pool) = setup_test_environment().await;
@@ -188,6 +225,7 @@ async fn test_import_course_minimal_payload() {
     let payload = ImportCoursePayload {
         instructor_id,
         public: true,
+        dry_run: false,
         course_data: ImportCourseData {
             title: "Minimal Course".to_string(),
             description: "".to_string(),
@@ -224,6 +262,120 @@ async fn test_import_course_minimal_payload() {
     assert_eq!(count_modules_for_course(&pool, new_course_id).await, 0);
 }
 
+#[tokio::test]
+async fn test_import_course_payload_too_large() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 4;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "importer_big@test.com",
+        "Importer Big",
+    )
+    .await;
+
+    let initial_course_count = count_courses(&pool).await;
+    let mut payload = create_valid_import_payload(instructor_id);
+    payload.course_data.description = "x".repeat(1536 * 1024);
+
+    let response = server.post("/editor/import_course").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 413);
+    assert!(body.data.is_none());
+    assert_eq!(count_courses(&pool).await, initial_course_count);
+}
+
+#[tokio::test]
+async fn test_import_course_accepts_each_exercise_mode() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 1;
+    create_test_instructor(&pool, instructor_id, "modes@test.com", "Modes").await;
+
+    for (mode, mode_parameters) in [
+        ("code", json!({})),
+        ("quiz", json!({"options": ["a", "b"], "answer": 1})),
+        ("fill_in", json!({"blanks": ["x"]})),
+    ] {
+        let mut payload = create_valid_import_payload(instructor_id);
+        let exercise = &mut payload.course_data.modules[0].exercises[0];
+        exercise.mode = mode.to_string();
+        exercise.mode_parameters = mode_parameters;
+
+        let response = server.post("/editor/import_course").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK, "mode {}", mode);
+    }
+    assert_eq!(count_courses(&pool).await, 3);
+}
+
+#[tokio::test]
+async fn test_import_course_rejects_invalid_exercise_mode() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 1;
+    create_test_instructor(&pool, instructor_id, "badmode@test.com", "Bad Mode").await;
+
+    for (mode, mode_parameters) in [
+        ("essay", json!({})),
+        ("code", json!(["not", "an", "object"])),
+        ("quiz", json!({"options": []})),
+        ("fill_in", json!({"answer": "x"})),
+    ] {
+        let mut payload = create_valid_import_payload(instructor_id);
+        let exercise = &mut payload.course_data.modules[0].exercises[0];
+        exercise.mode = mode.to_string();
+        exercise.mode_parameters = mode_parameters;
+
+        let response = server.post("/editor/import_course").json(&payload).await;
+        assert_eq!(
+            response.status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "mode {}",
+            mode
+        );
+        let body: ApiResponse<Value> = response.json();
+        assert!(body.status_message.contains("Exercise 1.1"));
+    }
+    assert_eq!(count_courses(&pool).await, 0);
+}
+
+#[tokio::test]
+async fn test_import_course_dry_run_persists_nothing() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 37;
+    create_test_instructor(&pool, instructor_id, "dry_run@test.com", "Dry Run").await;
+
+    let mut payload = create_valid_import_payload(instructor_id);
+    payload.dry_run = true;
+    let response = server.post("/editor/import_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ImportDryRunSummary> = response.json();
+    let summary = body.data.unwrap();
+    assert_eq!(summary.course_title, "Imported Course");
+    assert_eq!(summary.module_count, 2);
+    assert_eq!(summary.exercise_count, 1);
+    assert_eq!(
+        summary.warnings,
+        vec!["Module 'Module 2' has no exercises.".to_string()]
+    );
+
+    assert_eq!(count_courses(&pool).await, 0);
+    let conn = pool.get().await.unwrap();
+    let (modules, exercises, ownerships) = conn
+        .interact(|conn| {
+            use lightweight_fgpe_server::schema::{course_ownership, exercises, modules};
+            Ok::<_, diesel::result::Error>((
+                modules::table.count().get_result::<i64>(conn)?,
+                exercises::table.count().get_result::<i64>(conn)?,
+                course_ownership::table.count().get_result::<i64>(conn)?,
+            ))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((modules, exercises, ownerships), (0, 0, 0));
+}
+
 // export_course
 
 #[tokio::test]
@@ -232,9 +384,9 @@ async fn test_export_course_success_owner() {
     let instructor_id = 10;
     let course_id = create_test_course(&pool, "Export Course").await;
     let module1_id = create_test_module(&pool, course_id, 1, "Export Mod 1").await;
-    let module2_id = create_test_module(&pool, course_id, 2, "Export Mod 2").await;
-    let ex1_id = create_test_exercise(&pool, module1_id, 1, "Export Ex 1.1").await;
-    let ex2_id = create_test_exercise(&pool, module1_id, 2, "Export Ex 1.2").await;
+    create_test_module(&pool, course_id, 2, "Export Mod 2").await;
+    create_test_exercise(&pool, module1_id, 1, "Export Ex 1.1").await;
+    create_test_exercise(&pool, module1_id, 2, "Export Ex 1.2").await;
 
     create_test_instructor(&pool, instructor_id, "exporter@test.com", "Exporter").await;
     create_test_course_ownership(&pool, instructor_id, course_id, true).await;
@@ -262,6 +414,42 @@ async fn test_export_course_success_owner() {
     assert_eq!(export_data.modules[1].exercises.len(), 0);
 }
 
+#[tokio::test]
+async fn test_export_course_compressed_on_accept_encoding() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 12;
+    let course_id = create_test_course(&pool, "Export Course Compressed").await;
+    for module_order in 1..=5 {
+        let module_id =
+            create_test_module(&pool, course_id, module_order, "Export Mod Compressed").await;
+        for exercise_order in 1..=5 {
+            create_test_exercise(&pool, module_id, exercise_order, "Export Ex Compressed").await;
+        }
+    }
+
+    create_test_instructor(&pool, instructor_id, "exporter_gz@test.com", "Exporter GZ").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, true).await;
+
+    let url = format!(
+        "/editor/export_course?instructor_id={}&course_id={}",
+        instructor_id, course_id
+    );
+
+    let response = server
+        .get(&url)
+        .add_header(header::ACCEPT_ENCODING, "gzip")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header(header::CONTENT_ENCODING), "gzip");
+    assert_eq!(&response.as_bytes()[..2], &[0x1f, 0x8b]);
+
+    let response = server.get(&url).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.maybe_header(header::CONTENT_ENCODING).is_none());
+    let body: ApiResponse<ExportCourseResponse> = response.json();
+    assert_eq!(body.data.unwrap().modules.len(), 5);
+}
+
 #[tokio::test]
 async fn test_export_course_success_admin() {
     let (server, pool) = setup_test_environment().await;
@@ -388,3 +576,807 @@ async fn test_export_course_bad_request_missing_param() {
         .await;
     assert_eq!(response2.status_code(), StatusCode::BAD_REQUEST);
 }
+
+// export_module / import_module
+#[tokio::test]
+async fn test_export_module_import_module_round_trip() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 2691;
+    create_test_instructor(&pool, instructor_id, "modbundle@test.com", "Module Bundle").await;
+    create_test_instructor(&pool, 2692, "modbundle_x@test.com", "Module Bundle X").await;
+    let source_course_id = create_test_course(&pool, "Bundle Source Course").await;
+    let target_course_id = create_test_course(&pool, "Bundle Target Course").await;
+    create_test_course_ownership(&pool, instructor_id, source_course_id, true).await;
+    create_test_course_ownership(&pool, instructor_id, target_course_id, true).await;
+    let module_id = create_test_module(&pool, source_course_id, 1, "Bundled Module").await;
+    create_test_exercise(&pool, module_id, 1, "Bundled Ex 1").await;
+    create_test_exercise(&pool, module_id, 2, "Bundled Ex 2").await;
+    create_test_module(&pool, target_course_id, 1, "Existing Target Module").await;
+
+    let export = |module_id: i64| {
+        let server = &server;
+        async move {
+            let response = server
+                .get("/editor/export_module")
+                .add_query_param("instructor_id", instructor_id)
+                .add_query_param("module_id", module_id)
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response.json::<ApiResponse<Value>>().data.unwrap()
+        }
+    };
+
+    let bundle = export(module_id).await;
+    assert_eq!(bundle["title"], "Bundled Module");
+    let titles: Vec<&str> = bundle["exercises"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["Bundled Ex 1", "Bundled Ex 2"]);
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "course_id": target_course_id,
+        "module_data": bundle,
+    });
+    let response = server.post("/editor/import_module").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let imported_module_id = response.json::<ApiResponse<i64>>().data.unwrap();
+    assert_eq!(
+        get_module_ids_in_order(&pool, target_course_id).await.len(),
+        2
+    );
+    assert_eq!(
+        count_exercises_for_module(&pool, imported_module_id).await,
+        2
+    );
+
+    // appended after the target course's existing module, otherwise identical
+    let mut reexported = export(imported_module_id).await;
+    assert_eq!(reexported["order"], 2);
+    reexported["order"] = bundle["order"].clone();
+    assert_eq!(reexported, bundle);
+
+    let response = server
+        .post("/editor/import_module")
+        .json(&json!({
+            "instructor_id": 2692,
+            "course_id": target_course_id,
+            "module_data": bundle,
+        }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    let response = server
+        .get("/editor/export_module")
+        .add_query_param("instructor_id", 2692)
+        .add_query_param("module_id", module_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// add_exercise_tag / remove_exercise_tag
+
+#[tokio::test]
+async fn test_add_and_remove_exercise_tag_owner() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 20;
+    let course_id = create_test_course(&pool, "Tag Course").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Tag Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Tag Exercise").await;
+    create_test_instructor(&pool, instructor_id, "tagger@test.com", "Tagger").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, true).await;
+
+    for tag in ["  Recursion ", "loops", "recursion"] {
+        let payload = ExerciseTagPayload {
+            instructor_id,
+            exercise_id,
+            tag: tag.to_string(),
+        };
+        let response = server.post("/editor/add_exercise_tag").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let body: ApiResponse<bool> = response.json();
+        assert!(body.data.unwrap());
+    }
+    assert_eq!(
+        get_exercise_tags(&pool, exercise_id).await,
+        vec!["loops".to_string(), "recursion".to_string()]
+    );
+
+    let payload = ExerciseTagPayload {
+        instructor_id,
+        exercise_id,
+        tag: "LOOPS".to_string(),
+    };
+    let response = server
+        .post("/editor/remove_exercise_tag")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        get_exercise_tags(&pool, exercise_id).await,
+        vec!["recursion".to_string()]
+    );
+
+    let response = server
+        .post("/editor/remove_exercise_tag")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_add_exercise_tag_forbidden_non_owner() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = 21;
+    let other_id = 22;
+    let course_id = create_test_course(&pool, "Tag Course F").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Tag Module F").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Tag Exercise F").await;
+    create_test_instructor(&pool, owner_id, "tag_owner@test.com", "Tag Owner").await;
+    create_test_instructor(&pool, other_id, "tag_other@test.com", "Tag Other").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+
+    let payload = ExerciseTagPayload {
+        instructor_id: other_id,
+        exercise_id,
+        tag: "loops".to_string(),
+    };
+    let response = server.post("/editor/add_exercise_tag").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert!(get_exercise_tags(&pool, exercise_id).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_exercise_tag_invalid_and_not_found() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Tag Course I").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Tag Module I").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Tag Exercise I").await;
+
+    let payload = ExerciseTagPayload {
+        instructor_id: 0,
+        exercise_id,
+        tag: "   ".to_string(),
+    };
+    let response = server.post("/editor/add_exercise_tag").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let payload = ExerciseTagPayload {
+        instructor_id: 0,
+        exercise_id: 999_999,
+        tag: "loops".to_string(),
+    };
+    let response = server.post("/editor/add_exercise_tag").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// reorder_modules / reorder_exercises
+
+#[tokio::test]
+async fn test_reorder_modules_success() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 30;
+    let course_id = create_test_course(&pool, "Reorder Course").await;
+    let m1 = create_test_module(&pool, course_id, 1, "Module 1").await;
+    let m2 = create_test_module(&pool, course_id, 2, "Module 2").await;
+    let m3 = create_test_module(&pool, course_id, 3, "Module 3").await;
+    create_test_instructor(&pool, instructor_id, "reorder@test.com", "Reorder").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, true).await;
+
+    let payload = ReorderModulesPayload {
+        instructor_id,
+        course_id,
+        module_ids: vec![m3, m1, m2],
+    };
+    let response = server.post("/editor/reorder_modules").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        get_module_ids_in_order(&pool, course_id).await,
+        vec![m3, m1, m2]
+    );
+}
+
+#[tokio::test]
+async fn test_reorder_modules_incomplete_ids_rejected() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 31;
+    let course_id = create_test_course(&pool, "Reorder Course I").await;
+    let m1 = create_test_module(&pool, course_id, 1, "Module 1").await;
+    let m2 = create_test_module(&pool, course_id, 2, "Module 2").await;
+    let m3 = create_test_module(&pool, course_id, 3, "Module 3").await;
+    create_test_instructor(&pool, instructor_id, "reorder_i@test.com", "Reorder I").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, true).await;
+
+    for module_ids in [vec![m3, m1], vec![m3, m1, m1], vec![m3, m2, m1, 999_999]] {
+        let payload = ReorderModulesPayload {
+            instructor_id,
+            course_id,
+            module_ids,
+        };
+        let response = server.post("/editor/reorder_modules").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    assert_eq!(
+        get_module_ids_in_order(&pool, course_id).await,
+        vec![m1, m2, m3]
+    );
+}
+
+#[tokio::test]
+async fn test_reorder_exercises_success_and_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = 32;
+    let other_id = 33;
+    let course_id = create_test_course(&pool, "Reorder Course E").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Module E").await;
+    let e1 = create_test_exercise(&pool, module_id, 1, "Ex 1").await;
+    let e2 = create_test_exercise(&pool, module_id, 2, "Ex 2").await;
+    let e3 = create_test_exercise(&pool, module_id, 3, "Ex 3").await;
+    create_test_instructor(&pool, owner_id, "reorder_e@test.com", "Reorder E").await;
+    create_test_instructor(&pool, other_id, "reorder_x@test.com", "Reorder X").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+
+    let payload = ReorderExercisesPayload {
+        instructor_id: other_id,
+        module_id,
+        exercise_ids: vec![e2, e3, e1],
+    };
+    let response = server
+        .post("/editor/reorder_exercises")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let payload = ReorderExercisesPayload {
+        instructor_id: owner_id,
+        ..payload
+    };
+    let response = server
+        .post("/editor/reorder_exercises")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        get_exercise_ids_in_order(&pool, module_id).await,
+        vec![e2, e3, e1]
+    );
+}
+
+// order uniqueness / renumber_course
+
+#[tokio::test]
+async fn test_import_course_duplicate_orders_conflict() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 34;
+    create_test_instructor(&pool, instructor_id, "dup_order@test.com", "Dup Order").await;
+
+    let mut payload = create_valid_import_payload(instructor_id);
+    payload.course_data.modules[1].order = 1;
+    payload.course_data.modules[1].language = "en".to_string();
+    let response = server.post("/editor/import_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains("module order 1 (en)"));
+
+    let mut payload = create_valid_import_payload(instructor_id);
+    let exercise = &payload.course_data.modules[0].exercises[0];
+    let mut duplicate: ImportExerciseData =
+        serde_json::from_value(serde_json::to_value(exercise).unwrap()).unwrap();
+    duplicate.title = "Exercise 1.2".to_string();
+    payload.course_data.modules[0].exercises.push(duplicate);
+    let response = server.post("/editor/import_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains("exercise order 1 (en/py)"));
+    assert!(body.status_message.contains("Module 1"));
+
+    assert_eq!(count_courses(&pool).await, 0);
+}
+
+#[tokio::test]
+async fn test_duplicate_module_order_rejected_by_database() {
+    let (_server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Dup Order DB Course").await;
+    create_test_module(&pool, course_id, 1, "Module 1").await;
+
+    let conn = pool.get().await.unwrap();
+    let result = conn
+        .interact(move |conn| {
+            use lightweight_fgpe_server::schema::modules;
+            diesel::insert_into(modules::table)
+                .values((
+                    modules::course_id.eq(course_id),
+                    modules::order.eq(1),
+                    modules::title.eq("Module 1 again"),
+                    modules::description.eq(""),
+                    modules::language.eq("en"),
+                    modules::start_date.eq(chrono::Utc::now()),
+                    modules::end_date.eq(chrono::Utc::now()),
+                ))
+                .execute(conn)
+        })
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _
+        ))
+    ));
+    assert_eq!(count_modules_for_course(&pool, course_id).await, 1);
+}
+
+#[tokio::test]
+async fn test_renumber_course_compacts_gaps() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 35;
+    let other_id = 36;
+    let course_id = create_test_course(&pool, "Renumber Course").await;
+    let m_late = create_test_module(&pool, course_id, 7, "Module Late").await;
+    let m_early = create_test_module(&pool, course_id, 3, "Module Early").await;
+    let e_b = create_test_exercise(&pool, m_early, 10, "Ex B").await;
+    let e_a = create_test_exercise(&pool, m_early, 4, "Ex A").await;
+    create_test_instructor(&pool, instructor_id, "renumber@test.com", "Renumber").await;
+    create_test_instructor(&pool, other_id, "renumber_x@test.com", "Renumber X").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, true).await;
+
+    let payload = RenumberCoursePayload {
+        instructor_id: other_id,
+        course_id,
+    };
+    let response = server.post("/editor/renumber_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let payload = RenumberCoursePayload {
+        instructor_id,
+        course_id,
+    };
+    let response = server.post("/editor/renumber_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let conn = pool.get().await.unwrap();
+    let (module_orders, exercise_orders) = conn
+        .interact(move |conn| {
+            use lightweight_fgpe_server::schema::{exercises, modules};
+            let module_orders = modules::table
+                .filter(modules::course_id.eq(course_id))
+                .order(modules::order.asc())
+                .select((modules::id, modules::order))
+                .load::<(i64, i32)>(conn)?;
+            let exercise_orders = exercises::table
+                .filter(exercises::module_id.eq(m_early))
+                .order(exercises::order.asc())
+                .select((exercises::id, exercises::order))
+                .load::<(i64, i32)>(conn)?;
+            Ok::<_, diesel::result::Error>((module_orders, exercise_orders))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(module_orders, vec![(m_early, 1), (m_late, 2)]);
+    assert_eq!(exercise_orders, vec![(e_a, 1), (e_b, 2)]);
+}
+
+// recalculate_course_games
+
+#[tokio::test]
+async fn test_recalculate_course_games_updates_every_game() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2671, "recalc@test.com", "Owner").await;
+    let other_id = create_test_instructor(&pool, 2672, "recalc_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Recalc Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Recalc Module").await;
+    create_test_exercise(&pool, module_id, 1, "Recalc Ex 1").await;
+    let py_game_id = create_test_game(&pool, course_id, "Recalc Py Game", 1).await;
+    let java_game_id = create_test_game(&pool, course_id, "Recalc Java Game", 1).await;
+
+    // the course grows after both games were created
+    create_test_exercise(&pool, module_id, 2, "Recalc Ex 2").await;
+    let java_ex_id = create_test_exercise(&pool, module_id, 3, "Recalc Java Ex").await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        use lightweight_fgpe_server::schema::{exercises, games};
+        diesel::update(games::table.find(java_game_id))
+            .set(games::programming_language.eq("java"))
+            .execute(conn)?;
+        diesel::update(exercises::table.find(java_ex_id))
+            .set(exercises::programming_language.eq("java"))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .post("/editor/recalculate_course_games")
+        .json(&RecalculateCourseGamesPayload {
+            instructor_id: other_id,
+            course_id,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/editor/recalculate_course_games")
+        .json(&RecalculateCourseGamesPayload {
+            instructor_id: owner_id,
+            course_id,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let counts: Vec<(i64, i32)> = response
+        .json::<ApiResponse<Vec<GameExerciseCount>>>()
+        .data
+        .unwrap()
+        .into_iter()
+        .map(|game| (game.game_id, game.total_exercises))
+        .collect();
+    assert_eq!(counts, vec![(py_game_id, 2), (java_game_id, 1)]);
+
+    let stored = conn
+        .interact(move |conn| {
+            use lightweight_fgpe_server::schema::games;
+            games::table
+                .filter(games::course_id.eq(course_id))
+                .order_by(games::id.asc())
+                .select((games::id, games::total_exercises))
+                .load::<(i64, i32)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored, counts);
+}
+
+// get_course_exercise_difficulty
+
+#[tokio::test]
+async fn test_get_course_exercise_difficulty_pools_games() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2601, "difficulty@test.com", "Owner").await;
+    let other_id = create_test_instructor(&pool, 2602, "difficulty_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Difficulty Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Difficulty Module").await;
+    let pooled_ex = create_test_exercise(&pool, module_id, 1, "Pooled Ex").await;
+    let untouched_ex = create_test_exercise(&pool, module_id, 2, "Untouched Ex").await;
+    let default_game = create_test_game(&pool, course_id, "Difficulty Game A", 2).await;
+    let strict_game = create_test_game(&pool, course_id, "Difficulty Game B", 2).await;
+    create_test_player(&pool, 2611, "difficulty_p@test.com", "Player").await;
+    for game_id in [default_game, strict_game] {
+        create_test_player_registration(&pool, 2611, game_id).await;
+    }
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        use lightweight_fgpe_server::schema::games::dsl::*;
+        diesel::update(games.find(strict_game))
+            .set(success_threshold.eq(Some(90.0)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    // default game: 100 passes, 20 fails; strict game (90): 80 fails, 95 passes
+    create_test_submission(&pool, 2611, default_game, pooled_ex, true, 1.0).await;
+    create_test_submission(&pool, 2611, default_game, pooled_ex, false, 0.2).await;
+    create_test_submission(&pool, 2611, strict_game, pooled_ex, false, 0.8).await;
+    create_test_submission(&pool, 2611, strict_game, pooled_ex, true, 0.95).await;
+
+    let response = server
+        .get("/editor/get_course_exercise_difficulty")
+        .add_query_param("instructor_id", owner_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<CourseExerciseDifficulty>> = response.json();
+    let stats = body.data.unwrap();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].exercise_id, pooled_ex);
+    assert_eq!(stats[0].attempts, 4);
+    assert_eq!(stats[0].successful_attempts, 2);
+    assert!((stats[0].success_rate - 50.0).abs() < 1e-9);
+    assert!((stats[0].difficulty - 50.0).abs() < 1e-9);
+    assert_eq!(stats[1].exercise_id, untouched_ex);
+    assert_eq!(stats[1].attempts, 0);
+    assert_eq!(stats[1].difficulty, 0.0);
+
+    let response = server
+        .get("/editor/get_course_exercise_difficulty")
+        .add_query_param("instructor_id", other_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_exercise_games
+
+#[tokio::test]
+async fn test_get_exercise_games_lists_games_of_course() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2651, "exgames@test.com", "Owner").await;
+    let other_id = create_test_instructor(&pool, 2652, "exgames_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Exercise Games Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Exercise Games Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Exercise Games Ex").await;
+    let running_game = create_test_game(&pool, course_id, "Exercise Games A", 1).await;
+    let stopped_game = create_test_game(&pool, course_id, "Exercise Games B", 1).await;
+    update_game_status(&pool, stopped_game, false, false).await;
+    let unrelated_course_id = create_test_course(&pool, "Exercise Games Unrelated").await;
+    create_test_game(&pool, unrelated_course_id, "Exercise Games C", 1).await;
+    create_test_player(&pool, 2661, "exgames_p1@test.com", "Player 1").await;
+    create_test_player(&pool, 2662, "exgames_p2@test.com", "Player 2").await;
+    create_test_player_registration(&pool, 2661, running_game).await;
+    create_test_player_registration(&pool, 2662, running_game).await;
+    create_test_player_registration(&pool, 2661, stopped_game).await;
+
+    let response = server
+        .get("/editor/get_exercise_games")
+        .add_query_param("instructor_id", owner_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let games = response
+        .json::<ApiResponse<Vec<ExerciseGameSummary>>>()
+        .data
+        .unwrap();
+    let summary: Vec<(i64, &str, bool, i64)> = games
+        .iter()
+        .map(|g| (g.game_id, g.title.as_str(), g.active, g.player_count))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (running_game, "Exercise Games A", true, 2),
+            (stopped_game, "Exercise Games B", false, 1),
+        ]
+    );
+
+    let response = server
+        .get("/editor/get_exercise_games")
+        .add_query_param("instructor_id", other_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_course_enrollment_summary
+
+#[tokio::test]
+async fn test_get_course_enrollment_summary_counts_distinct_players() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2653, "enroll@test.com", "Owner").await;
+    let other_id = create_test_instructor(&pool, 2654, "enroll_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Enrollment Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let game_a = create_test_game(&pool, course_id, "Enrollment A", 1).await;
+    let game_b = create_test_game(&pool, course_id, "Enrollment B", 1).await;
+    let unrelated_course_id = create_test_course(&pool, "Enrollment Unrelated").await;
+    let unrelated_game = create_test_game(&pool, unrelated_course_id, "Enrollment C", 1).await;
+    create_test_player(&pool, 2671, "enroll_p1@test.com", "Player 1").await;
+    create_test_player(&pool, 2672, "enroll_p2@test.com", "Player 2").await;
+    create_test_player(&pool, 2673, "enroll_p3@test.com", "Player 3").await;
+    create_test_player(&pool, 2674, "enroll_p4@test.com", "Player 4").await;
+    create_test_player(&pool, 2675, "enroll_p5@test.com", "Player 5").await;
+    create_test_player_registration(&pool, 2671, game_a).await;
+    create_test_player_registration(&pool, 2672, game_a).await;
+    create_test_player_registration(&pool, 2671, game_b).await;
+    create_test_player_registration(&pool, 2673, game_b).await;
+    let left_registration = create_test_player_registration(&pool, 2674, game_b).await;
+    create_test_player_registration(&pool, 2675, unrelated_game).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        use lightweight_fgpe_server::schema::player_registrations::dsl::*;
+        diesel::update(player_registrations.find(left_registration))
+            .set(left_at.eq(Some(chrono::Utc::now())))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/editor/get_course_enrollment_summary")
+        .add_query_param("instructor_id", owner_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let summary = response
+        .json::<ApiResponse<CourseEnrollmentSummary>>()
+        .data
+        .unwrap();
+    assert_eq!(summary.course_id, course_id);
+    assert_eq!(summary.distinct_players, 3);
+    assert_eq!(summary.registrations, 4);
+    let games: Vec<(i64, &str, i64)> = summary
+        .games
+        .iter()
+        .map(|g| (g.game_id, g.title.as_str(), g.registrations))
+        .collect();
+    assert_eq!(
+        games,
+        vec![(game_a, "Enrollment A", 2), (game_b, "Enrollment B", 2)]
+    );
+
+    let response = server
+        .get("/editor/get_course_enrollment_summary")
+        .add_query_param("instructor_id", other_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_course_tree
+
+#[tokio::test]
+async fn test_get_course_tree_nests_modules_and_exercises_in_order() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2801, "tree@test.com", "Tree Owner").await;
+    let other_id = create_test_instructor(&pool, 2802, "tree_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Tree Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    // created out of order to check that the tree is sorted by `order`
+    let second_module = create_test_module(&pool, course_id, 2, "Tree Module B").await;
+    let first_module = create_test_module(&pool, course_id, 1, "Tree Module A").await;
+    let b2 = create_test_exercise(&pool, second_module, 2, "B2").await;
+    let a1 = create_test_exercise(&pool, first_module, 1, "A1").await;
+    let b1 = create_test_exercise(&pool, second_module, 1, "B1").await;
+    let a2 = create_test_exercise(&pool, first_module, 2, "A2").await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        use lightweight_fgpe_server::schema::exercises::dsl::*;
+        diesel::update(exercises.find(b2))
+            .set(hidden.eq(true))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/editor/get_course_tree")
+        .add_query_param("instructor_id", owner_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<CourseTreeResponse> = response.json();
+    let tree = body.data.unwrap();
+    assert_eq!(tree.id, course_id);
+    assert_eq!(tree.title, "Tree Course");
+
+    let module_ids: Vec<i64> = tree.modules.iter().map(|m| m.id).collect();
+    assert_eq!(module_ids, vec![first_module, second_module]);
+    let exercise_ids: Vec<Vec<i64>> = tree
+        .modules
+        .iter()
+        .map(|m| m.exercises.iter().map(|e| e.id).collect())
+        .collect();
+    assert_eq!(exercise_ids, vec![vec![a1, a2], vec![b1, b2]]);
+    assert_eq!(tree.modules[1].exercises[0].title, "B1");
+    assert!(tree.modules[1].exercises[1].hidden);
+    assert!(!tree.modules[0].exercises[0].hidden);
+
+    let response = server
+        .get("/editor/get_course_tree")
+        .add_query_param("instructor_id", other_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// delete_course
+
+#[tokio::test]
+async fn test_delete_course_blocked_by_games() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2701, "delcourse@test.com", "Owner").await;
+    let course_id = create_test_course(&pool, "Delete Blocked Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    create_test_game(&pool, course_id, "Blocking Game", 1).await;
+
+    let payload = DeleteCoursePayload {
+        instructor_id: owner_id,
+        course_id,
+        force: false,
+    };
+    let response = server.post("/editor/delete_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    assert_eq!(count_courses(&pool).await, 1);
+
+    // only admins may force
+    let payload = DeleteCoursePayload {
+        instructor_id: owner_id,
+        course_id,
+        force: true,
+    };
+    let response = server.post("/editor/delete_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(count_courses(&pool).await, 1);
+}
+
+#[tokio::test]
+async fn test_delete_course_without_games() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2702, "delcourse_ok@test.com", "Owner").await;
+    let course_id = create_test_course(&pool, "Delete Clean Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Delete Module").await;
+    create_test_exercise(&pool, module_id, 1, "Delete Ex").await;
+
+    let payload = DeleteCoursePayload {
+        instructor_id: owner_id,
+        course_id,
+        force: false,
+    };
+    let response = server.post("/editor/delete_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<bool> = response.json();
+    assert!(body.data.unwrap());
+
+    assert_eq!(count_courses(&pool).await, 0);
+    assert_eq!(count_modules_for_course(&pool, course_id).await, 0);
+    assert_eq!(count_exercises_for_module(&pool, module_id).await, 0);
+    assert!(!check_course_ownership(&pool, owner_id, course_id).await);
+
+    let response = server.post("/editor/delete_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_delete_course_admin_force_archives_games() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Delete Forced Course").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Forced Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Forced Ex").await;
+    let game_id = create_test_game(&pool, course_id, "Archived Game", 1).await;
+    create_test_player(&pool, 2711, "delcourse_p@test.com", "Player").await;
+    create_test_player_registration(&pool, 2711, game_id).await;
+    create_test_submission(&pool, 2711, game_id, exercise_id, true, 1.0).await;
+
+    let payload = DeleteCoursePayload {
+        instructor_id: 0,
+        course_id,
+        force: true,
+    };
+    let response = server.post("/editor/delete_course").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(count_courses(&pool).await, 0);
+
+    let conn = pool.get().await.unwrap();
+    let (archived, games_left, archived_submissions): (Vec<(i64, String, Value)>, i64, Vec<i64>) =
+        conn.interact(move |conn| {
+            use lightweight_fgpe_server::schema::{archived_games, archived_submissions, games};
+            let archived = archived_games::table
+                .filter(archived_games::course_id.eq(course_id))
+                .select((
+                    archived_games::game_id,
+                    archived_games::title,
+                    archived_games::snapshot,
+                ))
+                .load::<(i64, String, Value)>(conn)?;
+            let games_left = games::table.count().get_result::<i64>(conn)?;
+            let archived_submissions = archived_submissions::table
+                .filter(archived_submissions::game_id.eq(game_id))
+                .select(archived_submissions::player_id)
+                .load::<i64>(conn)?;
+            Ok::<_, diesel::result::Error>((archived, games_left, archived_submissions))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(games_left, 0);
+    assert_eq!(archived.len(), 1);
+    assert_eq!(archived[0].0, game_id);
+    assert_eq!(archived[0].1, "Archived Game");
+    assert_eq!(archived[0].2["player_count"], 1);
+    assert_eq!(archived[0].2["submission_count"], 1);
+    assert_eq!(archived[0].2["registrations"][0]["player_id"], 2711);
+    assert_eq!(archived_submissions, vec![2711]);
+}
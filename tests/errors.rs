@@ -0,0 +1,126 @@
+use axum::body::to_bytes;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use lightweight_fgpe_server::errors::AppError;
+use lightweight_fgpe_server::response::ApiResponse;
+use std::collections::HashSet;
+
+/// The expected status of every variant. The match has no wildcard arm,
+/// so adding a variant to `AppError` fails to compile until it is listed here.
+fn expected_status(error: &AppError) -> StatusCode {
+    match error {
+        AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+        AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+        AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+        AppError::NotFound(_) => StatusCode::NOT_FOUND,
+        AppError::MethodNotAllowed(_) => StatusCode::METHOD_NOT_ALLOWED,
+        AppError::Conflict(_) => StatusCode::CONFLICT,
+        AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+        AppError::UnprocessableEntity(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+        AppError::BadGateway(_) => StatusCode::BAD_GATEWAY,
+        AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        AppError::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+        AppError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn every_variant() -> Vec<AppError> {
+    let message = || "message".to_string();
+    vec![
+        AppError::BadRequest(message()),
+        AppError::Unauthorized(message()),
+        AppError::Forbidden(message()),
+        AppError::NotFound(message()),
+        AppError::MethodNotAllowed(message()),
+        AppError::Conflict(message()),
+        AppError::PayloadTooLarge(message()),
+        AppError::UnprocessableEntity(message()),
+        AppError::TooManyRequests(message()),
+        AppError::BadGateway(message()),
+        AppError::ServiceUnavailable(message()),
+        AppError::GatewayTimeout(message()),
+        AppError::InternalServerError(anyhow::anyhow!("secret detail")),
+    ]
+}
+
+#[tokio::test]
+async fn test_every_variant_maps_to_its_status_and_envelope() {
+    let variants = every_variant();
+    let statuses = variants.iter().map(expected_status).collect::<HashSet<_>>();
+    assert_eq!(statuses.len(), variants.len(), "variants share a status");
+
+    for error in variants {
+        let expected = expected_status(&error);
+        assert_eq!(error.status_code(), expected, "{:?}", error);
+
+        let response = error.into_response();
+        assert_eq!(response.status(), expected);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: ApiResponse<()> = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.status_code, expected.as_u16());
+        assert!(body.data.is_none());
+        if expected == StatusCode::INTERNAL_SERVER_ERROR {
+            assert!(!body.status_message.contains("secret detail"));
+        } else {
+            assert_eq!(body.status_message, "message");
+        }
+    }
+}
+
+#[test]
+fn test_diesel_not_found_maps_to_404() {
+    let error = AppError::from(diesel::result::Error::NotFound);
+    assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+
+    let error = AppError::from(diesel::result::Error::RollbackTransaction);
+    assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn test_constraint_violations_map_by_kind() {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+    let violation = |kind| DieselError::DatabaseError(kind, Box::new("detail".to_string()));
+    let cases = [
+        (
+            DatabaseErrorKind::ForeignKeyViolation,
+            StatusCode::NOT_FOUND,
+            "Could not save: a referenced record does not exist.",
+        ),
+        (
+            DatabaseErrorKind::UniqueViolation,
+            StatusCode::CONFLICT,
+            "Could not save: a record with the same values already exists.",
+        ),
+        (
+            DatabaseErrorKind::CheckViolation,
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Could not save: a value is not allowed.",
+        ),
+        (
+            DatabaseErrorKind::NotNullViolation,
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Could not save: a value is not allowed.",
+        ),
+        (
+            DatabaseErrorKind::SerializationFailure,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "",
+        ),
+    ];
+    for (kind, expected, expected_message) in cases {
+        let error = AppError::from_constraint_violation(violation(kind), "Could not save");
+        assert_eq!(error.status_code(), expected, "{:?}", error);
+        match error {
+            AppError::NotFound(message)
+            | AppError::Conflict(message)
+            | AppError::UnprocessableEntity(message) => assert_eq!(message, expected_message),
+            AppError::InternalServerError(_) => {}
+            other => panic!("unexpected variant {:?}", other),
+        }
+    }
+
+    let error = AppError::from_constraint_violation(DieselError::NotFound, "Could not save");
+    assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+}
@@ -7,10 +7,12 @@ pub(crate) use deadpool_diesel::postgres::{
 };
 use diesel::ExpressionMethods;
 use diesel::dsl::count_star;
+use diesel::pg::data_types::PgInterval;
 use diesel::prelude::*;
 use diesel::result::Error as DieselError;
+use lightweight_fgpe_server::config::AppConfig;
 use lightweight_fgpe_server::model::editor::{
-    NewCourse, NewCourseOwnership, NewExercise, NewModule,
+    NewCourse, NewCourseOwnership, NewExercise, NewExerciseTag, NewModule,
 };
 use lightweight_fgpe_server::model::student::NewPlayerUnlock;
 use lightweight_fgpe_server::model::student::{NewPlayerRegistration, NewSubmission};
@@ -23,7 +25,7 @@ use lightweight_fgpe_server::schema::{courses, exercises, modules};
 use lightweight_fgpe_server::schema::{
     player_groups::dsl as pg_dsl, player_registrations::dsl as pr_dsl,
 };
-use lightweight_fgpe_server::{init_test_router, schema};
+use lightweight_fgpe_server::{init_test_router_with_config, schema};
 use serde_json::json;
 use uuid::Uuid;
 
@@ -68,9 +70,13 @@ pub fn get_test_db_pool() -> TestPool {
 }
 
 pub async fn setup_test_environment() -> (TestServer, TestPool) {
+    setup_test_environment_with_config(AppConfig::default()).await
+}
+
+pub async fn setup_test_environment_with_config(config: AppConfig) -> (TestServer, TestPool) {
     let test_pool = get_test_db_pool();
     clear_test_database(&test_pool).await;
-    let app: Router = init_test_router(test_pool.clone());
+    let app: Router = init_test_router_with_config(test_pool.clone(), config);
     let server = TestServer::new(app).expect("Failed to create TestServer");
     (server, test_pool)
 }
@@ -83,11 +89,17 @@ async fn clear_test_database(pool: &TestPool) {
             diesel::delete(schema::submissions::table).execute(tx_conn)?;
             diesel::delete(schema::player_rewards::table).execute(tx_conn)?;
             diesel::delete(schema::player_unlocks::table).execute(tx_conn)?;
+            diesel::delete(schema::game_checkpoints::table).execute(tx_conn)?;
             diesel::delete(schema::player_registrations::table).execute(tx_conn)?;
             diesel::delete(schema::player_groups::table).execute(tx_conn)?;
             diesel::delete(schema::invites::table).execute(tx_conn)?;
+            diesel::delete(schema::game_end_summaries::table).execute(tx_conn)?;
+            diesel::delete(schema::archived_games::table).execute(tx_conn)?;
+            diesel::delete(schema::archived_submissions::table).execute(tx_conn)?;
+            diesel::delete(schema::game_exercises::table).execute(tx_conn)?;
             diesel::delete(schema::game_ownership::table).execute(tx_conn)?;
             diesel::delete(schema::course_ownership::table).execute(tx_conn)?;
+            diesel::delete(schema::exercise_tags::table).execute(tx_conn)?;
             diesel::delete(schema::exercises::table).execute(tx_conn)?;
             diesel::delete(schema::rewards::table).execute(tx_conn)?;
             diesel::delete(schema::games::table).execute(tx_conn)?;
@@ -140,6 +152,7 @@ pub async fn create_test_instructor(
     .expect("Failed to insert test instructor")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_course(pool: &TestPool, title: &str) -> i64 {
     let title_string = title.to_string();
     let conn = pool
@@ -167,6 +180,7 @@ pub async fn create_test_course(pool: &TestPool, title: &str) -> i64 {
     .expect("Failed to insert test course")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_game(
     pool: &TestPool,
     course_id: i64,
@@ -189,6 +203,9 @@ pub async fn create_test_game(
             module_lock: 0.0,
             exercise_lock: false,
             total_exercises,
+            success_threshold: None,
+            max_attempts_per_exercise: None,
+            min_meaningful_result: None,
             start_date: Utc::now(),
             end_date: Utc::now() + chrono::Duration::days(30),
         };
@@ -202,6 +219,7 @@ pub async fn create_test_game(
     .expect("Failed to insert test game")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_game_ownership(
     pool: &TestPool,
     instructor_id: i64,
@@ -265,6 +283,7 @@ pub async fn create_test_player(
     .expect("Failed to insert test player")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_player_registration(pool: &TestPool, player_id: i64, game_id: i64) -> i64 {
     let conn = pool
         .get()
@@ -295,6 +314,7 @@ pub async fn create_test_player_registration(pool: &TestPool, player_id: i64, ga
     .expect("Failed to insert or handle conflict for test player registration")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_group_with_id(pool: &TestPool, id: i64, name: &'static str) -> i64 {
     let conn = pool
         .get()
@@ -319,6 +339,7 @@ pub async fn create_test_group_with_id(pool: &TestPool, id: i64, name: &'static
     .expect("Failed to insert test group with ID")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_group_ownership(
     pool: &TestPool,
     instructor_id: i64,
@@ -350,6 +371,7 @@ pub async fn create_test_group_ownership(
     .expect("Failed to insert test group ownership");
 }
 
+#[allow(dead_code)]
 pub async fn add_player_to_group(pool: &TestPool, player_id: i64, group_id: i64) {
     let conn = pool
         .get()
@@ -374,6 +396,7 @@ pub async fn add_player_to_group(pool: &TestPool, player_id: i64, group_id: i64)
     .expect("Failed to insert test player_group link");
 }
 
+#[allow(dead_code)]
 pub async fn update_player_status(pool: &TestPool, player_id: i64, disabled: bool) {
     let conn = pool
         .get()
@@ -389,6 +412,26 @@ pub async fn update_player_status(pool: &TestPool, player_id: i64, disabled: boo
     .expect("Failed to update player status");
 }
 
+#[allow(dead_code)]
+pub async fn update_game_status(pool: &TestPool, game_id: i64, active: bool, public: bool) {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for game update");
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(game_id))
+            .set((
+                schema::games::active.eq(active),
+                schema::games::public.eq(public),
+            ))
+            .execute(conn)
+    })
+    .await
+    .expect("Interact failed")
+    .expect("Failed to update game status");
+}
+
+#[allow(dead_code)]
 pub async fn create_test_module(pool: &TestPool, course_id: i64, order: i32, title: &str) -> i64 {
     let title_string = title.to_string();
     let conn = pool
@@ -415,6 +458,7 @@ pub async fn create_test_module(pool: &TestPool, course_id: i64, order: i32, tit
     .expect("Failed to insert test module")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_exercise(pool: &TestPool, module_id: i64, order: i32, title: &str) -> i64 {
     let title_string = title.to_string();
     let conn = pool
@@ -451,6 +495,8 @@ pub async fn create_test_exercise(pool: &TestPool, module_id: i64, order: i32, t
     .expect("Failed to insert test exercise")
 }
 
+/// Inserts a submission; `result` is a fraction (0.0-1.0) and is stored on the 0-100 percentage scale.
+#[allow(dead_code)]
 pub async fn create_test_submission(
     pool: &TestPool,
     player_id: i64,
@@ -479,6 +525,7 @@ pub async fn create_test_submission(
             feedback: "".to_string(),
             earned_rewards: json!([]),
             entered_at: Utc::now(),
+            time_spent_seconds: None,
         };
         diesel::insert_into(schema::submissions::table)
             .values(&new_submission)
@@ -490,6 +537,7 @@ pub async fn create_test_submission(
     .expect("Failed to insert test submission")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_invite(
     pool: &TestPool,
     instructor_id: i64,
@@ -518,6 +566,7 @@ pub async fn create_test_invite(
     .expect("Failed to insert test invite")
 }
 
+#[allow(dead_code)]
 pub async fn check_player_in_game(pool: &TestPool, player_id: i64, game_id: i64) -> bool {
     let conn = pool.get().await.expect("Failed to get conn for game check");
     conn.interact(move |conn| {
@@ -534,6 +583,7 @@ pub async fn check_player_in_game(pool: &TestPool, player_id: i64, game_id: i64)
     .expect("DB query failed for game check")
 }
 
+#[allow(dead_code)]
 pub async fn check_player_in_group(pool: &TestPool, player_id: i64, group_id: i64) -> bool {
     let conn = pool
         .get()
@@ -553,6 +603,7 @@ pub async fn check_player_in_group(pool: &TestPool, player_id: i64, group_id: i6
     .expect("DB query failed for group check")
 }
 
+#[allow(dead_code)]
 pub async fn count_player_game_registrations(pool: &TestPool, player_id: i64) -> i64 {
     let conn = pool.get().await.expect("Failed to get conn for game count");
     conn.interact(move |conn| {
@@ -567,6 +618,7 @@ pub async fn count_player_game_registrations(pool: &TestPool, player_id: i64) ->
     .expect("DB query failed for game count")
 }
 
+#[allow(dead_code)]
 pub async fn count_player_group_memberships(pool: &TestPool, player_id: i64) -> i64 {
     let conn = pool
         .get()
@@ -584,6 +636,58 @@ pub async fn count_player_group_memberships(pool: &TestPool, player_id: i64) ->
     .expect("DB query failed for group count")
 }
 
+#[allow(dead_code)]
+pub async fn create_test_reward(pool: &TestPool, course_id: i64, name: &'static str) -> i64 {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for reward insert");
+    conn.interact(move |conn| {
+        diesel::insert_into(schema::rewards::table)
+            .values((
+                schema::rewards::course_id.eq(course_id),
+                schema::rewards::name.eq(name),
+                schema::rewards::description.eq("Test reward"),
+                schema::rewards::message_when_won.eq("Well done!"),
+                schema::rewards::valid_period.eq(Some(PgInterval::from_days(30))),
+            ))
+            .returning(schema::rewards::id)
+            .get_result(conn)
+    })
+    .await
+    .expect("Interact failed")
+    .expect("Failed to insert test reward")
+}
+
+#[allow(dead_code)]
+pub async fn create_test_player_reward(
+    pool: &TestPool,
+    player_id: i64,
+    reward_id: i64,
+    game_id: i64,
+    count: i32,
+) {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for player reward insert");
+    conn.interact(move |conn| {
+        diesel::insert_into(schema::player_rewards::table)
+            .values((
+                schema::player_rewards::player_id.eq(player_id),
+                schema::player_rewards::reward_id.eq(reward_id),
+                schema::player_rewards::game_id.eq(game_id),
+                schema::player_rewards::count.eq(count),
+                schema::player_rewards::expires_at.eq(Utc::now() + chrono::Duration::days(30)),
+            ))
+            .execute(conn)
+    })
+    .await
+    .expect("Interact failed")
+    .expect("Failed to insert test player reward");
+}
+
+#[allow(dead_code)]
 pub async fn create_test_player_unlock(pool: &TestPool, player_id: i64, exercise_id: i64) {
     let conn = pool
         .get()
@@ -608,6 +712,81 @@ pub async fn create_test_player_unlock(pool: &TestPool, player_id: i64, exercise
     .expect("Failed to insert test player unlock");
 }
 
+#[allow(dead_code)]
+pub async fn create_test_exercise_tag(pool: &TestPool, exercise_id: i64, tag: &str) {
+    let tag_string = tag.to_string();
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for exercise tag insert");
+    conn.interact(move |conn| {
+        diesel::insert_into(schema::exercise_tags::table)
+            .values(&NewExerciseTag {
+                exercise_id,
+                tag: tag_string,
+            })
+            .execute(conn)
+    })
+    .await
+    .expect("Interact failed")
+    .expect("Failed to insert test exercise tag");
+}
+
+#[allow(dead_code)]
+pub async fn get_exercise_tags(pool: &TestPool, exercise_id: i64) -> Vec<String> {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for exercise tag query");
+    conn.interact(move |conn| {
+        schema::exercise_tags::table
+            .filter(schema::exercise_tags::exercise_id.eq(exercise_id))
+            .select(schema::exercise_tags::tag)
+            .order_by(schema::exercise_tags::tag.asc())
+            .load::<String>(conn)
+    })
+    .await
+    .expect("Interact failed for exercise tag query")
+    .expect("DB query failed for exercise tag query")
+}
+
+#[allow(dead_code)]
+pub async fn get_module_ids_in_order(pool: &TestPool, course_id: i64) -> Vec<i64> {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for module order query");
+    conn.interact(move |conn| {
+        modules::table
+            .filter(modules::course_id.eq(course_id))
+            .order_by(modules::order.asc())
+            .select(modules::id)
+            .load::<i64>(conn)
+    })
+    .await
+    .expect("Interact failed for module order query")
+    .expect("DB query failed for module order query")
+}
+
+#[allow(dead_code)]
+pub async fn get_exercise_ids_in_order(pool: &TestPool, module_id: i64) -> Vec<i64> {
+    let conn = pool
+        .get()
+        .await
+        .expect("Failed to get conn for exercise order query");
+    conn.interact(move |conn| {
+        exercises::table
+            .filter(exercises::module_id.eq(module_id))
+            .order_by(exercises::order.asc())
+            .select(exercises::id)
+            .load::<i64>(conn)
+    })
+    .await
+    .expect("Interact failed for exercise order query")
+    .expect("DB query failed for exercise order query")
+}
+
+#[allow(dead_code)]
 pub async fn check_player_unlock_exists(pool: &TestPool, player_id: i64, exercise_id: i64) -> bool {
     let conn = pool
         .get()
@@ -626,6 +805,7 @@ pub async fn check_player_unlock_exists(pool: &TestPool, player_id: i64, exercis
     .expect("DB query failed for unlock check")
 }
 
+#[allow(dead_code)]
 pub async fn create_test_course_ownership(
     pool: &TestPool,
     instructor_id: i64,
@@ -657,6 +837,7 @@ pub async fn create_test_course_ownership(
     .expect("Failed to insert test course ownership");
 }
 
+#[allow(dead_code)]
 pub async fn count_courses(pool: &TestPool) -> i64 {
     let conn = pool
         .get()
@@ -668,6 +849,7 @@ pub async fn count_courses(pool: &TestPool) -> i64 {
         .expect("DB query failed for course count")
 }
 
+#[allow(dead_code)]
 pub async fn count_modules_for_course(pool: &TestPool, course_id: i64) -> i64 {
     let conn = pool
         .get()
@@ -684,6 +866,7 @@ pub async fn count_modules_for_course(pool: &TestPool, course_id: i64) -> i64 {
     .expect("DB query failed for module count")
 }
 
+#[allow(dead_code)]
 pub async fn count_exercises_for_module(pool: &TestPool, module_id: i64) -> i64 {
     let conn = pool
         .get()
@@ -700,6 +883,7 @@ pub async fn count_exercises_for_module(pool: &TestPool, module_id: i64) -> i64
     .expect("DB query failed for exercise count")
 }
 
+#[allow(dead_code)]
 pub async fn check_course_ownership(pool: &TestPool, instructor_id: i64, course_id: i64) -> bool {
     let conn = pool
         .get()
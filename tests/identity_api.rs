@@ -0,0 +1,164 @@
+use axum::http::StatusCode;
+use lightweight_fgpe_server::auth::{
+    API_KEY_HEADER, TEST_ANONYMOUS_HEADER, TEST_EMAIL_HEADER, TEST_SUBJECT_HEADER,
+};
+use lightweight_fgpe_server::config::AppConfig;
+use lightweight_fgpe_server::model::identity::{IdentityRole, WhoAmIResponse};
+use lightweight_fgpe_server::response::ApiResponse;
+
+mod helpers;
+use helpers::{
+    create_test_instructor, create_test_player, setup_test_environment,
+    setup_test_environment_with_config,
+};
+
+// whoami
+
+#[tokio::test]
+async fn test_whoami_resolves_instructor() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 9101;
+    create_test_instructor(&pool, instructor_id, "whoami_inst@test.com", "WhoAmI Inst").await;
+
+    let response = server
+        .get("/whoami")
+        .add_header(TEST_SUBJECT_HEADER, "kc-subject-9101")
+        .add_header(TEST_EMAIL_HEADER, "whoami_inst@test.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<WhoAmIResponse> = response.json();
+    let identity = body.data.unwrap();
+    assert_eq!(identity.id, instructor_id);
+    assert_eq!(identity.role, IdentityRole::Instructor);
+    assert_eq!(identity.email, "whoami_inst@test.com");
+}
+
+#[tokio::test]
+async fn test_whoami_resolves_admin() {
+    let (server, pool) = setup_test_environment().await;
+    create_test_instructor(&pool, 0, "whoami_admin@test.com", "WhoAmI Admin").await;
+
+    let response = server
+        .get("/whoami")
+        .add_header(TEST_EMAIL_HEADER, "whoami_admin@test.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<WhoAmIResponse> = response.json();
+    let identity = body.data.unwrap();
+    assert_eq!(identity.id, 0);
+    assert_eq!(identity.role, IdentityRole::Admin);
+}
+
+#[tokio::test]
+async fn test_whoami_resolves_player() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 9111;
+    create_test_player(&pool, player_id, "whoami_player@test.com", "WhoAmI Player").await;
+
+    let response = server
+        .get("/whoami")
+        .add_header(TEST_SUBJECT_HEADER, "kc-subject-9111")
+        .add_header(TEST_EMAIL_HEADER, "whoami_player@test.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<WhoAmIResponse> = response.json();
+    let identity = body.data.unwrap();
+    assert_eq!(identity.id, player_id);
+    assert_eq!(identity.role, IdentityRole::Player);
+}
+
+#[tokio::test]
+async fn test_whoami_soft_deleted_player_not_found() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 9112;
+    create_test_player(
+        &pool,
+        player_id,
+        "whoami_deleted@test.com",
+        "WhoAmI Deleted",
+    )
+    .await;
+
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&serde_json::json!({ "instructor_id": 0, "player_id": player_id }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/whoami")
+        .add_header(TEST_SUBJECT_HEADER, "kc-subject-9112")
+        .add_header(TEST_EMAIL_HEADER, "whoami_deleted@test.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_whoami_unknown_subject() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server
+        .get("/whoami")
+        .add_header(TEST_SUBJECT_HEADER, "kc-subject-unknown")
+        .add_header(TEST_EMAIL_HEADER, "nobody@test.com")
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_whoami_without_identity() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server.get("/whoami").await;
+
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+// api keys
+
+#[tokio::test]
+async fn test_api_key_authenticates_service_account_as_admin() {
+    let config = AppConfig {
+        api_keys: vec!["svc-dashboard-key".to_string()],
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    create_test_player(&pool, 9121, "apikey_player@test.com", "ApiKey Player").await;
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", 0)
+        .add_header(TEST_ANONYMOUS_HEADER, "true")
+        .add_header(API_KEY_HEADER, "svc-dashboard-key")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", 0)
+        .add_header(TEST_ANONYMOUS_HEADER, "true")
+        .add_header(API_KEY_HEADER, "not-a-key")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", 0)
+        .add_header(TEST_ANONYMOUS_HEADER, "true")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+
+    let response = server
+        .get("/student/get_player_score")
+        .add_query_param("player_id", 9121)
+        .add_query_param("game_id", 1)
+        .add_header(TEST_ANONYMOUS_HEADER, "true")
+        .add_header(API_KEY_HEADER, "svc-dashboard-key")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
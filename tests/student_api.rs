@@ -1,27 +1,83 @@
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
+use axum::routing::post;
+use axum::{Json, Router};
 use bigdecimal::{BigDecimal, FromPrimitive};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use diesel::ExpressionMethods;
-use diesel::{QueryDsl, RunQueryDsl};
+use diesel::{Connection, QueryDsl, RunQueryDsl};
+use lightweight_fgpe_server::auth::{TEST_ANONYMOUS_HEADER, TEST_EMAIL_HEADER};
+use lightweight_fgpe_server::config::AppConfig;
+use lightweight_fgpe_server::model::editor::ExerciseMode;
 use lightweight_fgpe_server::model::student::{
-    CourseDataResponse, ExerciseDataResponse, GameMetadata, LastSolutionResponse,
-    ModuleDataResponse,
+    CatalogPageResponse, CheckpointSummary, CompletionStatusResponse, CourseDataResponse,
+    CourseModuleData, ExerciseDataResponse, ExerciseLockState, ExerciseWorkspaceResponse,
+    GameMetadata, LastSolutionResponse, ModuleDataResponse, ParsedCourseDataResponse,
+    PlayerRankResponse, PlayerRegistrationResponse, PlayerScoreResponse, PlayerStreakResponse,
+    StudentCourseTreeResponse, SubmitResult,
 };
 use lightweight_fgpe_server::payloads::student::{
-    JoinGamePayload, LeaveGamePayload, LoadGamePayload, SaveGamePayload, SetGameLangPayload,
-    SubmitSolutionPayload, UnlockPayload,
+    JoinGamePayload, LeaveGamePayload, LoadCheckpointPayload, LoadGamePayload,
+    SaveCheckpointPayload, SaveGamePayload, SetGameLangPayload, SubmitSolutionPayload,
+    UnlockManyPayload, UnlockPayload,
 };
 use lightweight_fgpe_server::response::ApiResponse;
 use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
 
 mod helpers;
 use helpers::{
-    check_player_in_game, check_player_unlock_exists, create_test_course, create_test_exercise,
-    create_test_game, create_test_module, create_test_player, create_test_player_registration,
-    create_test_player_unlock, create_test_submission, setup_test_environment,
+    add_player_to_group, check_player_in_game, check_player_unlock_exists, create_test_course,
+    create_test_exercise, create_test_exercise_tag, create_test_game, create_test_group_with_id,
+    create_test_instructor, create_test_invite, create_test_module, create_test_player,
+    create_test_player_registration, create_test_player_reward, create_test_player_unlock,
+    create_test_reward, create_test_submission, setup_test_environment,
+    setup_test_environment_with_config, update_game_status,
 };
 use lightweight_fgpe_server::schema;
 
+// public routes
+
+#[tokio::test]
+async fn test_public_student_routes_skip_authentication() {
+    let config = AppConfig {
+        public_student_routes: vec![
+            "get_available_games".to_string(),
+            "get_public_courses".to_string(),
+        ],
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let course_id = create_test_course(&pool, "Anonymous Course").await;
+    let game_id = create_test_game(&pool, course_id, "Anonymous Game", 0).await;
+    update_game_status(&pool, game_id, true, true).await;
+    create_test_player(&pool, 150, "anonymous@test.com", "Anonymous Player").await;
+
+    let response = server
+        .get("/student/get_available_games")
+        .add_header(TEST_ANONYMOUS_HEADER, "1")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<CatalogPageResponse> = response.json();
+    assert_eq!(body.data.unwrap().ids, vec![game_id]);
+
+    let response = server
+        .get("/student/get_player_games?player_id=150")
+        .add_header(TEST_ANONYMOUS_HEADER, "1")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_student_routes_require_authentication_by_default() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server
+        .get("/student/get_available_games")
+        .add_header(TEST_ANONYMOUS_HEADER, "1")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNAUTHORIZED);
+}
+
 // get_available_games
 
 #[tokio::test]
@@ -62,12 +118,12 @@ async fn test_get_available_games_success() {
     let response = server.get("/student/get_available_games").await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<Vec<i64>> = response.json();
+    let body: ApiResponse<CatalogPageResponse> = response.json();
     assert_eq!(body.status_code, 200);
     assert!(body.data.is_some());
-    let mut game_ids = body.data.unwrap();
-    game_ids.sort();
-    assert_eq!(game_ids, vec![game1_id, game3_id]);
+    let page = body.data.unwrap();
+    assert_eq!(page.ids, vec![game1_id, game3_id]);
+    assert!(page.next_cursor.is_none());
 }
 
 #[tokio::test]
@@ -90,10 +146,140 @@ async fn test_get_available_games_success_none_available() {
     let response = server.get("/student/get_available_games").await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<Vec<i64>> = response.json();
+    let body: ApiResponse<CatalogPageResponse> = response.json();
     assert_eq!(body.status_code, 200);
     assert!(body.data.is_some());
-    assert!(body.data.unwrap().is_empty());
+    assert!(body.data.unwrap().ids.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_available_games_includes_eligible_private_games() {
+    let (server, pool) = setup_test_environment().await;
+    let member_id = 916;
+    let outsider_id = 917;
+    let course_id = create_test_course(&pool, "Eligible Course").await;
+    let public_game_id = create_test_game(&pool, course_id, "Eligible Public Game", 1).await;
+    let private_game_id = create_test_game(&pool, course_id, "Eligible Private Game", 1).await;
+    update_game_status(&pool, public_game_id, true, true).await;
+    let group_id = create_test_group_with_id(&pool, 300, "Eligible Group").await;
+    create_test_instructor(&pool, 916, "eligible_inst@test.com", "Eligible Inst").await;
+    create_test_invite(&pool, 916, Some(private_game_id), Some(group_id)).await;
+    create_test_player(&pool, member_id, "eligible_m@test.com", "Eligible M").await;
+    create_test_player(&pool, outsider_id, "eligible_o@test.com", "Eligible O").await;
+    add_player_to_group(&pool, member_id, group_id).await;
+
+    let ids_for = |player_id: Option<i64>| {
+        let mut request = server.get("/student/get_available_games");
+        if let Some(player_id) = player_id {
+            request = request.add_query_param("player_id", player_id);
+        }
+        async move {
+            let response = request.await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response
+                .json::<ApiResponse<CatalogPageResponse>>()
+                .data
+                .unwrap()
+                .ids
+        }
+    };
+
+    assert_eq!(ids_for(None).await, vec![public_game_id]);
+    assert_eq!(ids_for(Some(outsider_id)).await, vec![public_game_id]);
+    assert_eq!(
+        ids_for(Some(member_id)).await,
+        vec![public_game_id, private_game_id]
+    );
+
+    // the catalog of another player cannot be requested
+    let response = server
+        .get("/student/get_available_games")
+        .add_query_param("player_id", member_id)
+        .add_header(TEST_EMAIL_HEADER, "eligible_o@test.com")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_get_available_games_cursor_paging_stable_under_inserts() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Paged Course").await;
+    let mut expected = Vec::new();
+    for i in 0..5 {
+        let game_id = create_test_game(&pool, course_id, &format!("Paged Game {}", i), 1).await;
+        update_game_status(&pool, game_id, true, true).await;
+        expected.push(game_id);
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor: Option<i64> = None;
+    loop {
+        let mut request = server
+            .get("/student/get_available_games")
+            .add_query_param("limit", 2);
+        if let Some(after_id) = cursor {
+            request = request.add_query_param("after_id", after_id);
+        }
+        let body: ApiResponse<CatalogPageResponse> = request.await.json();
+        let page = body.data.unwrap();
+        seen.extend(page.ids);
+
+        if cursor.is_none() {
+            // A game published mid-iteration must show up once, without shifting earlier pages.
+            let late_id = create_test_game(&pool, course_id, "Late Game", 1).await;
+            update_game_status(&pool, late_id, true, true).await;
+            expected.push(late_id);
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(seen, expected);
+}
+
+// get_public_courses
+
+#[tokio::test]
+async fn test_get_public_courses_cursor_paging() {
+    let (server, pool) = setup_test_environment().await;
+    let mut expected = Vec::new();
+    for i in 0..3 {
+        let course_id = create_test_course(&pool, &format!("Public Course {}", i)).await;
+        expected.push(course_id);
+    }
+    let private_id = create_test_course(&pool, "Private Course").await;
+    let publish = expected.clone();
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::courses::table.filter(schema::courses::id.eq_any(publish)))
+            .set(schema::courses::public.eq(true))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/student/get_public_courses")
+        .add_query_param("limit", 2)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<CatalogPageResponse> = response.json();
+    let first = body.data.unwrap();
+    assert_eq!(first.ids, expected[..2].to_vec());
+    assert_eq!(first.next_cursor, Some(expected[1]));
+
+    let response = server
+        .get("/student/get_public_courses")
+        .add_query_param("limit", 2)
+        .add_query_param("after_id", first.next_cursor.unwrap())
+        .await;
+    let body: ApiResponse<CatalogPageResponse> = response.json();
+    let second = body.data.unwrap();
+    assert_eq!(second.ids, vec![expected[2]]);
+    assert!(second.next_cursor.is_none());
+    assert!(!second.ids.contains(&private_id));
 }
 
 // join_game
@@ -209,6 +395,7 @@ async fn test_save_game_success() {
     let payload = SaveGamePayload {
         player_registrations_id: registration_id,
         game_state: game_state.clone(),
+        if_saved_at: None,
     };
 
     let response = server.post("/student/save_game").json(&payload).await;
@@ -237,6 +424,84 @@ async fn test_save_game_success() {
     assert!(saved_at > Utc::now() - chrono::Duration::seconds(5));
 }
 
+#[tokio::test]
+async fn test_save_game_precondition_matching_succeeds() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 202;
+    let course_id = create_test_course(&pool, "Save Precond Course").await;
+    let game_id = create_test_game(&pool, course_id, "Save Precond Game", 1).await;
+    create_test_player(&pool, player_id, "save_pre@test.com", "Save Pre Player").await;
+    let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+
+    let metadata = server
+        .get(&format!("/student/get_game_metadata/{}", registration_id))
+        .await
+        .json::<ApiResponse<GameMetadata>>()
+        .data
+        .unwrap();
+
+    let payload = SaveGamePayload {
+        player_registrations_id: registration_id,
+        game_state: json!({"level": 2}),
+        if_saved_at: Some(metadata.saved_at),
+    };
+    let response = server.post("/student/save_game").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(response.json::<ApiResponse<bool>>().data.unwrap_or(false));
+}
+
+#[tokio::test]
+async fn test_save_game_precondition_stale_conflict() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 203;
+    let course_id = create_test_course(&pool, "Save Stale Course").await;
+    let game_id = create_test_game(&pool, course_id, "Save Stale Game", 1).await;
+    create_test_player(&pool, player_id, "save_stale@test.com", "Save Stale Player").await;
+    let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+
+    let read_saved_at = server
+        .get(&format!("/student/get_game_metadata/{}", registration_id))
+        .await
+        .json::<ApiResponse<GameMetadata>>()
+        .data
+        .unwrap()
+        .saved_at;
+
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let other_tab = SaveGamePayload {
+        player_registrations_id: registration_id,
+        game_state: json!({"level": 3, "tab": "other"}),
+        if_saved_at: None,
+    };
+    let response = server.post("/student/save_game").json(&other_tab).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let payload = SaveGamePayload {
+        player_registrations_id: registration_id,
+        game_state: json!({"level": 2}),
+        if_saved_at: Some(read_saved_at),
+    };
+    let response = server.post("/student/save_game").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains("game state changed"));
+
+    let conn = pool.get().await.unwrap();
+    let saved_state: Value = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .find(registration_id)
+                .select(schema::player_registrations::game_state)
+                .first(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved_state, json!({"level": 3, "tab": "other"}));
+}
+
 #[tokio::test]
 async fn test_save_game_not_found_registration() {
     let (server, _pool) = setup_test_environment().await;
@@ -245,6 +510,7 @@ async fn test_save_game_not_found_registration() {
     let payload = SaveGamePayload {
         player_registrations_id: non_existent_registration_id,
         game_state: json!({}),
+        if_saved_at: None,
     };
 
     let response = server.post("/student/save_game").json(&payload).await;
@@ -255,6 +521,37 @@ async fn test_save_game_not_found_registration() {
     assert!(body.status_message.contains("Player registration"));
 }
 
+#[tokio::test]
+async fn test_save_game_forbidden_after_leaving() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 205;
+    let course_id = create_test_course(&pool, "Save Left Course").await;
+    let game_id = create_test_game(&pool, course_id, "Save Left Game", 1).await;
+    create_test_player(&pool, player_id, "save_left@test.com", "Save Left Player").await;
+    let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+    server
+        .post("/student/leave_game")
+        .json(&LeaveGamePayload { player_id, game_id })
+        .await
+        .assert_status_ok();
+
+    let payload = SaveGamePayload {
+        player_registrations_id: registration_id,
+        game_state: json!({"level": 9}),
+        if_saved_at: None,
+    };
+
+    let response = server.post("/student/save_game").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    let body: ApiResponse<Value> = response.json();
+    assert_eq!(body.status_code, 403);
+    assert!(
+        body.status_message
+            .contains("Player not registered in game")
+    );
+}
+
 // load_game
 
 #[tokio::test]
@@ -308,6 +605,106 @@ async fn test_load_game_not_found_registration() {
     assert_eq!(body.status_code, 404);
 }
 
+// checkpoints
+
+#[tokio::test]
+async fn test_checkpoints_save_load_list_and_cap() {
+    let config = AppConfig {
+        max_checkpoints_per_registration: 2,
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let player_id = 206;
+    let course_id = create_test_course(&pool, "Checkpoint Course").await;
+    let game_id = create_test_game(&pool, course_id, "Checkpoint Game", 1).await;
+    create_test_player(&pool, player_id, "checkpoint@test.com", "Checkpoint Player").await;
+    let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+
+    for (name, game_state) in [
+        ("before boss", json!({"level": 3})),
+        ("after boss", json!({"level": 4})),
+    ] {
+        let payload = SaveCheckpointPayload {
+            player_registrations_id: registration_id,
+            name: name.to_string(),
+            game_state,
+        };
+        let response = server.post("/student/save_checkpoint").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK, "{}", name);
+    }
+
+    for (name, expected) in [
+        ("before boss", json!({"level": 3})),
+        ("after boss", json!({"level": 4})),
+    ] {
+        let payload = LoadCheckpointPayload {
+            player_registrations_id: registration_id,
+            name: name.to_string(),
+        };
+        let response = server.post("/student/load_checkpoint").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK, "{}", name);
+        assert_eq!(
+            response.json::<ApiResponse<Value>>().data.unwrap(),
+            expected
+        );
+    }
+
+    let over_cap = SaveCheckpointPayload {
+        player_registrations_id: registration_id,
+        name: "third".to_string(),
+        game_state: json!({"level": 5}),
+    };
+    let response = server
+        .post("/student/save_checkpoint")
+        .json(&over_cap)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+
+    let overwrite = SaveCheckpointPayload {
+        player_registrations_id: registration_id,
+        name: "after boss".to_string(),
+        game_state: json!({"level": 6}),
+    };
+    let response = server
+        .post("/student/save_checkpoint")
+        .json(&overwrite)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/student/list_checkpoints")
+        .add_query_param("player_registrations_id", registration_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let names: Vec<String> = response
+        .json::<ApiResponse<Vec<CheckpointSummary>>>()
+        .data
+        .unwrap()
+        .into_iter()
+        .map(|checkpoint| checkpoint.name)
+        .collect();
+    assert_eq!(names, vec!["after boss", "before boss"]);
+
+    let response = server
+        .post("/student/load_game")
+        .json(&LoadGamePayload {
+            player_registrations_id: registration_id,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        response.json::<ApiResponse<Value>>().data.unwrap(),
+        json!({})
+    );
+
+    let missing = LoadCheckpointPayload {
+        player_registrations_id: registration_id,
+        name: "third".to_string(),
+    };
+    let response = server.post("/student/load_checkpoint").json(&missing).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // leave_game
 
 #[tokio::test]
@@ -342,6 +739,21 @@ async fn test_leave_game_success() {
 
     assert!(left_at.is_some());
     assert!(left_at.unwrap() > Utc::now() - chrono::Duration::seconds(5));
+
+    // a retried leave succeeds without touching the registration
+    let response = server.post("/student/leave_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let left_at_after_retry: Option<chrono::DateTime<Utc>> = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .find(registration_id)
+                .select(schema::player_registrations::left_at)
+                .first(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(left_at_after_retry, left_at);
 }
 
 #[tokio::test]
@@ -361,12 +773,12 @@ async fn test_leave_game_not_found_registration() {
     assert_eq!(body.status_code, 404);
     assert!(
         body.status_message
-            .contains("Active player registration not found")
+            .contains("Player registration not found")
     );
 }
 
 #[tokio::test]
-async fn test_leave_game_already_left() {
+async fn test_leave_game_already_left_is_noop() {
     let (server, pool) = setup_test_environment().await;
     let player_id = 403;
     let course_id = create_test_course(&pool, "Leave AL Course").await;
@@ -374,10 +786,11 @@ async fn test_leave_game_already_left() {
     create_test_player(&pool, player_id, "leave_al@test.com", "Leave AL Player").await;
     let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
 
+    let left_at = Utc::now() - chrono::Duration::days(1);
     let conn = pool.get().await.unwrap();
     conn.interact(move |conn| {
         diesel::update(schema::player_registrations::table.find(registration_id))
-            .set(schema::player_registrations::left_at.eq(Utc::now()))
+            .set(schema::player_registrations::left_at.eq(left_at))
             .execute(conn)
     })
     .await
@@ -387,7 +800,21 @@ async fn test_leave_game_already_left() {
     let payload = LeaveGamePayload { player_id, game_id };
     let response = server.post("/student/leave_game").json(&payload).await;
 
-    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let stored_left_at: Option<chrono::DateTime<Utc>> = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .find(registration_id)
+                .select(schema::player_registrations::left_at)
+                .first(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stored_left_at.map(|t| t.timestamp_micros()),
+        Some(left_at.timestamp_micros())
+    );
 }
 
 // set_game_lang
@@ -555,7 +982,7 @@ async fn test_get_player_games_success_all() {
 
     let response = server
         .get(&format!(
-            "/student/get_player_games?player_id={}&active=false",
+            "/student/get_player_games?player_id={}&active=false&include_left=true",
             player_id
         ))
         .await;
@@ -571,6 +998,62 @@ async fn test_get_player_games_success_all() {
     assert_eq!(reg_ids, expected_ids);
 }
 
+#[tokio::test]
+async fn test_get_player_games_excludes_left_by_default_and_pages() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 604;
+    let course_id = create_test_course(&pool, "PlayerGames Paging Course").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "pg_paging@test.com",
+        "Player Games Paging",
+    )
+    .await;
+    let mut reg_ids = Vec::new();
+    for title in ["PGP Game 1", "PGP Game 2", "PGP Game 3"] {
+        let game_id = create_test_game(&pool, course_id, title, 1).await;
+        reg_ids.push(create_test_player_registration(&pool, player_id, game_id).await);
+    }
+    let reg_left_id = reg_ids[1];
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_registrations::table.find(reg_left_id))
+            .set(schema::player_registrations::left_at.eq(Utc::now()))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let player_games = |query: &'static str| {
+        let server = &server;
+        async move {
+            let response = server
+                .get(&format!(
+                    "/student/get_player_games?player_id={}&active=false{}",
+                    player_id, query
+                ))
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response.json::<ApiResponse<Vec<i64>>>().data.unwrap()
+        }
+    };
+
+    assert_eq!(player_games("").await, vec![reg_ids[0], reg_ids[2]]);
+    assert_eq!(player_games("&include_left=true").await, reg_ids);
+    assert_eq!(
+        player_games("&include_left=true&offset=1&limit=1").await,
+        vec![reg_ids[1]]
+    );
+    assert_eq!(
+        player_games("&include_left=true&offset=2&limit=2").await,
+        vec![reg_ids[2]]
+    );
+    assert_eq!(player_games("&offset=1&limit=1").await, vec![reg_ids[2]]);
+}
+
 #[tokio::test]
 async fn test_get_player_games_success_no_registrations() {
     let (server, pool) = setup_test_environment().await;
@@ -607,6 +1090,68 @@ async fn test_get_player_games_not_found_player() {
     assert!(body.status_message.contains("Player with ID"));
 }
 
+// get_player_registrations
+
+#[tokio::test]
+async fn test_get_player_registrations_includes_left_games() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 621;
+    let course_id = create_test_course(&pool, "Registrations Course").await;
+    let kept_game_id = create_test_game(&pool, course_id, "Registrations Kept Game", 1).await;
+    let left_game_id = create_test_game(&pool, course_id, "Registrations Left Game", 1).await;
+    create_test_player(&pool, player_id, "registrations@test.com", "Reg Player").await;
+    let kept_registration_id =
+        create_test_player_registration(&pool, player_id, kept_game_id).await;
+    let left_registration_id =
+        create_test_player_registration(&pool, player_id, left_game_id).await;
+
+    let payload = LeaveGamePayload {
+        player_id,
+        game_id: left_game_id,
+    };
+    let response = server.post("/student/leave_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get(&format!(
+            "/student/get_player_registrations?player_id={}",
+            player_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<PlayerRegistrationResponse>> = response.json();
+    let registrations = body.data.unwrap();
+    assert_eq!(registrations.len(), 2);
+
+    let kept = registrations
+        .iter()
+        .find(|r| r.registration_id == kept_registration_id)
+        .unwrap();
+    assert_eq!(kept.game_id, kept_game_id);
+    assert_eq!(kept.game_title, "Registrations Kept Game");
+    assert_eq!(kept.language, "en");
+    assert!(kept.left_at.is_none());
+
+    let left = registrations
+        .iter()
+        .find(|r| r.registration_id == left_registration_id)
+        .unwrap();
+    assert_eq!(left.game_id, left_game_id);
+    assert_eq!(left.game_title, "Registrations Left Game");
+    assert!(left.left_at.is_some());
+}
+
+#[tokio::test]
+async fn test_get_player_registrations_not_found_player() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server
+        .get("/student/get_player_registrations?player_id=99621")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // get_game_metadata
 
 #[tokio::test]
@@ -648,6 +1193,43 @@ async fn test_get_game_metadata_success() {
     assert!(metadata.left_at.is_none());
 }
 
+#[tokio::test]
+async fn test_get_game_metadata_conditional_get() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 702;
+    let course_id = create_test_course(&pool, "ETag Course").await;
+    let game_id = create_test_game(&pool, course_id, "ETag Game", 5).await;
+    create_test_player(&pool, player_id, "meta_etag@test.com", "ETag Player").await;
+    let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+    let url = format!("/student/get_game_metadata/{}", registration_id);
+
+    let response = server.get(&url).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let etag = response.header(header::ETAG);
+
+    let response = server
+        .get(&url)
+        .add_header(header::IF_NONE_MATCH, etag.clone())
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_MODIFIED);
+    assert!(response.as_bytes().is_empty());
+
+    let payload = SaveGamePayload {
+        player_registrations_id: registration_id,
+        game_state: json!({"level": 2}),
+        if_saved_at: None,
+    };
+    let response = server.post("/student/save_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get(&url)
+        .add_header(header::IF_NONE_MATCH, etag.clone())
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_ne!(response.header(header::ETAG), etag);
+}
+
 #[tokio::test]
 async fn test_get_game_metadata_not_found_registration() {
     let (server, _pool) = setup_test_environment().await;
@@ -674,7 +1256,7 @@ async fn test_get_course_data_success() {
     let game_id = create_test_game(&pool, course_id, "CourseData Game", 3).await;
     let module1_id = create_test_module(&pool, course_id, 1, "CD Mod EN 1").await;
     let module2_id = create_test_module(&pool, course_id, 2, "CD Mod EN 2").await;
-    let _module3_id = create_test_module(&pool, course_id, 1, "CD Mod FR 1").await;
+    let _module3_id = create_test_module(&pool, course_id, 3, "CD Mod FR 1").await;
 
     let conn = pool.get().await.unwrap();
     conn.interact(move |conn| {
@@ -692,7 +1274,10 @@ async fn test_get_course_data_success() {
             .set(schema::modules::language.eq("en"))
             .execute(conn)?;
         diesel::update(schema::modules::table.find(_module3_id))
-            .set(schema::modules::language.eq("fr"))
+            .set((
+                schema::modules::language.eq("fr"),
+                schema::modules::order.eq(1),
+            ))
             .execute(conn)?;
         Ok::<_, diesel::result::Error>(())
     })
@@ -766,17 +1351,146 @@ async fn test_get_course_data_not_found_game() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
 
-// get_module_data
-
 #[tokio::test]
-async fn test_get_module_data_success() {
+async fn test_get_course_data_parsed_success() {
     let (server, pool) = setup_test_environment().await;
-    let course_id = create_test_course(&pool, "ModuleData Course").await;
-    let module_id = create_test_module(&pool, course_id, 1, "ModuleData Module").await;
-    let ex1_id = create_test_exercise(&pool, module_id, 1, "MD Ex PY 1").await;
+    let course_id = create_test_course(&pool, "CourseData Parsed Course").await;
+    let game_id = create_test_game(&pool, course_id, "CourseData Parsed Game", 0).await;
+    let module_id = create_test_module(&pool, course_id, 1, "CD Parsed EN 1").await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::courses::table.find(course_id))
+            .set((
+                schema::courses::gamification_rule_conditions
+                    .eq(r#"{"first_solve":{"min_result":50}}"#),
+                schema::courses::gamification_complex_rules.eq("[]"),
+                schema::courses::gamification_rule_results.eq(r#"{"score":{"exercise":10}}"#),
+            ))
+            .execute(conn)?;
+        diesel::update(schema::modules::table.find(module_id))
+            .set(schema::modules::language.eq("en"))
+            .execute(conn)?;
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get(&format!(
+            "/student/get_course_data_parsed?game_id={}&language=en",
+            game_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ParsedCourseDataResponse> = response.json();
+    let data = body.data.unwrap();
+    assert_eq!(
+        data.gamification_rule_conditions,
+        json!({"first_solve": {"min_result": 50}})
+    );
+    assert_eq!(data.gamification_complex_rules, json!([]));
+    assert_eq!(
+        data.gamification_rule_results["score"]["exercise"],
+        json!(10)
+    );
+    assert_eq!(data.module_ids, vec![module_id]);
+}
+
+#[tokio::test]
+async fn test_get_course_data_parsed_corrupt_blob() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "CourseData Corrupt Course").await;
+    let game_id = create_test_game(&pool, course_id, "CourseData Corrupt Game", 0).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::courses::table.find(course_id))
+            .set(schema::courses::gamification_complex_rules.eq("{not json"))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get(&format!(
+            "/student/get_course_data_parsed?game_id={}&language=en",
+            game_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: ApiResponse<()> = response.json();
+    assert!(body.status_message.contains(&format!(
+        "game {} has invalid JSON in gamification_complex_rules",
+        game_id
+    )));
+}
+
+// get_course_tree
+
+#[tokio::test]
+async fn test_get_course_tree_hides_hidden_exercises_until_unlocked() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 830;
+    let course_id = create_test_course(&pool, "Tree Student Course").await;
+    let game_id = create_test_game(&pool, course_id, "Tree Student Game", 4).await;
+    let second_module = create_test_module(&pool, course_id, 2, "Tree Student Module B").await;
+    let first_module = create_test_module(&pool, course_id, 1, "Tree Student Module A").await;
+    let a1 = create_test_exercise(&pool, first_module, 1, "Tree A1").await;
+    let b2 = create_test_exercise(&pool, second_module, 2, "Tree B2").await;
+    let b1 = create_test_exercise(&pool, second_module, 1, "Tree B1").await;
+    let a2 = create_test_exercise(&pool, first_module, 2, "Tree A2").await;
+    create_test_player(&pool, player_id, "tree_student@test.com", "Tree Student").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::exercises::table.filter(schema::exercises::id.eq_any([a2, b1])))
+            .set(schema::exercises::hidden.eq(true))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    create_test_player_unlock(&pool, player_id, b1).await;
+
+    let response = server
+        .get(&format!(
+            "/student/get_course_tree?game_id={}&player_id={}&language=en",
+            game_id, player_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<StudentCourseTreeResponse> = response.json();
+    let tree = body.data.unwrap();
+    assert_eq!(tree.course_id, course_id);
+    let nested: Vec<(i64, Vec<i64>)> = tree
+        .modules
+        .iter()
+        .map(|m| (m.id, m.exercises.iter().map(|e| e.id).collect()))
+        .collect();
+    assert_eq!(
+        nested,
+        vec![(first_module, vec![a1]), (second_module, vec![b1, b2])]
+    );
+}
+
+// get_module_data
+
+#[tokio::test]
+async fn test_get_module_data_success() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "ModuleData Course").await;
+    let module_id = create_test_module(&pool, course_id, 1, "ModuleData Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "MD Ex PY 1").await;
     let ex2_id = create_test_exercise(&pool, module_id, 2, "MD Ex PY 2").await;
-    let _ex3_id = create_test_exercise(&pool, module_id, 1, "MD Ex RS 1").await;
-    let _ex4_id = create_test_exercise(&pool, module_id, 1, "MD Ex PY FR 1").await;
+    let _ex3_id = create_test_exercise(&pool, module_id, 3, "MD Ex RS 1").await;
+    let _ex4_id = create_test_exercise(&pool, module_id, 4, "MD Ex PY FR 1").await;
 
     let conn = pool.get().await.unwrap();
     conn.interact(move |conn| {
@@ -796,12 +1510,14 @@ async fn test_get_module_data_success() {
             .set((
                 schema::exercises::language.eq("en"),
                 schema::exercises::programming_language.eq("rs"),
+                schema::exercises::order.eq(1),
             ))
             .execute(conn)?;
         diesel::update(schema::exercises::table.find(_ex4_id))
             .set((
                 schema::exercises::language.eq("fr"),
                 schema::exercises::programming_language.eq("py"),
+                schema::exercises::order.eq(1),
             ))
             .execute(conn)?;
         Ok::<_, diesel::result::Error>(())
@@ -902,6 +1618,7 @@ async fn test_get_exercise_data_success_basic() {
     assert!(body.data.is_some());
     let data = body.data.unwrap();
     assert_eq!(data.title, "ExData Ex 1");
+    assert_eq!(data.mode, ExerciseMode::Code);
     assert!(!data.hidden);
     assert!(!data.locked);
 }
@@ -1209,6 +1926,182 @@ async fn test_get_exercise_data_not_found_exercise() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
 
+// get_exercise_lock_states
+
+#[tokio::test]
+async fn test_get_exercise_lock_states_exercise_lock_follows_prerequisites() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 820;
+    let course_id = create_test_course(&pool, "LockStates Course").await;
+    let game_id = create_test_game(&pool, course_id, "LockStates Game", 3).await;
+    let module_id = create_test_module(&pool, course_id, 1, "LockStates Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "LockStates Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "LockStates Ex 2").await;
+    let ex3_id = create_test_exercise(&pool, module_id, 3, "LockStates Ex 3").await;
+    create_test_player(&pool, player_id, "lockstates@test.com", "LockStates P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(game_id))
+            .set(schema::games::exercise_lock.eq(true))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let url = format!(
+        "/student/get_exercise_lock_states?player_id={}&game_id={}&module_id={}",
+        player_id, game_id, module_id
+    );
+    let locked_ids = |states: Vec<ExerciseLockState>| -> Vec<(i64, bool)> {
+        states
+            .into_iter()
+            .map(|s| (s.exercise_id, s.locked))
+            .collect()
+    };
+
+    let response = server.get(&url).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<ExerciseLockState>> = response.json();
+    assert_eq!(
+        locked_ids(body.data.unwrap()),
+        vec![(ex1_id, false), (ex2_id, true), (ex3_id, true)]
+    );
+
+    create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    let body: ApiResponse<Vec<ExerciseLockState>> = server.get(&url).await.json();
+    assert_eq!(
+        locked_ids(body.data.unwrap()),
+        vec![(ex1_id, false), (ex2_id, false), (ex3_id, true)]
+    );
+
+    create_test_player_unlock(&pool, player_id, ex3_id).await;
+    let body: ApiResponse<Vec<ExerciseLockState>> = server.get(&url).await.json();
+    assert_eq!(
+        locked_ids(body.data.unwrap()),
+        vec![(ex1_id, false), (ex2_id, false), (ex3_id, false)]
+    );
+}
+
+#[tokio::test]
+async fn test_get_exercise_lock_states_module_outside_game() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 821;
+    let course_id = create_test_course(&pool, "LockStates NF Course").await;
+    let other_course_id = create_test_course(&pool, "LockStates NF Other Course").await;
+    let game_id = create_test_game(&pool, course_id, "LockStates NF Game", 1).await;
+    let module_id = create_test_module(&pool, other_course_id, 1, "LockStates NF Module").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "lockstates_nf@test.com",
+        "LockStates NF P",
+    )
+    .await;
+
+    let response = server
+        .get(&format!(
+            "/student/get_exercise_lock_states?player_id={}&game_id={}&module_id={}",
+            player_id, game_id, module_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.text().contains("Module with ID"));
+}
+
+// get_course_modules_data
+
+#[tokio::test]
+async fn test_get_course_modules_data_orders_and_flags_locked_modules() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 822;
+    let course_id = create_test_course(&pool, "ModulesData Course").await;
+    let game_id = create_test_game(&pool, course_id, "ModulesData Game", 4).await;
+    let module2_id = create_test_module(&pool, course_id, 2, "ModulesData Module 2").await;
+    let module1_id = create_test_module(&pool, course_id, 1, "ModulesData Module 1").await;
+    let module3_id = create_test_module(&pool, course_id, 3, "ModulesData Module 3").await;
+    let ex1_id = create_test_exercise(&pool, module1_id, 1, "ModulesData Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module1_id, 2, "ModulesData Ex 2").await;
+    let java_ex_id = create_test_exercise(&pool, module1_id, 3, "ModulesData Java Ex").await;
+    let ex3_id = create_test_exercise(&pool, module2_id, 1, "ModulesData Ex 3").await;
+    let ex4_id = create_test_exercise(&pool, module3_id, 1, "ModulesData Ex 4").await;
+    create_test_player(&pool, player_id, "modulesdata@test.com", "ModulesData P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(game_id))
+            .set(schema::games::module_lock.eq(0.5))
+            .execute(conn)?;
+        diesel::update(schema::exercises::table.find(java_ex_id))
+            .set(schema::exercises::programming_language.eq("java"))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let url = format!(
+        "/student/get_course_modules_data?player_id={}&game_id={}&language=en",
+        player_id, game_id
+    );
+    let summary = |modules: Vec<CourseModuleData>| -> Vec<(i64, i32, Vec<i64>, bool)> {
+        modules
+            .into_iter()
+            .map(|m| (m.module_id, m.data.order, m.data.exercise_ids, m.locked))
+            .collect()
+    };
+
+    let response = server.get(&url).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<CourseModuleData>> = response.json();
+    assert_eq!(
+        summary(body.data.unwrap()),
+        vec![
+            (module1_id, 1, vec![ex1_id, ex2_id], false),
+            (module2_id, 2, vec![ex3_id], true),
+            (module3_id, 3, vec![ex4_id], true),
+        ]
+    );
+
+    create_test_submission(&pool, player_id, game_id, ex2_id, true, 1.0).await;
+    let body: ApiResponse<Vec<CourseModuleData>> = server.get(&url).await.json();
+    assert_eq!(
+        summary(body.data.unwrap()),
+        vec![
+            (module1_id, 1, vec![ex1_id, ex2_id], false),
+            (module2_id, 2, vec![ex3_id], false),
+            (module3_id, 3, vec![ex4_id], true),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_course_modules_data_requires_registration() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 823;
+    let course_id = create_test_course(&pool, "ModulesData NR Course").await;
+    let game_id = create_test_game(&pool, course_id, "ModulesData NR Game", 1).await;
+    create_test_player(
+        &pool,
+        player_id,
+        "modulesdata_nr@test.com",
+        "ModulesData NR P",
+    )
+    .await;
+
+    let response = server
+        .get(&format!(
+            "/student/get_course_modules_data?player_id={}&game_id={}&language=en",
+            player_id, game_id
+        ))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
 // submit_solution
 
 #[tokio::test]
@@ -1229,19 +2122,20 @@ async fn test_submit_solution_success_first_correct() {
         client: "test".to_string(),
         submitted_code: "correct".to_string(),
         metrics: json!({}),
-        result: BigDecimal::from_f64(1.0).unwrap(),
+        result: BigDecimal::from(100),
         result_description: json!({"status": "pass"}),
         feedback: "".to_string(),
         entered_at: Utc::now(),
         earned_rewards: json!([]),
+        time_spent_seconds: None,
     };
 
     let response = server.post("/student/submit_solution").json(&payload).await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
+    let body: ApiResponse<SubmitResult> = response.json();
     assert_eq!(body.status_code, 200);
-    assert!(body.data.unwrap_or(false));
+    assert!(body.data.unwrap().first_solution);
 
     let conn = pool.get().await.unwrap();
     let (sub_count, progress): (i64, i32) = conn
@@ -1295,18 +2189,19 @@ async fn test_submit_solution_success_subsequent_correct() {
         client: "test".to_string(),
         submitted_code: "correct again".to_string(),
         metrics: json!({}),
-        result: BigDecimal::from_f64(1.0).unwrap(),
+        result: BigDecimal::from(100),
         result_description: json!({"status": "pass"}),
         feedback: "".to_string(),
         entered_at: Utc::now(),
         earned_rewards: json!([]),
+        time_spent_seconds: None,
     };
 
     let response = server.post("/student/submit_solution").json(&payload).await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(!body.data.unwrap_or(true));
+    let body: ApiResponse<SubmitResult> = response.json();
+    assert!(!body.data.unwrap().first_solution);
 
     let conn = pool.get().await.unwrap();
     let (sub_count, progress): (i64, i32) = conn
@@ -1354,13 +2249,14 @@ async fn test_submit_solution_success_incorrect() {
         feedback: "Try again".to_string(),
         entered_at: Utc::now(),
         earned_rewards: json!([]),
+        time_spent_seconds: None,
     };
 
     let response = server.post("/student/submit_solution").json(&payload).await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(!body.data.unwrap_or(true));
+    let body: ApiResponse<SubmitResult> = response.json();
+    assert!(!body.data.unwrap().first_solution);
 
     let conn = pool.get().await.unwrap();
     let (sub_count, progress): (i64, i32) = conn
@@ -1385,6 +2281,50 @@ async fn test_submit_solution_success_incorrect() {
     assert_eq!(progress, 0);
 }
 
+#[tokio::test]
+async fn test_submit_solution_reports_progress_new_vs_resolve() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 906;
+    let course_id = create_test_course(&pool, "Submit Result Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Result Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Result Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Result Ex 1").await;
+    create_test_player(&pool, player_id, "submit_res@test.com", "Submit Result P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let payload = SubmitSolutionPayload {
+        player_id,
+        exercise_id,
+        game_id,
+        client: "test".to_string(),
+        submitted_code: "correct".to_string(),
+        metrics: json!({}),
+        result: BigDecimal::from(100),
+        result_description: json!({"status": "pass"}),
+        feedback: "".to_string(),
+        entered_at: Utc::now(),
+        earned_rewards: json!([]),
+        time_spent_seconds: None,
+    };
+
+    let response = server.post("/student/submit_solution").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let first = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+    assert!(first.first_solution);
+    assert_eq!(first.solved_exercises, 1);
+    assert!((first.progress - 50.0).abs() < f64::EPSILON);
+    assert!(first.new_rewards.is_empty());
+
+    let response = server.post("/student/submit_solution").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let second = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+    assert!(!second.first_solution);
+    assert_eq!(second.solved_exercises, 1);
+    assert!((second.progress - 50.0).abs() < f64::EPSILON);
+}
+
 #[tokio::test]
 async fn test_submit_solution_triggers_unlock() {
     let (server, pool) = setup_test_environment().await;
@@ -1413,11 +2353,12 @@ async fn test_submit_solution_triggers_unlock() {
         client: "test".to_string(),
         submitted_code: "correct".to_string(),
         metrics: json!({}),
-        result: BigDecimal::from_f64(1.0).unwrap(),
+        result: BigDecimal::from(100),
         result_description: json!({"status": "pass"}),
         feedback: "".to_string(),
         entered_at: Utc::now(),
         earned_rewards: json!([]),
+        time_spent_seconds: None,
     };
 
     let response = server.post("/student/submit_solution").json(&payload).await;
@@ -1431,7 +2372,7 @@ async fn test_submit_solution_triggers_unlock() {
 }
 
 #[tokio::test]
-async fn test_submit_solution_not_found_registration() {
+async fn test_submit_solution_forbidden_not_registered() {
     let (server, pool) = setup_test_environment().await;
     let player_id = 905;
     let course_id = create_test_course(&pool, "Submit NFReg Course").await;
@@ -1452,104 +2393,1091 @@ async fn test_submit_solution_not_found_registration() {
         feedback: "".to_string(),
         entered_at: Utc::now(),
         earned_rewards: json!([]),
+        time_spent_seconds: None,
     };
 
     let response = server.post("/student/submit_solution").json(&payload).await;
 
-    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
-    assert!(response.text().contains("Player registration not found"));
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert!(response.text().contains("not registered in game"));
 }
 
-// unlock
+fn minimal_submission(player_id: i64, exercise_id: i64, game_id: i64) -> SubmitSolutionPayload {
+    SubmitSolutionPayload {
+        player_id,
+        exercise_id,
+        game_id,
+        client: "test".to_string(),
+        submitted_code: "code".to_string(),
+        metrics: json!({}),
+        result: BigDecimal::from(100),
+        result_description: json!({}),
+        feedback: "".to_string(),
+        entered_at: Utc::now(),
+        earned_rewards: json!([]),
+        time_spent_seconds: None,
+    }
+}
 
 #[tokio::test]
-async fn test_unlock_success_new_unlock() {
+async fn test_submit_solution_uses_game_success_threshold() {
     let (server, pool) = setup_test_environment().await;
-    let player_id = 1001;
-    let course_id = create_test_course(&pool, "Unlock Course").await;
-    let module_id = create_test_module(&pool, course_id, 1, "Unlock Module").await;
-    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock Ex 1").await;
-    create_test_player(&pool, player_id, "unlock@test.com", "Unlock Player").await;
-
-    let payload = UnlockPayload {
+    let player_id = 921;
+    let course_id = create_test_course(&pool, "Submit Threshold Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Threshold Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Threshold Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Threshold Ex 1").await;
+    create_test_player(
+        &pool,
         player_id,
-        exercise_id,
-    };
+        "submit_thr@test.com",
+        "Submit Threshold P",
+    )
+    .await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(game_id))
+            .set(schema::games::success_threshold.eq(Some(80.0)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
 
-    let response = server.post("/student/unlock").json(&payload).await;
+    let submit = |result: i32| {
+        let mut payload = minimal_submission(player_id, exercise_id, game_id);
+        payload.result = BigDecimal::from(result);
+        server.post("/student/submit_solution").json(&payload)
+    };
 
-    assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<()> = response.json();
-    assert_eq!(body.status_code, 200);
+    let result = submit(70)
+        .await
+        .json::<ApiResponse<SubmitResult>>()
+        .data
+        .unwrap();
+    assert!(!result.first_solution);
+    assert_eq!(result.solved_exercises, 0);
 
-    assert!(
-        check_player_unlock_exists(&pool, player_id, exercise_id).await,
-        "Unlock record should exist"
-    );
+    let result = submit(80)
+        .await
+        .json::<ApiResponse<SubmitResult>>()
+        .data
+        .unwrap();
+    assert!(result.first_solution);
+    assert_eq!(result.solved_exercises, 1);
 }
 
 #[tokio::test]
-async fn test_unlock_success_already_unlocked() {
+async fn test_submit_solution_outside_game_subset_keeps_progress() {
     let (server, pool) = setup_test_environment().await;
-    let player_id = 1002;
-    let course_id = create_test_course(&pool, "Unlock AL Course").await;
-    let module_id = create_test_module(&pool, course_id, 1, "Unlock AL Module").await;
-    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock AL Ex 1").await;
-    create_test_player(&pool, player_id, "unlock_al@test.com", "Unlock AL Player").await;
-    create_test_player_unlock(&pool, player_id, exercise_id).await;
-
-    let payload = UnlockPayload {
+    let player_id = 920;
+    let course_id = create_test_course(&pool, "Submit Subset Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Subset Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Subset Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Submit Subset Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Submit Subset Ex 2").await;
+    create_test_player(
+        &pool,
         player_id,
-        exercise_id,
-    };
-
-    let response = server.post("/student/unlock").json(&payload).await;
+        "submit_subset@test.com",
+        "Submit Subset P",
+    )
+    .await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::insert_into(schema::game_exercises::table)
+            .values((
+                schema::game_exercises::game_id.eq(game_id),
+                schema::game_exercises::exercise_id.eq(ex1_id),
+            ))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
 
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, ex2_id, game_id))
+        .await;
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<()> = response.json();
-    assert_eq!(body.status_code, 200);
+    let result = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+    assert!(result.first_solution);
+    assert_eq!(result.solved_exercises, 0);
 
-    assert!(
-        check_player_unlock_exists(&pool, player_id, exercise_id).await,
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, ex1_id, game_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let result = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+    assert_eq!(result.solved_exercises, 1);
+    assert!((result.progress - 100.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn test_submit_solution_earned_rewards_accumulate() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 919;
+    let course_id = create_test_course(&pool, "Submit Reward Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Reward Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Reward Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Submit Reward Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Submit Reward Ex 2").await;
+    let reward_id = create_test_reward(&pool, course_id, "Submit Reward Badge").await;
+    create_test_player(&pool, player_id, "sub_reward@test.com", "Sub Reward P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    // rewards are granted on first solutions only; a repeat earns nothing
+    for (exercise_id, expected_rewards) in [
+        (ex1_id, vec![reward_id]),
+        (ex1_id, vec![]),
+        (ex2_id, vec![reward_id]),
+    ] {
+        let payload = SubmitSolutionPayload {
+            earned_rewards: json!([reward_id]),
+            ..minimal_submission(player_id, exercise_id, game_id)
+        };
+        let response = server.post("/student/submit_solution").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let result = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+        assert_eq!(result.new_rewards, expected_rewards);
+    }
+
+    let conn = pool.get().await.unwrap();
+    let counts = conn
+        .interact(move |conn| {
+            schema::player_rewards::table
+                .filter(schema::player_rewards::player_id.eq(player_id))
+                .select(schema::player_rewards::count)
+                .load::<i32>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(counts, vec![2]);
+}
+
+#[tokio::test]
+async fn test_submit_solution_max_attempts_per_exercise() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Attempt Cap Course").await;
+    let capped_game_id = create_test_game(&pool, course_id, "Attempt Cap Game", 2).await;
+    let open_game_id = create_test_game(&pool, course_id, "No Cap Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Attempt Cap Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Attempt Cap Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Attempt Cap Ex 2").await;
+    create_test_player(&pool, 941, "attempt_cap@test.com", "Attempt Cap").await;
+    create_test_player_registration(&pool, 941, capped_game_id).await;
+    create_test_player_registration(&pool, 941, open_game_id).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(capped_game_id))
+            .set(schema::games::max_attempts_per_exercise.eq(Some(2)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let submit = |exercise_id: i64, game_id: i64, result: i32| {
+        let server = &server;
+        async move {
+            let payload = SubmitSolutionPayload {
+                result: BigDecimal::from(result),
+                ..minimal_submission(941, exercise_id, game_id)
+            };
+            server
+                .post("/student/submit_solution")
+                .json(&payload)
+                .await
+                .status_code()
+        }
+    };
+
+    // under the cap
+    assert_eq!(submit(ex1_id, capped_game_id, 0).await, StatusCode::OK);
+    assert_eq!(submit(ex1_id, capped_game_id, 0).await, StatusCode::OK);
+    // at the cap
+    assert_eq!(
+        submit(ex1_id, capped_game_id, 100).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        submit(ex1_id, capped_game_id, 100).await,
+        StatusCode::FORBIDDEN
+    );
+
+    // the cap is per exercise, and no longer applies once the exercise is solved
+    assert_eq!(submit(ex2_id, capped_game_id, 100).await, StatusCode::OK);
+    assert_eq!(submit(ex2_id, capped_game_id, 100).await, StatusCode::OK);
+    assert_eq!(submit(ex2_id, capped_game_id, 0).await, StatusCode::OK);
+
+    // without a cap attempts are unlimited
+    for _ in 0..4 {
+        assert_eq!(submit(ex1_id, open_game_id, 0).await, StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_submit_solution_max_code_length() {
+    let config = AppConfig {
+        max_code_length: 20,
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let player_id = 913;
+    let course_id = create_test_course(&pool, "Submit Length Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Length Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Length Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Length Ex 1").await;
+    create_test_player(&pool, player_id, "submit_len@test.com", "Submit Length P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let mut payload = minimal_submission(player_id, exercise_id, game_id);
+    payload.submitted_code = "é".repeat(20);
+    let response = server.post("/student/submit_solution").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    payload.submitted_code = "é".repeat(21);
+    let response = server.post("/student/submit_solution").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(response.text().contains("must not exceed 20 characters"));
+}
+
+#[tokio::test]
+async fn test_submit_solution_evaluator_overrides_client_result() {
+    // mock evaluator failing every submission and recording what it was sent
+    let received = Arc::new(Mutex::new(None::<Value>));
+    let evaluator = Router::new().route(
+        "/evaluate",
+        post({
+            let received = received.clone();
+            move |Json(request): Json<Value>| async move {
+                *received.lock().unwrap() = Some(request);
+                Json(json!({ "result": 0, "result_description": { "failed_tests": 2 } }))
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, evaluator).await });
+
+    let config = AppConfig {
+        evaluator_url: Some(format!("http://{}/evaluate", address).parse().unwrap()),
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let player_id = 915;
+    let course_id = create_test_course(&pool, "Submit Eval Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Eval Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Eval Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Eval Ex 1").await;
+    create_test_player(&pool, player_id, "submit_eval@test.com", "Submit Eval P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    // the client claims a perfect score
+    let mut payload = minimal_submission(player_id, exercise_id, game_id);
+    payload.submitted_code = "print('hi')".to_string();
+    let response = server.post("/student/submit_solution").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let result = response.json::<ApiResponse<SubmitResult>>().data.unwrap();
+    assert!(!result.first_solution);
+    assert_eq!(result.solved_exercises, 0);
+
+    let request = received.lock().unwrap().take().unwrap();
+    assert_eq!(request["exercise_id"], exercise_id);
+    assert_eq!(request["submitted_code"], "print('hi')");
+    assert_eq!(request["programming_language"], "py");
+    assert!(request.get("test_code").is_some());
+
+    let conn = pool.get().await.unwrap();
+    let (stored_result, stored_description) = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .select((
+                    schema::submissions::result,
+                    schema::submissions::result_description,
+                ))
+                .first::<(BigDecimal, Value)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored_result, BigDecimal::from(0));
+    assert_eq!(stored_description, json!({ "failed_tests": 2 }));
+}
+
+#[tokio::test]
+async fn test_submit_solution_evaluator_checks_and_out_of_range_verdict() {
+    // mock evaluator answering an impossible score and counting its calls
+    let calls = Arc::new(Mutex::new(0));
+    let evaluator = Router::new().route(
+        "/evaluate",
+        post({
+            let calls = calls.clone();
+            move || async move {
+                *calls.lock().unwrap() += 1;
+                Json(json!({ "result": 150 }))
+            }
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, evaluator).await });
+
+    let config = AppConfig {
+        evaluator_url: Some(format!("http://{}/evaluate", address).parse().unwrap()),
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let (player_id, outsider_id) = (922, 923);
+    let course_id = create_test_course(&pool, "Submit Eval Range Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Eval Range Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Eval Range Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Eval Range Ex").await;
+    create_test_player(&pool, player_id, "submit_range@test.com", "Range P").await;
+    create_test_player(&pool, outsider_id, "submit_range_o@test.com", "Range O").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    // an unregistered player is turned away without calling the evaluator
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(outsider_id, exercise_id, game_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(*calls.lock().unwrap(), 0);
+
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, exercise_id, game_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::BAD_GATEWAY);
+    assert_eq!(*calls.lock().unwrap(), 1);
+
+    let conn = pool.get().await.unwrap();
+    let stored = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .count()
+                .get_result::<i64>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored, 0);
+}
+
+#[tokio::test]
+async fn test_request_timeout_returns_gateway_timeout() {
+    let config = AppConfig {
+        request_timeout: std::time::Duration::from_millis(200),
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let player_id = 918;
+    let course_id = create_test_course(&pool, "Submit Timeout Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Timeout Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Timeout Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Timeout Ex 1").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "submit_timeout@test.com",
+        "Submit Timeout P",
+    )
+    .await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    // Hold the registration row long enough for the submission to exceed the timeout.
+    let lock_conn = pool.get().await.unwrap();
+    let holder = lock_conn.interact(move |conn| {
+        conn.transaction(|conn| {
+            schema::player_registrations::table
+                .filter(schema::player_registrations::player_id.eq(player_id))
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .select(schema::player_registrations::id)
+                .for_update()
+                .first::<i64>(conn)?;
+            diesel::sql_query("SELECT pg_sleep(0.6)").execute(conn)?;
+            Ok::<_, diesel::result::Error>(())
+        })
+    });
+    let payload = minimal_submission(player_id, exercise_id, game_id);
+    let submission = async {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        server.post("/student/submit_solution").json(&payload).await
+    };
+    let (held, response) = tokio::join!(holder, submission);
+    held.unwrap().unwrap();
+
+    assert_eq!(response.status_code(), StatusCode::GATEWAY_TIMEOUT);
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 504);
+    assert!(body.data.is_none());
+}
+
+#[tokio::test]
+async fn test_submit_solution_concurrent_first_solutions() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 914;
+    let course_id = create_test_course(&pool, "Submit Race Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Race Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Race Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Race Ex 1").await;
+    create_test_player(&pool, player_id, "submit_race@test.com", "Submit Race P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    // Hold the registration row while both submissions arrive, so they are in flight together.
+    let lock_conn = pool.get().await.unwrap();
+    let holder = lock_conn.interact(move |conn| {
+        conn.transaction(|conn| {
+            schema::player_registrations::table
+                .filter(schema::player_registrations::player_id.eq(player_id))
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .select(schema::player_registrations::id)
+                .for_update()
+                .first::<i64>(conn)?;
+            diesel::sql_query("SELECT pg_sleep(0.3)").execute(conn)?;
+            Ok::<_, diesel::result::Error>(())
+        })
+    });
+    let payload = minimal_submission(player_id, exercise_id, game_id);
+    let submissions = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        tokio::join!(
+            server.post("/student/submit_solution").json(&payload),
+            server.post("/student/submit_solution").json(&payload)
+        )
+    };
+    let (held, (first, second)) = tokio::join!(holder, submissions);
+    held.unwrap().unwrap();
+    first.assert_status_ok();
+    second.assert_status_ok();
+
+    let conn = pool.get().await.unwrap();
+    let first_solutions: Vec<bool> = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .select(schema::submissions::first_solution)
+                .load(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first_solutions.len(), 2);
+    assert_eq!(first_solutions.iter().filter(|first| **first).count(), 1);
+}
+
+#[tokio::test]
+async fn test_submit_solution_forbidden_after_leaving() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 910;
+    let course_id = create_test_course(&pool, "Submit Left Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Left Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Left Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Left Ex 1").await;
+    create_test_player(&pool, player_id, "submit_left@test.com", "Submit Left P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    server
+        .post("/student/leave_game")
+        .json(&LeaveGamePayload { player_id, game_id })
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, exercise_id, game_id))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert!(response.text().contains("not registered in game"));
+
+    let conn = pool.get().await.unwrap();
+    let submission_count: i64 = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(submission_count, 0);
+}
+
+#[tokio::test]
+async fn test_submit_solution_not_found_game() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 911;
+    let non_existent_game_id = 9912;
+    let course_id = create_test_course(&pool, "Submit NFGame Course").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit NFGame Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit NFGame Ex 1").await;
+    create_test_player(&pool, player_id, "submit_nfg@test.com", "Submit NFGame P").await;
+
+    let response = server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(
+            player_id,
+            exercise_id,
+            non_existent_game_id,
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.text().contains("Game with ID"));
+}
+
+#[tokio::test]
+async fn test_submit_solution_payload_too_large() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 907;
+    let course_id = create_test_course(&pool, "Submit Big Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Big Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Big Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Big Ex 1").await;
+    create_test_player(&pool, player_id, "submit_big@test.com", "Submit Big P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let payload = SubmitSolutionPayload {
+        player_id,
+        exercise_id,
+        game_id,
+        client: "test".to_string(),
+        submitted_code: "x".repeat(3 * 1024 * 1024),
+        metrics: json!({}),
+        result: BigDecimal::from(100),
+        result_description: json!({}),
+        feedback: "".to_string(),
+        entered_at: Utc::now(),
+        earned_rewards: json!([]),
+        time_spent_seconds: None,
+    };
+
+    let response = server.post("/student/submit_solution").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 413);
+}
+
+#[tokio::test]
+async fn test_submit_solution_records_time_spent() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 907;
+    let course_id = create_test_course(&pool, "Submit Time Course").await;
+    let game_id = create_test_game(&pool, course_id, "Submit Time Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Submit Time Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Submit Time Ex 1").await;
+    create_test_player(&pool, player_id, "submit_time@test.com", "Submit Time P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let mut payload = SubmitSolutionPayload {
+        player_id,
+        exercise_id,
+        game_id,
+        client: "test".to_string(),
+        submitted_code: "timed".to_string(),
+        metrics: json!({}),
+        result: BigDecimal::from_f64(100.0).unwrap(),
+        result_description: json!({"status": "pass"}),
+        feedback: "".to_string(),
+        entered_at: Utc::now(),
+        earned_rewards: json!([]),
+        time_spent_seconds: Some(-5),
+    };
+
+    let response = server.post("/student/submit_solution").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    payload.time_spent_seconds = Some(95);
+    let response = server.post("/student/submit_solution").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let conn = pool.get().await.unwrap();
+    let recorded: Vec<Option<i32>> = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .select(schema::submissions::time_spent_seconds)
+                .load(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(recorded, vec![Some(95)]);
+}
+
+// unlock
+
+#[tokio::test]
+async fn test_unlock_success_new_unlock() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1001;
+    let course_id = create_test_course(&pool, "Unlock Course").await;
+    let game_id = create_test_game(&pool, course_id, "Unlock Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock Ex 1").await;
+    create_test_player(&pool, player_id, "unlock@test.com", "Unlock Player").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let payload = UnlockPayload {
+        player_id,
+        exercise_id,
+    };
+
+    let response = server.post("/student/unlock").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 200);
+
+    assert!(
+        check_player_unlock_exists(&pool, player_id, exercise_id).await,
+        "Unlock record should exist"
+    );
+}
+
+#[tokio::test]
+async fn test_unlock_success_already_unlocked() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1002;
+    let course_id = create_test_course(&pool, "Unlock AL Course").await;
+    let game_id = create_test_game(&pool, course_id, "Unlock AL Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock AL Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock AL Ex 1").await;
+    create_test_player(&pool, player_id, "unlock_al@test.com", "Unlock AL Player").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_unlock(&pool, player_id, exercise_id).await;
+
+    let payload = UnlockPayload {
+        player_id,
+        exercise_id,
+    };
+
+    let response = server.post("/student/unlock").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 200);
+
+    assert!(
+        check_player_unlock_exists(&pool, player_id, exercise_id).await,
         "Unlock record should still exist"
     );
 }
 
 #[tokio::test]
-async fn test_unlock_not_found_player() {
+async fn test_unlock_not_found_player() {
+    let (server, pool) = setup_test_environment().await;
+    let non_existent_player_id = 9981;
+    let course_id = create_test_course(&pool, "Unlock NF P Course").await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock NF P Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock NF P Ex 1").await;
+
+    let payload = UnlockPayload {
+        player_id: non_existent_player_id,
+        exercise_id,
+    };
+
+    let response = server.post("/student/unlock").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.text().contains("Player with ID"));
+}
+
+#[tokio::test]
+async fn test_unlock_not_found_exercise() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1003;
+    let non_existent_exercise_id = 9982;
+    create_test_player(&pool, player_id, "unlock_nfe@test.com", "Unlock NFE Player").await;
+
+    let payload = UnlockPayload {
+        player_id,
+        exercise_id: non_existent_exercise_id,
+    };
+
+    let response = server.post("/student/unlock").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.text().contains("Exercise with ID"));
+}
+
+#[tokio::test]
+async fn test_unlock_forbidden_not_registered() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1004;
+    let course_id = create_test_course(&pool, "Unlock NR Course").await;
+    let other_course_id = create_test_course(&pool, "Unlock NR Other Course").await;
+    let other_game_id = create_test_game(&pool, other_course_id, "Unlock NR Other Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock NR Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock NR Ex 1").await;
+    create_test_player(&pool, player_id, "unlock_nr@test.com", "Unlock NR Player").await;
+    create_test_player_registration(&pool, player_id, other_game_id).await;
+
+    let payload = UnlockPayload {
+        player_id,
+        exercise_id,
+    };
+
+    let response = server.post("/student/unlock").json(&payload).await;
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert!(response.text().contains("not registered"));
+    assert!(!check_player_unlock_exists(&pool, player_id, exercise_id).await);
+}
+
+#[tokio::test]
+async fn test_unlock_forbidden_after_leaving() {
     let (server, pool) = setup_test_environment().await;
-    let non_existent_player_id = 9981;
-    let course_id = create_test_course(&pool, "Unlock NF P Course").await;
-    let module_id = create_test_module(&pool, course_id, 1, "Unlock NF P Module").await;
-    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock NF P Ex 1").await;
+    let player_id = 1005;
+    let course_id = create_test_course(&pool, "Unlock Left Course").await;
+    let game_id = create_test_game(&pool, course_id, "Unlock Left Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock Left Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Unlock Left Ex 1").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "unlock_left@test.com",
+        "Unlock Left Player",
+    )
+    .await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    server
+        .post("/student/leave_game")
+        .json(&LeaveGamePayload { player_id, game_id })
+        .await
+        .assert_status_ok();
 
     let payload = UnlockPayload {
-        player_id: non_existent_player_id,
+        player_id,
         exercise_id,
     };
 
     let response = server.post("/student/unlock").json(&payload).await;
 
-    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
-    assert!(response.text().contains("Player with ID"));
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert!(!check_player_unlock_exists(&pool, player_id, exercise_id).await);
 }
 
+// unlock_many
+
 #[tokio::test]
-async fn test_unlock_not_found_exercise() {
+async fn test_unlock_many_skips_already_unlocked() {
     let (server, pool) = setup_test_environment().await;
-    let player_id = 1003;
-    let non_existent_exercise_id = 9982;
-    create_test_player(&pool, player_id, "unlock_nfe@test.com", "Unlock NFE Player").await;
+    let player_id = 1006;
+    let course_id = create_test_course(&pool, "Unlock Many Course").await;
+    let other_course_id = create_test_course(&pool, "Unlock Many Other Course").await;
+    let game_id = create_test_game(&pool, course_id, "Unlock Many Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unlock Many Module").await;
+    let other_module_id =
+        create_test_module(&pool, other_course_id, 1, "Unlock Many Other Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Unlock Many Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Unlock Many Ex 2").await;
+    let ex3_id = create_test_exercise(&pool, module_id, 3, "Unlock Many Ex 3").await;
+    let foreign_ex_id =
+        create_test_exercise(&pool, other_module_id, 1, "Unlock Many Foreign").await;
+    create_test_player(&pool, player_id, "unlock_many@test.com", "Unlock Many P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_unlock(&pool, player_id, ex2_id).await;
 
-    let payload = UnlockPayload {
+    let payload = UnlockManyPayload {
         player_id,
-        exercise_id: non_existent_exercise_id,
+        game_id,
+        exercise_ids: vec![ex3_id, ex2_id, ex1_id, ex3_id],
     };
+    let response = server.post("/student/unlock_many").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let newly_unlocked = response.json::<ApiResponse<Vec<i64>>>().data.unwrap();
+    assert_eq!(newly_unlocked, vec![ex1_id, ex3_id]);
 
-    let response = server.post("/student/unlock").json(&payload).await;
+    // repeating the call unlocks nothing new
+    let response = server.post("/student/unlock_many").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(
+        response
+            .json::<ApiResponse<Vec<i64>>>()
+            .data
+            .unwrap()
+            .is_empty()
+    );
+
+    let conn = pool.get().await.unwrap();
+    let unlock_count: i64 = conn
+        .interact(move |conn| {
+            schema::player_unlocks::table
+                .filter(schema::player_unlocks::player_id.eq(player_id))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unlock_count, 3);
 
+    // an exercise of another course fails the whole batch
+    let payload = UnlockManyPayload {
+        player_id,
+        game_id,
+        exercise_ids: vec![foreign_ex_id],
+    };
+    let response = server.post("/student/unlock_many").json(&payload).await;
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
-    assert!(response.text().contains("Exercise with ID"));
+    assert!(!check_player_unlock_exists(&pool, player_id, foreign_ex_id).await);
+}
+
+// get_player_score
+
+#[tokio::test]
+async fn test_get_player_score_rewards_add_and_unlocks_subtract() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1007;
+    let course_id = create_test_course(&pool, "Score Course").await;
+    let game_id = create_test_game(&pool, course_id, "Score Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Score Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Score Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Score Ex 2").await;
+    let reward_id = create_test_reward(&pool, course_id, "Score Badge").await;
+    create_test_player(&pool, player_id, "score@test.com", "Score P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let score = || async {
+        let response = server
+            .get("/student/get_player_score")
+            .add_query_param("player_id", player_id)
+            .add_query_param("game_id", game_id)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let score = response
+            .json::<ApiResponse<PlayerScoreResponse>>()
+            .data
+            .unwrap();
+        (score.base, score.rewards, score.penalties, score.total)
+    };
+
+    // nothing solved, earned or unlocked yet
+    assert_eq!(score().await, (0, 0, 0, 0));
+
+    server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, ex1_id, game_id))
+        .await
+        .assert_status_ok();
+    assert_eq!(score().await, (10, 0, 0, 10));
+    create_test_player_reward(&pool, player_id, reward_id, game_id, 2).await;
+    assert_eq!(score().await, (10, 10, 0, 20));
+
+    // revealing ex2 before solving it costs points, also once it is solved
+    server
+        .post("/student/unlock")
+        .json(&UnlockPayload {
+            player_id,
+            exercise_id: ex2_id,
+        })
+        .await
+        .assert_status_ok();
+    assert_eq!(score().await, (10, 10, 2, 18));
+    server
+        .post("/student/submit_solution")
+        .json(&minimal_submission(player_id, ex2_id, game_id))
+        .await
+        .assert_status_ok();
+    assert_eq!(score().await, (20, 10, 2, 28));
+
+    let response = server
+        .get("/student/get_player_score")
+        .add_query_param("player_id", player_id)
+        .add_query_param("game_id", game_id)
+        .add_header(TEST_EMAIL_HEADER, "someone_else@test.com")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_get_player_score_uses_game_success_threshold() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1012;
+    let course_id = create_test_course(&pool, "Score Threshold Course").await;
+    let game_id = create_test_game(&pool, course_id, "Score Threshold Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Score Threshold Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Score Threshold Ex").await;
+    create_test_player(&pool, player_id, "score_thr@test.com", "Score Threshold P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::games::table.find(game_id))
+            .set(schema::games::success_threshold.eq(Some(80.0)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let base_score = || async {
+        server
+            .get("/student/get_player_score")
+            .add_query_param("player_id", player_id)
+            .add_query_param("game_id", game_id)
+            .await
+            .json::<ApiResponse<PlayerScoreResponse>>()
+            .data
+            .unwrap()
+            .base
+    };
+
+    for (result, expected_base) in [(70, 0), (80, 10)] {
+        let mut payload = minimal_submission(player_id, exercise_id, game_id);
+        payload.result = BigDecimal::from(result);
+        server
+            .post("/student/submit_solution")
+            .json(&payload)
+            .await
+            .assert_status_ok();
+        assert_eq!(base_score().await, expected_base);
+    }
+}
+
+#[tokio::test]
+async fn test_get_player_score_ignores_exercises_outside_game_subset() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1013;
+    let course_id = create_test_course(&pool, "Score Subset Course").await;
+    let game_id = create_test_game(&pool, course_id, "Score Subset Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Score Subset Module").await;
+    let included_id = create_test_exercise(&pool, module_id, 1, "Score Subset Ex 1").await;
+    let excluded_id = create_test_exercise(&pool, module_id, 2, "Score Subset Ex 2").await;
+    create_test_player(&pool, player_id, "score_subset@test.com", "Score Subset P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::insert_into(schema::game_exercises::table)
+            .values((
+                schema::game_exercises::game_id.eq(game_id),
+                schema::game_exercises::exercise_id.eq(included_id),
+            ))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    create_test_submission(&pool, player_id, game_id, excluded_id, true, 100.0).await;
+
+    let response = server
+        .get("/student/get_player_score")
+        .add_query_param("player_id", player_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let score = response
+        .json::<ApiResponse<PlayerScoreResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(score.base, 0);
+
+    create_test_submission(&pool, player_id, game_id, included_id, true, 100.0).await;
+    let score = server
+        .get("/student/get_player_score")
+        .add_query_param("player_id", player_id)
+        .add_query_param("game_id", game_id)
+        .await
+        .json::<ApiResponse<PlayerScoreResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(score.base, 10);
+}
+
+// get_player_rank
+
+#[tokio::test]
+async fn test_get_player_rank_middle_player() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Rank Course").await;
+    let game_id = create_test_game(&pool, course_id, "Rank Game", 3).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Rank Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Rank Ex 1").await;
+
+    // (player, solved exercises, submissions)
+    let standings = [(1008, 3, 3), (1009, 2, 2), (1010, 2, 5), (1011, 0, 0)];
+    let emails = [
+        "rank1@test.com",
+        "rank2@test.com",
+        "rank3@test.com",
+        "rank4@test.com",
+    ];
+    for ((player_id, solved, attempts), email) in standings.into_iter().zip(emails) {
+        create_test_player(&pool, player_id, email, "Rank P").await;
+        let registration_id = create_test_player_registration(&pool, player_id, game_id).await;
+        let conn = pool.get().await.unwrap();
+        conn.interact(move |conn| {
+            diesel::update(schema::player_registrations::table.find(registration_id))
+                .set(schema::player_registrations::progress.eq(solved))
+                .execute(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        for _ in 0..attempts {
+            create_test_submission(&pool, player_id, game_id, exercise_id, false, 0.0).await;
+        }
+    }
+    // a player who left the game is not a participant
+    create_test_player(&pool, 1012, "rank_left@test.com", "Rank Left").await;
+    let left_registration_id = create_test_player_registration(&pool, 1012, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_registrations::table.find(left_registration_id))
+            .set((
+                schema::player_registrations::progress.eq(3),
+                schema::player_registrations::left_at.eq(Some(Utc::now())),
+            ))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let rank = |player_id: i64| {
+        let server = &server;
+        async move {
+            let response = server
+                .get("/student/get_player_rank")
+                .add_query_param("player_id", player_id)
+                .add_query_param("game_id", game_id)
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response
+                .json::<ApiResponse<PlayerRankResponse>>()
+                .data
+                .unwrap()
+        }
+    };
+
+    let middle = rank(1009).await;
+    assert_eq!(middle.rank, 2);
+    assert_eq!(middle.total_participants, 4);
+    assert_eq!(middle.solved_exercises, 2);
+    assert_eq!(middle.attempts, 2);
+    // same solved count, more attempts
+    assert_eq!(rank(1010).await.rank, 3);
+    assert_eq!(rank(1008).await.rank, 1);
+    assert_eq!(rank(1011).await.rank, 4);
+
+    let response = server
+        .get("/student/get_player_rank")
+        .add_query_param("player_id", 1012)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
 }
 
 // get_last_solution
@@ -1569,7 +3497,7 @@ async fn test_get_last_solution_success_correct_exists() {
     tokio::time::sleep(std::time::Duration::from_millis(10)).await;
     create_test_submission(&pool, player_id, game_id, exercise_id, true, 1.0).await;
     tokio::time::sleep(std::time::Duration::from_millis(10)).await;
-    create_test_submission(&pool, player_id, game_id, exercise_id, false, 0.5).await;
+    create_test_submission(&pool, player_id, game_id, exercise_id, false, 0.4).await;
 
     let response = server
         .get(&format!(
@@ -1587,6 +3515,40 @@ async fn test_get_last_solution_success_correct_exists() {
     assert_eq!(solution.unwrap().result, BigDecimal::from(100));
 }
 
+#[tokio::test]
+async fn test_get_last_solution_result_is_percentage_number() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1110;
+    let course_id = create_test_course(&pool, "LastSol Pct Course").await;
+    let game_id = create_test_game(&pool, course_id, "LastSol Pct Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "LastSol Pct Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "LastSol Pct Ex 1").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "lastsolpct@test.com",
+        "LastSol Pct Player",
+    )
+    .await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    create_test_submission(&pool, player_id, game_id, exercise_id, true, 0.75).await;
+
+    let response = server
+        .get(&format!(
+            "/student/get_last_solution?player_id={}&exercise_id={}",
+            player_id, exercise_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["result"], json!(75.0));
+
+    let typed: ApiResponse<Option<LastSolutionResponse>> = response.json();
+    assert_eq!(typed.data.unwrap().unwrap().result, BigDecimal::from(75));
+}
+
 #[tokio::test]
 async fn test_get_last_solution_success_only_incorrect_exists() {
     let (server, pool) = setup_test_environment().await;
@@ -1676,3 +3638,373 @@ async fn test_get_last_solution_not_found_exercise() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
     assert!(response.text().contains("Exercise with ID"));
 }
+
+// list_exercises_by_tag
+
+#[tokio::test]
+async fn test_list_exercises_by_tag_within_game_course() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Tagged Course").await;
+    let other_course_id = create_test_course(&pool, "Other Tagged Course").await;
+    let game_id = create_test_game(&pool, course_id, "Tagged Game", 3).await;
+    let module2_id = create_test_module(&pool, course_id, 2, "Second Module").await;
+    let module1_id = create_test_module(&pool, course_id, 1, "First Module").await;
+    let other_module_id = create_test_module(&pool, other_course_id, 1, "Other Module").await;
+    let ex_late = create_test_exercise(&pool, module2_id, 1, "Late Recursion").await;
+    let ex_early = create_test_exercise(&pool, module1_id, 2, "Early Recursion").await;
+    let ex_untagged = create_test_exercise(&pool, module1_id, 1, "Loops Only").await;
+    let ex_other = create_test_exercise(&pool, other_module_id, 1, "Other Recursion").await;
+    create_test_exercise_tag(&pool, ex_late, "recursion").await;
+    create_test_exercise_tag(&pool, ex_early, "recursion").await;
+    create_test_exercise_tag(&pool, ex_untagged, "loops").await;
+    create_test_exercise_tag(&pool, ex_other, "recursion").await;
+
+    let response = server
+        .get("/student/list_exercises_by_tag")
+        .add_query_param("game_id", game_id)
+        .add_query_param("tag", "Recursion")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<i64>> = response.json();
+    assert_eq!(body.data.unwrap(), vec![ex_early, ex_late]);
+
+    let response = server
+        .get("/student/list_exercises_by_tag")
+        .add_query_param("game_id", game_id)
+        .add_query_param("tag", "graphs")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<i64>> = response.json();
+    assert!(body.data.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_exercises_by_tag_game_not_found() {
+    let (server, _pool) = setup_test_environment().await;
+    let response = server
+        .get("/student/list_exercises_by_tag")
+        .add_query_param("game_id", 999_999)
+        .add_query_param("tag", "recursion")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_player_streak
+
+#[tokio::test]
+async fn test_get_player_streak_counts_consecutive_days() {
+    let (server, pool) = setup_test_environment().await;
+    let (streak_player, lapsed_player) = (1901, 1902);
+    let course_id = create_test_course(&pool, "Streak Course").await;
+    let game_id = create_test_game(&pool, course_id, "Streak Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Streak Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Streak Ex").await;
+    create_test_player(&pool, streak_player, "streak@test.com", "Streaker").await;
+    create_test_player(&pool, lapsed_player, "lapsed@test.com", "Lapsed").await;
+    create_test_player_registration(&pool, streak_player, game_id).await;
+    create_test_player_registration(&pool, lapsed_player, game_id).await;
+
+    let today_start = Utc::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 1)
+        .unwrap()
+        .and_utc();
+    // Two submissions today, then yesterday, two days ago, and a gap before four days ago.
+    let mut seeded = Vec::new();
+    for days_ago in [0, 0, 1, 2, 4] {
+        let id = create_test_submission(&pool, streak_player, game_id, ex_id, false, 0.3).await;
+        seeded.push((id, days_ago));
+    }
+    let lapsed_id = create_test_submission(&pool, lapsed_player, game_id, ex_id, true, 1.0).await;
+    seeded.push((lapsed_id, 2));
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (id, days_ago) in seeded {
+            diesel::update(schema::submissions::table.find(id))
+                .set(
+                    schema::submissions::entered_at
+                        .eq(today_start - chrono::Duration::days(days_ago)),
+                )
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/student/get_player_streak")
+        .add_query_param("player_id", streak_player)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<PlayerStreakResponse> = response.json();
+    let streak = body.data.unwrap();
+    assert_eq!(streak.current_streak, 3);
+    assert_eq!(streak.last_active_date, Some(today_start.date_naive()));
+
+    let response = server
+        .get("/student/get_player_streak")
+        .add_query_param("player_id", lapsed_player)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<PlayerStreakResponse> = response.json();
+    assert_eq!(body.data.unwrap().current_streak, 0);
+}
+
+#[tokio::test]
+async fn test_get_player_streak_not_registered() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Streak Course NR").await;
+    let game_id = create_test_game(&pool, course_id, "Streak Game NR", 1).await;
+    create_test_player(&pool, 1903, "streak_nr@test.com", "Not Registered").await;
+
+    let response = server
+        .get("/student/get_player_streak")
+        .add_query_param("player_id", 1903)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_completion_status_complete_and_incomplete() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Certificate Course").await;
+    let game_id = create_test_game(&pool, course_id, "Certificate Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Certificate Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Certificate Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Certificate Ex 2").await;
+    create_test_player(&pool, 1311, "cert_done@test.com", "Cert Done").await;
+    create_test_player(&pool, 1312, "cert_partial@test.com", "Cert Partial").await;
+    create_test_player_registration(&pool, 1311, game_id).await;
+    create_test_player_registration(&pool, 1312, game_id).await;
+    create_test_submission(&pool, 1311, game_id, ex1_id, false, 0.3).await;
+    create_test_submission(&pool, 1311, game_id, ex1_id, true, 1.0).await;
+    let last = create_test_submission(&pool, 1311, game_id, ex2_id, true, 1.0).await;
+    create_test_submission(&pool, 1312, game_id, ex1_id, true, 1.0).await;
+
+    let last_entered_at = pool
+        .get()
+        .await
+        .unwrap()
+        .interact(move |conn| {
+            schema::submissions::table
+                .find(last)
+                .select(schema::submissions::entered_at)
+                .first::<DateTime<Utc>>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+    let status = |player_id: i64| {
+        let server = &server;
+        async move {
+            let response = server
+                .get("/student/get_completion_status")
+                .add_query_param("player_id", player_id)
+                .add_query_param("game_id", game_id)
+                .await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response
+                .json::<ApiResponse<CompletionStatusResponse>>()
+                .data
+                .unwrap()
+        }
+    };
+
+    let done = status(1311).await;
+    assert!(done.complete);
+    assert_eq!((done.solved_exercises, done.total_exercises), (2, 2));
+    assert_eq!(done.completed_at, Some(last_entered_at));
+    assert_eq!(done.attempts, 3);
+    assert_eq!(done.player_display_name, "Cert Done");
+    assert_eq!(done.game_title, "Certificate Game");
+    assert_eq!(done.course_title, "Certificate Course");
+
+    let partial = status(1312).await;
+    assert!(!partial.complete);
+    assert_eq!((partial.solved_exercises, partial.total_exercises), (1, 2));
+    assert_eq!(partial.completed_at, None);
+    assert_eq!(partial.attempts, 1);
+}
+
+#[tokio::test]
+async fn test_get_exercise_workspace_returns_last_submitted_code() {
+    let (server, pool) = setup_test_environment().await;
+    let player_id = 1951;
+    let course_id = create_test_course(&pool, "Workspace Course").await;
+    let game_id = create_test_game(&pool, course_id, "Workspace Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Workspace Module").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Workspace Ex").await;
+    create_test_player(&pool, player_id, "workspace@test.com", "Workspace Player").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let response = server
+        .get("/student/get_exercise_workspace")
+        .add_query_param("player_id", player_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ExerciseWorkspaceResponse> = response.json();
+    let workspace = body.data.unwrap();
+    assert_eq!(workspace.init_code, "");
+    assert!(workspace.last_submitted_code.is_none());
+    assert!(workspace.last_submitted_at.is_none());
+
+    for code in ["first attempt", "second attempt"] {
+        let payload = SubmitSolutionPayload {
+            player_id,
+            exercise_id,
+            game_id,
+            client: "test".to_string(),
+            submitted_code: code.to_string(),
+            metrics: json!({}),
+            result: BigDecimal::from_f64(20.0).unwrap(),
+            result_description: json!({"status": "fail"}),
+            feedback: "".to_string(),
+            entered_at: Utc::now(),
+            earned_rewards: json!([]),
+            time_spent_seconds: None,
+        };
+        let response = server.post("/student/submit_solution").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+
+    let response = server
+        .get("/student/get_exercise_workspace")
+        .add_header(TEST_EMAIL_HEADER, "workspace@test.com")
+        .add_query_param("player_id", player_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ExerciseWorkspaceResponse> = response.json();
+    let workspace = body.data.unwrap();
+    assert_eq!(workspace.init_code, "");
+    assert_eq!(
+        workspace.last_submitted_code.as_deref(),
+        Some("second attempt")
+    );
+    assert!(workspace.last_submitted_at.is_some());
+}
+
+#[tokio::test]
+async fn test_get_exercise_workspace_soft_deleted_player_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Workspace Course D").await;
+    let game_id = create_test_game(&pool, course_id, "Workspace Game D", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Workspace Module D").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Workspace Ex D").await;
+    create_test_player(&pool, 1954, "workspace_deleted@test.com", "Deleted").await;
+    create_test_player_registration(&pool, 1954, game_id).await;
+
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&json!({ "instructor_id": 0, "player_id": 1954 }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/student/get_exercise_workspace")
+        .add_header(TEST_EMAIL_HEADER, "workspace_deleted@test.com")
+        .add_query_param("player_id", 1954)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_get_exercise_workspace_other_player_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let course_id = create_test_course(&pool, "Workspace Course F").await;
+    let game_id = create_test_game(&pool, course_id, "Workspace Game F", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Workspace Module F").await;
+    let exercise_id = create_test_exercise(&pool, module_id, 1, "Workspace Ex F").await;
+    create_test_player(&pool, 1952, "workspace_owner@test.com", "Owner").await;
+    create_test_player(&pool, 1953, "workspace_peer@test.com", "Peer").await;
+    create_test_player_registration(&pool, 1952, game_id).await;
+    create_test_submission(&pool, 1952, game_id, exercise_id, false, 0.2).await;
+
+    let response = server
+        .get("/student/get_exercise_workspace")
+        .add_header(TEST_EMAIL_HEADER, "workspace_peer@test.com")
+        .add_query_param("player_id", 1952)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", exercise_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/student/get_exercise_workspace")
+        .add_query_param("player_id", 1952)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", 999_999)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_ip_rate_limit_throttles_per_forwarded_address() {
+    let config = AppConfig {
+        ip_rate_limit_per_sec: 1,
+        ip_rate_limit_burst: 2,
+        trust_forwarded_for: true,
+        ..AppConfig::default()
+    };
+    let (server, _pool) = setup_test_environment_with_config(config).await;
+    let request = |forwarded_for: &'static str| {
+        server
+            .get("/student/get_public_courses")
+            .add_header("x-forwarded-for", forwarded_for)
+    };
+
+    for _ in 0..2 {
+        let response = request("203.0.113.7").await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+    let response = request("203.0.113.7").await;
+    assert_eq!(response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.header("retry-after"), "1");
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 429);
+
+    let response = request("198.51.100.23").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_ip_rate_limit_ignores_client_supplied_forwarded_addresses() {
+    let config = AppConfig {
+        ip_rate_limit_per_sec: 1,
+        ip_rate_limit_burst: 2,
+        trust_forwarded_for: true,
+        ..AppConfig::default()
+    };
+    let (server, _pool) = setup_test_environment_with_config(config).await;
+    let request = |forwarded_for: &'static str| {
+        server
+            .get("/student/get_public_courses")
+            .add_header("x-forwarded-for", forwarded_for)
+    };
+
+    // the client rotates a spoofed leftmost address; the proxy appends its real address last
+    for spoofed in ["192.0.2.1, 203.0.113.7", "192.0.2.2, 203.0.113.7"] {
+        let response = request(spoofed).await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+    let response = request("192.0.2.3, 203.0.113.7").await;
+    assert_eq!(response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+
+    // spoofing another client's address does not drain that client's bucket
+    let response = request("198.51.100.23, 203.0.113.7").await;
+    assert_eq!(response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    let response = request("198.51.100.23").await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
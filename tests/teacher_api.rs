@@ -1,19 +1,36 @@
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
+use axum::routing::post;
+use axum::{Json, Router};
+use chrono::{DateTime, Duration, Utc};
 use diesel::ExpressionMethods;
+use diesel::connection::SimpleConnection;
 use diesel::{QueryDsl, RunQueryDsl};
 use float_cmp::approx_eq;
+use lightweight_fgpe_server::auth::TEST_EMAIL_HEADER;
+use lightweight_fgpe_server::config::AppConfig;
+use lightweight_fgpe_server::model::student::PlayerScoreResponse;
 use lightweight_fgpe_server::model::teacher::{
-    ExerciseStatsResponse, InstructorGameMetadataResponse, InviteLinkResponse,
-    StudentExercisesResponse, StudentProgressResponse, SubmissionDataResponse,
+    CourseGameSummary, DisabledPlayerResponse, ExerciseStatsResponse, FirstSolver,
+    GameEndSummaryResponse, GameEndingSoonSummary, GameExercisesResponse, GameInstructorResponse,
+    GameTimelineResponse, GroupMemberResponse, GroupMetadataResponse, GroupStatsResponse,
+    ImportSubmissionsResponse, InstructorGameMetadataResponse, IntegrityReportResponse,
+    InviteDetailsResponse, InviteLinkResponse, InviteSummary, PlayerActivityDay,
+    PlayerGroupResponse, ProcessInviteResult, ProgressDistributionBucket, RecentSubmission,
+    ScoreHistogramBucket, StrugglingStudent, StudentExercisesResponse, StudentProgressResponse,
+    SubmissionDataResponse, TimelineCursor, TimelineEventType, UnattemptedExerciseResponse,
 };
 use lightweight_fgpe_server::payloads::teacher::{
-    ActivateGamePayload, AddGameInstructorPayload, AddGroupMemberPayload, CreateGamePayload,
-    CreateGroupPayload, CreatePlayerPayload, DeletePlayerPayload, DisablePlayerPayload,
-    DissolveGroupPayload, GenerateInviteLinkPayload, ModifyGamePayload, ProcessInviteLinkPayload,
-    RemoveGameInstructorPayload, RemoveGameStudentPayload, RemoveGroupMemberPayload,
-    StopGamePayload,
+    ActivateGamePayload, AddGameInstructorPayload, AddGroupMemberPayload, CloneGroupPayload,
+    CreateGamePayload, CreateGroupPayload, CreatePlayerPayload, DeletePlayerPayload,
+    DisablePlayerPayload, DissolveGroupPayload, ExcludeGameExercisesPayload,
+    GenerateInviteLinkPayload, GetSubmissionsDataPayload, GrantRewardPayload,
+    IncludeGameExercisesPayload, MergeGroupsPayload, ModifyGamePayload, ModifyGroupPayload,
+    ProcessInviteLinkPayload, RemoveGameInstructorPayload, RemoveGameStudentPayload,
+    RemoveGameStudentsPayload, RemoveGroupMemberPayload, RestorePlayerPayload,
+    SoftDeletePlayerPayload, StopGamePayload, TransferGroupOwnershipPayload,
 };
-use lightweight_fgpe_server::response::ApiResponse;
+use lightweight_fgpe_server::response::{ApiResponse, MSGPACK_CONTENT_TYPE};
+use lightweight_fgpe_server::scheduler::{archive_old_submissions, scan_ended_games};
 use serde_json::{Value, json};
 use uuid::Uuid;
 
@@ -23,11 +40,13 @@ use crate::helpers::{
     count_player_group_memberships,
 };
 use helpers::{
-    add_player_to_group, create_test_course, create_test_exercise, create_test_game,
-    create_test_game_ownership, create_test_group_ownership, create_test_group_with_id,
-    create_test_instructor, create_test_invite, create_test_module, create_test_player,
-    create_test_player_registration, create_test_submission, setup_test_environment,
-    update_player_status,
+    add_player_to_group, create_test_course, create_test_course_ownership, create_test_exercise,
+    create_test_exercise_tag, create_test_game, create_test_game_ownership,
+    create_test_group_ownership, create_test_group_with_id, create_test_instructor,
+    create_test_invite, create_test_module, create_test_player, create_test_player_registration,
+    create_test_player_reward, create_test_player_unlock, create_test_reward,
+    create_test_submission, setup_test_environment, setup_test_environment_with_config,
+    update_game_status, update_player_status,
 };
 use lightweight_fgpe_server::schema;
 
@@ -95,6 +114,102 @@ async fn test_get_instructor_games_success_no_games() {
     assert!(body.data.unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_get_instructor_games_msgpack_on_accept() {
+    let (server, pool) = setup_test_environment().await;
+
+    let instructor_id = 1004;
+    let course_id = create_test_course(&pool, "Test Course Msgpack").await;
+    let game_id = create_test_game(&pool, course_id, "Msgpack Game", 0).await;
+    create_test_instructor(&pool, instructor_id, "teacher4@test.com", "Teacher Four").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let response = server
+        .get("/teacher/get_instructor_games")
+        .add_query_param("instructor_id", instructor_id)
+        .add_header(header::ACCEPT, MSGPACK_CONTENT_TYPE)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.header(header::CONTENT_TYPE), MSGPACK_CONTENT_TYPE);
+    let body: ApiResponse<Vec<i64>> = rmp_serde::from_slice(response.as_bytes()).unwrap();
+    assert_eq!(body.status_code, 200);
+    assert_eq!(body.data, Some(vec![game_id]));
+
+    let response = server
+        .get("/teacher/get_instructor_games")
+        .add_query_param("instructor_id", 999_999)
+        .add_header(header::ACCEPT, MSGPACK_CONTENT_TYPE)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    let body: ApiResponse<Vec<i64>> = rmp_serde::from_slice(response.as_bytes()).unwrap();
+    assert_eq!(body.status_code, 404);
+    assert!(body.data.is_none());
+
+    let response = server
+        .get("/teacher/get_instructor_games")
+        .add_query_param("instructor_id", instructor_id)
+        .await;
+    assert_eq!(response.header(header::CONTENT_TYPE), "application/json");
+}
+
+#[tokio::test]
+async fn test_get_instructor_games_status_filters() {
+    let (server, pool) = setup_test_environment().await;
+
+    let instructor_id = 1003;
+    let course_id = create_test_course(&pool, "Test Course Filters").await;
+    let active_public = create_test_game(&pool, course_id, "Active Public", 0).await;
+    let active_private = create_test_game(&pool, course_id, "Active Private", 0).await;
+    let inactive_public = create_test_game(&pool, course_id, "Inactive Public", 0).await;
+    let inactive_private = create_test_game(&pool, course_id, "Inactive Private", 0).await;
+    update_game_status(&pool, active_public, true, true).await;
+    update_game_status(&pool, active_private, true, false).await;
+    update_game_status(&pool, inactive_public, false, true).await;
+    update_game_status(&pool, inactive_private, false, false).await;
+
+    create_test_instructor(&pool, instructor_id, "teacher3@test.com", "Teacher Three").await;
+    for game_id in [
+        active_public,
+        active_private,
+        inactive_public,
+        inactive_private,
+    ] {
+        create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    }
+
+    let cases: [(&str, Vec<i64>); 6] = [
+        (
+            "",
+            vec![
+                active_public,
+                active_private,
+                inactive_public,
+                inactive_private,
+            ],
+        ),
+        ("&active=true", vec![active_public, active_private]),
+        ("&active=false", vec![inactive_public, inactive_private]),
+        ("&public=true", vec![active_public, inactive_public]),
+        ("&active=true&public=false", vec![active_private]),
+        ("&active=false&public=true", vec![inactive_public]),
+    ];
+
+    for (filters, mut expected) in cases {
+        let response = server
+            .get(&format!(
+                "/teacher/get_instructor_games?instructor_id={}{}",
+                instructor_id, filters
+            ))
+            .await;
+
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let mut game_ids = response.json::<ApiResponse<Vec<i64>>>().data.unwrap();
+        game_ids.sort();
+        expected.sort();
+        assert_eq!(game_ids, expected, "filters: {}", filters);
+    }
+}
+
 #[tokio::test]
 async fn test_get_instructor_games_not_found() {
     let (server, _pool) = setup_test_environment().await;
@@ -179,6 +294,43 @@ async fn test_get_instructor_game_metadata_success_owner() {
     assert!(metadata.is_owner);
 }
 
+#[tokio::test]
+async fn test_get_instructor_game_metadata_conditional_get() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 2005;
+    let course_id = create_test_course(&pool, "Course For ETag").await;
+    let game_id = create_test_game(&pool, course_id, "ETag Game", 0).await;
+    create_test_instructor(&pool, instructor_id, "etag@test.com", "ETag Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let request = || {
+        server
+            .get("/teacher/get_instructor_game_metadata")
+            .add_query_param("instructor_id", instructor_id)
+            .add_query_param("game_id", game_id)
+    };
+
+    let response = request().await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let etag = response.header(header::ETAG);
+
+    let response = request()
+        .add_header(header::IF_NONE_MATCH, etag.clone())
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_MODIFIED);
+    assert!(response.as_bytes().is_empty());
+
+    update_game_status(&pool, game_id, false, false).await;
+
+    let response = request()
+        .add_header(header::IF_NONE_MATCH, etag.clone())
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_ne!(response.header(header::ETAG), etag);
+    let body: ApiResponse<InstructorGameMetadataResponse> = response.json();
+    assert!(!body.data.unwrap().active);
+}
+
 #[tokio::test]
 async fn test_get_instructor_game_metadata_success_non_owner() {
     let (server, pool) = setup_test_environment().await;
@@ -341,6 +493,232 @@ async fn test_get_instructor_game_metadata_bad_request_invalid_instructor_id() {
     assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
 }
 
+// get_games_metadata
+
+#[tokio::test]
+async fn test_get_games_metadata_skips_forbidden_games() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 2010;
+    let other_instructor_id = 2011;
+    let course_id = create_test_course(&pool, "Course For Batch Meta").await;
+    let owned_game_id = create_test_game(&pool, course_id, "Batch Owned Game", 0).await;
+    let shared_game_id = create_test_game(&pool, course_id, "Batch Shared Game", 0).await;
+    let forbidden_game_id = create_test_game(&pool, course_id, "Batch Forbidden Game", 0).await;
+
+    create_test_instructor(&pool, instructor_id, "batchmeta@test.com", "BatchMeta Inst").await;
+    create_test_instructor(
+        &pool,
+        other_instructor_id,
+        "batchmeta_other@test.com",
+        "BatchMeta Other",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, owned_game_id, true).await;
+    create_test_game_ownership(&pool, instructor_id, shared_game_id, false).await;
+    create_test_game_ownership(&pool, other_instructor_id, forbidden_game_id, true).await;
+
+    create_test_player(&pool, 2110, "batchmeta_p1@test.com", "BatchMeta P1").await;
+    create_test_player(&pool, 2111, "batchmeta_p2@test.com", "BatchMeta P2").await;
+    create_test_player(&pool, 2112, "batchmeta_p3@test.com", "BatchMeta P3").await;
+    create_test_player_registration(&pool, 2110, owned_game_id).await;
+    create_test_player_registration(&pool, 2111, owned_game_id).await;
+    create_test_player_registration(&pool, 2112, shared_game_id).await;
+    create_test_player_registration(&pool, 2110, forbidden_game_id).await;
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "game_ids": [shared_game_id, forbidden_game_id, owned_game_id],
+        "skip_forbidden": true
+    });
+    let response = server
+        .post("/teacher/get_games_metadata")
+        .json(&payload)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<InstructorGameMetadataResponse>> = response.json();
+    let games = body.data.unwrap();
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].id, shared_game_id);
+    assert_eq!(games[0].player_count, 1);
+    assert!(!games[0].is_owner);
+    assert_eq!(games[1].id, owned_game_id);
+    assert_eq!(games[1].title, "Batch Owned Game");
+    assert_eq!(games[1].player_count, 2);
+    assert!(games[1].is_owner);
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "game_ids": [owned_game_id, forbidden_game_id]
+    });
+    let response = server
+        .post("/teacher/get_games_metadata")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_get_games_metadata_not_found_game() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 2012;
+    let course_id = create_test_course(&pool, "Course For Batch Meta NF").await;
+    let game_id = create_test_game(&pool, course_id, "Batch NF Game", 0).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "batchmeta_nf@test.com",
+        "BatchMeta NF",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "game_ids": [game_id, 99991]
+    });
+    let response = server
+        .post("/teacher/get_games_metadata")
+        .json(&payload)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert!(response.text().contains("99991"));
+}
+
+// get_games_ending_soon
+
+#[tokio::test]
+async fn test_get_games_ending_soon_window() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 2020;
+    create_test_instructor(&pool, instructor_id, "ending@test.com", "Ending Inst").await;
+    create_test_instructor(&pool, 0, "ending_admin@test.com", "Ending Admin").await;
+    let course_id = create_test_course(&pool, "Ending Course").await;
+
+    let mut ending = Vec::new();
+    for (title, days) in [("Ends In 2", 2), ("Ends In 10", 10), ("Ends In 40", 40)] {
+        let game_id = create_test_game(&pool, course_id, title, 1).await;
+        create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+        ending.push((game_id, days));
+    }
+    let unowned_id = create_test_game(&pool, course_id, "Ends In 3 Unowned", 1).await;
+    ending.push((unowned_id, 3));
+    let inactive_id = create_test_game(&pool, course_id, "Ends In 1 Inactive", 1).await;
+    create_test_game_ownership(&pool, instructor_id, inactive_id, true).await;
+    update_game_status(&pool, inactive_id, false, false).await;
+    ending.push((inactive_id, 1));
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (game_id, days) in ending {
+            diesel::update(schema::games::table.find(game_id))
+                .set(schema::games::end_date.eq(Utc::now() + Duration::days(days)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_games_ending_soon")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("within_days", 7)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<GameEndingSoonSummary>> = response.json();
+    let titles: Vec<String> = body.data.unwrap().into_iter().map(|g| g.title).collect();
+    assert_eq!(titles, vec!["Ends In 2"]);
+
+    let response = server
+        .get("/teacher/get_games_ending_soon")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("within_days", 7)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<GameEndingSoonSummary>> = response.json();
+    let titles: Vec<String> = body.data.unwrap().into_iter().map(|g| g.title).collect();
+    assert_eq!(titles, vec!["Ends In 2", "Ends In 3 Unowned"]);
+
+    let response = server
+        .get("/teacher/get_games_ending_soon")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("within_days", -1)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+// get_games_by_course
+
+#[tokio::test]
+async fn test_get_games_by_course_lists_games_with_player_counts() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = create_test_instructor(&pool, 2401, "gbc_owner@test.com", "Owner").await;
+    let course_id = create_test_course(&pool, "Games By Course").await;
+    let other_course_id = create_test_course(&pool, "Other Course").await;
+    create_test_course_ownership(&pool, owner_id, course_id, true).await;
+    let game_a = create_test_game(&pool, course_id, "Course Game A", 1).await;
+    let game_b = create_test_game(&pool, course_id, "Course Game B", 1).await;
+    create_test_game(&pool, other_course_id, "Unrelated Game", 1).await;
+    create_test_player(&pool, 2411, "gbc_p1@test.com", "Player One").await;
+    create_test_player(&pool, 2412, "gbc_p2@test.com", "Player Two").await;
+    create_test_player(&pool, 2413, "gbc_p3@test.com", "Player Three").await;
+    for player_id in [2411, 2412, 2413] {
+        create_test_player_registration(&pool, player_id, game_a).await;
+    }
+    create_test_player_registration(&pool, 2411, game_b).await;
+
+    let response = server
+        .get("/teacher/get_games_by_course")
+        .add_query_param("instructor_id", owner_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<CourseGameSummary>> = response.json();
+    let games = body.data.unwrap();
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].id, game_a);
+    assert_eq!(games[0].title, "Course Game A");
+    assert_eq!(games[0].player_count, 3);
+    assert_eq!(games[1].id, game_b);
+    assert_eq!(games[1].player_count, 1);
+
+    let response = server
+        .get("/teacher/get_games_by_course")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("course_id", other_course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<CourseGameSummary>> = response.json();
+    let games = body.data.unwrap();
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[0].player_count, 0);
+}
+
+#[tokio::test]
+async fn test_get_games_by_course_not_owner() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = create_test_instructor(&pool, 2402, "gbc_other@test.com", "Other").await;
+    let course_id = create_test_course(&pool, "Games By Course Forbidden").await;
+    create_test_course_ownership(&pool, instructor_id, course_id, false).await;
+
+    let response = server
+        .get("/teacher/get_games_by_course")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("course_id", course_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/teacher/get_games_by_course")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("course_id", 999_999)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // list_students
 
 #[tokio::test]
@@ -1014,6 +1392,77 @@ async fn test_get_student_exercises_not_registered() {
     );
 }
 
+// get_student_unattempted_exercises
+
+#[tokio::test]
+async fn test_get_student_unattempted_exercises_success() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8601;
+    let player_id = 8611;
+    let course_id = create_test_course(&pool, "Course Unattempted").await;
+    let game_id = create_test_game(&pool, course_id, "Unattempted Game", 4).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Unattempted Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Unatt 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Unatt 2").await;
+    let ex3_id = create_test_exercise(&pool, module_id, 3, "Unatt 3").await;
+    let ex4_id = create_test_exercise(&pool, module_id, 4, "Unatt 4").await;
+    let java_id = create_test_exercise(&pool, module_id, 5, "Unatt Java").await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::exercises::table.find(java_id))
+            .set(schema::exercises::programming_language.eq("java"))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    create_test_instructor(&pool, instructor_id, "unatt@test.com", "Unatt Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, player_id, "unatt_s1@test.com", "Unatt S1").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, player_id, game_id, ex3_id, false, 0.1).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_student_unattempted_exercises?instructor_id={}&game_id={}&player_id={}",
+            instructor_id, game_id, player_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<UnattemptedExerciseResponse>> = response.json();
+    let exercises = body.data.unwrap();
+    let ids: Vec<i64> = exercises.iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![ex2_id, ex4_id]);
+    assert_eq!(exercises[0].title, "Unatt 2");
+    assert_eq!(exercises[1].title, "Unatt 4");
+}
+
+#[tokio::test]
+async fn test_get_student_unattempted_exercises_not_registered() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8602;
+    let player_id = 8612;
+    let course_id = create_test_course(&pool, "Course Unattempted NR").await;
+    let game_id = create_test_game(&pool, course_id, "Unattempted Game NR", 1).await;
+
+    create_test_instructor(&pool, instructor_id, "unattnr@test.com", "UnattNR Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, player_id, "unattnr_s1@test.com", "UnattNR S1").await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_student_unattempted_exercises?instructor_id={}&game_id={}&player_id={}",
+            instructor_id, game_id, player_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 //  get_student_submissions
 #[tokio::test]
 async fn test_get_student_submissions_success_all() {
@@ -1088,83 +1537,491 @@ async fn test_get_student_submissions_success_only() {
     assert_eq!(sub_ids, vec![sub2_id, sub3_id]);
 }
 
-// get_submission_data
 #[tokio::test]
-async fn test_get_submission_data_success() {
+async fn test_get_student_submissions_include_code() {
     let (server, pool) = setup_test_environment().await;
-    let instructor_id = 7001;
-    let player_id = 7101;
-    let course_id = create_test_course(&pool, "Course SubData").await;
-    let game_id = create_test_game(&pool, course_id, "SubData Game", 1).await;
-    let module_id = create_test_module(&pool, course_id, 1, "SubData Module").await;
-    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubD 1").await;
-
-    create_test_instructor(&pool, instructor_id, "subdata@test.com", "SubData Inst").await;
-    create_test_player(&pool, player_id, "stud_subdata@test.com", "SubData Student").await;
+    let instructor_id = 6003;
+    let player_id = 6103;
+    let course_id = create_test_course(&pool, "Course SubList Code").await;
+    let game_id = create_test_game(&pool, course_id, "SubList Game Code", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SubList Module Code").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubL Code 1").await;
+
+    create_test_instructor(&pool, instructor_id, "sublistc@test.com", "SubListC Inst").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "stud_sublistc@test.com",
+        "SubListC Student",
+    )
+    .await;
     create_test_game_ownership(&pool, instructor_id, game_id, true).await;
     create_test_player_registration(&pool, player_id, game_id).await;
 
-    let sub_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
-
-    let response = server
-        .get(&format!(
-            "/teacher/get_submission_data?instructor_id={}&submission_id={}",
-            instructor_id, sub_id
-        ))
-        .await;
+    let sub1_id = create_test_submission(&pool, player_id, game_id, ex1_id, false, 0.4).await;
+    let sub2_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for id in [sub1_id, sub2_id] {
+            diesel::update(schema::submissions::table.find(id))
+                .set(schema::submissions::submitted_code.eq(format!("print({})", id)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
 
+    let url = format!(
+        "/teacher/get_student_submissions?instructor_id={}&game_id={}&player_id={}",
+        instructor_id, game_id, player_id
+    );
+    let ids = server
+        .get(&url)
+        .await
+        .json::<ApiResponse<Vec<i64>>>()
+        .data
+        .unwrap();
+
+    let response = server.get(&format!("{}&include_code=true", url)).await;
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<SubmissionDataResponse> = response.json();
-    assert_eq!(body.status_code, 200);
-    let data = body.data.unwrap();
-    assert_eq!(data.id, sub_id);
-    assert_eq!(data.player_id, player_id);
-    assert_eq!(data.game_id, game_id);
-    assert_eq!(data.exercise_id, ex1_id);
-    assert!(data.first_solution);
+    let submissions = response
+        .json::<ApiResponse<Vec<SubmissionDataResponse>>>()
+        .data
+        .unwrap();
+    assert_eq!(submissions.iter().map(|s| s.id).collect::<Vec<_>>(), ids);
+    for submission in &submissions {
+        assert_eq!(submission.player_id, player_id);
+        assert_eq!(
+            submission.submitted_code,
+            format!("print({})", submission.id)
+        );
+    }
 }
 
+// get_student_best_submissions
 #[tokio::test]
-async fn test_get_submission_data_forbidden() {
+async fn test_get_student_best_submissions_picks_highest_then_earliest() {
     let (server, pool) = setup_test_environment().await;
-    let owner_instructor_id = 7002;
-    let forbidden_instructor_id = 7003;
-    let player_id = 7102;
-    let course_id = create_test_course(&pool, "Course SubData F").await;
-    let game_id = create_test_game(&pool, course_id, "SubData Game F", 1).await;
-    let module_id = create_test_module(&pool, course_id, 1, "SubData Module F").await;
-    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubD F 1").await;
-
-    create_test_instructor(
-        &pool,
-        owner_instructor_id,
-        "subdatao@test.com",
-        "SubDataO Inst",
-    )
-    .await;
-    create_test_instructor(
-        &pool,
-        forbidden_instructor_id,
-        "subdataf@test.com",
-        "SubDataF Inst",
-    )
-    .await;
+    let instructor_id = 6004;
+    let player_id = 6104;
+    let course_id = create_test_course(&pool, "Course Best Subs").await;
+    let game_id = create_test_game(&pool, course_id, "Best Subs Game", 3).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Best Subs Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Best Subs 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Best Subs 2").await;
+    create_test_exercise(&pool, module_id, 3, "Best Subs 3").await;
+
+    create_test_instructor(&pool, instructor_id, "bestsubs@test.com", "BestSubs Inst").await;
+    create_test_instructor(&pool, 6005, "bestsubs_x@test.com", "BestSubs Other").await;
     create_test_player(
         &pool,
         player_id,
-        "stud_subdataf@test.com",
-        "SubDataF Student",
+        "stud_bestsubs@test.com",
+        "BestSubs Student",
     )
     .await;
-    create_test_game_ownership(&pool, owner_instructor_id, game_id, true).await;
+    create_test_player(&pool, 6105, "stud_bestsubs_x@test.com", "BestSubs Other").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
     create_test_player_registration(&pool, player_id, game_id).await;
-    let sub_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+
+    create_test_submission(&pool, player_id, game_id, ex1_id, false, 0.4).await;
+    let best_ex1 = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, player_id, game_id, ex1_id, false, 0.8).await;
+    create_test_submission(&pool, player_id, game_id, ex2_id, true, 0.9).await;
+    let best_ex2 = create_test_submission(&pool, player_id, game_id, ex2_id, true, 0.9).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::submissions::table.find(best_ex2))
+            .set(schema::submissions::entered_at.eq(Utc::now() - Duration::hours(1)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
 
     let response = server
-        .get(&format!(
-            "/teacher/get_submission_data?instructor_id={}&submission_id={}",
-            forbidden_instructor_id, sub_id
-        ))
+        .get("/teacher/get_student_best_submissions")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let submissions = response
+        .json::<ApiResponse<Vec<SubmissionDataResponse>>>()
+        .data
+        .unwrap();
+    let found: Vec<(i64, i64)> = submissions.iter().map(|s| (s.exercise_id, s.id)).collect();
+    assert_eq!(found, vec![(ex1_id, best_ex1), (ex2_id, best_ex2)]);
+
+    let response = server
+        .get("/teacher/get_student_best_submissions")
+        .add_query_param("instructor_id", 6005)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/teacher/get_student_best_submissions")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", 6105)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_struggling_students
+#[tokio::test]
+async fn test_get_struggling_students_flags_repeated_failures() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 6006;
+    let stuck_id = 6106;
+    let solver_id = 6107;
+    let course_id = create_test_course(&pool, "Course Struggling").await;
+    let game_id = create_test_game(&pool, course_id, "Struggling Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Struggling Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Struggling 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Struggling 2").await;
+
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "struggling@test.com",
+        "Struggling Inst",
+    )
+    .await;
+    create_test_player(&pool, stuck_id, "stud_stuck@test.com", "Stuck Student").await;
+    create_test_player(&pool, solver_id, "stud_solver@test.com", "Solver Student").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, stuck_id, game_id).await;
+    create_test_player_registration(&pool, solver_id, game_id).await;
+
+    for _ in 0..5 {
+        create_test_submission(&pool, stuck_id, game_id, ex1_id, false, 0.2).await;
+        create_test_submission(&pool, solver_id, game_id, ex1_id, false, 0.2).await;
+    }
+    for _ in 0..4 {
+        create_test_submission(&pool, stuck_id, game_id, ex2_id, false, 0.3).await;
+    }
+    create_test_submission(&pool, solver_id, game_id, ex1_id, true, 1.0).await;
+
+    let response = server
+        .get("/teacher/get_struggling_students")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("min_failed_attempts", 5)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let students = response
+        .json::<ApiResponse<Vec<StrugglingStudent>>>()
+        .data
+        .unwrap();
+    assert_eq!(students.len(), 1);
+    assert_eq!(students[0].player_id, stuck_id);
+    assert_eq!(students[0].exercise_ids, vec![ex1_id]);
+
+    let response = server
+        .get("/teacher/get_struggling_students")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("min_failed_attempts", 0)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+}
+
+// get_player_score
+
+#[tokio::test]
+async fn test_get_player_score_uses_course_score_rules() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 6015;
+    let other_instructor_id = 6016;
+    let player_id = 6115;
+    let outsider_id = 6116;
+    let course_id = create_test_course(&pool, "Course Score Rules").await;
+    let game_id = create_test_game(&pool, course_id, "Score Rules Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Score Rules Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Score Rules Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Score Rules Ex 2").await;
+    create_test_instructor(&pool, instructor_id, "scorerules@test.com", "Score Inst").await;
+    create_test_instructor(
+        &pool,
+        other_instructor_id,
+        "scorerules_o@test.com",
+        "Score Other Inst",
+    )
+    .await;
+    create_test_player(&pool, player_id, "stud_score@test.com", "Score Student").await;
+    create_test_player(&pool, outsider_id, "out_score@test.com", "Score Outsider").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_unlock(&pool, player_id, ex2_id).await;
+    create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, player_id, game_id, ex1_id, false, 1.0).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::courses::table.find(course_id))
+            .set(
+                schema::courses::gamification_rule_results
+                    .eq(r#"{"score": {"exercise_points": 7, "unlock_penalty": 3}}"#),
+            )
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_player_score")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let score = response
+        .json::<ApiResponse<PlayerScoreResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(score.base, 7);
+    assert_eq!(score.rewards, 0);
+    assert_eq!(score.penalties, 3);
+    assert_eq!(score.total, 4);
+
+    let response = server
+        .get("/teacher/get_player_score")
+        .add_query_param("instructor_id", other_instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/teacher/get_player_score")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", outsider_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_player_activity_by_day
+
+#[tokio::test]
+async fn test_get_player_activity_by_day_counts_per_utc_date() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 6013;
+    let player_id = 6113;
+    let idle_player_id = 6114;
+    let course_id = create_test_course(&pool, "Course Activity").await;
+    let game_id = create_test_game(&pool, course_id, "Activity Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Activity Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Activity Ex").await;
+
+    create_test_instructor(&pool, instructor_id, "activity@test.com", "Activity Inst").await;
+    create_test_player(
+        &pool,
+        player_id,
+        "stud_activity@test.com",
+        "Activity Student",
+    )
+    .await;
+    create_test_player(
+        &pool,
+        idle_player_id,
+        "idle_activity@test.com",
+        "Idle Student",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_registration(&pool, idle_player_id, game_id).await;
+
+    let day = Utc::now().date_naive() - Duration::days(10);
+    let mut seeded = Vec::new();
+    for (days_later, hour) in [(0, 0), (0, 23), (1, 12), (3, 1), (3, 2), (3, 23)] {
+        let id = create_test_submission(&pool, player_id, game_id, ex_id, false, 0.3).await;
+        let entered_at = (day + Duration::days(days_later))
+            .and_hms_opt(hour, 30, 0)
+            .unwrap()
+            .and_utc();
+        seeded.push((id, entered_at));
+    }
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (id, entered_at) in seeded {
+            diesel::update(schema::submissions::table.find(id))
+                .set(schema::submissions::entered_at.eq(entered_at))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_player_activity_by_day")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<PlayerActivityDay>> = response.json();
+    let activity: Vec<_> = body
+        .data
+        .unwrap()
+        .into_iter()
+        .map(|d| (d.date, d.submission_count))
+        .collect();
+    assert_eq!(
+        activity,
+        vec![
+            (day, 2),
+            (day + Duration::days(1), 1),
+            (day + Duration::days(3), 3)
+        ]
+    );
+
+    let response = server
+        .get("/teacher/get_player_activity_by_day")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", idle_player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<PlayerActivityDay>> = response.json();
+    assert!(body.data.unwrap().is_empty());
+
+    let response = server
+        .get("/teacher/get_player_activity_by_day")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", 999_999)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_submission_data
+#[tokio::test]
+async fn test_get_submission_data_success() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 7001;
+    let player_id = 7101;
+    let course_id = create_test_course(&pool, "Course SubData").await;
+    let game_id = create_test_game(&pool, course_id, "SubData Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SubData Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubD 1").await;
+
+    create_test_instructor(&pool, instructor_id, "subdata@test.com", "SubData Inst").await;
+    create_test_player(&pool, player_id, "stud_subdata@test.com", "SubData Student").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let sub_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_submission_data?instructor_id={}&submission_id={}",
+            instructor_id, sub_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<SubmissionDataResponse> = response.json();
+    assert_eq!(body.status_code, 200);
+    let data = body.data.unwrap();
+    assert_eq!(data.id, sub_id);
+    assert_eq!(data.player_id, player_id);
+    assert_eq!(data.game_id, game_id);
+    assert_eq!(data.exercise_id, ex1_id);
+    assert!(data.first_solution);
+}
+
+#[tokio::test]
+async fn test_get_submission_data_result_is_percentage_number() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 7010;
+    let player_id = 7110;
+    let course_id = create_test_course(&pool, "Course SubData Pct").await;
+    let game_id = create_test_game(&pool, course_id, "SubData Game Pct", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SubData Module Pct").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubD Pct 1").await;
+
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "subdatapct@test.com",
+        "SubDataPct Inst",
+    )
+    .await;
+    create_test_player(
+        &pool,
+        player_id,
+        "stud_subdatapct@test.com",
+        "SubDataPct Student",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    let sub_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 0.75).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_submission_data?instructor_id={}&submission_id={}",
+            instructor_id, sub_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: Value = response.json();
+    assert_eq!(body["data"]["result"], json!(75.0));
+}
+
+#[tokio::test]
+async fn test_get_submission_data_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_instructor_id = 7002;
+    let forbidden_instructor_id = 7003;
+    let player_id = 7102;
+    let course_id = create_test_course(&pool, "Course SubData F").await;
+    let game_id = create_test_game(&pool, course_id, "SubData Game F", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SubData Module F").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubD F 1").await;
+
+    create_test_instructor(
+        &pool,
+        owner_instructor_id,
+        "subdatao@test.com",
+        "SubDataO Inst",
+    )
+    .await;
+    create_test_instructor(
+        &pool,
+        forbidden_instructor_id,
+        "subdataf@test.com",
+        "SubDataF Inst",
+    )
+    .await;
+    create_test_player(
+        &pool,
+        player_id,
+        "stud_subdataf@test.com",
+        "SubDataF Student",
+    )
+    .await;
+    create_test_game_ownership(&pool, owner_instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    let sub_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_submission_data?instructor_id={}&submission_id={}",
+            forbidden_instructor_id, sub_id
+        ))
         .await;
 
     assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
@@ -1187,6 +2044,102 @@ async fn test_get_submission_data_not_found() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
 
+// get_submissions_data
+#[tokio::test]
+async fn test_get_submissions_data_forbidden_handling() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 7011;
+    let other_instructor_id = 7012;
+    let player_id = 7111;
+    let course_id = create_test_course(&pool, "Course SubsData").await;
+    let game_id = create_test_game(&pool, course_id, "SubsData Game", 2).await;
+    let other_game_id = create_test_game(&pool, course_id, "SubsData Other Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SubsData Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "SubsD 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "SubsD 2").await;
+
+    create_test_instructor(&pool, instructor_id, "subsdata@test.com", "SubsData Inst").await;
+    create_test_instructor(
+        &pool,
+        other_instructor_id,
+        "subsdatao@test.com",
+        "SubsDataO Inst",
+    )
+    .await;
+    create_test_player(
+        &pool,
+        player_id,
+        "stud_subsdata@test.com",
+        "SubsData Student",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_game_ownership(&pool, other_instructor_id, other_game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_registration(&pool, player_id, other_game_id).await;
+
+    let sub1_id = create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    let sub2_id = create_test_submission(&pool, player_id, game_id, ex2_id, false, 0.2).await;
+    let forbidden_sub_id =
+        create_test_submission(&pool, player_id, other_game_id, ex1_id, true, 1.0).await;
+
+    let payload = GetSubmissionsDataPayload {
+        instructor_id,
+        submission_ids: vec![sub2_id, forbidden_sub_id, sub1_id],
+        skip_forbidden: false,
+    };
+    let response = server
+        .post("/teacher/get_submissions_data")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let payload = GetSubmissionsDataPayload {
+        skip_forbidden: true,
+        ..payload
+    };
+    let response = server
+        .post("/teacher/get_submissions_data")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let data = response
+        .json::<ApiResponse<Vec<SubmissionDataResponse>>>()
+        .data
+        .unwrap();
+    let ids: Vec<i64> = data.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![sub2_id, sub1_id]);
+}
+
+#[tokio::test]
+async fn test_get_submissions_data_limits() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 7013;
+    create_test_instructor(&pool, instructor_id, "subsdatal@test.com", "SubsDataL Inst").await;
+
+    let payload = GetSubmissionsDataPayload {
+        instructor_id,
+        submission_ids: (1..=101).collect(),
+        skip_forbidden: false,
+    };
+    let response = server
+        .post("/teacher/get_submissions_data")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::BAD_REQUEST);
+
+    let payload = GetSubmissionsDataPayload {
+        instructor_id,
+        submission_ids: vec![99711],
+        skip_forbidden: false,
+    };
+    let response = server
+        .post("/teacher/get_submissions_data")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // get_exercise_stats
 #[tokio::test]
 async fn test_get_exercise_stats_success() {
@@ -1216,85 +2169,584 @@ async fn test_get_exercise_stats_success() {
 
     let response = server
         .get(&format!(
-            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
-            instructor_id, game_id, ex_id
+            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+            instructor_id, game_id, ex_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ExerciseStatsResponse> = response.json();
+    assert_eq!(body.status_code, 200);
+    let stats = body.data.unwrap();
+
+    assert_eq!(stats.attempts, 4);
+    assert_eq!(stats.successful_attempts, 1);
+    assert!(approx_eq!(f64, stats.difficulty, 75.0, ulps = 2));
+    assert!(approx_eq!(
+        f64,
+        stats.solved_percentage,
+        33.33333333333333,
+        ulps = 2
+    ));
+}
+
+#[tokio::test]
+async fn test_get_exercise_stats_min_meaningful_result() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8008;
+    let player_id = 8108;
+    let course_id = create_test_course(&pool, "Course ExStats Min").await;
+    let game_id = create_test_game(&pool, course_id, "ExStats Game Min", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "ExStats Module Min").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "ExS Min 1").await;
+
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "exstatsmin@test.com",
+        "ExStatsMin Inst",
+    )
+    .await;
+    create_test_player(&pool, player_id, "stud_exsmin@test.com", "ExStatsMin S1").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    create_test_submission(&pool, player_id, game_id, ex_id, false, 0.0).await;
+    create_test_submission(&pool, player_id, game_id, ex_id, false, 0.0).await;
+    create_test_submission(&pool, player_id, game_id, ex_id, false, 0.0).await;
+    create_test_submission(&pool, player_id, game_id, ex_id, false, 0.3).await;
+    create_test_submission(&pool, player_id, game_id, ex_id, true, 0.9).await;
+
+    let fetch_stats = || {
+        let server = &server;
+        async move {
+            server
+                .get("/teacher/get_exercise_stats")
+                .add_query_param("instructor_id", instructor_id)
+                .add_query_param("game_id", game_id)
+                .add_query_param("exercise_id", ex_id)
+                .await
+                .json::<ApiResponse<ExerciseStatsResponse>>()
+                .data
+                .unwrap()
+        }
+    };
+    let stats = fetch_stats().await;
+    assert_eq!(stats.attempts, 5);
+    assert!(approx_eq!(f64, stats.difficulty, 80.0, ulps = 2));
+
+    let response = server
+        .post("/teacher/modify_game")
+        .json(&json!({
+            "instructor_id": instructor_id,
+            "game_id": game_id,
+            "min_meaningful_result": 1.0
+        }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let stats = fetch_stats().await;
+    assert_eq!(stats.attempts, 2);
+    assert_eq!(stats.successful_attempts, 1);
+    assert!(approx_eq!(f64, stats.difficulty, 50.0, ulps = 2));
+
+    let all_stats = server
+        .get("/teacher/get_all_exercise_stats")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .await
+        .json::<ApiResponse<Vec<Value>>>()
+        .data
+        .unwrap();
+    assert_eq!(all_stats[0]["attempts"], 2);
+
+    let response = server
+        .post("/teacher/modify_game")
+        .json(&json!({
+            "instructor_id": instructor_id,
+            "game_id": game_id,
+            "min_meaningful_result": 101.0
+        }))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_get_exercise_stats_no_attempts() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8002;
+    let course_id = create_test_course(&pool, "Course ExStats NA").await;
+    let game_id = create_test_game(&pool, course_id, "ExStats Game NA", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "ExStats Module NA").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "ExS NA 1").await;
+
+    create_test_instructor(&pool, instructor_id, "exstatsna@test.com", "ExStatsNA Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+            instructor_id, game_id, ex_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ExerciseStatsResponse> = response.json();
+    let stats = body.data.unwrap();
+    assert_eq!(stats.attempts, 0);
+    assert_eq!(stats.successful_attempts, 0);
+    assert!(approx_eq!(f64, stats.difficulty, 0.0, ulps = 2));
+    assert!(approx_eq!(f64, stats.solved_percentage, 0.0, ulps = 2));
+    assert!(stats.average_time_to_solve_seconds.is_none());
+}
+
+#[tokio::test]
+async fn test_time_spent_aggregates_ignore_legacy_rows() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8003;
+    let player_id = 8104;
+    let other_player_id = 8105;
+    let course_id = create_test_course(&pool, "Course TimeSpent").await;
+    let game_id = create_test_game(&pool, course_id, "TimeSpent Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "TimeSpent Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "TimeSpent Ex").await;
+
+    create_test_instructor(&pool, instructor_id, "timespent@test.com", "TimeSpent Inst").await;
+    create_test_player(&pool, player_id, "stud_ts1@test.com", "TimeSpent S1").await;
+    create_test_player(&pool, other_player_id, "stud_ts2@test.com", "TimeSpent S2").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_player_registration(&pool, other_player_id, game_id).await;
+
+    let failed = create_test_submission(&pool, player_id, game_id, ex_id, false, 0.2).await;
+    let solved = create_test_submission(&pool, player_id, game_id, ex_id, true, 0.9).await;
+    let other_solved =
+        create_test_submission(&pool, other_player_id, game_id, ex_id, true, 1.0).await;
+    // legacy row without a recorded time
+    create_test_submission(&pool, other_player_id, game_id, ex_id, false, 0.8).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (submission_id, seconds) in [(failed, 30), (solved, 90), (other_solved, 150)] {
+            diesel::update(schema::submissions::table.find(submission_id))
+                .set(schema::submissions::time_spent_seconds.eq(Some(seconds)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let stats = server
+        .get(&format!(
+            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+            instructor_id, game_id, ex_id
+        ))
+        .await
+        .json::<ApiResponse<ExerciseStatsResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(stats.successful_attempts, 3);
+    assert_eq!(stats.average_time_to_solve_seconds, Some(120.0));
+
+    let progress = server
+        .get(&format!(
+            "/teacher/get_student_progress?instructor_id={}&game_id={}&player_id={}",
+            instructor_id, game_id, player_id
+        ))
+        .await
+        .json::<ApiResponse<StudentProgressResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(progress.time_spent_seconds, 120);
+
+    let progress = server
+        .get(&format!(
+            "/teacher/get_student_progress?instructor_id={}&game_id={}&player_id={}",
+            instructor_id, game_id, other_player_id
+        ))
+        .await
+        .json::<ApiResponse<StudentProgressResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(progress.time_spent_seconds, 150);
+}
+
+#[tokio::test]
+async fn test_get_exercise_stats_not_found_exercise() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8003;
+    let course_id = create_test_course(&pool, "Course ExStats NFE").await;
+    let game_id = create_test_game(&pool, course_id, "ExStats Game NFE", 0).await;
+    let non_existent_ex_id = 99001;
+
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "exstatsnfe@test.com",
+        "ExStatsNFE Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+            instructor_id, game_id, non_existent_ex_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains(&format!(
+        "Exercise with ID {} not found",
+        non_existent_ex_id
+    )));
+}
+
+#[tokio::test]
+async fn test_success_threshold_per_game() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8011;
+    let player_id = 8111;
+    let course_id = create_test_course(&pool, "Course Threshold").await;
+    let strict_game_id = create_test_game(&pool, course_id, "Threshold Strict Game", 1).await;
+    let default_game_id = create_test_game(&pool, course_id, "Threshold Default Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Threshold Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Threshold Ex").await;
+
+    create_test_instructor(&pool, instructor_id, "threshold@test.com", "Threshold Inst").await;
+    create_test_player(&pool, player_id, "stud_threshold@test.com", "Threshold S").await;
+    for game_id in [strict_game_id, default_game_id] {
+        create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+        create_test_player_registration(&pool, player_id, game_id).await;
+        create_test_submission(&pool, player_id, game_id, ex_id, false, 0.6).await;
+    }
+
+    let payload = ModifyGamePayload {
+        instructor_id,
+        game_id: strict_game_id,
+        title: None,
+        public: None,
+        active: None,
+        description: None,
+        module_lock: None,
+        exercise_lock: None,
+        success_threshold: Some(70.0),
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
+    };
+    let response = server.post("/teacher/modify_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    for (game_id, expected_successes) in [(strict_game_id, 0), (default_game_id, 1)] {
+        let stats = server
+            .get(&format!(
+                "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+                instructor_id, game_id, ex_id
+            ))
+            .await
+            .json::<ApiResponse<ExerciseStatsResponse>>()
+            .data
+            .unwrap();
+        assert_eq!(stats.successful_attempts, expected_successes);
+
+        let exercise_submissions = server
+            .get(&format!(
+                "/teacher/get_exercise_submissions?instructor_id={}&game_id={}&exercise_id={}&success_only=true",
+                instructor_id, game_id, ex_id
+            ))
+            .await
+            .json::<ApiResponse<Vec<i64>>>()
+            .data
+            .unwrap();
+        assert_eq!(exercise_submissions.len() as i64, expected_successes);
+
+        let student_submissions = server
+            .get(&format!(
+                "/teacher/get_student_submissions?instructor_id={}&game_id={}&player_id={}&success_only=true",
+                instructor_id, game_id, player_id
+            ))
+            .await
+            .json::<ApiResponse<Vec<i64>>>()
+            .data
+            .unwrap();
+        assert_eq!(student_submissions.len() as i64, expected_successes);
+    }
+}
+
+#[tokio::test]
+async fn test_modify_game_success_threshold_out_of_range() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8012;
+    let course_id = create_test_course(&pool, "Course Threshold R").await;
+    let game_id = create_test_game(&pool, course_id, "Threshold Range Game", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "thresholdr@test.com",
+        "ThresholdR Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "game_id": game_id,
+        "success_threshold": 120.0
+    });
+    let response = server.post("/teacher/modify_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_modify_game_max_attempts_per_exercise() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8013;
+    let course_id = create_test_course(&pool, "Course Attempt Cap").await;
+    let game_id = create_test_game(&pool, course_id, "Attempt Cap Game", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "attemptcap@test.com",
+        "AttemptCap Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let modify = |max_attempts: i32| {
+        let server = &server;
+        async move {
+            server
+                .post("/teacher/modify_game")
+                .json(&json!({
+                    "instructor_id": instructor_id,
+                    "game_id": game_id,
+                    "max_attempts_per_exercise": max_attempts
+                }))
+                .await
+                .status_code()
+        }
+    };
+    assert_eq!(modify(0).await, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(modify(3).await, StatusCode::OK);
+
+    let conn = pool.get().await.unwrap();
+    let max_attempts = conn
+        .interact(move |conn| {
+            schema::games::table
+                .find(game_id)
+                .select(schema::games::max_attempts_per_exercise)
+                .first::<Option<i32>>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(max_attempts, Some(3));
+}
+
+// get_all_exercise_stats
+#[tokio::test]
+async fn test_get_all_exercise_stats_matches_per_exercise_stats() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8031;
+    let player1_id = 8131;
+    let player2_id = 8132;
+    let course_id = create_test_course(&pool, "Course AllExStats").await;
+    let game_id = create_test_game(&pool, course_id, "AllExStats Game", 3).await;
+    let module2_id = create_test_module(&pool, course_id, 2, "AllExStats Module 2").await;
+    let module1_id = create_test_module(&pool, course_id, 1, "AllExStats Module 1").await;
+    let ex3_id = create_test_exercise(&pool, module2_id, 1, "AllExS 3").await;
+    let ex1_id = create_test_exercise(&pool, module1_id, 1, "AllExS 1").await;
+    let ex2_id = create_test_exercise(&pool, module1_id, 2, "AllExS 2").await;
+
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "allexstats@test.com",
+        "AllExStats Inst",
+    )
+    .await;
+    create_test_player(&pool, player1_id, "stud_allexs1@test.com", "AllExStats S1").await;
+    create_test_player(&pool, player2_id, "stud_allexs2@test.com", "AllExStats S2").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player1_id, game_id).await;
+    create_test_player_registration(&pool, player2_id, game_id).await;
+
+    create_test_submission(&pool, player1_id, game_id, ex1_id, false, 0.4).await;
+    let solved_id = create_test_submission(&pool, player1_id, game_id, ex1_id, true, 0.9).await;
+    create_test_submission(&pool, player2_id, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, player2_id, game_id, ex2_id, false, 0.1).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::submissions::table.find(solved_id))
+            .set(schema::submissions::time_spent_seconds.eq(Some(90)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_all_exercise_stats?instructor_id={}&game_id={}",
+            instructor_id, game_id
         ))
         .await;
-
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<ExerciseStatsResponse> = response.json();
-    assert_eq!(body.status_code, 200);
-    let stats = body.data.unwrap();
-
-    assert_eq!(stats.attempts, 4);
-    assert_eq!(stats.successful_attempts, 1);
-    assert!(approx_eq!(f64, stats.difficulty, 75.0, ulps = 2));
-    assert!(approx_eq!(
-        f64,
-        stats.solved_percentage,
-        33.33333333333333,
-        ulps = 2
-    ));
+    let batch = response.json::<ApiResponse<Vec<Value>>>().data.unwrap();
+    let batch_ids: Vec<i64> = batch
+        .iter()
+        .map(|entry| entry["exercise_id"].as_i64().unwrap())
+        .collect();
+    assert_eq!(batch_ids, vec![ex1_id, ex2_id, ex3_id]);
+
+    for mut entry in batch {
+        let exercise_id = entry["exercise_id"].as_i64().unwrap();
+        entry.as_object_mut().unwrap().remove("exercise_id");
+        let single = server
+            .get(&format!(
+                "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+                instructor_id, game_id, exercise_id
+            ))
+            .await
+            .json::<ApiResponse<Value>>()
+            .data
+            .unwrap();
+        assert_eq!(entry, single, "exercise {}", exercise_id);
+    }
 }
 
+// get_exercise_score_histogram
 #[tokio::test]
-async fn test_get_exercise_stats_no_attempts() {
+async fn test_get_exercise_score_histogram_success() {
     let (server, pool) = setup_test_environment().await;
-    let instructor_id = 8002;
-    let course_id = create_test_course(&pool, "Course ExStats NA").await;
-    let game_id = create_test_game(&pool, course_id, "ExStats Game NA", 1).await;
-    let module_id = create_test_module(&pool, course_id, 1, "ExStats Module NA").await;
-    let ex_id = create_test_exercise(&pool, module_id, 1, "ExS NA 1").await;
-
-    create_test_instructor(&pool, instructor_id, "exstatsna@test.com", "ExStatsNA Inst").await;
+    let instructor_id = 8021;
+    let player_id = 8121;
+    let course_id = create_test_course(&pool, "Course Histogram").await;
+    let game_id = create_test_game(&pool, course_id, "Histogram Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Histogram Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Histogram Ex").await;
+
+    create_test_instructor(&pool, instructor_id, "histogram@test.com", "Histogram Inst").await;
+    create_test_player(&pool, player_id, "stud_histogram@test.com", "Histogram S").await;
     create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+
+    for result in [0.0, 0.05, 0.15, 0.19, 0.55, 0.9, 0.95, 1.0] {
+        create_test_submission(&pool, player_id, game_id, ex_id, false, result).await;
+    }
 
     let response = server
         .get(&format!(
-            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
+            "/teacher/get_exercise_score_histogram?instructor_id={}&game_id={}&exercise_id={}",
             instructor_id, game_id, ex_id
         ))
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<ExerciseStatsResponse> = response.json();
-    let stats = body.data.unwrap();
-    assert_eq!(stats.attempts, 0);
-    assert_eq!(stats.successful_attempts, 0);
-    assert!(approx_eq!(f64, stats.difficulty, 0.0, ulps = 2));
-    assert!(approx_eq!(f64, stats.solved_percentage, 0.0, ulps = 2));
+    let histogram = response
+        .json::<ApiResponse<Vec<ScoreHistogramBucket>>>()
+        .data
+        .unwrap();
+    assert_eq!(histogram.len(), 10);
+    let counts: Vec<i64> = histogram.iter().map(|b| b.count).collect();
+    assert_eq!(counts, vec![2, 2, 0, 0, 0, 1, 0, 0, 0, 3]);
+    assert_eq!((histogram[0].min_score, histogram[0].max_score), (0, 9));
+    assert_eq!((histogram[9].min_score, histogram[9].max_score), (90, 100));
 }
 
 #[tokio::test]
-async fn test_get_exercise_stats_not_found_exercise() {
+async fn test_get_exercise_score_histogram_exercise_not_found() {
     let (server, pool) = setup_test_environment().await;
-    let instructor_id = 8003;
-    let course_id = create_test_course(&pool, "Course ExStats NFE").await;
-    let game_id = create_test_game(&pool, course_id, "ExStats Game NFE", 0).await;
-    let non_existent_ex_id = 99001;
-
+    let instructor_id = 8022;
+    let course_id = create_test_course(&pool, "Course Histogram NF").await;
+    let game_id = create_test_game(&pool, course_id, "Histogram Game NF", 1).await;
     create_test_instructor(
         &pool,
         instructor_id,
-        "exstatsnfe@test.com",
-        "ExStatsNFE Inst",
+        "histogramnf@test.com",
+        "HistogramNF Inst",
     )
     .await;
     create_test_game_ownership(&pool, instructor_id, game_id, true).await;
 
     let response = server
         .get(&format!(
-            "/teacher/get_exercise_stats?instructor_id={}&game_id={}&exercise_id={}",
-            instructor_id, game_id, non_existent_ex_id
+            "/teacher/get_exercise_score_histogram?instructor_id={}&game_id={}&exercise_id={}",
+            instructor_id, game_id, 99802
         ))
         .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_exercise_first_solvers
+#[tokio::test]
+async fn test_get_exercise_first_solvers_fastest_first() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8011;
+    let course_id = create_test_course(&pool, "Course FirstSolvers").await;
+    let game_id = create_test_game(&pool, course_id, "FirstSolvers Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "FirstSolvers Module").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "FirstSolvers Ex").await;
+    create_test_instructor(&pool, instructor_id, "firstsolv@test.com", "FirstSolv Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    for (player_id, email) in [
+        (8111, "firstsolv_a@test.com"),
+        (8112, "firstsolv_b@test.com"),
+        (8113, "firstsolv_c@test.com"),
+        (8114, "firstsolv_d@test.com"),
+    ] {
+        create_test_player(&pool, player_id, email, email).await;
+        create_test_player_registration(&pool, player_id, game_id).await;
+    }
+    let slow = create_test_submission(&pool, 8111, game_id, ex_id, true, 1.0).await;
+    let fastest = create_test_submission(&pool, 8112, game_id, ex_id, true, 0.8).await;
+    let middle = create_test_submission(&pool, 8113, game_id, ex_id, true, 0.9).await;
+    let failed = create_test_submission(&pool, 8114, game_id, ex_id, false, 0.1).await;
+
+    let base = Utc::now() - Duration::hours(2);
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (sub_id, minutes) in [(slow, 30), (fastest, 5), (middle, 12), (failed, 1)] {
+            diesel::update(schema::submissions::table.find(sub_id))
+                .set(schema::submissions::entered_at.eq(base + Duration::minutes(minutes)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_exercise_first_solvers")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", ex_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let solvers = response
+        .json::<ApiResponse<Vec<FirstSolver>>>()
+        .data
+        .unwrap();
+    let ids: Vec<i64> = solvers.iter().map(|solver| solver.player_id).collect();
+    assert_eq!(ids, vec![8112, 8113, 8111]);
+    assert_eq!(solvers[0].display_name, "firstsolv_b@test.com");
+    assert_eq!(
+        solvers[0].entered_at.timestamp(),
+        (base + Duration::minutes(5)).timestamp()
+    );
 
+    let response = server
+        .get("/teacher/get_exercise_first_solvers")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("exercise_id", 999_999)
+        .await;
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
-    let body: ApiResponse<Value> = response.json();
-    assert!(body.status_message.contains(&format!(
-        "Exercise with ID {} not found",
-        non_existent_ex_id
-    )));
 }
 
 // get_exercise_submissions
@@ -1367,6 +2819,318 @@ async fn test_get_exercise_submissions_success_only() {
     assert_eq!(sub_ids, vec![sub1_id]);
 }
 
+// get_group_stats
+
+#[tokio::test]
+async fn test_get_group_stats_compares_groups() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8401;
+    let course_id = create_test_course(&pool, "Course GroupStats").await;
+    let game_id = create_test_game(&pool, course_id, "GroupStats Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "GroupStats Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "GrS 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "GrS 2").await;
+    let group_a_id = create_test_group_with_id(&pool, 8451, "Group Stats A").await;
+    let group_b_id = create_test_group_with_id(&pool, 8452, "Group Stats B").await;
+
+    create_test_instructor(&pool, instructor_id, "grstats@test.com", "GrStats Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    for (player_id, email, group_id) in [
+        (8411, "grs_a1@test.com", group_a_id),
+        (8412, "grs_a2@test.com", group_a_id),
+        (8413, "grs_b1@test.com", group_b_id),
+    ] {
+        create_test_player(&pool, player_id, email, "GrStats Student").await;
+        create_test_player_registration(&pool, player_id, game_id).await;
+        add_player_to_group(&pool, player_id, group_id).await;
+    }
+
+    create_test_submission(&pool, 8411, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, 8411, game_id, ex2_id, true, 0.8).await;
+    create_test_submission(&pool, 8412, game_id, ex1_id, false, 0.2).await;
+    create_test_submission(&pool, 8413, game_id, ex1_id, false, 0.1).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(
+            schema::player_registrations::table
+                .filter(schema::player_registrations::player_id.eq(8411)),
+        )
+        .set(schema::player_registrations::progress.eq(2))
+        .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_group_stats?instructor_id={}&game_id={}&group_id={}",
+            instructor_id, game_id, group_a_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let stats_a = response
+        .json::<ApiResponse<GroupStatsResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(stats_a.members, 2);
+    assert_eq!(stats_a.total_submissions, 3);
+    assert_eq!(stats_a.solved_exercises, 2);
+    assert!(approx_eq!(f64, stats_a.average_progress, 50.0, ulps = 2));
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_group_stats?instructor_id={}&game_id={}&group_id={}",
+            instructor_id, game_id, group_b_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let stats_b = response
+        .json::<ApiResponse<GroupStatsResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(stats_b.members, 1);
+    assert_eq!(stats_b.total_submissions, 1);
+    assert_eq!(stats_b.solved_exercises, 0);
+    assert!(approx_eq!(f64, stats_b.average_progress, 0.0, ulps = 2));
+}
+
+#[tokio::test]
+async fn test_get_group_stats_not_found_group() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8402;
+    let course_id = create_test_course(&pool, "Course GroupStats NF").await;
+    let game_id = create_test_game(&pool, course_id, "GroupStats Game NF", 1).await;
+
+    create_test_instructor(&pool, instructor_id, "grstatsnf@test.com", "GrStatsNF Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_group_stats?instructor_id={}&game_id={}&group_id={}",
+            instructor_id, game_id, 99999
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_progress_distribution
+#[tokio::test]
+async fn test_get_progress_distribution_buckets_active_players() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8021;
+    let course_id = create_test_course(&pool, "Course ProgressDist").await;
+    let game_id = create_test_game(&pool, course_id, "ProgressDist Game", 4).await;
+    let module_id = create_test_module(&pool, course_id, 1, "ProgressDist Module").await;
+    let mut exercise_ids = Vec::new();
+    for order in 1..=4 {
+        let name = format!("ProgressDist Ex {}", order);
+        exercise_ids.push(create_test_exercise(&pool, module_id, order, &name).await);
+    }
+    create_test_instructor(&pool, instructor_id, "progdist@test.com", "ProgDist Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    // (player, exercises first-solved): 0%, 25%, 50%, 50%, 100%, and one player who left
+    let players = [
+        (8121, "progdist_a@test.com", 0),
+        (8122, "progdist_b@test.com", 1),
+        (8123, "progdist_c@test.com", 2),
+        (8124, "progdist_d@test.com", 2),
+        (8125, "progdist_e@test.com", 4),
+        (8126, "progdist_f@test.com", 4),
+    ];
+    for (player_id, email, solved) in players {
+        create_test_player(&pool, player_id, email, email).await;
+        create_test_player_registration(&pool, player_id, game_id).await;
+        for exercise_id in &exercise_ids[..solved] {
+            create_test_submission(&pool, player_id, game_id, *exercise_id, true, 1.0).await;
+        }
+    }
+    // a repeated first solution and a failed attempt don't add progress
+    create_test_submission(&pool, 8122, game_id, exercise_ids[0], true, 1.0).await;
+    create_test_submission(&pool, 8122, game_id, exercise_ids[1], false, 0.2).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(
+            schema::player_registrations::table
+                .filter(schema::player_registrations::player_id.eq(8126)),
+        )
+        .set(schema::player_registrations::left_at.eq(Utc::now()))
+        .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_progress_distribution")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let distribution = response
+        .json::<ApiResponse<Vec<ProgressDistributionBucket>>>()
+        .data
+        .unwrap();
+    assert_eq!(distribution.len(), 10);
+    assert_eq!(distribution[9].min_progress, 90);
+    assert_eq!(distribution[9].max_progress, 100);
+    let counts: Vec<i64> = distribution.iter().map(|bucket| bucket.players).collect();
+    assert_eq!(counts, vec![1, 0, 1, 0, 0, 2, 0, 0, 0, 1]);
+
+    let response = server
+        .get("/teacher/get_progress_distribution")
+        .add_query_param("instructor_id", 999_999)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_game_end_summary
+
+#[tokio::test]
+async fn test_get_game_end_summary_after_scan() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8501;
+    let course_id = create_test_course(&pool, "Course EndSummary").await;
+    let game_id = create_test_game(&pool, course_id, "EndSummary Game", 4).await;
+    let _running_game_id = create_test_game(&pool, course_id, "Running Game", 4).await;
+
+    create_test_instructor(&pool, instructor_id, "endsum@test.com", "EndSum Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, 8511, "endsum_s1@test.com", "EndSum S1").await;
+    create_test_player(&pool, 8512, "endsum_s2@test.com", "EndSum S2").await;
+    let reg1_id = create_test_player_registration(&pool, 8511, game_id).await;
+    create_test_player_registration(&pool, 8512, game_id).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_registrations::table.find(reg1_id))
+            .set(schema::player_registrations::progress.eq(2))
+            .execute(conn)?;
+        diesel::update(schema::games::table.find(game_id))
+            .set(schema::games::end_date.eq(chrono::Utc::now() - chrono::Duration::hours(1)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_game_end_summary?instructor_id={}&game_id={}",
+            instructor_id, game_id
+        ))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+    let created = scan_ended_games(&pool).await.unwrap();
+    assert_eq!(created, 1);
+    let created_again = scan_ended_games(&pool).await.unwrap();
+    assert_eq!(created_again, 0);
+
+    let response = server
+        .get(&format!(
+            "/teacher/get_game_end_summary?instructor_id={}&game_id={}",
+            instructor_id, game_id
+        ))
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<GameEndSummaryResponse> = response.json();
+    let summary = body.data.unwrap();
+    assert_eq!(summary.game_id, game_id);
+    assert_eq!(summary.player_count, 2);
+    assert!(approx_eq!(f64, summary.average_progress, 25.0, ulps = 2));
+}
+
+#[tokio::test]
+async fn test_archive_old_submissions_keeps_recent_and_latest_first_solutions() {
+    let (_server, pool) = setup_test_environment().await;
+    let player_id = 8521;
+    let course_id = create_test_course(&pool, "Archive Subs Course").await;
+    let ended_game_id = create_test_game(&pool, course_id, "Archive Subs Ended", 2).await;
+    let running_game_id = create_test_game(&pool, course_id, "Archive Subs Running", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Archive Subs Mod").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Archive Subs Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Archive Subs Ex 2").await;
+    create_test_player(&pool, player_id, "archsubs@test.com", "ArchSubs P").await;
+    create_test_player_registration(&pool, player_id, ended_game_id).await;
+    create_test_player_registration(&pool, player_id, running_game_id).await;
+
+    let old_failed =
+        create_test_submission(&pool, player_id, ended_game_id, ex1_id, false, 0.2).await;
+    let old_first =
+        create_test_submission(&pool, player_id, ended_game_id, ex1_id, true, 1.0).await;
+    let superseded_first =
+        create_test_submission(&pool, player_id, ended_game_id, ex2_id, true, 1.0).await;
+    let latest_first =
+        create_test_submission(&pool, player_id, ended_game_id, ex2_id, true, 1.0).await;
+    let recent_failed =
+        create_test_submission(&pool, player_id, ended_game_id, ex1_id, false, 0.3).await;
+    let running_failed =
+        create_test_submission(&pool, player_id, running_game_id, ex1_id, false, 0.1).await;
+
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        let aged = [
+            (old_failed, 60),
+            (old_first, 59),
+            (superseded_first, 61),
+            (latest_first, 60),
+            (running_failed, 60),
+        ];
+        for (id, days) in aged {
+            diesel::update(schema::submissions::table.find(id))
+                .set(schema::submissions::entered_at.eq(Utc::now() - Duration::days(days)))
+                .execute(conn)?;
+        }
+        diesel::update(schema::games::table.find(ended_game_id))
+            .set(schema::games::end_date.eq(Utc::now() - Duration::days(1)))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let retention = std::time::Duration::from_secs(30 * 24 * 60 * 60);
+    let archived = archive_old_submissions(&pool, retention).await.unwrap();
+    assert_eq!(archived, 2);
+    assert_eq!(archive_old_submissions(&pool, retention).await.unwrap(), 0);
+
+    let (mut remaining, archived_rows) = conn
+        .interact(|conn| {
+            let remaining = schema::submissions::table
+                .select(schema::submissions::id)
+                .load::<i64>(conn)?;
+            let archived_rows = schema::archived_submissions::table
+                .order_by(schema::archived_submissions::submission_id.asc())
+                .select((
+                    schema::archived_submissions::submission_id,
+                    schema::archived_submissions::snapshot,
+                ))
+                .load::<(i64, Value)>(conn)?;
+            Ok::<_, diesel::result::Error>((remaining, archived_rows))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    remaining.sort();
+    assert_eq!(
+        remaining,
+        vec![old_first, latest_first, recent_failed, running_failed]
+    );
+    let archived_ids: Vec<i64> = archived_rows.iter().map(|(id, _)| *id).collect();
+    assert_eq!(archived_ids, vec![old_failed, superseded_first]);
+    let (_, snapshot) = &archived_rows[0];
+    assert_eq!(snapshot["id"], old_failed);
+    assert_eq!(snapshot["submitted_code"], "print('test')");
+}
+
 // create_game
 #[tokio::test]
 async fn test_create_game_success() {
@@ -1386,6 +3150,9 @@ async fn test_create_game_success() {
         programming_language: "rust".to_string(),
         module_lock: 0.0,
         exercise_lock: false,
+        success_threshold: None,
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
     };
 
     let response = server.post("/teacher/create_game").json(&payload).await;
@@ -1471,6 +3238,71 @@ async fn test_create_game_language_not_allowed() {
     assert!(body.status_message.contains("not allowed for course"));
 }
 
+#[tokio::test]
+async fn test_create_game_token_mismatch_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let token_instructor_id = 10004;
+    let claimed_instructor_id = 10005;
+    let course_id = create_test_course(&pool, "Course CreateG Token").await;
+    create_test_instructor(
+        &pool,
+        token_instructor_id,
+        "creategta@test.com",
+        "CreateGTA Inst",
+    )
+    .await;
+    create_test_instructor(
+        &pool,
+        claimed_instructor_id,
+        "creategtb@test.com",
+        "CreateGTB Inst",
+    )
+    .await;
+
+    let payload = json!({
+        "instructor_id": claimed_instructor_id,
+        "title": "Game Token Mismatch",
+        "course_id": course_id,
+        "programming_language": "py"
+    });
+
+    let response = server
+        .post("/teacher/create_game")
+        .add_header(TEST_EMAIL_HEADER, "creategta@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/teacher/create_game")
+        .add_header(TEST_EMAIL_HEADER, "creategtb@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_create_game_token_not_instructor_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 10006;
+    let course_id = create_test_course(&pool, "Course CreateG NotInst").await;
+    create_test_instructor(&pool, instructor_id, "creategni@test.com", "CreateGNI Inst").await;
+
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "title": "Game Not Instructor",
+        "course_id": course_id,
+        "programming_language": "py"
+    });
+
+    let response = server
+        .post("/teacher/create_game")
+        .add_header(TEST_EMAIL_HEADER, "someone_else@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
 // modify_game
 #[tokio::test]
 async fn test_modify_game_success() {
@@ -1490,6 +3322,9 @@ async fn test_modify_game_success() {
         public: None,
         module_lock: None,
         exercise_lock: None,
+        success_threshold: None,
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
     };
 
     let response = server.post("/teacher/modify_game").json(&payload).await;
@@ -1531,6 +3366,9 @@ async fn test_modify_game_forbidden() {
         description: None,
         module_lock: None,
         exercise_lock: None,
+        success_threshold: None,
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
     };
 
     let response = server.post("/teacher/modify_game").json(&payload).await;
@@ -1553,10 +3391,99 @@ async fn test_modify_game_not_found() {
         description: None,
         module_lock: None,
         exercise_lock: None,
+        success_threshold: None,
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
+    };
+
+    let response = server.post("/teacher/modify_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_modify_game_token_mismatch_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let token_instructor_id = 11005;
+    let owner_instructor_id = 11006;
+    let course_id = create_test_course(&pool, "Course Modify Token").await;
+    let game_id = create_test_game(&pool, course_id, "Modify Token Title", 1).await;
+    create_test_instructor(
+        &pool,
+        token_instructor_id,
+        "modifygta@test.com",
+        "ModifyGTA Inst",
+    )
+    .await;
+    create_test_instructor(
+        &pool,
+        owner_instructor_id,
+        "modifygtb@test.com",
+        "ModifyGTB Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, owner_instructor_id, game_id, true).await;
+
+    let payload = ModifyGamePayload {
+        instructor_id: owner_instructor_id,
+        game_id,
+        title: Some("Impersonated Update".to_string()),
+        public: None,
+        active: None,
+        description: None,
+        module_lock: None,
+        exercise_lock: None,
+        success_threshold: None,
+        max_attempts_per_exercise: None,
+        min_meaningful_result: None,
     };
 
-    let response = server.post("/teacher/modify_game").json(&payload).await;
-    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    let response = server
+        .post("/teacher/modify_game")
+        .add_header(TEST_EMAIL_HEADER, "modifygta@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_game_instructors
+#[tokio::test]
+async fn test_get_game_instructors_lists_owner_flags() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = 12011;
+    let co_instructor_id = 12012;
+    let outsider_id = 12013;
+    let course_id = create_test_course(&pool, "Course GetInst").await;
+    let game_id = create_test_game(&pool, course_id, "GetInst Game", 1).await;
+    create_test_instructor(&pool, owner_id, "getgi_owner@test.com", "GetGI Owner").await;
+    create_test_instructor(&pool, co_instructor_id, "getgi_co@test.com", "GetGI Co").await;
+    create_test_instructor(&pool, outsider_id, "getgi_out@test.com", "GetGI Out").await;
+    create_test_game_ownership(&pool, owner_id, game_id, true).await;
+    create_test_game_ownership(&pool, co_instructor_id, game_id, false).await;
+
+    let response = server
+        .get("/teacher/get_game_instructors")
+        .add_query_param("instructor_id", co_instructor_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let instructors = response
+        .json::<ApiResponse<Vec<GameInstructorResponse>>>()
+        .data
+        .unwrap();
+    assert_eq!(instructors.len(), 2);
+    assert_eq!(instructors[0].instructor_id, owner_id);
+    assert_eq!(instructors[0].email, "getgi_owner@test.com");
+    assert_eq!(instructors[0].display_name, "GetGI Owner");
+    assert!(instructors[0].owner);
+    assert_eq!(instructors[1].instructor_id, co_instructor_id);
+    assert!(!instructors[1].owner);
+
+    let response = server
+        .get("/teacher/get_game_instructors")
+        .add_query_param("instructor_id", outsider_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
 }
 
 // add_game_instructor
@@ -1714,7 +3641,121 @@ async fn test_add_game_instructor_instructor_to_add_not_found() {
     )));
 }
 
+#[tokio::test]
+async fn test_add_game_instructor_demote_last_owner_conflict() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = 12101;
+    let course_id = create_test_course(&pool, "Course AddInst Demote").await;
+    let game_id = create_test_game(&pool, course_id, "AddInst Game Demote", 1).await;
+    create_test_instructor(&pool, owner_id, "addgidem@test.com", "AddGIDem Inst").await;
+    create_test_game_ownership(&pool, owner_id, game_id, true).await;
+
+    let payload = AddGameInstructorPayload {
+        requesting_instructor_id: owner_id,
+        game_id,
+        instructor_to_add_id: owner_id,
+        is_owner: false,
+    };
+
+    let response = server
+        .post("/teacher/add_game_instructor")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains("last owner"));
+
+    let conn = pool.get().await.unwrap();
+    let still_owner = conn
+        .interact(move |conn| {
+            schema::game_ownership::table
+                .filter(schema::game_ownership::game_id.eq(game_id))
+                .filter(schema::game_ownership::instructor_id.eq(owner_id))
+                .select(schema::game_ownership::owner)
+                .first::<bool>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(still_owner);
+}
+
 // remove_game_instructor
+#[tokio::test]
+async fn test_remove_game_instructor_other_owner_remains() {
+    let (server, pool) = setup_test_environment().await;
+    let requesting_instructor_id = 13101;
+    let instructor_to_remove_id = 13102;
+    let course_id = create_test_course(&pool, "Course RemInst Owners").await;
+    let game_id = create_test_game(&pool, course_id, "RemInst Game Owners", 1).await;
+    create_test_instructor(
+        &pool,
+        requesting_instructor_id,
+        "remgiown1@test.com",
+        "RemGIOwn1 Inst",
+    )
+    .await;
+    create_test_instructor(
+        &pool,
+        instructor_to_remove_id,
+        "remgiown2@test.com",
+        "RemGIOwn2 Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, requesting_instructor_id, game_id, true).await;
+    create_test_game_ownership(&pool, instructor_to_remove_id, game_id, true).await;
+
+    let payload = RemoveGameInstructorPayload {
+        requesting_instructor_id,
+        game_id,
+        instructor_to_remove_id,
+    };
+
+    let response = server
+        .post("/teacher/remove_game_instructor")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    // The remaining owner is now the last one and cannot remove themselves.
+    let payload = RemoveGameInstructorPayload {
+        requesting_instructor_id,
+        game_id,
+        instructor_to_remove_id: requesting_instructor_id,
+    };
+
+    let response = server
+        .post("/teacher/remove_game_instructor")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_remove_game_instructor_last_owner_conflict() {
+    let (server, pool) = setup_test_environment().await;
+    let admin_id = create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+    let owner_id = 13103;
+    let course_id = create_test_course(&pool, "Course RemInst Last").await;
+    let game_id = create_test_game(&pool, course_id, "RemInst Game Last", 1).await;
+    create_test_instructor(&pool, owner_id, "remgilast@test.com", "RemGILast Inst").await;
+    create_test_game_ownership(&pool, owner_id, game_id, true).await;
+
+    let payload = RemoveGameInstructorPayload {
+        requesting_instructor_id: admin_id,
+        game_id,
+        instructor_to_remove_id: owner_id,
+    };
+
+    let response = server
+        .post("/teacher/remove_game_instructor")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    let body: ApiResponse<Value> = response.json();
+    assert!(body.status_message.contains("last owner"));
+}
+
 #[tokio::test]
 async fn test_remove_game_instructor_success() {
     let (server, pool) = setup_test_environment().await;
@@ -1835,6 +3876,170 @@ async fn test_remove_game_instructor_not_associated() {
     )));
 }
 
+// include_game_exercises / exclude_game_exercises
+#[tokio::test]
+async fn test_include_game_exercises_subset_drives_progress() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8031;
+    let player_id = 8131;
+    let course_id = create_test_course(&pool, "Course Subset").await;
+    let game_id = create_test_game(&pool, course_id, "Subset Game", 4).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Subset Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Subset Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Subset Ex 2").await;
+    let ex3_id = create_test_exercise(&pool, module_id, 3, "Subset Ex 3").await;
+    let _ex4_id = create_test_exercise(&pool, module_id, 4, "Subset Ex 4").await;
+    create_test_instructor(&pool, instructor_id, "subset@test.com", "Subset Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, player_id, "subset_p@test.com", "Subset Player").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_submission(&pool, player_id, game_id, ex1_id, true, 1.0).await;
+    create_test_submission(&pool, player_id, game_id, ex3_id, true, 1.0).await;
+
+    let response = server
+        .post("/teacher/include_game_exercises")
+        .json(&IncludeGameExercisesPayload {
+            instructor_id,
+            game_id,
+            exercise_ids: vec![ex2_id, ex1_id, ex1_id],
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let subset = response
+        .json::<ApiResponse<GameExercisesResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(subset.total_exercises, 2);
+    assert_eq!(subset.exercise_ids, vec![ex1_id, ex2_id]);
+
+    let conn = pool.get().await.unwrap();
+    let (total_exercises, registration_progress) = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .inner_join(schema::games::table)
+                .filter(schema::player_registrations::player_id.eq(player_id))
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .select((
+                    schema::games::total_exercises,
+                    schema::player_registrations::progress,
+                ))
+                .first::<(i32, i32)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(total_exercises, 2);
+    assert_eq!(registration_progress, 1);
+
+    let response = server
+        .get("/teacher/get_student_progress")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let progress = response
+        .json::<ApiResponse<StudentProgressResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(progress.solved_exercises, 1);
+    assert!(approx_eq!(f64, progress.progress, 50.0, ulps = 2));
+
+    let response = server
+        .get("/teacher/get_student_unattempted_exercises")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let unattempted = response
+        .json::<ApiResponse<Vec<UnattemptedExerciseResponse>>>()
+        .data
+        .unwrap();
+    let unattempted_ids: Vec<i64> = unattempted.iter().map(|e| e.id).collect();
+    assert_eq!(unattempted_ids, vec![ex2_id]);
+
+    let other_course_id = create_test_course(&pool, "Course Subset Other").await;
+    let other_module_id = create_test_module(&pool, other_course_id, 1, "Other Module").await;
+    let foreign_id = create_test_exercise(&pool, other_module_id, 1, "Other Ex").await;
+    let response = server
+        .post("/teacher/include_game_exercises")
+        .json(&IncludeGameExercisesPayload {
+            instructor_id,
+            game_id,
+            exercise_ids: vec![foreign_id],
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let java_id = create_test_exercise(&pool, module_id, 5, "Subset Java Ex").await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::exercises::table.find(java_id))
+            .set(schema::exercises::programming_language.eq("java"))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    let response = server
+        .post("/teacher/include_game_exercises")
+        .json(&IncludeGameExercisesPayload {
+            instructor_id,
+            game_id,
+            exercise_ids: vec![java_id],
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_exclude_game_exercises_falls_back_to_course() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 8032;
+    let course_id = create_test_course(&pool, "Course Exclude").await;
+    let game_id = create_test_game(&pool, course_id, "Exclude Game", 3).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Exclude Module").await;
+    let ex1_id = create_test_exercise(&pool, module_id, 1, "Exclude Ex 1").await;
+    let ex2_id = create_test_exercise(&pool, module_id, 2, "Exclude Ex 2").await;
+    let ex3_id = create_test_exercise(&pool, module_id, 3, "Exclude Ex 3").await;
+    create_test_instructor(&pool, instructor_id, "exclude@test.com", "Exclude Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_instructor(&pool, 8033, "exclude_other@test.com", "Exclude Other").await;
+    create_test_game_ownership(&pool, 8033, game_id, false).await;
+
+    let exclude = |instructor_id: i64, exercise_ids: Vec<i64>| {
+        server
+            .post("/teacher/exclude_game_exercises")
+            .json(&ExcludeGameExercisesPayload {
+                instructor_id,
+                game_id,
+                exercise_ids,
+            })
+    };
+
+    let response = exclude(8033, vec![ex2_id]).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = exclude(instructor_id, vec![ex2_id]).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let subset = response
+        .json::<ApiResponse<GameExercisesResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(subset.total_exercises, 2);
+    assert_eq!(subset.exercise_ids, vec![ex1_id, ex3_id]);
+
+    let response = exclude(instructor_id, vec![ex1_id, ex3_id]).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let subset = response
+        .json::<ApiResponse<GameExercisesResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(subset.total_exercises, 3);
+    assert!(subset.exercise_ids.is_empty());
+}
+
 // activate_game
 #[tokio::test]
 async fn test_activate_game_success() {
@@ -1887,6 +4092,64 @@ async fn test_stop_game_success() {
     assert!(body.data.unwrap_or(false));
 }
 
+#[tokio::test]
+async fn test_stop_game_sends_webhook_event() {
+    // mock receiver forwarding every delivered event
+    let (sender, mut received) = tokio::sync::mpsc::unbounded_channel::<Value>();
+    let receiver = Router::new().route(
+        "/hooks",
+        post(move |Json(event): Json<Value>| async move {
+            sender.send(event).unwrap();
+        }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await });
+
+    let config = AppConfig {
+        webhook_url: Some(format!("http://{}/hooks", address).parse().unwrap()),
+        webhook_events: vec!["game.stopped".to_string()],
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let instructor_id = 15002;
+    let course_id = create_test_course(&pool, "Course Stop Hook").await;
+    let game_id = create_test_game(&pool, course_id, "Stop Hook Game", 1).await;
+    create_test_instructor(&pool, instructor_id, "stophook@test.com", "StopHook Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+
+    // not subscribed to, so never delivered
+    let payload = ActivateGamePayload {
+        instructor_id,
+        game_id,
+    };
+    let response = server.post("/teacher/activate_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let payload = StopGamePayload {
+        instructor_id,
+        game_id,
+    };
+    let response = server.post("/teacher/stop_game").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), received.recv())
+        .await
+        .expect("No webhook event arrived")
+        .unwrap();
+    assert_eq!(event["event"], "game.stopped");
+    assert_eq!(event["game_id"], game_id);
+    assert_eq!(event["instructor_id"], instructor_id);
+    assert!(
+        event["timestamp"]
+            .as_str()
+            .unwrap()
+            .parse::<DateTime<Utc>>()
+            .is_ok()
+    );
+    assert!(received.try_recv().is_err());
+}
+
 // remove_game_student
 #[tokio::test]
 async fn test_remove_game_student_success() {
@@ -1944,6 +4207,163 @@ async fn test_remove_game_student_not_registered() {
     );
 }
 
+// remove_game_students
+#[tokio::test]
+async fn test_remove_game_students_removes_registered_and_skips_others() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 16003;
+    let student_ids = [16103, 16104, 16105];
+    let unregistered_id = 16106;
+    let kept_id = 16107;
+    let course_id = create_test_course(&pool, "Course RemStuds").await;
+    let game_id = create_test_game(&pool, course_id, "RemStuds Game", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "remstuds_inst@test.com",
+        "RemStuds Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, student_ids[0], "remstuds0@test.com", "RemStuds S0").await;
+    create_test_player(&pool, student_ids[1], "remstuds1@test.com", "RemStuds S1").await;
+    create_test_player(&pool, student_ids[2], "remstuds2@test.com", "RemStuds S2").await;
+    create_test_player(&pool, kept_id, "remstuds_kept@test.com", "RemStuds Kept").await;
+    for student_id in student_ids.into_iter().chain([kept_id]) {
+        create_test_player_registration(&pool, student_id, game_id).await;
+    }
+    create_test_player(
+        &pool,
+        unregistered_id,
+        "remstuds_nr@test.com",
+        "RemStuds NR",
+    )
+    .await;
+
+    let payload = RemoveGameStudentsPayload {
+        instructor_id,
+        game_id,
+        student_ids: vec![
+            student_ids[0],
+            student_ids[1],
+            student_ids[2],
+            unregistered_id,
+            99999,
+        ],
+    };
+    let response = server
+        .post("/teacher/remove_game_students")
+        .json(&payload)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    assert_eq!(body.data, Some(3));
+
+    let conn = pool.get().await.unwrap();
+    let remaining: Vec<i64> = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .select(schema::player_registrations::player_id)
+                .load(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(remaining, vec![kept_id]);
+}
+
+#[tokio::test]
+async fn test_remove_game_students_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 16004;
+    let student_id = 16108;
+    let course_id = create_test_course(&pool, "Course RemStuds F").await;
+    let game_id = create_test_game(&pool, course_id, "RemStuds Game F", 1).await;
+    create_test_instructor(&pool, instructor_id, "remstudsf@test.com", "RemStudsF Inst").await;
+    create_test_player(&pool, student_id, "remstudsf_s@test.com", "RemStudsF S").await;
+    create_test_player_registration(&pool, student_id, game_id).await;
+
+    let payload = RemoveGameStudentsPayload {
+        instructor_id,
+        game_id,
+        student_ids: vec![student_id],
+    };
+    let response = server
+        .post("/teacher/remove_game_students")
+        .json(&payload)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// grant_reward
+#[tokio::test]
+async fn test_grant_reward_skips_existing_holders() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24061;
+    let course_id = create_test_course(&pool, "Grant Reward Course").await;
+    let game_id = create_test_game(&pool, course_id, "Grant Reward Game", 1).await;
+    let reward_id = create_test_reward(&pool, course_id, "Participation").await;
+    let other_course_id = create_test_course(&pool, "Grant Reward Other Course").await;
+    let other_reward_id = create_test_reward(&pool, other_course_id, "Elsewhere").await;
+    create_test_instructor(&pool, instructor_id, "grant@test.com", "Grant Inst").await;
+    create_test_instructor(&pool, 24062, "grant_co@test.com", "Grant Co").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_game_ownership(&pool, 24062, game_id, false).await;
+    for (player_id, email) in [
+        (24341, "grant_a@test.com"),
+        (24342, "grant_b@test.com"),
+        (24343, "grant_c@test.com"),
+    ] {
+        create_test_player(&pool, player_id, email, "Grant P").await;
+        create_test_player_registration(&pool, player_id, game_id).await;
+    }
+    create_test_player(&pool, 24344, "grant_out@test.com", "Grant Out").await;
+    create_test_player_reward(&pool, 24342, reward_id, game_id, 1).await;
+
+    let grant = |instructor_id: i64, reward_id: i64, player_ids: Vec<i64>| {
+        server
+            .post("/teacher/grant_reward")
+            .json(&GrantRewardPayload {
+                instructor_id,
+                game_id,
+                reward_id,
+                player_ids,
+            })
+    };
+
+    let response = grant(instructor_id, reward_id, vec![24341, 24342, 24343, 24341]).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ApiResponse<i64>>().data, Some(2));
+
+    let conn = pool.get().await.unwrap();
+    let holders = conn
+        .interact(move |conn| {
+            schema::player_rewards::table
+                .filter(schema::player_rewards::reward_id.eq(reward_id))
+                .filter(schema::player_rewards::game_id.eq(game_id))
+                .select((
+                    schema::player_rewards::player_id,
+                    schema::player_rewards::count,
+                ))
+                .order(schema::player_rewards::player_id.asc())
+                .load::<(i64, i32)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(holders, vec![(24341, 1), (24342, 1), (24343, 1)]);
+
+    let response = grant(instructor_id, reward_id, vec![24341, 24344]).await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    let response = grant(instructor_id, other_reward_id, vec![24341]).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    let response = grant(24062, reward_id, vec![24341]).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
 // translate_email_to_player_id
 #[tokio::test]
 async fn test_translate_email_success() {
@@ -2067,6 +4487,120 @@ async fn test_create_group_member_not_found() {
     );
 }
 
+// get_group_metadata / modify_group
+#[tokio::test]
+async fn test_get_group_metadata_created_at_populated() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 18501;
+    create_test_instructor(&pool, instructor_id, "groupmeta@test.com", "GroupMeta Inst").await;
+
+    let before = Utc::now();
+    let payload = CreateGroupPayload {
+        instructor_id,
+        display_name: "Group Meta".to_string(),
+        display_avatar: None,
+        member_list: vec![],
+    };
+    let response = server.post("/teacher/create_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let group_id = response.json::<ApiResponse<i64>>().data.unwrap();
+
+    let response = server
+        .get("/teacher/get_group_metadata")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("group_id", group_id)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let metadata = response
+        .json::<ApiResponse<GroupMetadataResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(metadata.id, group_id);
+    assert_eq!(metadata.display_name, "Group Meta");
+    assert!(metadata.created_at >= before - Duration::seconds(5));
+    assert!(metadata.created_at <= Utc::now() + Duration::seconds(5));
+    assert_eq!(metadata.updated_at, metadata.created_at);
+}
+
+#[tokio::test]
+async fn test_modify_group_advances_updated_at() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 18502;
+    let group_id = create_test_group_with_id(&pool, 185, "Group Modify").await;
+    create_test_instructor(&pool, instructor_id, "groupmod@test.com", "GroupMod Inst").await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+
+    let metadata_before = server
+        .get("/teacher/get_group_metadata")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("group_id", group_id)
+        .await
+        .json::<ApiResponse<GroupMetadataResponse>>()
+        .data
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+    let payload = ModifyGroupPayload {
+        instructor_id,
+        group_id,
+        display_name: Some("Group Modified".to_string()),
+        display_avatar: None,
+    };
+    let response = server.post("/teacher/modify_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let metadata_after = server
+        .get("/teacher/get_group_metadata")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("group_id", group_id)
+        .await
+        .json::<ApiResponse<GroupMetadataResponse>>()
+        .data
+        .unwrap();
+
+    assert_eq!(metadata_after.display_name, "Group Modified");
+    assert_eq!(metadata_after.created_at, metadata_before.created_at);
+    assert!(metadata_after.updated_at > metadata_before.updated_at);
+}
+
+#[tokio::test]
+async fn test_modify_group_name_conflict() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 18503;
+    let group_id = create_test_group_with_id(&pool, 186, "Group Modify C").await;
+    create_test_group_with_id(&pool, 187, "Group Taken").await;
+    create_test_instructor(&pool, instructor_id, "groupmodc@test.com", "GroupModC Inst").await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+
+    let payload = ModifyGroupPayload {
+        instructor_id,
+        group_id,
+        display_name: Some("Group Taken".to_string()),
+        display_avatar: None,
+    };
+    let response = server.post("/teacher/modify_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_modify_group_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 18504;
+    let group_id = create_test_group_with_id(&pool, 188, "Group Modify F").await;
+    create_test_instructor(&pool, instructor_id, "groupmodf@test.com", "GroupModF Inst").await;
+
+    let payload = ModifyGroupPayload {
+        instructor_id,
+        group_id,
+        display_name: Some("Group Modify F2".to_string()),
+        display_avatar: None,
+    };
+    let response = server.post("/teacher/modify_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
 // dissolve_group
 #[tokio::test]
 async fn test_dissolve_group_success() {
@@ -2143,6 +4677,251 @@ async fn test_dissolve_group_not_found() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
 
+// merge_groups
+#[tokio::test]
+async fn test_merge_groups_overlapping_member() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 21005;
+    let source_group_id = 83;
+    let target_group_id = 84;
+    create_test_instructor(&pool, instructor_id, "mergeg@test.com", "MergeG Inst").await;
+    create_test_group_with_id(&pool, source_group_id, "Group Merge Source").await;
+    create_test_group_with_id(&pool, target_group_id, "Group Merge Target").await;
+    create_test_group_ownership(&pool, instructor_id, source_group_id, true).await;
+    create_test_group_ownership(&pool, instructor_id, target_group_id, true).await;
+    create_test_player(&pool, 21106, "mergeg_p1@test.com", "MergeG P1").await;
+    create_test_player(&pool, 21107, "mergeg_p2@test.com", "MergeG P2").await;
+    create_test_player(&pool, 21108, "mergeg_p3@test.com", "MergeG P3").await;
+    add_player_to_group(&pool, 21106, source_group_id).await;
+    add_player_to_group(&pool, 21107, source_group_id).await;
+    add_player_to_group(&pool, 21107, target_group_id).await;
+    add_player_to_group(&pool, 21108, target_group_id).await;
+
+    let payload = MergeGroupsPayload {
+        instructor_id,
+        source_group_id,
+        target_group_id,
+    };
+    let response = server.post("/teacher/merge_groups").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    assert_eq!(body.data, Some(3));
+
+    let conn = pool.get().await.unwrap();
+    let (target_members, source_exists) = conn
+        .interact(move |conn| {
+            let mut members = schema::player_groups::table
+                .filter(schema::player_groups::group_id.eq(target_group_id))
+                .select(schema::player_groups::player_id)
+                .load::<i64>(conn)?;
+            members.sort();
+            let source_exists = diesel::select(diesel::dsl::exists(
+                schema::groups::table.find(source_group_id),
+            ))
+            .get_result::<bool>(conn)?;
+            Ok::<_, diesel::result::Error>((members, source_exists))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(target_members, vec![21106, 21107, 21108]);
+    assert!(!source_exists);
+
+    let response = server.post("/teacher/merge_groups").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// clone_group
+#[tokio::test]
+async fn test_clone_group_copies_active_members() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 21009;
+    let source_group_id = 85;
+    create_test_instructor(&pool, instructor_id, "cloneg@test.com", "CloneG Inst").await;
+    create_test_group_with_id(&pool, source_group_id, "Group Clone Source").await;
+    create_test_group_ownership(&pool, instructor_id, source_group_id, true).await;
+    create_test_player(&pool, 21109, "cloneg_p1@test.com", "CloneG P1").await;
+    create_test_player(&pool, 21110, "cloneg_p2@test.com", "CloneG P2").await;
+    create_test_player(&pool, 21111, "cloneg_p3@test.com", "CloneG P3").await;
+    add_player_to_group(&pool, 21109, source_group_id).await;
+    add_player_to_group(&pool, 21110, source_group_id).await;
+    add_player_to_group(&pool, 21111, source_group_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_groups::table.find((21111_i64, source_group_id)))
+            .set(schema::player_groups::left_at.eq(Some(Utc::now())))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let payload = CloneGroupPayload {
+        instructor_id,
+        source_group_id,
+        display_name: "Group Clone Copy".to_string(),
+    };
+    let response = server.post("/teacher/clone_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    let new_group_id = body.data.unwrap();
+    assert_ne!(new_group_id, source_group_id);
+
+    let (members, owners) = conn
+        .interact(move |conn| {
+            let mut members = schema::player_groups::table
+                .filter(schema::player_groups::group_id.eq(new_group_id))
+                .select(schema::player_groups::player_id)
+                .load::<i64>(conn)?;
+            members.sort();
+            let owners = schema::group_ownership::table
+                .filter(schema::group_ownership::group_id.eq(new_group_id))
+                .select((
+                    schema::group_ownership::instructor_id,
+                    schema::group_ownership::owner,
+                ))
+                .load::<(i64, bool)>(conn)?;
+            Ok::<_, diesel::result::Error>((members, owners))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(members, vec![21109, 21110]);
+    assert_eq!(owners, vec![(instructor_id, true)]);
+
+    let response = server.post("/teacher/clone_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
+// transfer_group_ownership
+async fn group_owner_flags(pool: &helpers::TestPool, group_id: i64) -> Vec<(i64, bool)> {
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        schema::group_ownership::table
+            .filter(schema::group_ownership::group_id.eq(group_id))
+            .select((
+                schema::group_ownership::instructor_id,
+                schema::group_ownership::owner,
+            ))
+            .order(schema::group_ownership::instructor_id.asc())
+            .load::<(i64, bool)>(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_transfer_group_ownership_success() {
+    let (server, pool) = setup_test_environment().await;
+    let group_id = 91;
+    create_test_instructor(&pool, 21006, "tgo_from@test.com", "TGO From").await;
+    create_test_instructor(&pool, 21007, "tgo_to@test.com", "TGO To").await;
+    create_test_group_with_id(&pool, group_id, "Group Transfer").await;
+    create_test_group_ownership(&pool, 21006, group_id, true).await;
+
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&TransferGroupOwnershipPayload {
+            current_owner_id: 21006,
+            group_id,
+            new_owner_id: 21007,
+            relinquish: false,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ApiResponse<bool>>().data, Some(true));
+    assert_eq!(
+        group_owner_flags(&pool, group_id).await,
+        vec![(21006, true), (21007, true)]
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_group_ownership_relinquish() {
+    let (server, pool) = setup_test_environment().await;
+    let group_id = 92;
+    create_test_instructor(&pool, 21008, "tgo_rel_from@test.com", "TGO Rel From").await;
+    create_test_instructor(&pool, 21009, "tgo_rel_to@test.com", "TGO Rel To").await;
+    create_test_group_with_id(&pool, group_id, "Group Transfer Relinquish").await;
+    create_test_group_ownership(&pool, 21008, group_id, true).await;
+    create_test_group_ownership(&pool, 21009, group_id, false).await;
+
+    let payload = TransferGroupOwnershipPayload {
+        current_owner_id: 21008,
+        group_id,
+        new_owner_id: 21009,
+        relinquish: true,
+    };
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(
+        group_owner_flags(&pool, group_id).await,
+        vec![(21008, false), (21009, true)]
+    );
+
+    // the former owner can no longer manage the group
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_transfer_group_ownership_forbidden_non_owner() {
+    let (server, pool) = setup_test_environment().await;
+    let group_id = 93;
+    create_test_instructor(&pool, 21010, "tgo_owner@test.com", "TGO Owner").await;
+    create_test_instructor(&pool, 21011, "tgo_other@test.com", "TGO Other").await;
+    create_test_group_with_id(&pool, group_id, "Group Transfer Forbidden").await;
+    create_test_group_ownership(&pool, 21010, group_id, true).await;
+
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&TransferGroupOwnershipPayload {
+            current_owner_id: 21011,
+            group_id,
+            new_owner_id: 21011,
+            relinquish: false,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&TransferGroupOwnershipPayload {
+            current_owner_id: 21011,
+            group_id,
+            new_owner_id: 21010,
+            relinquish: true,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        group_owner_flags(&pool, group_id).await,
+        vec![(21010, true)]
+    );
+
+    let response = server
+        .post("/teacher/transfer_group_ownership")
+        .json(&TransferGroupOwnershipPayload {
+            current_owner_id: 21010,
+            group_id,
+            new_owner_id: 99061,
+            relinquish: true,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        group_owner_flags(&pool, group_id).await,
+        vec![(21010, true)]
+    );
+}
+
 // add_group_member
 #[tokio::test]
 async fn test_add_group_member_success() {
@@ -2224,6 +5003,249 @@ async fn test_add_group_member_player_not_found() {
     )));
 }
 
+#[tokio::test]
+async fn test_add_group_member_rejected_beyond_max_group_size() {
+    let config = AppConfig {
+        max_group_size: 2,
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let instructor_id = 20004;
+    let group_id = 73;
+    create_test_instructor(&pool, instructor_id, "addgmmax@test.com", "AddGMMax Inst").await;
+    create_test_group_with_id(&pool, group_id, "Group Add Member Max").await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+    create_test_player(&pool, 20104, "addgmmax_p1@test.com", "AddGMMax P1").await;
+    create_test_player(&pool, 20105, "addgmmax_p2@test.com", "AddGMMax P2").await;
+    create_test_player(&pool, 20106, "addgmmax_p3@test.com", "AddGMMax P3").await;
+    create_test_player(&pool, 20107, "addgmmax_p4@test.com", "AddGMMax P4").await;
+    let add = |player_id: i64| {
+        let server = &server;
+        async move {
+            let payload = AddGroupMemberPayload {
+                instructor_id,
+                group_id,
+                player_id,
+            };
+            server
+                .post("/teacher/add_group_member")
+                .json(&payload)
+                .await
+                .status_code()
+        }
+    };
+
+    // a member who left does not count towards the limit
+    assert_eq!(add(20104).await, StatusCode::OK);
+    let payload = RemoveGroupMemberPayload {
+        instructor_id,
+        group_id,
+        player_id: 20104,
+    };
+    server
+        .post("/teacher/remove_group_member")
+        .json(&payload)
+        .await
+        .assert_status_ok();
+
+    assert_eq!(add(20105).await, StatusCode::OK);
+    assert_eq!(add(20106).await, StatusCode::OK);
+    assert_eq!(add(20107).await, StatusCode::CONFLICT);
+    // re-adding a current member is still accepted at the limit
+    assert_eq!(add(20106).await, StatusCode::OK);
+
+    let payload = CreateGroupPayload {
+        instructor_id,
+        display_name: "Group Create Max".to_string(),
+        display_avatar: None,
+        member_list: vec![20105, 20106, 20107],
+    };
+    let response = server.post("/teacher/create_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+
+    let payload = CreateGroupPayload {
+        member_list: vec![20105, 20106],
+        ..payload
+    };
+    let response = server.post("/teacher/create_group").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let invite_uuid = create_test_invite(&pool, instructor_id, None, Some(group_id)).await;
+    let payload = ProcessInviteLinkPayload {
+        player_id: 20107,
+        uuid: invite_uuid,
+        email: None,
+        display_name: None,
+    };
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    assert!(!check_player_in_group(&pool, 20107, group_id).await);
+
+    let source_group_id = 77;
+    create_test_group_with_id(&pool, source_group_id, "Group Merge Max").await;
+    create_test_group_ownership(&pool, instructor_id, source_group_id, true).await;
+    add_player_to_group(&pool, 20106, source_group_id).await;
+    add_player_to_group(&pool, 20107, source_group_id).await;
+    let payload = MergeGroupsPayload {
+        instructor_id,
+        source_group_id,
+        target_group_id: group_id,
+    };
+    let response = server.post("/teacher/merge_groups").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+    assert!(check_player_in_group(&pool, 20107, source_group_id).await);
+    assert!(!check_player_in_group(&pool, 20107, group_id).await);
+}
+
+// get_player_groups
+#[tokio::test]
+async fn test_get_player_groups_lists_active_memberships() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 20005;
+    let player_id = 20108;
+    create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+    create_test_instructor(&pool, instructor_id, "plgroups@test.com", "PlGroups Inst").await;
+    create_test_instructor(&pool, 20006, "plgroups_x@test.com", "PlGroups X").await;
+    create_test_instructor(&pool, 20007, "plgroups_y@test.com", "PlGroups Y").await;
+    create_test_player(&pool, player_id, "plgroups_p@test.com", "PlGroups P").await;
+    create_test_group_with_id(&pool, 74, "Player Groups A").await;
+    create_test_group_with_id(&pool, 75, "Player Groups B").await;
+    create_test_group_with_id(&pool, 76, "Player Groups C").await;
+    create_test_group_ownership(&pool, instructor_id, 74, true).await;
+    create_test_group_ownership(&pool, 20006, 75, true).await;
+    create_test_group_ownership(&pool, instructor_id, 76, true).await;
+    for group_id in [74, 75, 76] {
+        add_player_to_group(&pool, player_id, group_id).await;
+    }
+    let payload = RemoveGroupMemberPayload {
+        instructor_id,
+        group_id: 76,
+        player_id,
+    };
+    server
+        .post("/teacher/remove_group_member")
+        .json(&payload)
+        .await
+        .assert_status_ok();
+
+    let get = |instructor_id: i64| {
+        let server = &server;
+        async move {
+            server
+                .get("/teacher/get_player_groups")
+                .add_query_param("instructor_id", instructor_id)
+                .add_query_param("player_id", player_id)
+                .await
+        }
+    };
+
+    let response = get(0).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let groups = response
+        .json::<ApiResponse<Vec<PlayerGroupResponse>>>()
+        .data
+        .unwrap();
+    let found: Vec<(i64, &str)> = groups
+        .iter()
+        .map(|g| (g.group_id, g.display_name.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![(74, "Player Groups A"), (75, "Player Groups B")]
+    );
+
+    // other instructors only see the groups they own
+    let response = get(instructor_id).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let groups = response
+        .json::<ApiResponse<Vec<PlayerGroupResponse>>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        groups.iter().map(|g| g.group_id).collect::<Vec<_>>(),
+        vec![74]
+    );
+
+    assert_eq!(get(20007).await.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/teacher/get_player_groups")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("player_id", 99_208)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+// get_group_members
+#[tokio::test]
+async fn test_get_group_members_lists_only_active() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 21003;
+    let other_instructor_id = 21004;
+    let group_id = 82;
+    create_test_instructor(&pool, instructor_id, "getgm@test.com", "GetGM Inst").await;
+    create_test_instructor(&pool, other_instructor_id, "getgm_x@test.com", "GetGM X").await;
+    create_test_group_with_id(&pool, group_id, "Group Get Members").await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+    create_test_player(&pool, 21103, "getgm_p1@test.com", "GetGM P1").await;
+    create_test_player(&pool, 21104, "getgm_p2@test.com", "GetGM P2").await;
+    create_test_player(&pool, 21105, "getgm_p3@test.com", "GetGM P3").await;
+
+    for player_id in [21103, 21104, 21105] {
+        let payload = AddGroupMemberPayload {
+            instructor_id,
+            group_id,
+            player_id,
+        };
+        server
+            .post("/teacher/add_group_member")
+            .json(&payload)
+            .await
+            .assert_status_ok();
+    }
+    let payload = RemoveGroupMemberPayload {
+        instructor_id,
+        group_id,
+        player_id: 21104,
+    };
+    server
+        .post("/teacher/remove_group_member")
+        .json(&payload)
+        .await
+        .assert_status_ok();
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_groups::table.find((21105_i64, group_id)))
+            .set(schema::player_groups::left_at.eq(Some(Utc::now())))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_group_members")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("group_id", group_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<GroupMemberResponse>> = response.json();
+    let members = body.data.unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].player_id, 21103);
+    assert_eq!(members[0].display_name, "GetGM P1");
+    assert_eq!(members[0].email, "getgm_p1@test.com");
+
+    let response = server
+        .get("/teacher/get_group_members")
+        .add_query_param("instructor_id", other_instructor_id)
+        .add_query_param("group_id", group_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
 // remove_group_member
 #[tokio::test]
 async fn test_remove_group_member_success() {
@@ -2280,55 +5302,186 @@ async fn test_remove_group_member_not_member() {
     );
 }
 
-// create_player
+// create_player
+#[tokio::test]
+async fn test_create_player_success_admin() {
+    let (server, _pool) = setup_test_environment().await;
+    let admin_instructor_id = 0;
+
+    let payload = CreatePlayerPayload {
+        instructor_id: admin_instructor_id,
+        email: "newplayer_admin@test.com".to_string(),
+        display_name: "Admin Created Player".to_string(),
+        display_avatar: None,
+        game_id: None,
+        group_id: None,
+        language: None,
+    };
+
+    let response = server.post("/teacher/create_player").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    assert!(body.data.is_some());
+}
+
+#[tokio::test]
+async fn test_create_player_success_with_game_and_group() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 22001;
+    let course_id = create_test_course(&pool, "Course CreateP GG").await;
+    let game_id = create_test_game(&pool, course_id, "CreateP Game GG", 1).await;
+    let group_id = 90;
+    create_test_instructor(&pool, instructor_id, "createpgg@test.com", "CreatePGG Inst").await;
+    create_test_group_with_id(&pool, group_id, "CreateP Group GG").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+
+    let payload = CreatePlayerPayload {
+        instructor_id,
+        email: "newplayer_gg@test.com".to_string(),
+        display_name: "GG Created Player".to_string(),
+        display_avatar: None,
+        game_id: Some(game_id),
+        group_id: Some(group_id),
+        language: Some("fr".to_string()),
+    };
+
+    let response = server.post("/teacher/create_player").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    assert!(body.data.is_some());
+    let _new_player_id = body.data.unwrap();
+}
+
+async fn set_course_languages(pool: &helpers::TestPool, course_id: i64, languages: &'static str) {
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::courses::table.find(course_id))
+            .set(schema::courses::languages.eq(languages))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+}
+
+async fn registration_language(pool: &helpers::TestPool, player_id: i64, game_id: i64) -> String {
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        schema::player_registrations::table
+            .filter(schema::player_registrations::player_id.eq(player_id))
+            .filter(schema::player_registrations::game_id.eq(game_id))
+            .select(schema::player_registrations::language)
+            .first::<String>(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_player_default_language_fallback_chain() {
+    let config = AppConfig {
+        default_languages: vec!["de".to_string(), "fr".to_string()],
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let instructor_id = 22030;
+    let course_fr_id = create_test_course(&pool, "Course CreateP Lang FR").await;
+    let course_pt_id = create_test_course(&pool, "Course CreateP Lang PT").await;
+    set_course_languages(&pool, course_fr_id, "pt, fr").await;
+    set_course_languages(&pool, course_pt_id, "pt,es").await;
+    let game_fr_id = create_test_game(&pool, course_fr_id, "CreateP Game Lang FR", 1).await;
+    let game_pt_id = create_test_game(&pool, course_pt_id, "CreateP Game Lang PT", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "createplang@test.com",
+        "CreatePLang Inst",
+    )
+    .await;
+    create_test_game_ownership(&pool, instructor_id, game_fr_id, true).await;
+    create_test_game_ownership(&pool, instructor_id, game_pt_id, true).await;
+
+    let mut created = Vec::new();
+    for (email, game_id) in [
+        ("lang_fr@test.com", game_fr_id),
+        ("lang_pt@test.com", game_pt_id),
+    ] {
+        let payload = CreatePlayerPayload {
+            instructor_id,
+            email: email.to_string(),
+            display_name: "Lang Player".to_string(),
+            display_avatar: None,
+            game_id: Some(game_id),
+            group_id: None,
+            language: None,
+        };
+        let response = server.post("/teacher/create_player").json(&payload).await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        created.push((response.json::<ApiResponse<i64>>().data.unwrap(), game_id));
+    }
+
+    // "de" is offered by neither course: the next preference wins, else the course's first language
+    assert_eq!(
+        registration_language(&pool, created[0].0, created[0].1).await,
+        "fr"
+    );
+    assert_eq!(
+        registration_language(&pool, created[1].0, created[1].1).await,
+        "pt"
+    );
+}
+
 #[tokio::test]
-async fn test_create_player_success_admin() {
-    let (server, _pool) = setup_test_environment().await;
-    let admin_instructor_id = 0;
-
-    let payload = CreatePlayerPayload {
-        instructor_id: admin_instructor_id,
-        email: "newplayer_admin@test.com".to_string(),
-        display_name: "Admin Created Player".to_string(),
-        display_avatar: None,
-        game_id: None,
-        group_id: None,
-        language: None,
+async fn test_create_player_hourly_limit() {
+    let config = AppConfig {
+        player_creation_hourly_limit: 2,
+        ..AppConfig::default()
     };
-
-    let response = server.post("/teacher/create_player").json(&payload).await;
-    assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<i64> = response.json();
-    assert!(body.data.is_some());
-}
-
-#[tokio::test]
-async fn test_create_player_success_with_game_and_group() {
-    let (server, pool) = setup_test_environment().await;
-    let instructor_id = 22001;
-    let course_id = create_test_course(&pool, "Course CreateP GG").await;
-    let game_id = create_test_game(&pool, course_id, "CreateP Game GG", 1).await;
-    let group_id = 90;
-    create_test_instructor(&pool, instructor_id, "createpgg@test.com", "CreatePGG Inst").await;
-    create_test_group_with_id(&pool, group_id, "CreateP Group GG").await;
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let instructor_id = 22020;
+    let course_id = create_test_course(&pool, "Course CreateP Limit").await;
+    let game_id = create_test_game(&pool, course_id, "CreateP Game Limit", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "createplim@test.com",
+        "CreatePLim Inst",
+    )
+    .await;
     create_test_game_ownership(&pool, instructor_id, game_id, true).await;
-    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
 
-    let payload = CreatePlayerPayload {
+    let payload_for = |instructor_id: i64, n: usize| CreatePlayerPayload {
         instructor_id,
-        email: "newplayer_gg@test.com".to_string(),
-        display_name: "GG Created Player".to_string(),
+        email: format!("newplayer_lim{}@test.com", n),
+        display_name: format!("Limited Player {}", n),
         display_avatar: None,
         game_id: Some(game_id),
-        group_id: Some(group_id),
-        language: Some("fr".to_string()),
+        group_id: None,
+        language: None,
     };
 
-    let response = server.post("/teacher/create_player").json(&payload).await;
+    for n in 0..2 {
+        let response = server
+            .post("/teacher/create_player")
+            .json(&payload_for(instructor_id, n))
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+    }
+    let response = server
+        .post("/teacher/create_player")
+        .json(&payload_for(instructor_id, 2))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.text().contains("limit of 2 per hour"));
+
+    // the admin is exempt
+    let response = server
+        .post("/teacher/create_player")
+        .json(&payload_for(0, 3))
+        .await;
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<i64> = response.json();
-    assert!(body.data.is_some());
-    let _new_player_id = body.data.unwrap();
 }
 
 #[tokio::test]
@@ -2372,6 +5525,46 @@ async fn test_create_player_email_conflict() {
     assert_eq!(response.status_code(), StatusCode::CONFLICT);
 }
 
+#[tokio::test]
+async fn test_create_player_normalizes_email() {
+    let (server, _pool) = setup_test_environment().await;
+    let payload = CreatePlayerPayload {
+        instructor_id: 0,
+        email: "  Mixed.Case@Test.com ".to_string(),
+        display_name: "Mixed Case".to_string(),
+        display_avatar: None,
+        game_id: None,
+        group_id: None,
+        language: None,
+    };
+    let response = server.post("/teacher/create_player").json(&payload).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<i64> = response.json();
+    let player_id = body.data.unwrap();
+
+    for lookup in [
+        "mixed.case@test.com",
+        "MIXED.CASE@TEST.COM",
+        " Mixed.Case@test.com",
+    ] {
+        let response = server
+            .get("/teacher/translate_email_to_player_id")
+            .add_query_param("email", lookup)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK, "{}", lookup);
+        let body: ApiResponse<i64> = response.json();
+        assert_eq!(body.data.unwrap(), player_id);
+    }
+
+    let duplicate = CreatePlayerPayload {
+        email: "MIXED.case@test.COM".to_string(),
+        display_name: "Case Variant".to_string(),
+        ..payload
+    };
+    let response = server.post("/teacher/create_player").json(&duplicate).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
 // disable_player
 #[tokio::test]
 async fn test_disable_player_success_admin() {
@@ -2480,6 +5673,191 @@ async fn test_delete_player_not_found() {
     assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn test_delete_player_token_mismatch_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24002;
+    let player_id = 24103;
+    create_test_instructor(&pool, 0, "deletep_admin@test.com", "DeleteP Admin").await;
+    create_test_instructor(&pool, instructor_id, "deletept@test.com", "DeletePT Inst").await;
+    create_test_player(&pool, player_id, "deletep_t@test.com", "Delete Me T").await;
+
+    let payload = DeletePlayerPayload {
+        instructor_id: 0,
+        player_id,
+    };
+    let response = server
+        .post("/teacher/delete_player")
+        .add_header(TEST_EMAIL_HEADER, "deletept@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/teacher/delete_player")
+        .add_header(TEST_EMAIL_HEADER, "deletep_admin@test.com")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+}
+
+// soft_delete_player / restore_player
+#[tokio::test]
+async fn test_soft_delete_player_hides_from_listings() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24005;
+    let player_id = 24111;
+    let other_player_id = 24112;
+    let group_id = 101;
+    let course_id = create_test_course(&pool, "Course SoftDel").await;
+    let game_id = create_test_game(&pool, course_id, "SoftDel Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "SoftDel Mod").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "SoftDel Ex").await;
+    let failed_ex_id = create_test_exercise(&pool, module_id, 2, "SoftDel Failed Ex").await;
+    create_test_instructor(&pool, instructor_id, "softdel@test.com", "SoftDel Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_group_with_id(&pool, group_id, "SoftDel Group").await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+    for (id, email) in [
+        (player_id, "softdel_p1@test.com"),
+        (other_player_id, "softdel_p2@test.com"),
+    ] {
+        create_test_player(&pool, id, email, email).await;
+        create_test_player_registration(&pool, id, game_id).await;
+        add_player_to_group(&pool, id, group_id).await;
+        create_test_submission(&pool, id, game_id, failed_ex_id, false, 0.1).await;
+    }
+    create_test_submission(&pool, player_id, game_id, ex_id, true, 1.0).await;
+
+    let payload = SoftDeletePlayerPayload {
+        instructor_id: 0,
+        player_id,
+    };
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/teacher/list_students")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    let students = response.json::<ApiResponse<Vec<i64>>>().data.unwrap();
+    assert_eq!(students, vec![other_player_id]);
+
+    let response = server
+        .get("/teacher/get_group_members")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("group_id", group_id)
+        .await;
+    let members = response
+        .json::<ApiResponse<Vec<GroupMemberResponse>>>()
+        .data
+        .unwrap();
+    let member_ids: Vec<i64> = members.iter().map(|member| member.player_id).collect();
+    assert_eq!(member_ids, vec![other_player_id]);
+
+    let response = server
+        .get("/teacher/get_struggling_students")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("min_failed_attempts", 1)
+        .await;
+    let struggling = response
+        .json::<ApiResponse<Vec<StrugglingStudent>>>()
+        .data
+        .unwrap();
+    let struggling_ids: Vec<i64> = struggling.iter().map(|s| s.player_id).collect();
+    assert_eq!(struggling_ids, vec![other_player_id]);
+
+    let response = server
+        .get("/teacher/translate_email_to_player_id")
+        .add_query_param("email", "softdel_p1@test.com")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+
+    let conn = pool.get().await.unwrap();
+    let (registration_left, submissions) = conn
+        .interact(move |conn| {
+            let left_at = schema::player_registrations::table
+                .filter(schema::player_registrations::player_id.eq(player_id))
+                .select(schema::player_registrations::left_at)
+                .first::<Option<DateTime<Utc>>>(conn)?;
+            let submissions = schema::submissions::table
+                .filter(schema::submissions::player_id.eq(player_id))
+                .count()
+                .get_result::<i64>(conn)?;
+            Ok::<_, diesel::result::Error>((left_at, submissions))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(registration_left.is_some());
+    assert_eq!(submissions, 2);
+
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_restore_player_lists_player_again() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24006;
+    let player_id = 24113;
+    create_test_instructor(&pool, instructor_id, "restorep@test.com", "Restore Inst").await;
+    create_test_player(&pool, player_id, "restore_p@test.com", "Restore Me").await;
+
+    let restore = RestorePlayerPayload {
+        instructor_id: 0,
+        player_id,
+    };
+    let response = server.post("/teacher/restore_player").json(&restore).await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+
+    let payload = SoftDeletePlayerPayload {
+        instructor_id,
+        player_id,
+    };
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let payload = SoftDeletePlayerPayload {
+        instructor_id: 0,
+        player_id,
+    };
+    server
+        .post("/teacher/soft_delete_player")
+        .json(&payload)
+        .await
+        .assert_status_ok();
+    let response = server.post("/teacher/restore_player").json(&restore).await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let response = server
+        .get("/teacher/translate_email_to_player_id")
+        .add_query_param("email", "restore_p@test.com")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(response.json::<ApiResponse<i64>>().data, Some(player_id));
+
+    let response = server
+        .post("/teacher/restore_player")
+        .json(&RestorePlayerPayload {
+            instructor_id: 0,
+            player_id: 999_999,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // generate_invite_link
 #[tokio::test]
 async fn test_generate_invite_link_success_admin_no_context() {
@@ -2500,7 +5878,38 @@ async fn test_generate_invite_link_success_admin_no_context() {
         .await;
     assert_eq!(response.status_code(), StatusCode::OK);
     let body: ApiResponse<InviteLinkResponse> = response.json();
-    assert!(body.data.is_some());
+    assert!(body.data.unwrap().invite_url.is_none());
+}
+
+#[tokio::test]
+async fn test_generate_invite_link_composes_url_from_public_base() {
+    let config = AppConfig {
+        public_base_url: Some("https://play.example.org/fgpe/".parse().unwrap()),
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+
+    let response = server
+        .post("/teacher/generate_invite_link")
+        .json(&GenerateInviteLinkPayload {
+            instructor_id: 0,
+            game_id: None,
+            group_id: None,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let invite = response
+        .json::<ApiResponse<InviteLinkResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        invite.invite_url,
+        Some(format!(
+            "https://play.example.org/fgpe/join?invite={}",
+            invite.invite_uuid
+        ))
+    );
 }
 
 #[tokio::test]
@@ -2548,12 +5957,22 @@ async fn test_generate_invite_link_forbidden_no_group_permission() {
         .json(&payload)
         .await;
 
-    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
     let body: ApiResponse<Value> = response.json();
     assert!(
         body.status_message
             .contains("lacks permission for the specified group")
     );
+
+    let response = server
+        .post("/teacher/generate_invite_link")
+        .json(&GenerateInviteLinkPayload {
+            instructor_id,
+            game_id: None,
+            group_id: None,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
 }
 
 #[tokio::test]
@@ -2581,6 +6000,130 @@ async fn test_generate_invite_link_game_not_found() {
     );
 }
 
+// list_invites
+#[tokio::test]
+async fn test_list_invites_created_at_populated() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 25501;
+    let other_instructor_id = 25502;
+    let group_id = create_test_group_with_id(&pool, 255, "List Invites Group").await;
+    create_test_instructor(&pool, instructor_id, "listinv@test.com", "ListInv Inst").await;
+    create_test_instructor(
+        &pool,
+        other_instructor_id,
+        "listinvo@test.com",
+        "ListInvO Inst",
+    )
+    .await;
+    create_test_group_ownership(&pool, instructor_id, group_id, true).await;
+    let first_uuid = create_test_invite(&pool, instructor_id, None, Some(group_id)).await;
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let second_uuid = create_test_invite(&pool, instructor_id, None, Some(group_id)).await;
+    create_test_invite(&pool, other_instructor_id, None, None).await;
+
+    let response = server
+        .get("/teacher/list_invites")
+        .add_query_param("instructor_id", instructor_id)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let invites = response
+        .json::<ApiResponse<Vec<InviteSummary>>>()
+        .data
+        .unwrap();
+    assert_eq!(invites.len(), 2);
+    assert_eq!(invites[0].uuid, second_uuid);
+    assert_eq!(invites[1].uuid, first_uuid);
+    assert_eq!(invites[0].group_id, Some(group_id));
+    assert!(invites[0].created_at > invites[1].created_at);
+    assert!(invites[0].created_at <= Utc::now() + Duration::seconds(5));
+}
+
+#[tokio::test]
+async fn test_list_invites_instructor_not_found() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server
+        .get("/teacher/list_invites")
+        .add_query_param("instructor_id", 99255)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// get_invite_details
+#[tokio::test]
+async fn test_get_invite_details_game_and_group() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 25503;
+    let course_id = create_test_course(&pool, "Course Invite Details").await;
+    let game_id = create_test_game(&pool, course_id, "Invite Details Game", 1).await;
+    let group_id = create_test_group_with_id(&pool, 256, "Invite Details Group").await;
+    create_test_instructor(&pool, instructor_id, "invdet@test.com", "InvDet Inst").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, Some(game_id), Some(group_id)).await;
+
+    let response = server
+        .get("/teacher/get_invite_details")
+        .add_query_param("uuid", invite_uuid)
+        .await;
+
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let details = response
+        .json::<ApiResponse<InviteDetailsResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(details.uuid, invite_uuid);
+    assert_eq!(details.game_id, Some(game_id));
+    assert_eq!(details.game_title.as_deref(), Some("Invite Details Game"));
+    assert_eq!(details.group_id, Some(group_id));
+    assert_eq!(details.group_name.as_deref(), Some("Invite Details Group"));
+
+    // reading the details does not register anyone
+    let conn = pool.get().await.unwrap();
+    let registrations: i64 = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .count()
+                .get_result(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(registrations, 0);
+}
+
+#[tokio::test]
+async fn test_get_invite_details_deleted_target_and_unknown_uuid() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 25504;
+    let group_id = create_test_group_with_id(&pool, 257, "Invite Details Gone").await;
+    create_test_instructor(&pool, instructor_id, "invdetg@test.com", "InvDetG Inst").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, None, Some(group_id)).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| diesel::delete(schema::groups::table.find(group_id)).execute(conn))
+        .await
+        .unwrap()
+        .unwrap();
+
+    let response = server
+        .get("/teacher/get_invite_details")
+        .add_query_param("uuid", invite_uuid)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let details = response
+        .json::<ApiResponse<InviteDetailsResponse>>()
+        .data
+        .unwrap();
+    assert_eq!(details.group_id, None);
+    assert_eq!(details.group_name, None);
+
+    let response = server
+        .get("/teacher/get_invite_details")
+        .add_query_param("uuid", Uuid::new_v4())
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
 // process_invite_link
 #[tokio::test]
 async fn test_process_invite_link_success_add_to_game_group() {
@@ -2598,6 +6141,8 @@ async fn test_process_invite_link_success_add_to_game_group() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2605,8 +6150,56 @@ async fn test_process_invite_link_success_add_to_game_group() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(body.data.unwrap_or(false));
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: true,
+            added_to_group: true,
+            already_in_game: false,
+            already_in_group: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_process_invite_link_unsupported_default_language_falls_back() {
+    let config = AppConfig {
+        default_languages: vec!["de".to_string()],
+        ..AppConfig::default()
+    };
+    let (server, pool) = setup_test_environment_with_config(config).await;
+    let instructor_id = 26010;
+    let player_id = 26110;
+    let course_id = create_test_course(&pool, "Course Process Lang").await;
+    set_course_languages(&pool, course_id, "es,en").await;
+    let game_id = create_test_game(&pool, course_id, "Process Game Lang", 1).await;
+    create_test_instructor(
+        &pool,
+        instructor_id,
+        "processlang@test.com",
+        "ProcessL Inst",
+    )
+    .await;
+    create_test_player(&pool, player_id, "processlang_p@test.com", "ProcessL P").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, Some(game_id), None).await;
+
+    let payload = ProcessInviteLinkPayload {
+        player_id,
+        uuid: invite_uuid,
+        email: None,
+        display_name: None,
+    };
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert_eq!(registration_language(&pool, player_id, game_id).await, "es");
 }
 
 #[tokio::test]
@@ -2627,6 +6220,8 @@ async fn test_process_invite_link_success_already_member() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2634,8 +6229,20 @@ async fn test_process_invite_link_success_already_member() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(body.data.unwrap_or(false));
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: false,
+            added_to_group: false,
+            already_in_game: true,
+            already_in_group: true,
+        }
+    );
 }
 
 #[tokio::test]
@@ -2648,6 +6255,8 @@ async fn test_process_invite_link_invite_not_found() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: non_existent_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2671,8 +6280,41 @@ async fn test_process_invite_link_player_not_found() {
     let invite_uuid = create_test_invite(&pool, instructor_id, None, None).await;
 
     let payload = ProcessInviteLinkPayload {
-        player_id: non_existent_player_id,
+        player_id: non_existent_player_id,
+        uuid: invite_uuid,
+        email: None,
+        display_name: None,
+    };
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_process_invite_link_soft_deleted_player_not_found() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 26004;
+    let player_id = 26104;
+    create_test_instructor(&pool, instructor_id, "processsd@test.com", "ProcessSD Inst").await;
+    create_test_player(&pool, player_id, "processsd_p@test.com", "ProcessSD Player").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, None, None).await;
+
+    let response = server
+        .post("/teacher/soft_delete_player")
+        .json(&SoftDeletePlayerPayload {
+            instructor_id: 0,
+            player_id,
+        })
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+
+    let payload = ProcessInviteLinkPayload {
+        player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2708,6 +6350,8 @@ async fn test_process_invite_link_partial_add_to_group() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2715,10 +6359,19 @@ async fn test_process_invite_link_partial_add_to_group() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(
-        body.data.unwrap_or(false),
-        "API response data should be true"
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: false,
+            added_to_group: true,
+            already_in_game: true,
+            already_in_group: false,
+        }
     );
 
     assert!(
@@ -2768,6 +6421,8 @@ async fn test_process_invite_link_partial_add_to_game() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2775,10 +6430,19 @@ async fn test_process_invite_link_partial_add_to_game() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(
-        body.data.unwrap_or(false),
-        "API response data should be true"
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: true,
+            added_to_group: false,
+            already_in_game: false,
+            already_in_group: true,
+        }
     );
 
     assert!(
@@ -2826,6 +6490,8 @@ async fn test_process_invite_link_success_game_only() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2833,10 +6499,19 @@ async fn test_process_invite_link_success_game_only() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(
-        body.data.unwrap_or(false),
-        "API response data should be true"
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: true,
+            added_to_group: false,
+            already_in_game: false,
+            already_in_group: false,
+        }
     );
 
     assert!(
@@ -2880,6 +6555,8 @@ async fn test_process_invite_link_success_group_only() {
     let payload = ProcessInviteLinkPayload {
         player_id,
         uuid: invite_uuid,
+        email: None,
+        display_name: None,
     };
     let response = server
         .post("/teacher/process_invite_link")
@@ -2887,10 +6564,19 @@ async fn test_process_invite_link_success_group_only() {
         .await;
 
     assert_eq!(response.status_code(), StatusCode::OK);
-    let body: ApiResponse<bool> = response.json();
-    assert!(
-        body.data.unwrap_or(false),
-        "API response data should be true"
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+    assert_eq!(
+        result,
+        ProcessInviteResult {
+            player_id,
+            joined_game: false,
+            added_to_group: true,
+            already_in_game: false,
+            already_in_group: false,
+        }
     );
 
     assert!(
@@ -2908,3 +6594,764 @@ async fn test_process_invite_link_success_group_only() {
         "Player should still be in 0 games"
     );
 }
+
+// list_disabled_players
+#[tokio::test]
+async fn test_list_disabled_players_admin_paginated() {
+    let (server, pool) = setup_test_environment().await;
+    create_test_player(&pool, 24301, "dis1@test.com", "Disabled One").await;
+    create_test_player(&pool, 24302, "active@test.com", "Still Active").await;
+    create_test_player(&pool, 24303, "dis2@test.com", "Disabled Two").await;
+    update_player_status(&pool, 24301, true).await;
+    update_player_status(&pool, 24303, true).await;
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", 0)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<DisabledPlayerResponse>> = response.json();
+    let players = body.data.unwrap();
+    let ids: Vec<i64> = players.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![24301, 24303]);
+    assert_eq!(players[0].email, "dis1@test.com");
+    assert_eq!(players[1].display_name, "Disabled Two");
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("offset", 1)
+        .add_query_param("limit", 1)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<DisabledPlayerResponse>> = response.json();
+    let ids: Vec<i64> = body.data.unwrap().iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![24303]);
+}
+
+#[tokio::test]
+async fn test_list_disabled_players_forbidden_non_admin() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24011;
+    create_test_instructor(&pool, instructor_id, "listdis@test.com", "ListDis Inst").await;
+
+    let response = server
+        .get("/teacher/list_disabled_players")
+        .add_query_param("instructor_id", instructor_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_all_player_submissions
+#[tokio::test]
+async fn test_get_all_player_submissions_across_games() {
+    let (server, pool) = setup_test_environment().await;
+    create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+    create_test_instructor(&pool, 24061, "allsubs@test.com", "AllSubs Inst").await;
+    let course_id = create_test_course(&pool, "All Subs Course").await;
+    let game_a = create_test_game(&pool, course_id, "All Subs Game A", 1).await;
+    let game_b = create_test_game(&pool, course_id, "All Subs Game B", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "All Subs Mod").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "All Subs Ex").await;
+    let player_id = 24341;
+    create_test_player(&pool, player_id, "allsubs_a@test.com", "All Subs A").await;
+    create_test_player(&pool, 24342, "allsubs_b@test.com", "All Subs B").await;
+    create_test_player_registration(&pool, player_id, game_a).await;
+    create_test_player_registration(&pool, player_id, game_b).await;
+    let sub_a = create_test_submission(&pool, player_id, game_a, ex_id, true, 1.0).await;
+    let sub_b = create_test_submission(&pool, player_id, game_b, ex_id, false, 0.4).await;
+    create_test_submission(&pool, 24342, game_a, ex_id, true, 1.0).await;
+
+    let response = server
+        .get("/teacher/get_all_player_submissions")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<SubmissionDataResponse>> = response.json();
+    let submissions = body.data.unwrap();
+    let found: Vec<(i64, i64)> = submissions.iter().map(|s| (s.id, s.game_id)).collect();
+    assert_eq!(found, vec![(sub_a, game_a), (sub_b, game_b)]);
+    assert!(submissions.iter().all(|s| s.player_id == player_id));
+
+    let response = server
+        .get("/teacher/get_all_player_submissions")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("player_id", player_id)
+        .add_query_param("offset", 1)
+        .add_query_param("limit", 1)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<SubmissionDataResponse>> = response.json();
+    let ids: Vec<i64> = body.data.unwrap().iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![sub_b]);
+
+    let response = server
+        .get("/teacher/get_all_player_submissions")
+        .add_query_param("instructor_id", 24061)
+        .add_query_param("player_id", player_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .get("/teacher/get_all_player_submissions")
+        .add_query_param("instructor_id", 0)
+        .add_query_param("player_id", 99_999)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::NOT_FOUND);
+}
+
+// check_integrity
+#[tokio::test]
+async fn test_check_integrity_reports_orphaned_rows() {
+    let (server, pool) = setup_test_environment().await;
+    create_test_instructor(&pool, 0, "admin@test.com", "Admin User").await;
+    create_test_instructor(&pool, 24062, "integrity@test.com", "Integrity Inst").await;
+    let course_id = create_test_course(&pool, "Integrity Course").await;
+    let game_id = create_test_game(&pool, course_id, "Integrity Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Integrity Mod").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Integrity Ex").await;
+    let player_id = 24343;
+    create_test_player(&pool, player_id, "integrity_p@test.com", "Integrity P").await;
+    create_test_player_registration(&pool, player_id, game_id).await;
+    create_test_submission(&pool, player_id, game_id, ex_id, true, 1.0).await;
+
+    let check = || async {
+        let response = server
+            .get("/teacher/check_integrity")
+            .add_query_param("instructor_id", 0)
+            .await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let body: ApiResponse<IntegrityReportResponse> = response.json();
+        body.data.unwrap()
+    };
+
+    let report = check().await;
+    assert_eq!(report.orphaned_submissions, 0);
+    assert_eq!(report.orphaned_unlocks, 0);
+
+    // Foreign keys forbid orphans, so the triggers enforcing them are skipped for the seed.
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        conn.batch_execute(&format!(
+            "BEGIN;
+             SET LOCAL session_replication_role = replica;
+             INSERT INTO submissions (exercise_id, game_id, player_id, client, submitted_code,
+                 metrics, result, result_description, feedback, earned_rewards)
+             VALUES (999999, {game_id}, {player_id}, 'test', '', '{{}}', 0, '{{}}', '', '[]');
+             INSERT INTO player_unlocks (player_id, exercise_id) VALUES ({player_id}, 999999);
+             COMMIT;"
+        ))
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let report = check().await;
+    assert_eq!(report.orphaned_submissions, 1);
+    assert_eq!(report.orphaned_unlocks, 1);
+    assert_eq!(report.orphaned_registrations, 0);
+    assert_eq!(report.orphaned_memberships, 0);
+    assert_eq!(report.orphaned_rewards, 0);
+
+    let response = server
+        .get("/teacher/check_integrity")
+        .add_query_param("instructor_id", 24062)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_recent_submissions
+#[tokio::test]
+async fn test_get_recent_submissions_since() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24051;
+    let course_id = create_test_course(&pool, "Recent Subs Course").await;
+    let game_id = create_test_game(&pool, course_id, "Recent Subs Game", 1).await;
+    let other_game_id = create_test_game(&pool, course_id, "Recent Subs Other", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Recent Subs Mod").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Recent Subs Ex").await;
+    create_test_instructor(&pool, instructor_id, "recentsubs@test.com", "Recent Inst").await;
+    create_test_instructor(&pool, 24052, "recentsubs_x@test.com", "Recent Other").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, 24331, "recentsubs_a@test.com", "Recent A").await;
+    create_test_player(&pool, 24332, "recentsubs_b@test.com", "Recent B").await;
+    let old = create_test_submission(&pool, 24331, game_id, ex_id, false, 0.2).await;
+    let newer = create_test_submission(&pool, 24332, game_id, ex_id, true, 1.0).await;
+    let newest = create_test_submission(&pool, 24331, game_id, ex_id, true, 0.9).await;
+    let other_game = create_test_submission(&pool, 24331, other_game_id, ex_id, true, 1.0).await;
+
+    let base = Utc::now() - Duration::days(1);
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (sub_id, minutes) in [(old, 0), (newest, 20), (newer, 10), (other_game, 15)] {
+            diesel::update(schema::submissions::table.find(sub_id))
+                .set(schema::submissions::entered_at.eq(base + Duration::minutes(minutes)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let recent = |since: Option<DateTime<Utc>>| {
+        let server = &server;
+        async move {
+            let mut request = server
+                .get("/teacher/get_recent_submissions")
+                .add_query_param("instructor_id", instructor_id)
+                .add_query_param("game_id", game_id);
+            if let Some(since) = since {
+                request = request.add_query_param("since", since.to_rfc3339());
+            }
+            let response = request.await;
+            assert_eq!(response.status_code(), StatusCode::OK);
+            response
+                .json::<ApiResponse<Vec<RecentSubmission>>>()
+                .data
+                .unwrap()
+                .into_iter()
+                .map(|s| (s.id, s.player_id, s.first_solution))
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(
+        recent(None).await,
+        vec![
+            (old, 24331, false),
+            (newer, 24332, true),
+            (newest, 24331, true)
+        ]
+    );
+    assert_eq!(
+        recent(Some(base)).await,
+        vec![(newer, 24332, true), (newest, 24331, true)]
+    );
+    assert_eq!(recent(Some(base + Duration::minutes(20))).await, vec![]);
+
+    let response = server
+        .get("/teacher/get_recent_submissions")
+        .add_query_param("instructor_id", 24052)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// list_exercises_by_tag
+#[tokio::test]
+async fn test_list_exercises_by_tag_teacher() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24021;
+    let other_instructor_id = 24022;
+    create_test_instructor(&pool, instructor_id, "tags_t@test.com", "Tags T").await;
+    create_test_instructor(&pool, other_instructor_id, "tags_o@test.com", "Tags O").await;
+    let course_id = create_test_course(&pool, "Teacher Tag Course").await;
+    let game_id = create_test_game(&pool, course_id, "Teacher Tag Game", 2).await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Teacher Tag Module").await;
+    let ex1 = create_test_exercise(&pool, module_id, 1, "Tagged 1").await;
+    let ex2 = create_test_exercise(&pool, module_id, 2, "Tagged 2").await;
+    create_test_exercise_tag(&pool, ex2, "loops").await;
+    create_test_exercise_tag(&pool, ex1, "loops").await;
+
+    let response = server
+        .get("/teacher/list_exercises_by_tag")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("tag", "loops")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<Vec<i64>> = response.json();
+    assert_eq!(body.data.unwrap(), vec![ex1, ex2]);
+
+    let response = server
+        .get("/teacher/list_exercises_by_tag")
+        .add_query_param("instructor_id", other_instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("tag", "loops")
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// get_game_timeline
+#[tokio::test]
+async fn test_get_game_timeline_interleaves_players_chronologically() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24031;
+    let course_id = create_test_course(&pool, "Timeline Course").await;
+    let game_id = create_test_game(&pool, course_id, "Timeline Game", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Timeline Mod").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Timeline Ex").await;
+    create_test_instructor(&pool, instructor_id, "timeline@test.com", "Timeline Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, 24311, "timeline_a@test.com", "Timeline A").await;
+    create_test_player(&pool, 24312, "timeline_b@test.com", "Timeline B").await;
+    let reg_a = create_test_player_registration(&pool, 24311, game_id).await;
+    let reg_b = create_test_player_registration(&pool, 24312, game_id).await;
+    let sub_a1 = create_test_submission(&pool, 24311, game_id, ex_id, false, 0.2).await;
+    let sub_b1 = create_test_submission(&pool, 24312, game_id, ex_id, true, 1.0).await;
+    let sub_a2 = create_test_submission(&pool, 24311, game_id, ex_id, true, 0.9).await;
+
+    let base = Utc::now() - Duration::days(1);
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        for (reg_id, minutes) in [(reg_a, 0), (reg_b, 1)] {
+            diesel::update(schema::player_registrations::table.find(reg_id))
+                .set(schema::player_registrations::joined_at.eq(base + Duration::minutes(minutes)))
+                .execute(conn)?;
+        }
+        diesel::update(schema::player_registrations::table.find(reg_b))
+            .set(schema::player_registrations::left_at.eq(base + Duration::minutes(5)))
+            .execute(conn)?;
+        for (sub_id, minutes) in [(sub_a1, 2), (sub_b1, 3), (sub_a2, 4)] {
+            diesel::update(schema::submissions::table.find(sub_id))
+                .set(schema::submissions::entered_at.eq(base + Duration::minutes(minutes)))
+                .execute(conn)?;
+        }
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let response = server
+        .get("/teacher/get_game_timeline")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("limit", 4)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<GameTimelineResponse> = response.json();
+    let page = body.data.unwrap();
+    let summary: Vec<(TimelineEventType, i64)> = page
+        .events
+        .iter()
+        .map(|e| (e.event_type, e.player_id))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (TimelineEventType::Join, 24311),
+            (TimelineEventType::Join, 24312),
+            (TimelineEventType::Submission, 24311),
+            (TimelineEventType::Submission, 24312),
+        ]
+    );
+    assert_eq!(page.events[2].payload["submission_id"], json!(sub_a1));
+    let cursor = page.next_cursor.expect("full page should carry a cursor");
+
+    let response = server
+        .get("/teacher/get_game_timeline")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .add_query_param("limit", 4)
+        .add_query_param("after", cursor.occurred_at.to_rfc3339())
+        .add_query_param("after_type", "submission")
+        .add_query_param("after_id", cursor.id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<GameTimelineResponse> = response.json();
+    let page = body.data.unwrap();
+    let summary: Vec<(TimelineEventType, i64)> = page
+        .events
+        .iter()
+        .map(|e| (e.event_type, e.player_id))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (TimelineEventType::Submission, 24311),
+            (TimelineEventType::Leave, 24312),
+        ]
+    );
+    assert!(page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_get_game_timeline_pages_through_events_sharing_a_timestamp() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24033;
+    let course_id = create_test_course(&pool, "Timeline Course T").await;
+    let game_id = create_test_game(&pool, course_id, "Timeline Game T", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Timeline Mod T").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Timeline Ex T").await;
+    create_test_instructor(&pool, instructor_id, "timeline_t@test.com", "Timeline T").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, 24331, "timeline_ta@test.com", "Timeline TA").await;
+    create_test_player(&pool, 24332, "timeline_tb@test.com", "Timeline TB").await;
+    let reg_a = create_test_player_registration(&pool, 24331, game_id).await;
+    let reg_b = create_test_player_registration(&pool, 24332, game_id).await;
+    let mut sub_ids = Vec::new();
+    for _ in 0..5 {
+        sub_ids.push(create_test_submission(&pool, 24331, game_id, ex_id, false, 10.0).await);
+    }
+
+    let instant = Utc::now() - Duration::days(1);
+    let conn = pool.get().await.unwrap();
+    let registration_ids = [reg_a, reg_b];
+    let submission_ids = sub_ids.clone();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_registrations::table)
+            .filter(schema::player_registrations::id.eq_any(registration_ids))
+            .set(schema::player_registrations::joined_at.eq(instant))
+            .execute(conn)?;
+        diesel::update(schema::submissions::table)
+            .filter(schema::submissions::id.eq_any(submission_ids))
+            .set(schema::submissions::entered_at.eq(instant))
+            .execute(conn)?;
+        Ok::<_, diesel::result::Error>(())
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    let mut seen: Vec<(TimelineEventType, i64)> = Vec::new();
+    let mut cursor = None;
+    for _ in 0..5 {
+        let mut request = server
+            .get("/teacher/get_game_timeline")
+            .add_query_param("instructor_id", instructor_id)
+            .add_query_param("game_id", game_id)
+            .add_query_param("limit", 3);
+        if let Some(TimelineCursor {
+            occurred_at,
+            event_type,
+            id,
+        }) = cursor
+        {
+            let event_type = match event_type {
+                TimelineEventType::Submission => "submission",
+                TimelineEventType::Join => "join",
+                TimelineEventType::Leave => "leave",
+            };
+            request = request
+                .add_query_param("after", occurred_at.to_rfc3339())
+                .add_query_param("after_type", event_type)
+                .add_query_param("after_id", id);
+        }
+        let response = request.await;
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let body: ApiResponse<GameTimelineResponse> = response.json();
+        let page = body.data.unwrap();
+        seen.extend(page.events.iter().map(|e| {
+            let id = match e.event_type {
+                TimelineEventType::Submission => &e.payload["submission_id"],
+                _ => &e.payload["registration_id"],
+            };
+            (e.event_type, id.as_i64().unwrap())
+        }));
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    let mut expected: Vec<(TimelineEventType, i64)> = sub_ids
+        .iter()
+        .map(|id| (TimelineEventType::Submission, *id))
+        .collect();
+    expected.push((TimelineEventType::Join, reg_a));
+    expected.push((TimelineEventType::Join, reg_b));
+    assert_eq!(seen, expected);
+    assert!(cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_get_game_timeline_forbidden() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24032;
+    let course_id = create_test_course(&pool, "Timeline Course F").await;
+    let game_id = create_test_game(&pool, course_id, "Timeline Game F", 1).await;
+    create_test_instructor(&pool, instructor_id, "timeline_f@test.com", "Timeline F").await;
+
+    let response = server
+        .get("/teacher/get_game_timeline")
+        .add_query_param("instructor_id", instructor_id)
+        .add_query_param("game_id", game_id)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+}
+
+// import_submissions
+#[tokio::test]
+async fn test_import_submissions_sets_first_solution_on_earliest_pass() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 24041;
+    let (player_a, player_b) = (24411, 24412);
+    let course_id = create_test_course(&pool, "Import Course").await;
+    let game_id = create_test_game(&pool, course_id, "Import Game", 2).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Import Mod").await;
+    let ex1 = create_test_exercise(&pool, module_id, 1, "Import Ex 1").await;
+    let ex2 = create_test_exercise(&pool, module_id, 2, "Import Ex 2").await;
+    create_test_instructor(&pool, instructor_id, "import@test.com", "Import Inst").await;
+    create_test_game_ownership(&pool, instructor_id, game_id, true).await;
+    create_test_player(&pool, player_a, "import_a@test.com", "Import A").await;
+    create_test_player(&pool, player_b, "import_b@test.com", "Import B").await;
+    create_test_player_registration(&pool, player_a, game_id).await;
+    create_test_player_registration(&pool, player_b, game_id).await;
+    // Solved live just now; the imported, earlier pass should take over the flag.
+    let live_b = create_test_submission(&pool, player_b, game_id, ex1, true, 1.0).await;
+
+    let base =
+        chrono::DateTime::from_timestamp((Utc::now() - Duration::days(2)).timestamp(), 0).unwrap();
+    let record = |player_id: i64, exercise_id: i64, result: i32, minutes: i64| {
+        json!({
+            "player_id": player_id,
+            "exercise_id": exercise_id,
+            "result": result,
+            "entered_at": base + Duration::minutes(minutes),
+        })
+    };
+    let payload = json!({
+        "instructor_id": instructor_id,
+        "game_id": game_id,
+        "submissions": [
+            record(player_a, ex1, 80, 3),
+            record(player_a, ex1, 20, 1),
+            record(player_a, ex1, 60, 2),
+            record(player_a, ex2, 10, 1),
+            record(player_b, ex1, 100, 1),
+        ],
+    });
+    let response = server
+        .post("/teacher/import_submissions")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let body: ApiResponse<ImportSubmissionsResponse> = response.json();
+    let counts = body.data.unwrap();
+    assert_eq!(counts.imported, 5);
+    assert_eq!(counts.first_solutions, 2);
+
+    let conn = pool.get().await.unwrap();
+    let rows = conn
+        .interact(move |conn| {
+            schema::submissions::table
+                .filter(schema::submissions::game_id.eq(game_id))
+                .filter(schema::submissions::first_solution.eq(true))
+                .select((
+                    schema::submissions::id,
+                    schema::submissions::player_id,
+                    schema::submissions::exercise_id,
+                    schema::submissions::entered_at,
+                ))
+                .order_by(schema::submissions::player_id)
+                .load::<(i64, i64, i64, chrono::DateTime<Utc>)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        (rows[0].1, rows[0].2, rows[0].3),
+        (player_a, ex1, base + Duration::minutes(2))
+    );
+    assert_eq!(
+        (rows[1].1, rows[1].2, rows[1].3),
+        (player_b, ex1, base + Duration::minutes(1))
+    );
+    assert_ne!(rows[1].0, live_b);
+
+    let progress = conn
+        .interact(move |conn| {
+            schema::player_registrations::table
+                .filter(schema::player_registrations::game_id.eq(game_id))
+                .select((
+                    schema::player_registrations::player_id,
+                    schema::player_registrations::progress,
+                ))
+                .order_by(schema::player_registrations::player_id)
+                .load::<(i64, i32)>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(progress, vec![(player_a, 1), (player_b, 1)]);
+}
+
+#[tokio::test]
+async fn test_import_submissions_rejects_unregistered_player_and_non_owner() {
+    let (server, pool) = setup_test_environment().await;
+    let owner_id = 24042;
+    let other_id = 24043;
+    let course_id = create_test_course(&pool, "Import Course R").await;
+    let game_id = create_test_game(&pool, course_id, "Import Game R", 1).await;
+    let module_id = create_test_module(&pool, course_id, 1, "Import Mod R").await;
+    let ex_id = create_test_exercise(&pool, module_id, 1, "Import Ex R").await;
+    create_test_instructor(&pool, owner_id, "import_o@test.com", "Import O").await;
+    create_test_instructor(&pool, other_id, "import_x@test.com", "Import X").await;
+    create_test_game_ownership(&pool, owner_id, game_id, true).await;
+    create_test_game_ownership(&pool, other_id, game_id, false).await;
+    create_test_player(&pool, 24421, "import_r@test.com", "Import R").await;
+
+    let payload = |instructor_id: i64| {
+        json!({
+            "instructor_id": instructor_id,
+            "game_id": game_id,
+            "submissions": [{
+                "player_id": 24421,
+                "exercise_id": ex_id,
+                "result": 90,
+                "entered_at": Utc::now(),
+            }],
+        })
+    };
+
+    let response = server
+        .post("/teacher/import_submissions")
+        .json(&payload(other_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::FORBIDDEN);
+
+    let response = server
+        .post("/teacher/import_submissions")
+        .json(&payload(owner_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    // a player who left the game cannot receive imported submissions either
+    let registration_id = create_test_player_registration(&pool, 24421, game_id).await;
+    let conn = pool.get().await.unwrap();
+    conn.interact(move |conn| {
+        diesel::update(schema::player_registrations::table.find(registration_id))
+            .set(schema::player_registrations::left_at.eq(Some(Utc::now())))
+            .execute(conn)
+    })
+    .await
+    .unwrap()
+    .unwrap();
+    let response = server
+        .post("/teacher/import_submissions")
+        .json(&payload(owner_id))
+        .await;
+    assert_eq!(response.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_process_invite_link_provisions_new_player() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 26011;
+    let course_id = create_test_course(&pool, "Course Provision").await;
+    let game_id = create_test_game(&pool, course_id, "Provision Game", 1).await;
+    let group_id = 130;
+    create_test_instructor(&pool, instructor_id, "provision@test.com", "Provision Inst").await;
+    create_test_group_with_id(&pool, group_id, "Provision Group").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, Some(game_id), Some(group_id)).await;
+
+    let payload = ProcessInviteLinkPayload {
+        player_id: 99271,
+        uuid: invite_uuid,
+        email: Some("fresh.student@test.com".to_string()),
+        display_name: Some("Fresh Student".to_string()),
+    };
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    let result = response
+        .json::<ApiResponse<ProcessInviteResult>>()
+        .data
+        .unwrap();
+
+    let conn = pool.get().await.unwrap();
+    let (new_id, display_name) = conn
+        .interact(|conn| {
+            schema::players::table
+                .filter(schema::players::email.eq("fresh.student@test.com"))
+                .select((schema::players::id, schema::players::display_name))
+                .first::<(i64, String)>(conn)
+        })
+        .await
+        .unwrap()
+        .expect("invite should have provisioned the player");
+    assert_eq!(result.player_id, new_id);
+    assert_eq!(display_name, "Fresh Student");
+    assert!(check_player_in_game(&pool, new_id, game_id).await);
+    assert!(check_player_in_group(&pool, new_id, group_id).await);
+
+    // Provisioning again with the same email must not create a duplicate account.
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_process_invite_link_existing_player_ignores_email() {
+    let (server, pool) = setup_test_environment().await;
+    let instructor_id = 26012;
+    let player_id = 26111;
+    let course_id = create_test_course(&pool, "Course Existing").await;
+    let game_id = create_test_game(&pool, course_id, "Existing Game", 1).await;
+    create_test_instructor(&pool, instructor_id, "existing@test.com", "Existing Inst").await;
+    create_test_player(&pool, player_id, "existing_p@test.com", "Existing P").await;
+    let invite_uuid = create_test_invite(&pool, instructor_id, Some(game_id), None).await;
+
+    let payload = ProcessInviteLinkPayload {
+        player_id,
+        uuid: invite_uuid,
+        email: Some("unused@test.com".to_string()),
+        display_name: None,
+    };
+    let response = server
+        .post("/teacher/process_invite_link")
+        .json(&payload)
+        .await;
+    assert_eq!(response.status_code(), StatusCode::OK);
+    assert!(check_player_in_game(&pool, player_id, game_id).await);
+
+    let conn = pool.get().await.unwrap();
+    let unused_players = conn
+        .interact(|conn| {
+            schema::players::table
+                .filter(schema::players::email.eq("unused@test.com"))
+                .count()
+                .get_result::<i64>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unused_players, 0);
+}
+
+#[tokio::test]
+async fn test_wrong_method_returns_enveloped_405() {
+    let (server, _pool) = setup_test_environment().await;
+
+    let response = server.get("/teacher/create_game").await;
+
+    assert_eq!(response.status_code(), StatusCode::METHOD_NOT_ALLOWED);
+    let allow = response.header(axum::http::header::ALLOW);
+    assert!(allow.to_str().unwrap().contains("POST"));
+    let body: ApiResponse<()> = response.json();
+    assert_eq!(body.status_code, 405);
+    assert!(
+        body.status_message.contains("POST"),
+        "{}",
+        body.status_message
+    );
+    assert!(body.data.is_none());
+}
+
+#[tokio::test]
+async fn test_unknown_path_returns_enveloped_404() {
+    let (server, _pool) = setup_test_environment().await;
+
+    for path in ["/teacher/no_such_route", "/no_such_prefix"] {
+        let response = server.get(path).await;
+
+        assert_eq!(response.status_code(), StatusCode::NOT_FOUND, "{}", path);
+        let body: ApiResponse<()> = response.json();
+        assert_eq!(body.status_code, 404);
+        assert!(
+            body.status_message.contains(path),
+            "{}",
+            body.status_message
+        );
+        assert!(body.data.is_none());
+    }
+}